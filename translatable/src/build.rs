@@ -0,0 +1,393 @@
+//! Build-time translation generation module.
+//!
+//! This module contains the generation of a Rust file
+//! declaring the translations along typed accessors for
+//! them from a build script, so the translation files are
+//! parsed by the build script instead of by every macro
+//! invocation.
+
+use std::collections::BTreeMap;
+use std::fs::write;
+use std::io::Error as IoError;
+use std::path::Path;
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse_str;
+use thiserror::Error;
+use translatable_shared::data::config::{config_env_vars, load_config};
+use translatable_shared::data::translations::{
+    TranslationDataError,
+    embedded_translations,
+    load_translations,
+};
+use translatable_shared::misc::templating::FormatString;
+use translatable_shared::translations::node::TranslationObject;
+
+/// Translation generation error.
+///
+/// Represents errors that can occur while generating
+/// the translations file from a build script.
+#[derive(Error, Debug)]
+pub enum BuildError {
+    /// The configuration or the translations couldn't be loaded.
+    ///
+    /// **Parameters**
+    /// * `0` - The same error the macros would report.
+    #[error("{0:#}")]
+    Translations(#[from] TranslationDataError),
+
+    /// The generated file couldn't be written.
+    ///
+    /// **Parameters**
+    /// * `0` - The underlying I/O error.
+    #[error("Couldn't write the generated translations: {0:#}")]
+    Io(#[from] IoError),
+
+    /// Two paths result in the same accessor.
+    ///
+    /// **Parameters**
+    /// * `0` - The accessor name.
+    /// * `1` - The first path, displayed in `::` notation.
+    /// * `2` - The second path, displayed in `::` notation.
+    #[error("The paths '{1}' and '{2}' would both generate the accessor '{0}'")]
+    DuplicateAccessor(String, String, String),
+
+    /// A template key can't be used as an argument name.
+    ///
+    /// **Parameters**
+    /// * `0` - The template key.
+    /// * `1` - The translation path, displayed in `::` notation.
+    #[error("The template '{0}' in '{1}' can't be used as an argument name")]
+    InvalidArgument(String, String),
+}
+
+/// A generated accessor module.
+///
+/// Mirrors a group of translations, with an
+/// accessor per translation and a module per
+/// nested group.
+#[derive(Default)]
+struct AccessorModule {
+    /// The accessors by name, along the path they obtain.
+    accessors: BTreeMap<String, (String, TokenStream2)>,
+
+    /// The nested modules by name.
+    modules: BTreeMap<String, AccessorModule>,
+}
+
+/// Path segment to identifier conversion.
+///
+/// Same as the `translation_module!()` function names,
+/// the segment is lowercased, characters that can't be
+/// part of an identifier are replaced with underscores,
+/// identifiers starting with a digit are prefixed with
+/// one and keywords are suffixed with one.
+///
+/// **Arguments**
+/// * `segment` - The path segment.
+///
+/// **Returns**
+/// A valid identifier for the segment.
+fn segment_ident(segment: &str) -> Ident {
+    let mut name = segment
+        .to_lowercase()
+        .chars()
+        .map(|char| if char.is_ascii_alphanumeric() { char } else { '_' })
+        .collect::<String>();
+
+    if name.starts_with(|char: char| char.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    parse_str::<Ident>(&name).unwrap_or_else(|_| format_ident!("{name}_"))
+}
+
+/// Common path prefix of a directory to watch.
+///
+/// Glob patterns are cut before the first segment
+/// containing a glob metacharacter, as cargo can only
+/// watch existing paths.
+///
+/// **Arguments**
+/// * `path` - The configured path.
+///
+/// **Returns**
+/// The path to watch for changes.
+fn watched_path(path: &str) -> &str {
+    match path.find(['*', '?', '[']) {
+        Some(index) => path[..index]
+            .rsplit_once('/')
+            .map_or(".", |(directory, _)| directory),
+        None => path,
+    }
+}
+
+/// Template key to argument name conversion.
+///
+/// Positional keys such as `0` are named after
+/// their index, as in `arg0`, so named keys
+/// such as `arg0` or `language` are rejected.
+///
+/// **Arguments**
+/// * `key` - The template key.
+///
+/// **Returns**
+/// The argument identifier, if the key can be used as one.
+fn argument_ident(key: &str) -> Option<Ident> {
+    if key
+        .chars()
+        .all(|char| char.is_ascii_digit())
+    {
+        return Some(format_ident!("arg{key}"));
+    }
+
+    parse_str::<Ident>(key)
+        .ok()
+        .filter(|ident| {
+            let name = ident.to_string();
+            let positional = name
+                .strip_prefix("arg")
+                .is_some_and(|index| {
+                    index
+                        .parse::<usize>()
+                        .is_ok()
+                });
+
+            name != "language" && !positional
+        })
+}
+
+/// Translation accessor generation.
+///
+/// The accessor takes the language, a value per positional
+/// template key in index order and a value per named template
+/// key in alphabetical order, type constrained templates
+/// constrain their arguments as well.
+///
+/// **Arguments**
+/// * `path` - The translation path segments.
+/// * `translation` - The translation object.
+///
+/// **Returns**
+/// The accessor function tokens, or a [`BuildError::InvalidArgument`]
+/// if a template key can't be used as an argument name.
+fn accessor(path: &[String], translation: &TranslationObject) -> Result<TokenStream2, BuildError> {
+    let path_display = path.join("::");
+
+    let mut keys = translation
+        .values()
+        .flat_map(FormatString::placeholders)
+        .collect::<Vec<_>>();
+    keys.sort_by_key(|key| {
+        key.parse::<usize>()
+            .map_err(|_| key.clone())
+    });
+    keys.dedup();
+
+    let arguments = keys
+        .iter()
+        .map(|key| {
+            let ident = argument_ident(key)
+                .ok_or_else(|| BuildError::InvalidArgument(key.clone(), path_display.clone()))?;
+
+            let mut bounds = Vec::new();
+            for (constraint_key, constraint) in translation
+                .values()
+                .flat_map(|translation| translation.constraints())
+            {
+                if constraint_key == key && !bounds.contains(constraint) {
+                    bounds.push(*constraint);
+                }
+            }
+
+            let bounds = bounds
+                .iter()
+                .map(|constraint| constraint.trait_path());
+
+            Ok((ident, quote! { impl std::fmt::Display #(+ #bounds)* }))
+        })
+        .collect::<Result<Vec<_>, BuildError>>()?;
+
+    let name = segment_ident(&path[path.len() - 1]);
+    let doc = format!("Obtains the `{path_display}` translation in a language.");
+    let supers = (1..path.len()).map(|_| quote! { super:: });
+    let idents = arguments
+        .iter()
+        .map(|(ident, _)| ident);
+    let types = arguments
+        .iter()
+        .map(|(_, ty)| ty);
+    let values = idents.clone();
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[allow(dead_code)]
+        pub fn #name(
+            language: &translatable::Language,
+            #(#idents: #types),*
+        ) -> std::result::Result<String, translatable::Error> {
+            translatable::translate_with(
+                &*#(#supers)*TRANSLATIONS,
+                language.clone(),
+                &[#(#path.to_string()),*],
+                &std::collections::HashMap::from([#((#keys.to_string(), #values.to_string())),*]),
+            )
+        }
+    })
+}
+
+impl AccessorModule {
+    /// Accessor insertion.
+    ///
+    /// Creates the modules leading to the translation
+    /// path and inserts the accessor in the last one.
+    ///
+    /// **Arguments**
+    /// * `path` - The translation path segments.
+    /// * `accessor` - The accessor function tokens.
+    ///
+    /// **Returns**
+    /// A [`BuildError::DuplicateAccessor`] if the module already
+    /// has an accessor with the same name.
+    fn insert(&mut self, path: &[String], accessor: TokenStream2) -> Result<(), BuildError> {
+        let (name, modules) = path
+            .split_last()
+            .expect("Translation paths to have at least a segment.");
+
+        let module = modules
+            .iter()
+            .fold(self, |module, segment| {
+                module
+                    .modules
+                    .entry(segment_ident(segment).to_string())
+                    .or_default()
+            });
+
+        let name = segment_ident(name).to_string();
+        let path_display = path.join("::");
+
+        if let Some((other, _)) = module
+            .accessors
+            .insert(name.clone(), (path_display.clone(), accessor))
+        {
+            return Err(BuildError::DuplicateAccessor(name, other, path_display));
+        }
+
+        Ok(())
+    }
+
+    /// Module content generation.
+    ///
+    /// **Arguments**
+    /// * `prefix` - The path segments leading to this module.
+    ///
+    /// **Returns**
+    /// The accessors and the nested module declarations.
+    fn items(&self, prefix: &[&str]) -> TokenStream2 {
+        let accessors = self
+            .accessors
+            .values()
+            .map(|(_, accessor)| accessor);
+
+        let modules = self
+            .modules
+            .iter()
+            .map(|(name, module)| {
+                let prefix = [prefix, &[name]].concat();
+                let doc =
+                    format!("Accessors for the translations beneath `{}`.", prefix.join("::"));
+                let ident = format_ident!("{name}");
+                let items = module.items(&prefix);
+
+                quote! {
+                    #[doc = #doc]
+                    #[allow(dead_code)]
+                    pub mod #ident {
+                        #items
+                    }
+                }
+            });
+
+        quote! {
+            #(#accessors)*
+            #(#modules)*
+        }
+    }
+}
+
+/// Generate the translations file.
+///
+/// Meant to be called from a build script, loads the configuration
+/// and the translations exactly as the macros do, reading
+/// `./translatable.toml` and the environment from the package
+/// directory, then writes a Rust file to be included with
+/// `include!(concat!(env!("OUT_DIR"), "/translations.rs"))`.
+///
+/// The file declares a `TRANSLATIONS` static with the translation
+/// collection, limited to the configured `embed_languages`, which
+/// isn't constant data but a `LazyLock` constructing the collection
+/// on first access without reading or parsing any file, and a
+/// module per group of translations with an accessor per translation,
+/// so `greetings::formal` is obtained with `greetings::formal(&language)`.
+/// Each accessor takes a value per positional template key in index
+/// order, named `arg0`, `arg1` and so on, then a value per named template
+/// key in alphabetical order, and resolves the translation the same way
+/// [`translate_with`] does.
+///
+/// Cargo is told to run the build script again whenever the
+/// configuration, its environment variables or the translation
+/// directories change.
+///
+/// **Arguments**
+/// * `out_path` - The path of the file to write.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(())` - The file was written.
+/// * `Err(BuildError)` - If the translations couldn't be loaded, an accessor
+///   couldn't be generated or the file couldn't be written.
+///
+/// [`translate_with`]: crate::translate_with
+pub fn generate(out_path: impl AsRef<Path>) -> Result<(), BuildError> {
+    let config = load_config().map_err(TranslationDataError::from)?;
+    let translations = load_translations()?;
+
+    println!("cargo:rerun-if-changed=translatable.toml");
+    for var in config_env_vars() {
+        println!("cargo:rerun-if-env-changed={var}");
+    }
+    for path in [Some(config.path()), config.overlay_path()]
+        .into_iter()
+        .flatten()
+    {
+        println!("cargo:rerun-if-changed={}", watched_path(path));
+    }
+
+    let translations = embedded_translations(&translations, config.embed_languages());
+
+    let mut root = AccessorModule::default();
+    for (path, translation) in translations.leaves() {
+        root.insert(&path, accessor(&path, translation)?)?;
+    }
+
+    let collection = &*translations;
+    let items = root.items(&[]);
+
+    let generated = quote! {
+        /// The translations loaded in build time.
+        #[allow(dead_code)]
+        pub static TRANSLATIONS: std::sync::LazyLock<
+            translatable::shared::translations::collection::TranslationNodeCollection
+        > = std::sync::LazyLock::new(|| #collection);
+
+        #items
+    };
+
+    write(
+        out_path,
+        format!("// Generated by translatable::build::generate, do not edit.\n\n{generated}\n"),
+    )?;
+
+    Ok(())
+}
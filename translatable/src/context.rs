@@ -0,0 +1,52 @@
+//! Translation context module.
+//!
+//! This module declares the [`ContextFieldValue`] trait,
+//! which translation context fields of types other than
+//! `String` and `&str` and [`translation_into!()`] results
+//! are parsed trough.
+//!
+//! [`translation_into!()`]: crate::translation_into
+
+use std::error::Error;
+use std::str::FromStr;
+
+/// Parsed translation context field value.
+///
+/// Implemented for every type implementing [`FromStr`] with an
+/// error convertible into a boxed [`Error`], which are the
+/// `Send + Sync` errors along `String` and `&str`, so fields such
+/// as `u32` or a custom enum can be loaded from their translation
+/// in `#[translation_context]` structs or with [`translation_into!()`].
+///
+/// [`FromStr`]: std::str::FromStr
+/// [`Error`]: std::error::Error
+/// [`translation_into!()`]: crate::translation_into
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be loaded from a translation",
+    label = "parsed translations must implement `FromStr`",
+    note = "the `FromStr::Err` type must also implement `std::error::Error + Send + Sync`",
+    note = "use a `String` to load the translation as is"
+)]
+pub trait ContextFieldValue: Sized {
+    /// Parse the field value.
+    ///
+    /// **Arguments**
+    /// * `value` - The translation with its templates replaced.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(Self)` - The parsed value.
+    /// * `Err(Box<dyn Error + Send + Sync>)` - The parse error.
+    fn parse_field(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>>;
+}
+
+impl<T: FromStr> ContextFieldValue for T
+where
+    T::Err: Into<Box<dyn Error + Send + Sync>>,
+{
+    fn parse_field(value: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        value
+            .parse()
+            .map_err(Into::into)
+    }
+}
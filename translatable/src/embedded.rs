@@ -0,0 +1,145 @@
+//! Embedded translation loading module.
+//!
+//! This module contains the runtime parsing of
+//! translation files embedded in the binary, such
+//! as the ones in a directory baked in with
+//! `include_dir!`, without reading the file system.
+
+use std::path::Path;
+#[cfg(feature = "include_dir")]
+use std::str::from_utf8;
+
+#[cfg(feature = "include_dir")]
+use include_dir::Dir;
+use translatable_shared::data::config::MacroConfig;
+use translatable_shared::data::translations::{
+    TranslationDataError,
+    TranslationFile,
+    parse_translation_files,
+};
+use translatable_shared::translations::collection::TranslationNodeCollection;
+
+use crate::error::RuntimeError;
+
+/// Load embedded translation files.
+///
+/// Parses the provided files into a [`TranslationNodeCollection`]
+/// that can be consulted with [`translate_with`]. Only the files
+/// with the `toml` extension are parsed, so every file of an embedded
+/// directory may be passed as is.
+///
+/// The files are looked up in alphabetical order and parsed with the
+/// same loader as in compile-time with the default configuration,
+/// so these are validated the same way, merged per language with
+/// `"ignore"` semantics, the references are expanded and the templates
+/// are parsed with the default delimiters. Include directives are
+/// rejected, as there is no file system to read the included files from.
+///
+/// **Arguments**
+/// * `files` - The translation file paths along their contents.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(TranslationNodeCollection)` - The parsed translations.
+/// * `Err(RuntimeError)` - An [`EmbeddedTranslation`] error if any of the files
+///   couldn't be parsed or validated, or the references couldn't be expanded.
+///
+/// [`translate_with`]: crate::translate_with
+/// [`EmbeddedTranslation`]: crate::Error::EmbeddedTranslation
+pub fn load_translations<P: AsRef<Path>, C: AsRef<str>>(
+    files: impl IntoIterator<Item = (P, C)>,
+) -> Result<TranslationNodeCollection, RuntimeError> {
+    let mut files = files
+        .into_iter()
+        .filter(|(path, _)| {
+            path.as_ref()
+                .extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .map(|(path, contents)| {
+            (
+                path.as_ref()
+                    .to_string_lossy()
+                    .to_string(),
+                contents,
+            )
+        })
+        .collect::<Vec<_>>();
+    files.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let files = files
+        .iter()
+        .map(|(path, contents)| TranslationFile::Embedded(path, contents.as_ref()))
+        .collect::<Vec<_>>();
+
+    let config = MacroConfig::from_sources("", &[]).expect("Default configuration to be valid.");
+
+    let (translations, _) = parse_translation_files(&files, &config).map_err(|err| match err {
+        TranslationDataError::FileParse(path, err) => RuntimeError::EmbeddedTranslation(path, err),
+
+        // errors not attributed to a single file.
+        err => {
+            let paths = files
+                .iter()
+                .map(TranslationFile::path)
+                .collect::<Vec<_>>();
+
+            RuntimeError::EmbeddedTranslation(paths.join(", "), Box::new(err))
+        },
+    })?;
+
+    Ok(translations)
+}
+
+/// Load the translation files of an embedded directory.
+///
+/// Collects the files of a directory baked in with
+/// `include_dir!` along the ones in its subdirectories,
+/// then parses them with [`load_translations`].
+///
+/// Only available with the `include_dir` feature.
+///
+/// **Arguments**
+/// * `dir` - The embedded translations directory.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(TranslationNodeCollection)` - The parsed translations.
+/// * `Err(RuntimeError)` - An [`EmbeddedTranslation`] error if any of the
+///   `toml` files is not valid UTF-8, couldn't be parsed or the references
+///   couldn't be expanded.
+///
+/// [`EmbeddedTranslation`]: crate::Error::EmbeddedTranslation
+#[cfg(feature = "include_dir")]
+pub fn load_translations_dir(dir: &Dir<'_>) -> Result<TranslationNodeCollection, RuntimeError> {
+    fn files<'a>(dir: &'a Dir<'a>) -> Vec<(&'a Path, &'a [u8])> {
+        dir.files()
+            .map(|file| (file.path(), file.contents()))
+            .chain(
+                dir.dirs()
+                    .flat_map(files),
+            )
+            .collect()
+    }
+
+    let files = files(dir)
+        .into_iter()
+        .filter(|(path, _)| {
+            path.extension()
+                .is_some_and(|extension| extension == "toml")
+        })
+        .map(|(path, contents)| {
+            from_utf8(contents)
+                .map(|contents| (path, contents))
+                .map_err(|err| {
+                    RuntimeError::EmbeddedTranslation(
+                        path.to_string_lossy()
+                            .to_string(),
+                        Box::new(err.into()),
+                    )
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    load_translations(files)
+}
@@ -0,0 +1,112 @@
+//! Translatable enum module.
+//!
+//! This module declares the [`TranslatableEnum`] trait,
+//! implemented with `#[derive(TranslatableEnum)]`, and the
+//! replacement dispatch the macros use to render these
+//! enums as localized labels.
+
+use translatable_shared::misc::language::Language;
+
+use crate::error::RuntimeError;
+
+/// Enum with a localized label per variant.
+///
+/// Usually derived with `#[derive(TranslatableEnum)]`, which
+/// maps each variant to a translation path validated in compile
+/// time. Values implementing this trait passed as replacements
+/// to [`translation!()`] are replaced with their label in the
+/// resolved language instead of their [`Display`] value.
+///
+/// [`translation!()`]: crate::translation
+/// [`Display`]: std::fmt::Display
+pub trait TranslatableEnum {
+    /// The translation path of the variant.
+    ///
+    /// **Returns**
+    /// The path of the variant label, in `::` notation.
+    fn translation_path(&self) -> &'static str;
+
+    /// The label of the variant.
+    ///
+    /// **Arguments**
+    /// * `language` - The language to load the label in.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(String)` - The label in the language or its closest parent.
+    /// * `Err(RuntimeError)` - If the label is not available in the language.
+    fn translate(&self, language: &Language) -> Result<String, RuntimeError>;
+}
+
+/// Replacement value dispatch.
+///
+/// Used by the macros to render [`TranslatableEnum`] values
+/// as their label and any other value with its [`Display`]
+/// implementation, choosing in compile time by method
+/// resolution precedence.
+///
+/// [`Display`]: std::fmt::Display
+#[doc(hidden)]
+pub mod replacement {
+    use std::fmt::Display;
+
+    use translatable_shared::misc::language::Language;
+
+    use super::TranslatableEnum;
+
+    /// Replacement value wrapper.
+    ///
+    /// Call `to_replacement` on a reference to this
+    /// wrapper with both traits in scope.
+    pub struct Replacement<'a, T: ?Sized>(pub &'a T);
+
+    /// Enum replacement rendering.
+    ///
+    /// Preferred as it's implemented for the wrapper itself.
+    pub trait EnumReplacement {
+        /// Render the replacement.
+        ///
+        /// **Arguments**
+        /// * `language` - The language the translation was resolved in.
+        ///
+        /// **Returns**
+        /// The label in the language, or the variant path
+        /// if it's not available, same as a template without
+        /// a value is left untouched.
+        fn to_replacement(&self, language: &Language) -> String;
+    }
+
+    /// Display replacement rendering.
+    ///
+    /// Only reached trough auto-referencing when the
+    /// value is not a [`TranslatableEnum`].
+    pub trait DisplayReplacement {
+        /// Render the replacement.
+        ///
+        /// **Arguments**
+        /// * `language` - Unused, display values are not localized.
+        ///
+        /// **Returns**
+        /// The value display.
+        fn to_replacement(&self, language: &Language) -> String;
+    }
+
+    impl<T: TranslatableEnum + ?Sized> EnumReplacement for Replacement<'_, T> {
+        fn to_replacement(&self, language: &Language) -> String {
+            self.0
+                .translate(language)
+                .unwrap_or_else(|_| {
+                    self.0
+                        .translation_path()
+                        .to_string()
+                })
+        }
+    }
+
+    impl<T: Display + ?Sized> DisplayReplacement for &Replacement<'_, T> {
+        fn to_replacement(&self, _language: &Language) -> String {
+            self.0
+                .to_string()
+        }
+    }
+}
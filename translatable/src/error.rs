@@ -6,8 +6,11 @@
 //! method invocations.
 
 use thiserror::Error;
+use translatable_shared::data::translations::TranslationDataError;
 use translatable_shared::misc::language::Language;
+use translatable_shared::misc::templating::TemplateError;
 use translatable_shared::translations::node::TranslationNodeError;
+use translatable_shared::translations::resolution::ResolutionError;
 
 /// Macro runtime error handling.
 ///
@@ -19,6 +22,12 @@ use translatable_shared::translations::node::TranslationNodeError;
 /// a helper method for such purpose. Read it's
 /// documentation before using.
 ///
+/// It implements [`std::error::Error`] with the
+/// wrapped errors as its [`source`], and it's `Send`,
+/// `Sync` and `'static`, so it can be propagated with
+/// `?` into `anyhow` or `#[from]` application errors.
+///
+/// [`source`]: std::error::Error::source
 /// [`translation`]: crate::translation
 /// [`Display`]: std::fmt::Display
 #[derive(Error, Debug)]
@@ -56,12 +65,32 @@ pub enum RuntimeError {
     /// for that purpose.
     ///
     /// **Parameters**
-    /// * `0` - The path that could not be found
-    /// appended with it's separator.
+    /// * `0` - The segments of the path that could not be found.
+    /// * `1` - The translation file that defines most of the path, if any.
     ///
     /// [`LanguageNotAvailable`]: crate::Error::LanguageNotAvailable
-    #[error("The path '{0}' could not be found")]
-    PathNotFound(String),
+    #[error(
+        "The path '{path}' could not be found{hint}",
+        path = _0.join("::"),
+        hint = source_hint("closest match", _1)
+    )]
+    PathNotFound(Vec<String>, Option<String>),
+
+    /// Dynamic path resolve error.
+    ///
+    /// The specified path leads to a group of
+    /// translations instead of a translation,
+    /// usually because its last segment is missing.
+    ///
+    /// **Parameters**
+    /// * `0` - The segments of the path.
+    /// * `1` - The segments nested beneath the path.
+    #[error(
+        "The path '{path}' leads to a group of translations, available segments: {segments}",
+        path = _0.join("::"),
+        segments = _1.join(", ")
+    )]
+    SubtreePath(Vec<String>, Vec<String>),
 
     /// Dynamic language obtention error.
     ///
@@ -75,10 +104,184 @@ pub enum RuntimeError {
     ///
     /// **Parameters**
     /// * `0` - The language that is not available.
-    /// * `1` - The path for which the language is not available
-    /// appended with it's separator.
-    #[error("The language '{0:?}' ('{0:#}') is not available for the path '{1}'")]
-    LanguageNotAvailable(Language, String),
+    /// * `1` - The segments of the path for which the language is not
+    ///   available.
+    /// * `2` - The translation file where the path is defined, if known.
+    #[error(
+        "The language '{0:?}' ('{0:#}') is not available for the path '{path}'{hint}",
+        path = _1.join("::"),
+        hint = source_hint("defined", _2)
+    )]
+    LanguageNotAvailable(Language, Vec<String>, Option<String>),
+
+    /// Template replacement error.
+    ///
+    /// With `missing_replacements` configured as `error`,
+    /// a template had neither a value nor a default.
+    ///
+    /// The [`TemplateError`] is also the error
+    /// [`source`].
+    ///
+    /// **Parameters**
+    /// * `0` - The [`TemplateError`] derivation.
+    /// * `1` - The segments of the path of the translation containing the
+    ///   template.
+    ///
+    /// [`TemplateError`]: crate::shared::misc::templating::TemplateError
+    /// [`source`]: std::error::Error::source
+    #[error("{0:#} in the path '{path}'", path = _1.join("::"))]
+    Replacement(#[source] TemplateError, Vec<String>),
+
+    /// Translation context field parse error.
+    ///
+    /// A context field of a type other than `String`
+    /// and `&str` couldn't be parsed from its translation.
+    ///
+    /// The parse error is also the error [`source`].
+    ///
+    /// **Parameters**
+    /// * `0` - The segments of the path of the translation.
+    /// * `1` - The translation that couldn't be parsed.
+    /// * `2` - The parse error.
+    ///
+    /// [`source`]: std::error::Error::source
+    #[error(
+        "The translation '{1}' in the path '{path}' couldn't be parsed: {2}",
+        path = _0.join("::")
+    )]
+    ContextFieldParse(Vec<String>, String, #[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// Parsed translation error.
+    ///
+    /// The translation obtained by [`translation_into!()`]
+    /// couldn't be parsed into the requested type.
+    ///
+    /// The parse error is also the error [`source`].
+    ///
+    /// **Parameters**
+    /// * `0` - The translation that couldn't be parsed.
+    /// * `1` - The parse error.
+    ///
+    /// [`translation_into!()`]: crate::translation_into
+    /// [`source`]: std::error::Error::source
+    #[error("The translation '{0}' couldn't be parsed: {1}")]
+    TranslationParse(String, #[source] Box<dyn std::error::Error + Send + Sync>),
+
+    /// Embedded translation file error.
+    ///
+    /// Translation files embedded in the binary are
+    /// parsed in runtime by [`load_translations`], so
+    /// the errors usually reported in compile-time are
+    /// reported here.
+    ///
+    /// The [`TranslationDataError`] is also the
+    /// error [`source`].
+    ///
+    /// **Parameters**
+    /// * `0` - The translation file that couldn't be loaded.
+    /// * `1` - The [`TranslationDataError`] derivation.
+    ///
+    /// [`load_translations`]: crate::load_translations
+    /// [`TranslationDataError`]: crate::shared::data::translations::TranslationDataError
+    /// [`source`]: std::error::Error::source
+    #[error("The embedded translation file '{0}' couldn't be loaded: {1:#}")]
+    EmbeddedTranslation(String, #[source] Box<TranslationDataError>),
+
+    /// Hot reloaded translation file error.
+    ///
+    /// With the `hot-reload` feature translation files
+    /// are parsed in runtime, so the errors usually reported
+    /// in compile-time are reported here.
+    ///
+    /// The [`TranslationDataError`] is also the
+    /// error [`source`].
+    ///
+    /// **Parameters**
+    /// * `0` - The translation file that couldn't be reloaded.
+    /// * `1` - The [`TranslationDataError`] derivation.
+    ///
+    /// [`TranslationDataError`]: crate::shared::data::translations::TranslationDataError
+    /// [`source`]: std::error::Error::source
+    #[cfg(feature = "hot-reload")]
+    #[error("The translation file '{0}' couldn't be reloaded: {1:#}")]
+    TranslationReload(String, #[source] Box<TranslationDataError>),
+}
+
+/// Runtime error kind.
+///
+/// A stable discriminant for [`RuntimeError`]
+/// variants, obtained with [`RuntimeError::kind`],
+/// so errors can be told apart without depending
+/// on their message or their payload.
+///
+/// New kinds may be added along new errors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// See [`RuntimeError::TranslationNode`].
+    TranslationNode,
+
+    /// See [`RuntimeError::PathNotFound`].
+    PathNotFound,
+
+    /// See [`RuntimeError::SubtreePath`].
+    SubtreePath,
+
+    /// See [`RuntimeError::LanguageNotAvailable`].
+    LanguageNotAvailable,
+
+    /// See [`RuntimeError::Replacement`].
+    Replacement,
+
+    /// See [`RuntimeError::ContextFieldParse`].
+    ContextFieldParse,
+
+    /// See [`RuntimeError::TranslationParse`].
+    TranslationParse,
+
+    /// See [`RuntimeError::EmbeddedTranslation`].
+    EmbeddedTranslation,
+
+    /// See [`RuntimeError::TranslationReload`].
+    #[cfg(feature = "hot-reload")]
+    TranslationReload,
+}
+
+/// Translation file hint formatting.
+///
+/// **Arguments**
+/// * `prefix` - What the file is to the error.
+/// * `source` - The translation file path, if any.
+///
+/// **Returns**
+/// The hint to append to the error message, or an
+/// empty string if there is no source.
+fn source_hint(prefix: &str, source: &Option<String>) -> String {
+    source
+        .as_ref()
+        .map(|source| format!(", {prefix} in {source}"))
+        .unwrap_or_default()
+}
+
+/// Translation resolution error conversion.
+///
+/// Each resolution error is converted into the runtime
+/// error variant with the same name, so resolution errors
+/// can be propagated with `?`.
+impl From<ResolutionError> for RuntimeError {
+    fn from(error: ResolutionError) -> Self {
+        match error {
+            ResolutionError::PathNotFound(path, closest_match) => {
+                Self::PathNotFound(path, closest_match)
+            },
+
+            ResolutionError::SubtreePath(path, segments) => Self::SubtreePath(path, segments),
+
+            ResolutionError::LanguageNotAvailable(language, path, source) => {
+                Self::LanguageNotAvailable(language, path, source)
+            },
+        }
+    }
 }
 
 impl RuntimeError {
@@ -99,4 +302,50 @@ impl RuntimeError {
     pub fn cause(&self) -> String {
         format!("{self:#}")
     }
+
+    /// Runtime error kind.
+    ///
+    /// **Returns**
+    /// The [`ErrorKind`] matching this error variant.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::TranslationNode(_) => ErrorKind::TranslationNode,
+            Self::PathNotFound(..) => ErrorKind::PathNotFound,
+            Self::SubtreePath(..) => ErrorKind::SubtreePath,
+            Self::LanguageNotAvailable(..) => ErrorKind::LanguageNotAvailable,
+            Self::Replacement(..) => ErrorKind::Replacement,
+            Self::ContextFieldParse(..) => ErrorKind::ContextFieldParse,
+            Self::TranslationParse(..) => ErrorKind::TranslationParse,
+            Self::EmbeddedTranslation(..) => ErrorKind::EmbeddedTranslation,
+            #[cfg(feature = "hot-reload")]
+            Self::TranslationReload(..) => ErrorKind::TranslationReload,
+        }
+    }
+
+    /// Runtime error path.
+    ///
+    /// **Returns**
+    /// The segments of the translation path the error
+    /// refers to, if any.
+    pub fn path(&self) -> Option<&[String]> {
+        match self {
+            Self::PathNotFound(path, _)
+            | Self::SubtreePath(path, _)
+            | Self::LanguageNotAvailable(_, path, _)
+            | Self::Replacement(_, path)
+            | Self::ContextFieldParse(path, ..) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Runtime error language.
+    ///
+    /// **Returns**
+    /// The language the error refers to, if any.
+    pub fn language(&self) -> Option<&Language> {
+        match self {
+            Self::LanguageNotAvailable(language, ..) => Some(language),
+            _ => None,
+        }
+    }
 }
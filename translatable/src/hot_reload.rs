@@ -0,0 +1,171 @@
+//! Translation hot reloading module.
+//!
+//! This module contains the live translation
+//! collection consulted by the macro calls resolved
+//! in runtime when the `hot-reload` feature is enabled,
+//! instead of the translations embedded in compile-time.
+
+use std::fs::metadata;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::SystemTime;
+
+use translatable_shared::data::config::MacroConfig;
+use translatable_shared::data::translations::{
+    TranslationDataError,
+    TranslationFile,
+    parse_translation_files,
+};
+use translatable_shared::translations::collection::TranslationNodeCollection;
+
+use crate::error::RuntimeError;
+
+/// Live translation collection.
+///
+/// Holds the parsed [`TranslationNodeCollection`] along
+/// the fingerprint of the files it was parsed from, so
+/// the files are parsed again whenever any of them change.
+struct LiveTranslations {
+    /// Source files fingerprint.
+    ///
+    /// Each translation file path along its last
+    /// modification time, in seek order, followed
+    /// by the included files.
+    fingerprint: Vec<(String, Option<SystemTime>)>,
+
+    /// Included file paths.
+    ///
+    /// The files included by the translation files,
+    /// which are not known until these are parsed.
+    includes: Vec<String>,
+
+    /// The parsed translations.
+    ///
+    /// Shared with every caller that obtained
+    /// them while the fingerprint matched.
+    translations: Arc<TranslationNodeCollection>,
+}
+
+/// Global thread-safe live translations.
+///
+/// Empty until the first runtime lookup, then
+/// replaced whenever the fingerprint changes.
+static TRANSLATIONS: Mutex<Option<LiveTranslations>> = Mutex::new(None);
+
+/// Fingerprints a list of files.
+///
+/// **Arguments**
+/// * `paths` - The file paths to fingerprint.
+///
+/// **Returns**
+/// Each file path along its last modification time,
+/// `None` if it couldn't be obtained.
+fn fingerprint<'a>(paths: impl IntoIterator<Item = &'a str>) -> Vec<(String, Option<SystemTime>)> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+
+            (path.to_string(), modified)
+        })
+        .collect()
+}
+
+/// Obtains the live translations.
+///
+/// On every invocation the translation files, along the files they
+/// included the last time they were parsed, are fingerprinted with
+/// their modification time, only if the fingerprint differs
+/// from the last one, which is always the case on the first
+/// invocation, the files are read and parsed again.
+///
+/// The files themselves are collected in compile-time, so a
+/// translation file created after compiling requires recompiling
+/// to be picked up, modifying or deleting translations doesn't.
+///
+/// The files are parsed with the same loader as in compile-time,
+/// with the configuration the macros were compiled with, so the
+/// files are validated the same way and runtime lookups behave the
+/// same with or without hot reloading. Same as for the embedded
+/// translations, only the configured `embed_languages` are kept.
+///
+/// **Arguments**
+/// * `paths` - The absolute translation file paths in lookup order, along
+///   whether the last found definition of a key wins when resolving their
+///   includes.
+/// * `config` - The configuration the macros were compiled with.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(Arc<TranslationNodeCollection>)` - The up to date translations.
+/// * `Err(RuntimeError)` - If any of the translation files couldn't be read,
+///   parsed or validated.
+pub fn translations(
+    paths: &[(&str, bool)],
+    config: &MacroConfig,
+) -> Result<Arc<TranslationNodeCollection>, RuntimeError> {
+    let mut live = TRANSLATIONS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(live) = live.as_ref()
+        && live.fingerprint
+            == fingerprint(
+                paths
+                    .iter()
+                    .map(|(path, _)| *path)
+                    .chain(
+                        live.includes
+                            .iter()
+                            .map(String::as_str),
+                    ),
+            )
+    {
+        return Ok(Arc::clone(&live.translations));
+    }
+
+    let files = paths
+        .iter()
+        .map(|(path, overwrite)| TranslationFile::Path(path, *overwrite))
+        .collect::<Vec<_>>();
+
+    let (mut translations, includes) =
+        parse_translation_files(&files, config).map_err(|err| match err {
+            TranslationDataError::FileParse(path, err) => {
+                RuntimeError::TranslationReload(path, err)
+            },
+
+            // errors not attributed to a single file.
+            err => {
+                let paths = paths
+                    .iter()
+                    .map(|(path, _)| *path)
+                    .collect::<Vec<_>>();
+
+                RuntimeError::TranslationReload(paths.join(", "), Box::new(err))
+            },
+        })?;
+
+    if let Some(languages) = config.embed_languages() {
+        translations.retain_languages(languages);
+    }
+
+    let translations = Arc::new(translations);
+    *live = Some(LiveTranslations {
+        fingerprint: fingerprint(
+            paths
+                .iter()
+                .map(|(path, _)| *path)
+                .chain(
+                    includes
+                        .iter()
+                        .map(String::as_str),
+                ),
+        ),
+        includes,
+        translations: Arc::clone(&translations),
+    });
+
+    Ok(translations)
+}
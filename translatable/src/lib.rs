@@ -7,17 +7,103 @@
 
 #![warn(missing_docs)]
 
+mod context;
+mod embedded;
+mod enums;
 mod error;
+mod replacements;
+mod source;
+
+/// Translation hot reloading.
+///
+/// Only available with the `hot-reload`
+/// feature, consulted by the macro calls
+/// resolved in runtime.
+#[cfg(feature = "hot-reload")]
+#[doc(hidden)]
+pub mod hot_reload;
+
+/// Build-time translation generation.
+///
+/// Only available with the `build` feature,
+/// meant to be used from a build script as an
+/// alternative to the macros.
+#[cfg(feature = "build")]
+pub mod build;
 
 /// Runtime error re-export.
 ///
 /// This `use` statement renames
 /// the run time error as a common
 /// error by rust practice and exports
-/// it.
+/// it along its kind.
 #[rustfmt::skip]
 pub use error::RuntimeError as Error;
 
+#[rustfmt::skip]
+pub use error::ErrorKind;
+
+/// Translatable enum re-exports.
+///
+/// This `use` statement exports the
+/// trait for enums with localized labels
+/// along its derive macro.
+#[rustfmt::skip]
+pub use enums::TranslatableEnum;
+
+#[rustfmt::skip]
+pub use translatable_proc::TranslatableEnum;
+
+#[doc(hidden)]
+#[rustfmt::skip]
+pub use enums::replacement;
+
+/// Replacements struct re-exports.
+///
+/// This `use` statement exports the
+/// trait for structs spread as translation
+/// replacements along its derive macro.
+#[rustfmt::skip]
+pub use replacements::Replacements;
+
+#[rustfmt::skip]
+pub use translatable_proc::Replacements;
+
+#[doc(hidden)]
+#[rustfmt::skip]
+pub use replacements::keys as replacement_keys;
+
+/// Translation context re-export.
+///
+/// This `use` statement exports the
+/// trait parsed context fields are
+/// loaded trough.
+#[rustfmt::skip]
+pub use context::ContextFieldValue;
+
+/// Translation source re-exports.
+///
+/// This `use` statement exports the
+/// runtime lookup trough a user provided
+/// translation source along its trait.
+#[rustfmt::skip]
+pub use source::translate_with;
+
+#[rustfmt::skip]
+pub use shared::translations::source::TranslationSource;
+
+/// Embedded translations re-export.
+///
+/// This `use` statement exports the
+/// runtime parsing of translation files
+/// embedded in the binary.
+#[rustfmt::skip]
+pub use embedded::load_translations;
+
+#[cfg(feature = "include_dir")]
+#[rustfmt::skip]
+pub use embedded::load_translations_dir;
+
 /// Macro re-exports.
 ///
 /// This `use` statement re-exports
@@ -27,9 +113,42 @@ pub use error::RuntimeError as Error;
 #[rustfmt::skip]
 pub use translatable_proc::translation;
 
+#[rustfmt::skip]
+pub use translatable_proc::translation_with_lang;
+
+#[rustfmt::skip]
+pub use translatable_proc::try_translation;
+
+#[rustfmt::skip]
+pub use translatable_proc::translation_into;
+
+#[rustfmt::skip]
+pub use translatable_proc::translation_list;
+
 #[rustfmt::skip]
 pub use translatable_proc::translation_context;
 
+#[rustfmt::skip]
+pub use translatable_proc::translation_exists;
+
+#[rustfmt::skip]
+pub use translatable_proc::available_languages;
+
+#[rustfmt::skip]
+pub use translatable_proc::subtree;
+
+#[rustfmt::skip]
+pub use translatable_proc::translation_paths;
+
+#[rustfmt::skip]
+pub use translatable_proc::translation_hash;
+
+#[rustfmt::skip]
+pub use translatable_proc::translation_module;
+
+#[rustfmt::skip]
+pub use translatable_proc::localized;
+
 /// Language enum re-export.
 ///
 /// This `use` statement re-exports
@@ -38,6 +157,14 @@ pub use translatable_proc::translation_context;
 #[rustfmt::skip]
 pub use shared::misc::language::Language;
 
+/// Template arguments re-export.
+///
+/// This `use` statement re-exports
+/// the positional and named values
+/// translations are replaced with.
+#[rustfmt::skip]
+pub use shared::misc::templating::Args;
+
 #[doc(hidden)]
 #[rustfmt::skip]
 pub use translatable_shared as shared;
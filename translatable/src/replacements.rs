@@ -0,0 +1,81 @@
+//! Replacements struct module.
+//!
+//! This module declares the [`Replacements`] trait,
+//! implemented with `#[derive(Replacements)]`, and the
+//! type-level keys the macros validate its fields with.
+
+use std::collections::HashMap;
+
+use translatable_shared::misc::language::Language;
+
+/// Struct whose fields are translation replacements.
+///
+/// Usually derived with `#[derive(Replacements)]`, which maps
+/// each field to the template with its name. Values implementing
+/// this trait are spread into [`translation!()`] invocations such
+/// as `translation!("en", static path, ..replacements)`.
+///
+/// [`translation!()`]: crate::translation
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be spread as translation replacements",
+    label = "replacements must implement `Replacements`",
+    note = "derive it with `#[derive(Replacements)]`"
+)]
+pub trait Replacements {
+    /// The field names as a type-level list.
+    ///
+    /// A list of nested `(Key<HASH>, Rest)` tuples ending
+    /// with `()`, used by the macros to validate the fields
+    /// against the translation templates in compile time.
+    #[doc(hidden)]
+    type Keys;
+
+    /// Collect the replacements.
+    ///
+    /// **Arguments**
+    /// * `language` - The language the translation was resolved in.
+    ///
+    /// **Returns**
+    /// The value of each field keyed by its name, enums implementing
+    /// `TranslatableEnum` are replaced with their label.
+    fn to_replacements(&self, language: &Language) -> HashMap<String, String>;
+}
+
+/// Type-level replacement keys.
+///
+/// Used by the macros to check the keys of a [`Replacements`]
+/// struct in compile time, as its fields are not known while
+/// expanding the macro invocation it's spread into.
+#[doc(hidden)]
+pub mod keys {
+    use std::marker::PhantomData;
+
+    /// A replacement key, identified by its [`key_hash`].
+    pub struct Key<const HASH: u64>;
+
+    /// The key is the head of the list.
+    pub struct Here;
+
+    /// The key is in the rest of the list.
+    pub struct There<I>(PhantomData<I>);
+
+    /// Hashes a key name.
+    ///
+    /// **Arguments**
+    /// * `key` - The key name.
+    ///
+    /// **Returns**
+    /// The FNV-1a hash of the key.
+    pub const fn key_hash(key: &str) -> u64 {
+        let bytes = key.as_bytes();
+        let mut hash = 0xcbf29ce484222325u64;
+        let mut index = 0;
+
+        while index < bytes.len() {
+            hash = (hash ^ bytes[index] as u64).wrapping_mul(0x100000001b3);
+            index += 1;
+        }
+
+        hash
+    }
+}
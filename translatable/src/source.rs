@@ -0,0 +1,48 @@
+//! Pluggable translation source module.
+//!
+//! This module contains the runtime lookup trough
+//! a user provided [`TranslationSource`], decoupling
+//! the resolution from the embedded translations.
+
+use std::collections::HashMap;
+
+use translatable_shared::misc::language::Language;
+use translatable_shared::translations::source::TranslationSource;
+
+use crate::error::RuntimeError;
+
+/// Translate trough a translation source.
+///
+/// Resolves the path in the provided source and replaces
+/// its templates with the provided values, templates without
+/// a value are left untouched unless they declare a default.
+///
+/// Nothing is validated in compile-time, any source may be
+/// passed, including a [`TranslationNodeCollection`] or a
+/// `&dyn TranslationSource`.
+///
+/// **Arguments**
+/// * `source` - The source to resolve the translation from.
+/// * `language` - The requested language.
+/// * `path` - The sections of the translation path.
+/// * `replacements` - The template values by key.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(String)` - The translation with its templates replaced.
+/// * `Err(RuntimeError)` - A [`LanguageNotAvailable`] error without a file, as
+///   a source doesn't tell a missing path apart from a missing language.
+///
+/// [`TranslationNodeCollection`]: crate::shared::translations::collection::TranslationNodeCollection
+/// [`LanguageNotAvailable`]: crate::Error::LanguageNotAvailable
+pub fn translate_with<S: TranslationSource + ?Sized>(
+    source: &S,
+    language: Language,
+    path: &[String],
+    replacements: &HashMap<String, String>,
+) -> Result<String, RuntimeError> {
+    source
+        .resolve(language.clone(), path)
+        .map(|translation| translation.replace_with(replacements))
+        .ok_or_else(|| RuntimeError::LanguageNotAvailable(language, path.to_vec(), None))
+}
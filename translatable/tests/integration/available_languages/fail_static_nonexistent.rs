@@ -0,0 +1,6 @@
+#[allow(unused_imports)] // trybuild
+use translatable::available_languages;
+
+fn main() {
+    let _ = available_languages!(static non::existing::path);
+}
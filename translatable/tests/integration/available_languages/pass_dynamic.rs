@@ -0,0 +1,17 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, available_languages};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic() {
+    let languages = available_languages!(vec!["greetings", "norwegian"]);
+
+    assert_eq!(languages, Some(vec![Language::NO]));
+
+    let languages = available_languages!(vec!["non", "existing", "path"]);
+
+    assert_eq!(languages, None);
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
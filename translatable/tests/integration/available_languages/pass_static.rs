@@ -0,0 +1,13 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, available_languages};
+
+#[cfg(test)]
+#[test]
+pub fn pass_static() {
+    let languages = available_languages!(static greetings::formal);
+
+    assert_eq!(languages, Some(vec![Language::EN, Language::ES]));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,6 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+fn main() {
+    let _ = translation!(static greetings::formal);
+}
@@ -0,0 +1,8 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+// templates are replaced in runtime, so these can't be const.
+const INFORMAL: &str = translation!("en", static greetings::informal, user = "John");
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,14 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // replaced values are isolated in right-to-left translations only.
+    assert_eq!(
+        translation!("ar", static bidi::greeting, name = "Josh"),
+        "مرحبا \u{2068}Josh\u{2069}"
+    );
+    assert_eq!(translation!("en", static bidi::greeting, name = "Josh"), "Hello Josh");
+
+    let dynamic = translation!(Language::AR, vec!["bidi", "greeting"], name = "Josh");
+    assert_eq!(dynamic.unwrap(), "مرحبا \u{2068}Josh\u{2069}");
+}
@@ -0,0 +1,11 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // paths are matched regardless of their case.
+    assert_eq!(translation!("es", static Greetings::Formal), "Bueno conocerte.");
+    assert_eq!(translation!("en", static greetings::FORMAL), "Nice to meet you.");
+
+    let dynamic = translation!(Language::ES, vec!["GREETINGS", "formal"]);
+    assert_eq!(dynamic.unwrap(), "Bueno conocerte.");
+}
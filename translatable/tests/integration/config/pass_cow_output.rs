@@ -0,0 +1,50 @@
+#[allow(unused_imports)] // trybuild
+use std::borrow::Cow;
+
+#[allow(unused_imports)] // trybuild
+use translatable::{
+    Language,
+    TranslatableEnum,
+    translation,
+    translation_with_lang,
+    try_translation,
+};
+
+#[allow(dead_code)]
+#[derive(TranslatableEnum)]
+#[translatable(base_path = order::status)]
+enum OrderStatus {
+    Pending,
+}
+
+fn main() {
+    // translations without replacements are borrowed.
+    let translation: Cow<'static, str> = translation!("en", static greetings::formal);
+    assert!(matches!(translation, Cow::Borrowed("Nice to meet you.")));
+
+    let translation: Cow<'static, str> = translation!("en", static greetings::informal);
+    assert!(matches!(translation, Cow::Borrowed("What's good {user}?")));
+
+    let translation = translation!("en", static greetings::informal, user = "Josh");
+    assert!(matches!(translation, Cow::Owned(ref owned) if owned == "What's good Josh?"));
+
+    // hot reloaded translations are owned.
+    let translation = translation!(Language::ES, vec!["greetings", "formal"]).unwrap();
+    assert_eq!(translation, "Bueno conocerte.");
+
+    let translation = translation!(Language::ES, static greetings::informal, user = "Josh");
+    assert_eq!(translation.unwrap(), "Hey Josh, todo bien?");
+
+    let (translation, language) = translation_with_lang!("nb", static greetings::norwegian);
+    assert!(matches!(translation, Cow::Borrowed("Hyggelig å møte deg.")));
+    assert_eq!(language, Language::NO);
+
+    let translation = try_translation!("en", static greetings::formal).unwrap();
+    assert!(matches!(translation, Cow::Borrowed(_)));
+
+    // derived labels are still strings.
+    let label: String = OrderStatus::Pending
+        .translate(&Language::ES)
+        .unwrap();
+    assert_eq!(label, "pendiente");
+}
@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use std::borrow::Cow;
+
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation};
+
+fn main() {
+    let translation: Cow<'static, str> =
+        translation!(Language::EN, vec!["greetings", "formal"]).unwrap();
+    assert_eq!(translation, "Nice to meet you.");
+
+    let translation = translation!(Language::EN, vec!["greetings", "informal"], user = "Josh");
+    assert_eq!(translation.unwrap(), "What's good Josh?");
+
+    assert!(matches!(
+        translation!(Language::EN, vec!["greetings", "informal"]),
+        Err(Error::Replacement(..))
+    ));
+}
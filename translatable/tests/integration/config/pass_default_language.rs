@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation, translation_with_lang, try_translation};
+
+fn main() {
+    // the configured language is used when none is passed.
+    assert_eq!(translation!(static greetings::formal), "Bueno conocerte.");
+    assert_eq!(translation!(static greetings::informal, user = "Juan"), "Hey Juan, todo bien?");
+    assert_eq!(translation!(inline { es = "Hola", en = "Hi" }), "Hola");
+
+    let (translation, language) = translation_with_lang!(static greetings::formal);
+    assert_eq!((translation.as_str(), language), ("Bueno conocerte.", Language::ES));
+
+    assert_eq!(try_translation!(static greetings::formal).unwrap(), "Bueno conocerte.");
+
+    let translation = translation!(unchecked greetings::formal);
+    assert_eq!(translation.unwrap(), "Bueno conocerte.");
+
+    // a passed language still takes precedence.
+    assert_eq!(translation!("en", static greetings::formal), "Nice to meet you.");
+}
@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation};
+
+fn main() {
+    // static resolution isn't affected.
+    assert_eq!(translation!("en", static greetings::formal), "Nice to meet you.");
+
+    let (spanish, english) = (Language::ES, Language::EN);
+
+    assert_eq!(translation!(spanish, static greetings::formal).unwrap(), "Bueno conocerte.");
+    assert!(matches!(
+        translation!(english, static greetings::formal),
+        Err(Error::LanguageNotAvailable(Language::EN, _, _))
+    ));
+
+    assert_eq!(translation!(spanish, vec!["greetings", "formal"]).unwrap(), "Bueno conocerte.");
+    assert!(matches!(
+        translation!(english, vec!["greetings", "formal"]),
+        Err(Error::LanguageNotAvailable(Language::EN, _, _))
+    ));
+}
@@ -0,0 +1,8 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+fn main() {
+    // empty translations are allowed by default.
+    assert_eq!(translation!("es", static greetings::formal), "");
+    assert_eq!(translation!("en", static greetings::formal), "Nice to meet you.");
+}
@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // replaced values are escaped, raw templates and the translation aren't.
+    assert_eq!(
+        translation!("en", static html::comment, author = "<script>", body = "<i>Hi</i>"),
+        "<b>&lt;script&gt;</b> wrote: <i>Hi</i>"
+    );
+
+    let translation = translation!(
+        Language::ES,
+        vec!["html", "comment"],
+        author = "Tom & \"Jerry\"",
+        body = "<i>Hola</i>"
+    );
+    assert_eq!(translation.unwrap(), "<b>Tom &amp; &quot;Jerry&quot;</b> escribió: <i>Hola</i>");
+
+    // inline translations are escaped too.
+    assert_eq!(translation!("en", inline { en = "Hi {name}" }, name = "<Ana>"), "Hi &lt;Ana&gt;");
+}
@@ -0,0 +1,24 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation, translation_list};
+
+fn main() {
+    let a = translation!("fr", static greetings::formal);
+    assert_eq!(a, "Bueno conocerte.");
+
+    let language = Language::FR;
+    let b = translation!(language, static greetings::formal);
+    assert_eq!(b.unwrap(), "Bueno conocerte.");
+
+    // the catch-all rule replaces the `no` parent.
+    let c = translation!("nb", static greetings::formal);
+    assert_eq!(c, "Nice to meet you.");
+
+    let d = translation!(Language::NB, vec!["greetings", "norwegian"]);
+    assert!(d.is_err());
+
+    let e = translation_list!("fr", static onboarding::tips);
+    assert_eq!(e, vec!["Explora el menú."]);
+
+    let f = translation_list!(Language::FR, static onboarding::tips);
+    assert_eq!(f.unwrap(), vec!["Explora el menú."]);
+}
@@ -0,0 +1,7 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+fn main() {
+    assert_eq!(translation!("es", static selection::app), "Aplicación");
+    assert_eq!(translation!("en", static selection::menu), "Menu");
+}
@@ -0,0 +1,40 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[allow(dead_code)]
+const SPANISH: Language = Language::ES;
+
+#[allow(dead_code)]
+const FORMAL: &str = translation!("es", static greetings::formal);
+
+#[allow(dead_code)]
+static NORWEGIAN: &str = translation!("nb", static greetings::norwegian);
+
+#[allow(dead_code)]
+const OK: &str = translation!("en", inline { en = "OK", es = "Vale" });
+
+#[allow(dead_code)]
+const LABEL: &str = translation!(Language::EN, static greetings::formal);
+
+#[allow(dead_code)]
+const QUALIFIED_LABEL: &str = translation!(translatable::Language::NB, static greetings::norwegian);
+
+#[allow(dead_code)]
+const CONST_FORMAL: &str = translation!(const SPANISH, static greetings::formal);
+
+fn main() {
+    assert_eq!(FORMAL, "Bueno conocerte.");
+    assert_eq!(NORWEGIAN, "Hyggelig å møte deg.");
+    assert_eq!(OK, "OK");
+    assert_eq!(LABEL, "Nice to meet you.");
+    assert_eq!(QUALIFIED_LABEL, "Hyggelig å møte deg.");
+    assert_eq!(CONST_FORMAL, "Bueno conocerte.");
+
+    // variant paths along replacements are still resolved in runtime.
+    let translation = translation!(Language::EN, static greetings::informal, user = "Josh");
+    assert_eq!(translation.unwrap(), "What's good Josh?");
+
+    // translations with replacements are still strings.
+    let translation: String = translation!("en", static greetings::informal, user = "Josh");
+    assert_eq!(translation, "What's good Josh?");
+}
@@ -0,0 +1,14 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    let formal: String = translation!("es", static greetings::formal);
+    assert_eq!(formal, "Bueno conocerte.");
+
+    let formal: String = translation!(const Language::ES, static greetings::formal);
+    assert_eq!(formal, "Bueno conocerte.");
+
+    // variant paths are resolved in runtime.
+    let formal = translation!(Language::EN, static greetings::formal);
+    assert_eq!(formal.unwrap(), "Nice to meet you.");
+}
@@ -0,0 +1,10 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // missing translations evaluate to their path.
+    assert_eq!(translation!("fr", static greetings::formal), "greetings::formal");
+
+    let path = translation!(Language::ES, vec!["non", "existing"]);
+    assert_eq!(path.unwrap(), "non::existing");
+}
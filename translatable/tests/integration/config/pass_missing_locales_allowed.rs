@@ -0,0 +1,13 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation};
+
+fn main() {
+    // inline translations don't need the translation files.
+    assert_eq!(translation!("en", inline { en = "Hello", es = "Hola" }), "Hello");
+
+    // there are no translations to look up in runtime.
+    assert!(matches!(
+        translation!(Language::EN, vec!["greetings", "formal"]),
+        Err(Error::PathNotFound(..))
+    ));
+}
@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation, translation_with_lang, try_translation};
+
+fn main() {
+    // missing languages are marked in compile time.
+    assert_eq!(translation!("fr", static greetings::formal), "⟪greetings::formal@fr⟫");
+    assert_eq!(
+        try_translation!("fr", static greetings::formal).unwrap(),
+        "⟪greetings::formal@fr⟫"
+    );
+
+    // missing paths and languages are marked in runtime.
+    let language = translation!(Language::FR, vec!["greetings", "formal"]);
+    assert_eq!(language.unwrap(), "⟪greetings::formal@fr⟫");
+
+    let path = translation!(Language::ES, vec!["non", "existing"]);
+    assert_eq!(path.unwrap(), "⟪non::existing@es⟫");
+
+    let (translation, language) =
+        translation_with_lang!(Language::FR, static greetings::formal).unwrap();
+    assert_eq!(translation, "⟪greetings::formal@fr⟫");
+    assert_eq!(language, Language::FR);
+
+    // available translations are not affected.
+    assert_eq!(translation!("en", static greetings::formal), "Nice to meet you.");
+}
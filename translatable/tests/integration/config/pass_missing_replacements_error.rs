@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation, translation_context};
+
+#[translation_context(base_path = greetings)]
+struct GreetingsContext {
+    informal: String,
+}
+
+fn main() {
+    assert_eq!(translation!("en", static greetings::informal, user = "Josh"), "What's good Josh?");
+    assert_eq!(translation!("en", static welcome::guest), "Welcome, Guest.");
+
+    assert!(matches!(
+        translation!(Language::EN, vec!["greetings", "informal"]),
+        Err(Error::Replacement(..))
+    ));
+
+    assert!(matches!(GreetingsContext::builder(Language::EN).build(), Err(Error::Replacement(..))));
+
+    let context = GreetingsContext::builder(Language::EN)
+        .informal_with(&std::collections::HashMap::from([("user", "Josh")]))
+        .build()
+        .unwrap();
+
+    assert_eq!(context.informal, "What's good Josh?");
+}
@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation, translation_context};
+
+#[translation_context(base_path = buttons::dialog)]
+struct DialogContext {
+    cancel: String,
+    confirm: String,
+}
+
+fn main() {
+    assert_eq!(translation!("es", static buttons::save), "Guardar");
+    assert_eq!(translation!("en", static menu::save), "Save as");
+    assert_eq!(translation!(Language::EN, vec!["buttons", "save"]).unwrap(), "Save");
+
+    let context = DialogContext::builder(Language::ES)
+        .build()
+        .unwrap();
+
+    assert_eq!(context.cancel, "Cancelar");
+    assert_eq!(context.confirm, "Confirmar");
+}
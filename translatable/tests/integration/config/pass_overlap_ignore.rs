@@ -0,0 +1,15 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // the first found translation is kept.
+    assert_eq!(translation!("en", static greetings::formal), "Hello from a.");
+
+    let translation = translation!(Language::ES, vec!["greetings", "formal"]);
+    assert_eq!(translation.unwrap(), "Hola desde a.");
+
+    // the files are merged per language.
+    assert_eq!(translation!("es", static greetings::farewell), "Adiós desde a.");
+    assert_eq!(translation!("en", static greetings::farewell), "Goodbye from c.");
+    assert_eq!(translation!("fr", static greetings::farewell), "Au revoir de b.");
+}
@@ -0,0 +1,15 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // the last found translation is kept.
+    assert_eq!(translation!("en", static greetings::formal), "Hello from c.");
+
+    let translation = translation!(Language::ES, vec!["greetings", "formal"]);
+    assert_eq!(translation.unwrap(), "Hola desde c.");
+
+    // the files are merged per language.
+    assert_eq!(translation!("es", static greetings::farewell), "Adiós desde c.");
+    assert_eq!(translation!("en", static greetings::farewell), "Goodbye from c.");
+    assert_eq!(translation!("fr", static greetings::farewell), "Au revoir de b.");
+}
@@ -0,0 +1,13 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // the overlay is kept regardless of the overlap strategy.
+    assert_eq!(translation!("en", static greetings::formal), "Hello from the overlay.");
+
+    let translation = translation!(Language::EN, vec!["greetings", "formal"]);
+    assert_eq!(translation.unwrap(), "Hello from the overlay.");
+
+    let translation = translation!(Language::ES, vec!["greetings", "formal"]);
+    assert_eq!(translation.unwrap(), "Hola desde el overlay.");
+}
@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, available_languages, translation, translation_exists};
+
+fn main() {
+    let path = "greetings.formal";
+
+    let dynamic = translation!(Language::ES, vec![path]);
+    assert_eq!(dynamic.unwrap(), "Bueno conocerte.");
+
+    // the split segments are trimmed.
+    let dynamic = translation!(Language::EN, vec!["greetings . informal"], user = "Josh");
+    assert_eq!(dynamic.unwrap(), "What's good Josh?");
+
+    // segments without the separator are looked up as they are.
+    let dynamic = translation!(Language::ES, vec!["greetings", "formal"]);
+    assert_eq!(dynamic.unwrap(), "Bueno conocerte.");
+
+    assert!(translation_exists!(Language::ES, vec![path]));
+    assert!(available_languages!(vec![path]).is_some());
+}
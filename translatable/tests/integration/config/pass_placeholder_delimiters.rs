@@ -0,0 +1,18 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    let user = "john";
+
+    assert_eq!(
+        translation!("es", static greetings::informal, user),
+        "Hola john, {esto} no es una plantilla."
+    );
+
+    assert_eq!(
+        translation!(Language::EN, static greetings::informal, user).unwrap(),
+        "Hey john, {this} is not a template."
+    );
+
+    assert_eq!(translation!("en", inline { en = "Bye %{user}." }, user), "Bye john.");
+}
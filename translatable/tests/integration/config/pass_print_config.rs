@@ -0,0 +1,10 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    let a = translation!("es", static greetings::formal);
+    assert_eq!(a, "Bueno conocerte.");
+
+    let b = translation!(Language::EN, vec!["greetings", "formal"]);
+    assert_eq!(b.unwrap(), "Nice to meet you.");
+}
@@ -0,0 +1,16 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+fn main() {
+    // the text is accented, padded and bracketed, templates are kept.
+    assert_eq!(
+        translation!("en", static greetings::informal, user = "john"),
+        "[Ŵĥáţ'š ĝööð john? ~~~]"
+    );
+
+    let translation = translation!(Language::ES, vec!["greetings", "formal"]);
+    assert_eq!(translation.unwrap(), "[Ɓûéñö çöñöçéŕţé. ~~~~~]");
+
+    // inline translations are pseudolocalized too.
+    assert_eq!(translation!("en", inline { en = "Hi {name}" }, name = "Ana"), "[Ĥî Ana ~]");
+}
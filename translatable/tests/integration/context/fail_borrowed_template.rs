@@ -0,0 +1,10 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    informal: &'static str,
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
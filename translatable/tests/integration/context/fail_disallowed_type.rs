@@ -3,7 +3,7 @@ use ::{std::collections::HashMap, translatable::translation_context};
 
 #[translation_context(base_path = greetings)]
 struct Context {
-    formal: i32,
+    formal: &'static [u8],
     informal: String,
 }
 
@@ -0,0 +1,18 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: &'static [u8],
+    informal: &'static u8,
+}
+
+#[translation_context(base_path = greetings)]
+struct PathContext {
+    missing: String,
+    #[path(also::missing)]
+    also_missing: String,
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
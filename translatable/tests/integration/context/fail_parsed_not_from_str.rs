@@ -0,0 +1,12 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+struct Greeting;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: Greeting,
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
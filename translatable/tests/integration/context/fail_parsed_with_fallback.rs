@@ -0,0 +1,10 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = limits, fallback_language = "en")]
+struct Context {
+    max_items: u32,
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,11 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    #[language("no")]
+    formal: String,
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
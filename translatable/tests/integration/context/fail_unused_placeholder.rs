@@ -0,0 +1,11 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    #[placeholders(user, count)]
+    informal: String,
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
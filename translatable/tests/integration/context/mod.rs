@@ -1,3 +1,9 @@
+pub mod pass_borrowed_fields;
+pub mod pass_builder;
 pub mod pass_fallback_catch;
+pub mod pass_field_base_path;
+pub mod pass_field_language;
 pub mod pass_invalid_runtime_language;
+pub mod pass_parsed_fields;
+pub mod pass_placeholders;
 pub mod pass_without_params;
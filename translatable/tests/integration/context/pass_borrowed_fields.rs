@@ -0,0 +1,54 @@
+#[allow(unused_imports)] // trybuild
+use ::{std::collections::HashMap, translatable::translation_context};
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: &'static str,
+    informal: String,
+}
+
+#[translation_context(base_path = greetings)]
+struct BorrowedContext {
+    #[path(norwegian)]
+    norwegian: &str,
+}
+
+#[translation_context(base_path = greetings, fallback_language = "en")]
+struct FallbackContext {
+    formal: &'static str,
+}
+
+#[test]
+fn pass_borrowed_fields() {
+    let translations =
+        Context::load_translations(translatable::Language::ES, &HashMap::from([("user", "John")]))
+            .unwrap();
+
+    assert_eq!(translations.formal, "Bueno conocerte.");
+    assert_eq!(translations.informal, "Hey John, todo bien?");
+
+    let translations = BorrowedContext::load_translations(
+        translatable::Language::NB,
+        &HashMap::<String, String>::new(),
+    )
+    .unwrap();
+
+    assert_eq!(translations.norwegian, "Hyggelig å møte deg.");
+
+    let translations = BorrowedContext::load_translations(
+        translatable::Language::ES,
+        &HashMap::<String, String>::new(),
+    );
+
+    assert!(translations.is_err());
+
+    let translations = FallbackContext::load_translations(
+        translatable::Language::AA,
+        &HashMap::<String, String>::new(),
+    );
+
+    assert_eq!(translations.formal, "Nice to meet you.");
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,44 @@
+#[allow(unused_imports)] // trybuild
+use ::{std::collections::HashMap, translatable::translation_context};
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: String,
+    informal: String,
+}
+
+#[translation_context(base_path = greetings, fallback_language = "en")]
+struct FallbackContext {
+    formal: &'static str,
+    informal: String,
+}
+
+#[test]
+fn pass_builder() {
+    let translations = Context::builder(translatable::Language::EN)
+        .informal_with(&HashMap::from([("user", "John")]))
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.formal, "Nice to meet you.");
+    assert_eq!(translations.informal, "What's good John?");
+
+    let translations = Context::builder(translatable::Language::EN)
+        .formal("Hello there.".into())
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.formal, "Hello there.");
+    assert_eq!(translations.informal, "What's good {user}?");
+
+    let translations = FallbackContext::builder(translatable::Language::AA)
+        .formal("Greetings.")
+        .informal_with(&HashMap::from([("user", "Jane")]))
+        .build();
+
+    assert_eq!(translations.formal, "Greetings.");
+    assert_eq!(translations.informal, "What's good Jane?");
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,28 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: String,
+    #[base_path(errors)]
+    #[path("404")]
+    not_found: &'static str,
+    #[base_path(auditory::actions)]
+    #[placeholders(author, target)]
+    delete_user: String,
+}
+
+#[test]
+fn pass_field_base_path() {
+    let translations = Context::builder(translatable::Language::EN)
+        .delete_user_with("Jane", "John")
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.formal, "Nice to meet you.");
+    assert_eq!(translations.not_found, "Page not found.");
+    assert_eq!(translations.delete_user, "Jane deleted the user John.");
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,35 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_context;
+
+#[translation_context(base_path = greetings)]
+struct Context {
+    formal: String,
+    #[language("es")]
+    #[path(formal)]
+    formal_es: &'static str,
+    #[language(translatable::Language::EN)]
+    #[path(informal)]
+    informal_en: String,
+}
+
+#[test]
+fn pass_field_language() {
+    let translations = Context::builder(translatable::Language::ES)
+        .informal_en_with(&std::collections::HashMap::from([("user", "John")]))
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.formal, "Bueno conocerte.");
+    assert_eq!(translations.formal_es, "Bueno conocerte.");
+    assert_eq!(translations.informal_en, "What's good John?");
+
+    let translations = Context::builder(translatable::Language::EN)
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.formal, "Nice to meet you.");
+    assert_eq!(translations.formal_es, "Bueno conocerte.");
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
@@ -0,0 +1,78 @@
+#[allow(unused_imports)] // trybuild
+use ::{
+    std::collections::HashMap,
+    std::num::ParseIntError,
+    std::str::FromStr,
+    translatable::translation_context,
+};
+
+#[derive(Debug, PartialEq)]
+enum Greeting {
+    Formal,
+    Other,
+}
+
+impl FromStr for Greeting {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Nice to meet you." => Ok(Self::Formal),
+            "" => Err("empty greeting".into()),
+            _ => Ok(Self::Other),
+        }
+    }
+}
+
+#[translation_context(base_path = limits)]
+struct Context {
+    max_items: u32,
+}
+
+#[translation_context(base_path = greetings)]
+struct EnumContext {
+    formal: Greeting,
+}
+
+#[translation_context(base_path = greetings)]
+struct InvalidContext {
+    #[path(formal)]
+    count: u32,
+}
+
+#[test]
+fn pass_parsed_fields() {
+    let translations =
+        Context::load_translations(translatable::Language::ES, &HashMap::<String, String>::new())
+            .unwrap();
+
+    assert_eq!(translations.max_items, 20);
+
+    let translations = Context::builder(translatable::Language::EN)
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.max_items, 10);
+
+    let translations = EnumContext::load_translations(
+        translatable::Language::EN,
+        &HashMap::<String, String>::new(),
+    )
+    .unwrap();
+
+    assert_eq!(translations.formal, Greeting::Formal);
+
+    let error = InvalidContext::load_translations(
+        translatable::Language::EN,
+        &HashMap::<String, String>::new(),
+    )
+    .map(|translations| translations.count);
+
+    assert!(matches!(
+        error,
+        Err(translatable::Error::ContextFieldParse(_, _, source)) if source.is::<ParseIntError>()
+    ));
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
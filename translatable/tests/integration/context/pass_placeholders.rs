@@ -0,0 +1,36 @@
+#[allow(unused_imports)] // trybuild
+use ::{std::collections::HashMap, translatable::translation_context};
+
+#[translation_context]
+struct Context {
+    #[path(greetings::informal)]
+    #[placeholders(user)]
+    informal: String,
+    #[path(auditory::actions::delete_user)]
+    #[placeholders(author, target)]
+    delete_user: String,
+}
+
+#[test]
+fn pass_placeholders() {
+    let translations = Context::builder(translatable::Language::EN)
+        .informal_with("John")
+        .delete_user_with("Jane", "John")
+        .build()
+        .unwrap();
+
+    assert_eq!(translations.informal, "What's good John?");
+    assert_eq!(translations.delete_user, "Jane deleted the user John.");
+
+    let translations = Context::load_translations(
+        translatable::Language::ES,
+        &HashMap::from([("user", "John"), ("author", "Jane"), ("target", "John")]),
+    )
+    .unwrap();
+
+    assert_eq!(translations.informal, "Hey John, todo bien?");
+    assert_eq!(translations.delete_user, "Jane ha borrado al usuario John.");
+}
+
+#[allow(unused)]
+fn main() {} // trybuild
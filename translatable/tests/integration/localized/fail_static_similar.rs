@@ -0,0 +1,6 @@
+#[allow(unused_imports)] // trybuild
+use translatable::localized;
+
+fn main() {
+    let _ = localized!(greetings::formel);
+}
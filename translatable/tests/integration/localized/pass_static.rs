@@ -0,0 +1,31 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, localized};
+
+#[cfg(test)]
+#[test]
+pub fn pass_static() {
+    const FORMAL: fn(&Language) -> Option<&'static str> = localized!(static greetings::formal);
+
+    assert_eq!(FORMAL(&Language::EN), Some("Nice to meet you."));
+    assert_eq!(FORMAL(&Language::ES), Some("Bueno conocerte."));
+    assert_eq!(FORMAL(&Language::FR), None);
+
+    // the language parents are tried.
+    let norwegian = localized!(greetings::norwegian);
+
+    assert_eq!(norwegian(&Language::NB), Some("Hyggelig å møte deg."));
+    assert_eq!(norwegian(&Language::EN), None);
+
+    // private use languages are matched by their tag.
+    let pseudo = localized!(pseudo::greeting);
+    let pseudo_language = Language::from_tag("en-XA").expect("Tag to be valid.");
+
+    assert_eq!(pseudo(&pseudo_language), Some("[Ñîçé ţö mééţ ýöü.]"));
+    assert_eq!(pseudo(&Language::EN), Some("Nice to meet you."));
+
+    let british = Language::from_tag("en-x-british").expect("Tag to be valid.");
+    assert_eq!(pseudo(&british), Some("Nice to meet you."));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,9 @@
+#[allow(unused_imports)] // trybuild
+use translatable::Replacements;
+
+#[derive(Replacements)]
+enum DeleteUser {
+    Author,
+}
+
+fn main() {}
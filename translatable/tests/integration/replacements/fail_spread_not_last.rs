@@ -0,0 +1,14 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Replacements, translation};
+
+#[derive(Replacements)]
+struct DeleteUser {
+    author: String,
+}
+
+fn main() {
+    let replacements = DeleteUser { author: "Josh".into() };
+
+    let _ =
+        translation!("en", static auditory::actions::delete_user, ..replacements, target = "Marie");
+}
@@ -0,0 +1,17 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Replacements, translation};
+
+#[derive(Replacements)]
+struct DeleteUser {
+    author: String,
+    victim: String,
+}
+
+fn main() {
+    let replacements = DeleteUser {
+        author: "Josh".into(),
+        victim: "Marie".into(),
+    };
+
+    let _ = translation!("en", static auditory::actions::delete_user, ..replacements);
+}
@@ -0,0 +1,2 @@
+pub mod pass_derive;
+pub mod pass_map;
@@ -0,0 +1,64 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, Replacements, TranslatableEnum, translation};
+
+#[allow(dead_code)]
+#[derive(Replacements)]
+struct DeleteUser {
+    author: String,
+    target: &'static str,
+}
+
+#[allow(dead_code)]
+#[derive(TranslatableEnum)]
+#[translatable(base_path = order::status)]
+enum OrderStatus {
+    Pending,
+    InTransit,
+}
+
+#[allow(dead_code)]
+#[derive(Replacements)]
+struct OrderPlaced {
+    status: OrderStatus,
+}
+
+#[cfg(test)]
+#[test]
+pub fn pass_derive() {
+    let replacements = DeleteUser { author: "Josh".into(), target: "Marie" };
+
+    let translation = translation!("en", static auditory::actions::delete_user, ..replacements);
+    assert_eq!(translation, "Josh deleted the user Marie.");
+
+    let translation =
+        translation!("es", static auditory::actions::delete_user, author = "Ana", ..replacements,);
+    assert_eq!(translation, "Ana ha borrado al usuario Marie.");
+
+    let translation =
+        translation!(Language::EN, static auditory::actions::delete_user, ..replacements)
+            .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Josh deleted the user Marie.");
+
+    let translation =
+        translation!("en", vec!["auditory", "actions", "delete_user"], ..replacements)
+            .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Josh deleted the user Marie.");
+
+    let translation = translation!(
+        "en",
+        inline { en = "{author} greets {target}" },
+        ..DeleteUser { author: "Josh".into(), target: "Marie" }
+    );
+    assert_eq!(translation, "Josh greets Marie");
+
+    let translation =
+        translation!("es", static order::placed, ..OrderPlaced { status: OrderStatus::InTransit });
+    assert_eq!(translation, "Tu pedido está en camino.");
+
+    let replacements = replacements.to_replacements(&Language::EN);
+    assert_eq!(replacements["author"], "Josh");
+    assert_eq!(replacements["target"], "Marie");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,44 @@
+#[allow(unused_imports)] // trybuild
+use std::collections::HashMap;
+
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_map() {
+    let replacements = HashMap::from([
+        ("author".to_string(), "Josh".to_string()),
+        ("target".to_string(), "Marie".to_string()),
+    ]);
+
+    let translation =
+        translation!("en", static auditory::actions::delete_user, replacements = replacements);
+    assert_eq!(translation, "Josh deleted the user Marie.");
+
+    // explicit replacements take precedence over the map.
+    let translation = translation!(
+        Language::ES,
+        vec!["auditory", "actions", "delete_user"],
+        author = "Ana",
+        replacements = &replacements,
+    )
+    .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Ana ha borrado al usuario Marie.");
+
+    // any map whose keys and values implement `Display`.
+    let translation = translation!(
+        "en",
+        inline { en = "{count} new messages" },
+        replacements = HashMap::from([("count", 3)])
+    );
+    assert_eq!(translation, "3 new messages");
+
+    // the shorthand is still a replacement.
+    let replacements = "Josh";
+    let translation = translation!("en", inline { en = "Hi {replacements}" }, replacements);
+    assert_eq!(translation, "Hi Josh");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,6 @@
+#[allow(unused_imports)] // trybuild
+use translatable::subtree;
+
+fn main() {
+    let _ = subtree!("es", static non::existing::path);
+}
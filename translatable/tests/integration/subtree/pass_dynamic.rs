@@ -0,0 +1,23 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, subtree};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic() {
+    let translations = subtree!(Language::EN, vec!["greetings"]);
+
+    assert_eq!(
+        translations,
+        vec![
+            ("greetings::formal".to_string(), "Nice to meet you.".to_string()),
+            ("greetings::informal".to_string(), "What's good {user}?".to_string()),
+        ]
+    );
+
+    let translations = subtree!(Language::EN, vec!["non", "existing", "path"]);
+
+    assert!(translations.is_empty());
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, subtree};
+
+#[cfg(test)]
+#[test]
+pub fn pass_static() {
+    let translations = subtree!("es", static greetings);
+
+    assert_eq!(
+        translations,
+        vec![
+            ("greetings::formal".to_string(), "Bueno conocerte.".to_string()),
+            ("greetings::informal".to_string(), "Hey {user}, todo bien?".to_string()),
+        ]
+    );
+
+    let translations = subtree!(Language::NO, static greetings);
+
+    assert_eq!(
+        translations,
+        vec![("greetings::norwegian".to_string(), "Hyggelig å møte deg.".to_string())]
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,7 @@
+#[allow(unused_imports)] // trybuild
+use translatable::TranslatableEnum;
+
+#[derive(TranslatableEnum)]
+struct OrderStatus;
+
+fn main() {}
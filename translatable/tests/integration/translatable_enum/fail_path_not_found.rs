@@ -0,0 +1,11 @@
+#[allow(unused_imports)] // trybuild
+use translatable::TranslatableEnum;
+
+#[derive(TranslatableEnum)]
+#[translatable(base_path = order::status)]
+enum OrderStatus {
+    Pending,
+    Delivered,
+}
+
+fn main() {}
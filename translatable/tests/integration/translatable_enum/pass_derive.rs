@@ -0,0 +1,44 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, TranslatableEnum, translation};
+
+#[allow(dead_code)]
+#[derive(TranslatableEnum)]
+#[translatable(base_path = order::status)]
+enum OrderStatus {
+    Pending,
+
+    #[translatable(path = in_transit)]
+    Shipped {
+        carrier: String,
+    },
+
+    InTransit,
+}
+
+#[cfg(test)]
+#[test]
+pub fn pass_derive() {
+    let status = OrderStatus::Shipped { carrier: "mail".into() };
+
+    assert_eq!(status.translation_path(), "order::status::in_transit");
+    assert_eq!(
+        OrderStatus::Pending
+            .translate(&Language::ES)
+            .unwrap(),
+        "pendiente"
+    );
+
+    let translation = translation!("es", static order::placed, status);
+    assert_eq!(translation, "Tu pedido está en camino.");
+
+    let translation =
+        translation!(Language::EN, static order::placed, status = OrderStatus::Pending)
+            .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Your order is pending.");
+
+    let translation = translation!("en", static order::placed, status = "gone");
+    assert_eq!(translation, "Your order is gone.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
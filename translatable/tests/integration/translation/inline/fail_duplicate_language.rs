@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    translation!("es", inline { es = "Hola", "es" = "Buenas" });
+}
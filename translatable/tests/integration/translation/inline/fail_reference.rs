@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation;
+
+fn main() {
+    translation!("en", inline { en = "Welcome to @{brand::name}" });
+}
@@ -0,0 +1,2 @@
+pub mod pass_dynamic_language;
+pub mod pass_static_language;
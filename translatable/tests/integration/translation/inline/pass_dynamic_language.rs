@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_language() {
+    let language = Language::ES;
+    let translation =
+        translation!(language, inline { en = "Hi {name}", es = "Hola {name}" }, name = "john")
+            .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Hola john");
+
+    let error = translation!(Language::FR.clone(), inline { en = "Hi" })
+        .expect_err("Expected the language not to be available");
+
+    assert!(matches!(error, Error::LanguageNotAvailable(Language::FR, _, None)));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,18 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_language() {
+    let name = "john";
+
+    assert_eq!(
+        translation!("es", inline { en = "Hi {name}", es = "Hola {name}" }, name),
+        "Hola john"
+    );
+    assert_eq!(translation!("EN", inline { "en" = "Hi {name}" }, name), "Hi john");
+    assert_eq!(translation!("nb", inline { no = "Hei" }), "Hei");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
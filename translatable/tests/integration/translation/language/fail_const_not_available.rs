@@ -0,0 +1,8 @@
+#[allow(unused_imports)]
+use translatable::{Language, translation};
+
+const FRENCH: Language = Language::FR;
+
+fn main() {
+    translation!(const FRENCH, static greetings::formal);
+}
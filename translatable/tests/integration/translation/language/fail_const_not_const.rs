@@ -0,0 +1,7 @@
+#[allow(unused_imports)]
+use translatable::{Language, translation};
+
+fn main() {
+    let language = Language::ES;
+    translation!(const language, static greetings::formal);
+}
@@ -1,5 +1,13 @@
+pub mod pass_const_language;
+pub mod pass_custom_tag;
 pub mod pass_dynamic_enum;
 pub mod pass_dynamic_expr;
+pub mod pass_dynamic_fallback_parent;
 pub mod pass_dynamic_invalid_runtime;
+pub mod pass_dynamic_reference;
+pub mod pass_fallback_override;
+pub mod pass_static_fallback_parent;
 pub mod pass_static_lowercase;
 pub mod pass_static_uppercase;
+pub mod pass_try_translation;
+pub mod pass_with_lang;
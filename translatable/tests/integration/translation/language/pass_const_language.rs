@@ -0,0 +1,38 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation, translation_with_lang};
+
+#[allow(dead_code)]
+const SPANISH: Language = Language::ES;
+
+#[allow(dead_code)]
+const NYNORSK: Language = Language::NN;
+
+#[cfg(test)]
+#[test]
+pub fn pass_const_language() {
+    // resolved in compile time, so these are literals.
+    const FORMAL: &str = translation!(const SPANISH, static greetings::formal);
+    assert_eq!(FORMAL, "Bueno conocerte.");
+
+    let norwegian: &str = translation!(const NYNORSK, static greetings::norwegian);
+    assert_eq!(norwegian, "Hyggelig å møte deg.");
+
+    let (translation, language) =
+        translation_with_lang!(const NYNORSK, static greetings::norwegian);
+    assert_eq!(translation, "Hyggelig å møte deg.");
+    assert_eq!(language, Language::NO);
+
+    let translation = translation!(const Language::EN, static greetings::informal, user = "Josh");
+    assert_eq!(translation, "What's good Josh?");
+
+    let translation = translation!(const SPANISH, inline { en = "Hi", es = "Hola" });
+    assert_eq!(translation, "Hola");
+
+    // dynamic paths are still resolved in runtime.
+    let translation = translation!(const SPANISH, vec!["greetings", "formal"])
+        .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Bueno conocerte.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,24 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_custom_tag() {
+    let translation = translation!("en-xa", static pseudo::greeting);
+
+    assert_eq!(translation, "[Ñîçé ţö mééţ ýöü.]");
+
+    let language = Language::from_tag("en-XA").expect("Expected the custom tag to parse");
+    let translation = translation!(language, vec!["pseudo", "greeting"])
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "[Ñîçé ţö mééţ ýöü.]");
+
+    let translation = translation!(Language::from_tag("en-XB").unwrap(), static pseudo::greeting)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Nice to meet you.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
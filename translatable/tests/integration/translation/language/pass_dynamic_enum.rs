@@ -4,7 +4,8 @@ use translatable::{Language, translation};
 #[cfg(test)]
 #[test]
 pub fn pass_dynamic_enum() {
-    let translation = translation!(Language::ES, static greetings::formal)
+    let language = Language::ES;
+    let translation = translation!(language, static greetings::formal)
         .expect("Expected translation generation to be OK");
 
     assert_eq!(translation, "Bueno conocerte.");
@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_fallback_parent() {
+    let translation = translation!(Language::NN, vec!["greetings", "norwegian"])
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Hyggelig å møte deg.");
+
+    let language = Language::SV;
+    let translation = translation!(language, static greetings::norwegian);
+
+    assert!(matches!(translation, Err(Error::LanguageNotAvailable(Language::SV, _, _))));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,28 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, subtree, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_reference() {
+    let my_lang = Language::ES;
+
+    let translation = translation!(&my_lang, static greetings::formal)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Bueno conocerte.");
+
+    let translation = translation!(&my_lang, vec!["greetings", "informal"], user = "John")
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Hey John, todo bien?");
+
+    let translations = subtree!(&my_lang, vec!["greetings"]);
+
+    assert_eq!(translations[0], ("greetings::formal".to_string(), "Bueno conocerte.".to_string()));
+
+    // the language is only borrowed.
+    assert_eq!(my_lang, Language::ES);
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,35 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_fallback_override() {
+    assert_eq!(translation!("fr", static greetings::formal, fallback = "en"), "Nice to meet you.");
+    assert_eq!(
+        translation!("nb", static greetings::norwegian, fallback = "en"),
+        "Hyggelig å møte deg."
+    );
+
+    let translation = translation!(Language::FR, static greetings::formal, fallback = Language::ES)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Bueno conocerte.");
+
+    let translation = translation!("fr", vec!["greetings", "formal"], fallback = "en")
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Nice to meet you.");
+
+    let language = Language::NB;
+    let translation = translation!(language, static greetings::norwegian, fallback = none);
+
+    assert!(matches!(translation, Err(Error::LanguageNotAvailable(Language::NB, _, _))));
+
+    let fallback = "fallback";
+    let translation = translation!("en", inline { en = "{fallback}" }, fallback);
+
+    assert_eq!(translation, "fallback");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,13 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_fallback_parent() {
+    let translation = translation!("nb", static greetings::norwegian);
+
+    assert_eq!(translation, "Hyggelig å møte deg.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,25 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, try_translation};
+
+#[allow(dead_code)]
+fn greetings(language: Language) -> Result<(String, String), Error> {
+    Ok((
+        try_translation!("es", static greetings::formal)?,
+        try_translation!(language, static greetings::formal)?,
+    ))
+}
+
+#[cfg(test)]
+#[test]
+pub fn pass_try_translation() {
+    let (formal, translated) =
+        greetings(Language::EN).expect("Expected translation generation to be OK");
+
+    assert_eq!(formal, "Bueno conocerte.");
+    assert_eq!(translated, "Nice to meet you.");
+
+    assert!(matches!(greetings(Language::FR), Err(Error::LanguageNotAvailable(..))));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
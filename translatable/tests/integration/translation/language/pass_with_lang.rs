@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_with_lang};
+
+#[cfg(test)]
+#[test]
+pub fn pass_with_lang() {
+    let (translation, language) = translation_with_lang!("es", static greetings::formal);
+
+    assert_eq!(translation, "Bueno conocerte.");
+    assert_eq!(language, Language::ES);
+
+    let (translation, language) = translation_with_lang!("nb", static greetings::norwegian);
+
+    assert_eq!(translation, "Hyggelig å møte deg.");
+    assert_eq!(language, Language::NO);
+
+    let (translation, language) =
+        translation_with_lang!(Language::NN, vec!["greetings", "norwegian"])
+            .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Hyggelig å møte deg.");
+    assert_eq!(language, Language::NO);
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -1,3 +1,4 @@
+pub mod inline;
 pub mod language;
 pub mod path;
 pub mod templates;
@@ -1,2 +1,10 @@
+pub mod pass_dynamic_closest_match;
 pub mod pass_dynamic_expr;
+pub mod pass_dynamic_nonexistent;
+pub mod pass_dynamic_subtree;
+pub mod pass_static_dotted_keys;
 pub mod pass_static_existing;
+pub mod pass_static_include;
+pub mod pass_static_literal_segments;
+pub mod pass_static_str;
+pub mod pass_unchecked;
@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_closest_match() {
+    let translation = translation!("es", vec!["greetings", "missing"]);
+
+    assert!(matches!(
+        translation,
+        Err(Error::PathNotFound(_, Some(source))) if source.ends_with("test.toml")
+    ));
+
+    let translation = translation!("es", vec!["non", "existing", "path"]);
+
+    assert!(matches!(translation, Err(Error::PathNotFound(_, None))));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,15 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_nonexistent() {
+    let translation = translation!("es", vec!["non", "existing", "path"]);
+
+    assert!(
+        matches!(translation, Err(Error::PathNotFound(path, _)) if path == ["non", "existing", "path"])
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,24 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, ErrorKind, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic_subtree() {
+    let translation = translation!("es", vec!["auditory", "actions"]);
+
+    assert!(
+        matches!(translation, Err(Error::SubtreePath(path, segments)) if path == ["auditory", "actions"] && segments == ["delete_user"])
+    );
+
+    let translation = translation!("es", vec!["greetings"]);
+
+    assert_eq!(
+        translation
+            .err()
+            .map(|error| error.kind()),
+        Some(ErrorKind::SubtreePath)
+    );
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,15 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_dotted_keys() {
+    assert_eq!(translation!("es", static cart::checkout), "Pagar");
+    assert_eq!(translation!("en", static cart::empty), "Your cart is empty.");
+
+    let translation = translation!(Language::EN, static cart::checkout);
+    assert_eq!(translation, "Checkout");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,13 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_include() {
+    let translation = translation!("es", static farewells::formal);
+
+    assert_eq!(translation, "Hasta luego.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,17 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{subtree, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_literal_segments() {
+    let not_found = translation!("en", static errors::"404");
+    let sign_in = translation!("es", static "errors"::"sign-in");
+    let errors = subtree!("en", static "errors");
+
+    assert_eq!(not_found, "Page not found.");
+    assert_eq!(sign_in, "No se pudo iniciar sesión.");
+    assert!(errors.contains(&("errors::404".to_string(), "Page not found.".to_string())));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
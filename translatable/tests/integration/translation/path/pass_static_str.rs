@@ -0,0 +1,15 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static_str() {
+    const FORMAL: &str = translation!("es", static greetings::formal);
+    let guest: String = translation!("en", static welcome::guest);
+
+    assert_eq!(FORMAL, "Bueno conocerte.");
+    assert_eq!(guest, "Welcome, Guest.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
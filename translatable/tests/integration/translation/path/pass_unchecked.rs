@@ -0,0 +1,24 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation, translation_exists};
+
+#[cfg(test)]
+#[test]
+pub fn pass_unchecked() {
+    let translation = translation!("es", unchecked plugin::greeting);
+
+    assert!(
+        matches!(translation, Err(Error::PathNotFound(path, _)) if path == ["plugin", "greeting"])
+    );
+
+    let translation =
+        translation!(Language::EN, unchecked static greetings::informal, user = "John");
+    assert_eq!(translation.unwrap(), "What's good John?");
+
+    let translation = translation!("en", unchecked errors::"404");
+    assert_eq!(translation.unwrap(), "Page not found.");
+
+    assert!(!translation_exists!("en", unchecked plugin::greeting));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -1,4 +1,12 @@
+pub mod pass_conditionals;
+pub mod pass_default_values;
+pub mod pass_directives;
+pub mod pass_empty_replacements;
+pub mod pass_format_specs;
 pub mod pass_ident_ref;
 pub mod pass_multiple_templates;
+pub mod pass_references;
+pub mod pass_shorthand;
 pub mod pass_trailing_comma;
 pub mod pass_trailing_comma_no_args;
+pub mod pass_typed_templates;
@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_conditionals() {
+    let translation = translation!("en", static inbox::status, unread = true, archived = false);
+
+    assert_eq!(translation, "You have new messages (unread), nothing archived.");
+
+    let translation =
+        translation!(Language::ES, vec!["inbox", "status"], unread = 0, archived = "yes")
+            .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Tienes mensajes nuevos, todos archivados.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,17 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_default_values() {
+    assert_eq!(translation!("en", static welcome::guest), "Welcome, Guest.");
+    assert_eq!(translation!("es", static welcome::guest, name = "Ana"), "Bienvenido, Ana.");
+
+    let translation = translation!(Language::ES, vec!["welcome", "guest"])
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Bienvenido, invitado.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
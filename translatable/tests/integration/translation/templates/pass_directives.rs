@@ -0,0 +1,19 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_directives() {
+    let translation = translation!("en", static checkout::total, price = 1234.5, count = 1200);
+
+    assert_eq!(translation, "Total: €1,234.50 for 1,200 items.");
+
+    let translation =
+        translation!(Language::ES, vec!["checkout", "total"], price = 1234.5, count = 1200)
+            .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Total: 1.234,50\u{a0}€ por 1.200 artículos.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,25 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation;
+
+#[cfg(test)]
+#[test]
+pub fn pass_empty_replacements() {
+    let author = "Juan";
+    let target = "Pepito";
+
+    let translation = translation!("es", static greetings::formal, ,);
+    assert_eq!(translation, "Bueno conocerte.");
+
+    let translation = translation!("es", static auditory::actions::delete_user, author, , target,,);
+    assert_eq!(translation, "Juan ha borrado al usuario Pepito.");
+
+    let translation = translation!("es", vec!["greetings", "formal"], ,)
+        .expect("Expected translation generation to be OK");
+    assert_eq!(translation, "Bueno conocerte.");
+
+    let translation = translation!("es", inline { es = "Hola {author}", },, author,);
+    assert_eq!(translation, "Hola Juan");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,24 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_format_specs() {
+    let translation = translation!("en", static stats::ratio, ratio = 1.23456, count = 12);
+
+    assert_eq!(translation, "Ratio of 1.23 over   12 games, 1.23456 exactly.");
+
+    let translation = translation!(Language::ES, static stats::ratio, ratio = 0.5, count = 3)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Ratio de 0.50 en    3 partidas, 0.5 exacto.");
+
+    // the specs of dynamic paths are not known in compile time.
+    let translation = translation!("en", vec!["stats", "ratio"], ratio = 0.5, count = 3)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Ratio of 0.5 over 3 games, 0.5 exactly.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_references() {
+    assert_eq!(translation!("en", static brand::welcome, user = "Ana"), "Welcome to Acme, Ana.");
+    assert_eq!(
+        translation!("es", static brand::footer, user = "Ana"),
+        "Bienvenido a Acme, Ana. Gracias."
+    );
+
+    let translation = translation!(Language::EN, vec!["brand", "footer"], user = "Ana")
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Welcome to Acme, Ana. Thanks.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,26 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_shorthand() {
+    let author = "Juan";
+    let target = "Marie";
+
+    // each shorthand is bound to the variable with the same name.
+    let translation = translation!("es", static auditory::actions::delete_user, author, target);
+    assert_eq!(translation, "Juan ha borrado al usuario Marie.");
+
+    // shorthands can be mixed with explicit replacements.
+    let translation =
+        translation!("en", static auditory::actions::delete_user, author, target = "Josh");
+    assert_eq!(translation, "Juan deleted the user Josh.");
+
+    // typed templates constrain shorthands the same way.
+    let count = 3;
+    let translation = translation!(Language::EN, static inbox::unread, count);
+    assert_eq!(translation.unwrap(), "You have 3 unread messages.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
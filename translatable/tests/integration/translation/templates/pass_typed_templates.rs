@@ -0,0 +1,20 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation};
+
+#[cfg(test)]
+#[test]
+pub fn pass_typed_templates() {
+    let count = 3u32;
+
+    let translation = translation!("en", static inbox::unread, count);
+
+    assert_eq!(translation, "You have 3 unread messages.");
+
+    let translation = translation!(Language::ES, static inbox::unread, count = &count)
+        .expect("Expected translation generation to be OK");
+
+    assert_eq!(translation, "Tienes 3 mensajes sin leer.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
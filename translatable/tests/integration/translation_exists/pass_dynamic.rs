@@ -0,0 +1,14 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_exists};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic() {
+    assert!(translation_exists!(Language::ES, vec!["greetings", "formal"]));
+    assert!(translation_exists!(Language::NB, vec!["greetings", "norwegian"]));
+    assert!(!translation_exists!(Language::FR, vec!["greetings", "formal"]));
+    assert!(!translation_exists!("en", vec!["non", "existing", "path"]));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,25 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_exists};
+
+#[cfg(test)]
+#[test]
+pub fn pass_static() {
+    // fully static checks are resolved in compile time.
+    const {
+        assert!(translation_exists!("es", static greetings::formal));
+        assert!(!translation_exists!("fr", static greetings::formal));
+
+        // falls back to the language parents.
+        assert!(translation_exists!("nb", static greetings::norwegian));
+
+        // absent paths are not an error.
+        assert!(!translation_exists!("en", static non::existing::path));
+    }
+
+    let language = Language::EN;
+    assert!(translation_exists!(language, static greetings::formal));
+    assert!(!translation_exists!(Language::FR, static greetings::formal));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_hash;
+
+#[allow(dead_code)]
+const VERSION: &str = translation_hash!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_const() {
+    assert_eq!(VERSION.len(), 16);
+    assert!(
+        VERSION
+            .chars()
+            .all(|char| char.is_ascii_hexdigit() && !char.is_ascii_uppercase())
+    );
+
+    assert_eq!(VERSION, translation_hash!());
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
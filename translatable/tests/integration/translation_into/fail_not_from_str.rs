@@ -0,0 +1,9 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_into;
+
+struct Greeting;
+
+#[allow(unused)]
+fn main() {
+    let _greeting: Result<Greeting, _> = translation_into!("en", static greetings::formal);
+}
@@ -0,0 +1,2 @@
+pub mod pass_dynamic;
+pub mod pass_static;
@@ -0,0 +1,25 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation_into};
+
+#[allow(dead_code)]
+fn max_items(language: Language) -> Result<u32, Error> {
+    let max_items: u32 = translation_into!(language, vec!["limits", "max_items"])?;
+
+    Ok(max_items)
+}
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic() {
+    assert_eq!(max_items(Language::ES).unwrap(), 20);
+    assert_eq!(max_items(Language::EN).unwrap(), 10);
+
+    assert!(matches!(max_items(Language::FR), Err(Error::LanguageNotAvailable(..))));
+
+    let error = translation_into!(Language::EN, static greetings::formal).map(|count: i8| count);
+
+    assert!(matches!(error, Err(Error::TranslationParse(..))));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
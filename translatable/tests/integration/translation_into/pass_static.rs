@@ -0,0 +1,31 @@
+#[allow(unused_imports)] // trybuild
+use ::{std::num::ParseIntError, translatable::Error, translatable::translation_into};
+
+#[allow(dead_code)]
+fn max_items() -> Result<(u32, u64), Error> {
+    Ok((
+        translation_into!("es", static limits::max_items)?,
+        translation_into!("en", static limits::max_items)?,
+    ))
+}
+
+#[cfg(test)]
+#[test]
+pub fn pass_static() {
+    assert_eq!(max_items().unwrap(), (20, 10));
+
+    let error = translation_into!("en", static greetings::formal).map(|count: u32| count);
+
+    assert!(
+        matches!(&error, Err(Error::TranslationParse(value, _)) if value == "Nice to meet you.")
+    );
+
+    // the parse error is kept as the source.
+    assert!(matches!(
+        error,
+        Err(Error::TranslationParse(_, source)) if source.is::<ParseIntError>()
+    ));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
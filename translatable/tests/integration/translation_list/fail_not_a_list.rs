@@ -0,0 +1,6 @@
+#[allow(unused_imports)]
+use translatable::translation_list;
+
+fn main() {
+    let _ = translation_list!("en", static greetings::formal);
+}
@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Error, Language, translation_list};
+
+#[cfg(test)]
+#[test]
+pub fn pass_dynamic() {
+    let steps =
+        translation_list!(Language::EN, static onboarding::steps, email = "user@example.com");
+
+    assert_eq!(
+        steps.expect("List to be available."),
+        vec!["Create an account.".to_string(), "Verify user@example.com.".to_string()]
+    );
+
+    let tips = translation_list!(Language::FR, static onboarding::tips);
+
+    assert!(matches!(tips, Err(Error::LanguageNotAvailable(..))));
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,21 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_list;
+
+#[cfg(test)]
+#[test]
+pub fn pass_static() {
+    let email = "user@example.com";
+    let steps = translation_list!("es", static onboarding::steps, email);
+
+    assert_eq!(
+        steps,
+        vec!["Crea una cuenta.".to_string(), "Verifica user@example.com.".to_string()]
+    );
+
+    let tips = translation_list!("en", static onboarding::tips);
+
+    assert_eq!(tips, vec!["Explore the menu.".to_string()]);
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,8 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_module};
+
+translation_module!(inbox);
+
+fn main() {
+    let _ = inbox::unread(&Language::EN, "three");
+}
@@ -0,0 +1,6 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_module;
+
+translation_module!(greetings::formal);
+
+fn main() {}
@@ -0,0 +1,23 @@
+#[allow(unused_imports)] // trybuild
+use translatable::{Language, translation_module};
+
+translation_module!(greetings);
+translation_module!(pub static errors);
+translation_module!(inbox);
+
+#[cfg(test)]
+#[test]
+pub fn pass_module() {
+    assert_eq!(greetings::formal(&Language::ES).unwrap(), "Bueno conocerte.");
+    assert_eq!(greetings::informal(&Language::EN, "John").unwrap(), "What's good John?");
+    assert_eq!(greetings::norwegian(&Language::NB).unwrap(), "Hyggelig å møte deg.");
+    assert!(greetings::formal(&Language::FR).is_err());
+
+    assert_eq!(errors::_404(&Language::EN).unwrap(), "Page not found.");
+    assert_eq!(errors::sign_in(&Language::ES).unwrap(), "No se pudo iniciar sesión.");
+
+    assert_eq!(inbox::unread(&Language::EN, 3).unwrap(), "You have 3 unread messages.");
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -0,0 +1,6 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_paths;
+
+fn main() {
+    let _ = translation_paths!(static greetings);
+}
@@ -0,0 +1,18 @@
+#[allow(unused_imports)] // trybuild
+use translatable::translation_paths;
+
+#[allow(dead_code)]
+const PATHS: &[&str] = translation_paths!();
+
+#[cfg(test)]
+#[test]
+pub fn pass_const() {
+    assert!(PATHS.contains(&"greetings::formal"));
+    assert!(PATHS.contains(&"auditory::actions::delete_user"));
+    assert!(PATHS.contains(&"farewells::formal"));
+
+    assert!(PATHS.is_sorted());
+}
+
+#[allow(dead_code)]
+fn main() {} // trybuild
@@ -7,7 +7,26 @@ use trybuild::TestCases;
 mod integration;
 
 const PATH_ENV: &str = "TRANSLATABLE_LOCALES_PATH";
+const OVERLAY_ENV: &str = "TRANSLATABLE_OVERLAY";
 const OVERLAP_ENV: &str = "TRANSLATABLE_OVERLAP";
+const EMBED_LANGUAGES_ENV: &str = "TRANSLATABLE_EMBED_LANGUAGES";
+const DEFAULT_LANGUAGE_ENV: &str = "TRANSLATABLE_DEFAULT_LANGUAGE";
+const NAMESPACE_BY_FILE_ENV: &str = "TRANSLATABLE_NAMESPACE_BY_FILE";
+const MISSING_REPLACEMENTS_ENV: &str = "TRANSLATABLE_MISSING_REPLACEMENTS";
+const MISSING_ENV: &str = "TRANSLATABLE_MISSING";
+const PLACEHOLDER_OPEN_ENV: &str = "TRANSLATABLE_PLACEHOLDER_OPEN";
+const PLACEHOLDER_CLOSE_ENV: &str = "TRANSLATABLE_PLACEHOLDER_CLOSE";
+const PATH_SEPARATOR_ENV: &str = "TRANSLATABLE_PATH_SEPARATOR";
+const ALLOW_EMPTY_ENV: &str = "TRANSLATABLE_ALLOW_EMPTY";
+const ALLOW_MISSING_LOCALES_ENV: &str = "TRANSLATABLE_ALLOW_MISSING_LOCALES";
+const CASE_INSENSITIVE_PATHS_ENV: &str = "TRANSLATABLE_CASE_INSENSITIVE_PATHS";
+const BIDI_ISOLATE_ENV: &str = "TRANSLATABLE_BIDI_ISOLATE";
+const ESCAPE_HTML_ENV: &str = "TRANSLATABLE_ESCAPE_HTML";
+const PSEUDOLOCALE_ENV: &str = "TRANSLATABLE_PSEUDOLOCALE";
+const COW_OUTPUT_ENV: &str = "TRANSLATABLE_COW_OUTPUT";
+const LITERAL_OUTPUT_ENV: &str = "TRANSLATABLE_LITERAL_OUTPUT";
+const FALLBACK_RULES_ENV: &str = "TRANSLATABLE_FALLBACK_RULES";
+const PRINT_CONFIG_ENV: &str = "TRANSLATABLE_PRINT_CONFIG";
 
 static ENV_MUTEX: Mutex<()> = Mutex::new(());
 
@@ -21,7 +40,26 @@ macro_rules! lock_env {
 unsafe fn set_default_env() {
     unsafe {
         set_locales_env("everything_valid");
+        remove_var(OVERLAY_ENV);
         remove_var(OVERLAP_ENV);
+        remove_var(EMBED_LANGUAGES_ENV);
+        remove_var(DEFAULT_LANGUAGE_ENV);
+        remove_var(NAMESPACE_BY_FILE_ENV);
+        remove_var(MISSING_REPLACEMENTS_ENV);
+        remove_var(MISSING_ENV);
+        remove_var(PLACEHOLDER_OPEN_ENV);
+        remove_var(PLACEHOLDER_CLOSE_ENV);
+        remove_var(PATH_SEPARATOR_ENV);
+        remove_var(ALLOW_EMPTY_ENV);
+        remove_var(ALLOW_MISSING_LOCALES_ENV);
+        remove_var(CASE_INSENSITIVE_PATHS_ENV);
+        remove_var(BIDI_ISOLATE_ENV);
+        remove_var(ESCAPE_HTML_ENV);
+        remove_var(PSEUDOLOCALE_ENV);
+        remove_var(COW_OUTPUT_ENV);
+        remove_var(LITERAL_OUTPUT_ENV);
+        remove_var(FALLBACK_RULES_ENV);
+        remove_var(PRINT_CONFIG_ENV);
     }
 }
 
@@ -45,6 +83,9 @@ fn valid_environment() {
         set_default_env();
         set_locales_env("everything_valid");
 
+        t.pass("./tests/integration/translation/inline/pass*.rs");
+        t.compile_fail("./tests/integration/translation/inline/fail*.rs");
+
         t.pass("./tests/integration/translation/language/pass*.rs");
         t.compile_fail("./tests/integration/translation/language/fail*.rs");
 
@@ -56,6 +97,37 @@ fn valid_environment() {
 
         t.pass("./tests/integration/context/pass*.rs");
         t.compile_fail("./tests/integration/context/fail*.rs");
+
+        t.pass("./tests/integration/available_languages/pass*.rs");
+        t.compile_fail("./tests/integration/available_languages/fail*.rs");
+
+        t.pass("./tests/integration/localized/pass*.rs");
+        t.compile_fail("./tests/integration/localized/fail*.rs");
+
+        t.pass("./tests/integration/subtree/pass*.rs");
+        t.compile_fail("./tests/integration/subtree/fail*.rs");
+
+        t.pass("./tests/integration/translation_exists/pass*.rs");
+
+        t.pass("./tests/integration/translation_hash/pass*.rs");
+
+        t.pass("./tests/integration/translation_into/pass*.rs");
+        t.compile_fail("./tests/integration/translation_into/fail*.rs");
+
+        t.pass("./tests/integration/translation_list/pass*.rs");
+        t.compile_fail("./tests/integration/translation_list/fail*.rs");
+
+        t.pass("./tests/integration/translation_module/pass*.rs");
+        t.compile_fail("./tests/integration/translation_module/fail*.rs");
+
+        t.pass("./tests/integration/translation_paths/pass*.rs");
+        t.compile_fail("./tests/integration/translation_paths/fail*.rs");
+
+        t.pass("./tests/integration/translatable_enum/pass*.rs");
+        t.compile_fail("./tests/integration/translatable_enum/fail*.rs");
+
+        t.pass("./tests/integration/replacements/pass*.rs");
+        t.compile_fail("./tests/integration/replacements/fail*.rs");
     }
 }
 
@@ -104,3 +176,527 @@ fn translations_malformed() {
         t.compile_fail("./tests/integration/config/fail_translations_malformed.rs");
     }
 }
+
+#[test]
+fn reference_cycle() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("reference_cycle");
+
+        // translations referencing each other.
+        t.compile_fail("./tests/integration/config/fail_reference_cycle.rs");
+    }
+}
+
+#[test]
+fn shadowed_path() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("shadowed_path");
+
+        // a translation and a nesting sharing a path across files.
+        t.compile_fail("./tests/integration/config/fail_shadowed_path.rs");
+    }
+}
+
+#[test]
+fn placeholder_mismatch() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("placeholder_mismatch");
+
+        // languages using different placeholders.
+        t.compile_fail("./tests/integration/config/fail_placeholder_mismatch.rs");
+    }
+}
+
+#[test]
+fn empty_translations_allowed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("empty_translations");
+
+        // empty translations are allowed by default.
+        t.pass("./tests/integration/config/pass_empty_translation_allowed.rs");
+    }
+}
+
+#[test]
+fn empty_translations_disallowed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("empty_translations");
+        set_var(ALLOW_EMPTY_ENV, "false");
+
+        // empty translations reported with `allow_empty` disabled.
+        t.compile_fail("./tests/integration/config/fail_empty_translation.rs");
+    }
+}
+
+#[test]
+fn missing_locales_allowed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PATH_ENV, "./nonexistent/translations/");
+        set_var(ALLOW_MISSING_LOCALES_ENV, "true");
+
+        // a missing translations directory loads no translations.
+        t.pass("./tests/integration/config/pass_missing_locales_allowed.rs");
+    }
+}
+
+#[test]
+fn missing_locales_disallowed() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PATH_ENV, "./nonexistent/translations/");
+
+        // a missing translations directory is an error by default.
+        t.compile_fail("./tests/integration/config/fail_missing_locales.rs");
+    }
+}
+
+#[test]
+fn glob_translations_path() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(
+            PATH_ENV,
+            canonicalize("./tests/environments/glob_selection/translations/")
+                .unwrap()
+                .join("**/*.locale.toml"),
+        );
+
+        // only files matching the glob pattern are loaded.
+        t.pass("./tests/integration/config/pass_glob_selection.rs");
+    }
+}
+
+#[test]
+fn glob_translations_path_no_matches() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PATH_ENV, "./nonexistent/**/*.locale.toml");
+
+        // a glob pattern without matches is an error.
+        t.compile_fail("./tests/integration/config/fail_glob_no_matches.rs");
+    }
+}
+
+#[test]
+fn overlap() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+        set_var(OVERLAP_ENV, "ignore");
+
+        // the first found translation is kept.
+        t.pass("./tests/integration/config/pass_overlap_ignore.rs");
+    }
+
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+        set_var(OVERLAP_ENV, "overwrite");
+
+        // the last found translation is kept.
+        t.pass("./tests/integration/config/pass_overlap_overwrite.rs");
+    }
+}
+
+#[test]
+fn overlay() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+        set_var(OVERLAP_ENV, "ignore");
+        set_var(OVERLAY_ENV, canonicalize("./tests/environments/overlapping/overlay/").unwrap());
+
+        // the overlay translations overwrite the base ones.
+        t.pass("./tests/integration/config/pass_overlay.rs");
+    }
+
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("overlapping");
+        set_var(OVERLAP_ENV, "ignore");
+        set_var(
+            OVERLAY_ENV,
+            canonicalize("./tests/environments/overlapping/overlay/")
+                .unwrap()
+                .join("*.toml"),
+        );
+
+        // the overlay may be a glob pattern too.
+        t.pass("./tests/integration/config/pass_overlay.rs");
+    }
+}
+
+#[test]
+fn embed_languages() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(EMBED_LANGUAGES_ENV, "es");
+
+        // only the embedded languages are available in runtime.
+        t.pass("./tests/integration/config/pass_embed_languages.rs");
+    }
+}
+
+#[test]
+fn default_language() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(DEFAULT_LANGUAGE_ENV, "es");
+
+        // the language may be omitted.
+        t.pass("./tests/integration/config/pass_default_language.rs");
+    }
+}
+
+#[test]
+fn default_language_missing() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+
+        // the language can't be omitted without a default.
+        t.compile_fail("./tests/integration/config/fail_default_language_missing.rs");
+    }
+}
+
+#[test]
+fn embed_languages_invalid() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(EMBED_LANGUAGES_ENV, "es,invalid");
+
+        // invalid language in the embedded languages.
+        t.compile_fail("./tests/integration/config/fail_embed_languages_invalid.rs");
+    }
+}
+
+#[test]
+fn namespace_by_file() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("namespaced");
+        set_var(NAMESPACE_BY_FILE_ENV, "true");
+
+        // paths are prefixed with their file name.
+        t.pass("./tests/integration/config/pass_namespace_by_file.rs");
+    }
+}
+
+#[test]
+fn missing_replacements_error() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(MISSING_REPLACEMENTS_ENV, "error");
+
+        // templates without a value are reported.
+        t.pass("./tests/integration/config/pass_missing_replacements_error.rs");
+        t.compile_fail("./tests/integration/config/fail_missing_replacements_error.rs");
+        t.compile_fail("./tests/integration/config/fail_missing_replacements_spread.rs");
+    }
+}
+
+#[test]
+fn placeholder_delimiters() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_locales_env("custom_delimiters");
+        set_var(PLACEHOLDER_OPEN_ENV, "%{");
+
+        // templates are delimited by the configured delimiters.
+        t.pass("./tests/integration/config/pass_placeholder_delimiters.rs");
+    }
+}
+
+#[test]
+fn placeholder_delimiters_invalid() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PLACEHOLDER_OPEN_ENV, "%");
+        set_var(PLACEHOLDER_CLOSE_ENV, "%");
+
+        // both delimiters can't be the same.
+        t.compile_fail("./tests/integration/config/fail_placeholder_delimiters_invalid.rs");
+    }
+}
+
+#[test]
+fn path_separator() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PATH_SEPARATOR_ENV, ".");
+
+        // dynamic paths split by the configured separator.
+        t.pass("./tests/integration/config/pass_path_separator.rs");
+    }
+}
+
+#[test]
+fn case_insensitive_paths() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(CASE_INSENSITIVE_PATHS_ENV, "true");
+
+        // paths matched regardless of their case.
+        t.pass("./tests/integration/config/pass_case_insensitive_paths.rs");
+    }
+}
+
+#[test]
+fn bidi_isolate() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(BIDI_ISOLATE_ENV, "true");
+
+        // replaced values isolated in right-to-left translations.
+        t.pass("./tests/integration/config/pass_bidi_isolate.rs");
+    }
+}
+
+#[test]
+fn escape_html() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(ESCAPE_HTML_ENV, "true");
+
+        // replaced values are html-escaped.
+        t.pass("./tests/integration/config/pass_escape_html.rs");
+    }
+}
+
+#[test]
+fn pseudolocale() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PSEUDOLOCALE_ENV, "true");
+
+        // translations are pseudolocalized.
+        t.pass("./tests/integration/config/pass_pseudolocale.rs");
+    }
+}
+
+#[test]
+fn cow_output() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(COW_OUTPUT_ENV, "true");
+
+        // translations are borrowed unless replaced.
+        t.pass("./tests/integration/config/pass_cow_output.rs");
+    }
+}
+
+#[test]
+fn literal_output() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+
+        // static translations without templates may initialize constants.
+        t.pass("./tests/integration/config/pass_literal_output.rs");
+        t.compile_fail("tests/integration/config/fail_literal_output_templates.rs");
+    }
+}
+
+#[test]
+fn literal_output_disabled() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(LITERAL_OUTPUT_ENV, "false");
+
+        // every static translation is a string when disabled.
+        t.pass("./tests/integration/config/pass_literal_output_disabled.rs");
+    }
+}
+
+#[test]
+fn cow_output_strict() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(COW_OUTPUT_ENV, "true");
+        set_var(MISSING_REPLACEMENTS_ENV, "error");
+
+        // the strict check doesn't allocate.
+        t.pass("./tests/integration/config/pass_cow_output_strict.rs");
+    }
+}
+
+#[test]
+fn missing_translations() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(MISSING_ENV, "marker");
+
+        // missing translations rendered as a marker.
+        t.pass("./tests/integration/config/pass_missing_marker.rs");
+    }
+
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(MISSING_ENV, "key");
+
+        // missing translations rendered as their path.
+        t.pass("./tests/integration/config/pass_missing_key.rs");
+    }
+}
+
+#[test]
+fn fallback_rules() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(FALLBACK_RULES_ENV, "fr*=de,es;*=en");
+
+        // languages fall back through the first matching rule.
+        t.pass("./tests/integration/config/pass_fallback_rules.rs");
+    }
+}
+
+#[test]
+fn print_config() {
+    unsafe {
+        let t = TestCases::new();
+
+        lock_env!();
+
+        set_default_env();
+        set_var(PRINT_CONFIG_ENV, "1");
+
+        // the resolved configuration is only printed.
+        t.pass("./tests/integration/config/pass_print_config.rs");
+    }
+}
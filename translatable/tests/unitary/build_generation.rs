@@ -0,0 +1,48 @@
+use std::env::{set_var, temp_dir};
+use std::fs::{canonicalize, read_to_string};
+
+use translatable::build::generate;
+
+#[test]
+pub fn generates_translation_accessors() {
+    unsafe {
+        set_var(
+            "TRANSLATABLE_LOCALES_PATH",
+            canonicalize("./tests/environments/everything_valid/translations/").unwrap(),
+        );
+    }
+
+    let path = temp_dir().join(format!("translatable-build-{}.rs", std::process::id()));
+    generate(&path).expect("Translations to be generated.");
+
+    let generated = read_to_string(&path).unwrap();
+    let file = syn::parse_file(&generated).expect("Generated file to be valid Rust.");
+
+    let names = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            syn::Item::Static(item) => Some(
+                item.ident
+                    .to_string(),
+            ),
+            syn::Item::Mod(item) => Some(
+                item.ident
+                    .to_string(),
+            ),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert!(names.contains(&"TRANSLATIONS".to_string()));
+    assert!(names.contains(&"greetings".to_string()));
+    assert!(generated.contains("pub fn formal (language : & translatable :: Language ,)"));
+    assert!(generated.contains(
+        "pub fn informal (language : & translatable :: Language , user : impl std :: fmt :: \
+         Display)"
+    ));
+    assert!(generated.contains(
+        "pub fn sent (language : & translatable :: Language , arg0 : impl std :: fmt :: Display , \
+         arg1 : impl std :: fmt :: Display)"
+    ));
+}
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
-use quote::quote;
+use quote::{ToTokens, quote};
+use toml_edit::DocumentMut;
 use translatable_shared::macros::collections::{map_to_tokens, map_transform_to_tokens};
+use translatable_shared::translations::node::TranslationNode;
 
 #[test]
 pub fn map_to_tokens_has_literals() {
@@ -36,3 +38,62 @@ pub fn map_transform_to_tokens_has_literals() {
 
     assert!(tokens.contains("vec![(\"key1\",1i32.to_string())]"));
 }
+
+#[test]
+pub fn map_tokens_are_deterministic() {
+    let keys = (0..64)
+        .map(|index| format!("key{index}"))
+        .collect::<Vec<_>>();
+
+    let forward = keys
+        .iter()
+        .map(|key| (key.clone(), key.len()))
+        .collect::<HashMap<_, _>>();
+    let backward = keys
+        .iter()
+        .rev()
+        .map(|key| (key.clone(), key.len()))
+        .collect::<HashMap<_, _>>();
+
+    assert_eq!(map_to_tokens(&forward).to_string(), map_to_tokens(&backward).to_string());
+    assert_eq!(
+        map_transform_to_tokens(&forward, |key, value| quote! { (#key, #value) }).to_string(),
+        map_transform_to_tokens(&backward, |key, value| quote! { (#key, #value) }).to_string()
+    );
+}
+
+#[test]
+pub fn translation_tokens_are_deterministic() {
+    let content = r#"
+        [greetings.formal]
+        en = "Nice to meet you."
+        es = "Bueno conocerte."
+        fr = "Enchanté."
+        de = "Freut mich."
+        it = "Piacere."
+        pt = "Prazer."
+
+        [greetings.informal]
+        en = "Hey"
+
+        [farewells.formal]
+        en = "Goodbye."
+    "#;
+
+    let tokens = || {
+        TranslationNode::try_from(
+            content
+                .parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+        .into_token_stream()
+        .to_string()
+    };
+
+    let expected = tokens();
+    for _ in 0..16 {
+        assert_eq!(tokens(), expected);
+    }
+}
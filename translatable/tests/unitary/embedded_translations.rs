@@ -0,0 +1,97 @@
+use std::collections::HashMap;
+
+use translatable::shared::data::translations::TranslationDataError;
+use translatable::{Error, ErrorKind, Language, load_translations, translate_with};
+
+const BASE: &str = r#"
+[greetings.formal]
+es = "Hola {name}"
+en = "Hello {name}"
+
+[greetings.brand]
+en = "Welcome to @{brand::name}"
+"#;
+
+const VENDOR: &str = r#"
+[greetings.formal]
+en = "Hi {name}"
+fr = "Bonjour {name}"
+
+[brand]
+name.en = "Translatable"
+"#;
+
+fn path(path: &str) -> Vec<String> {
+    path.split("::")
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+pub fn loads_embedded_translations() {
+    let translations = load_translations([
+        ("translations/vendor/brand.toml", VENDOR),
+        ("translations/base.toml", BASE),
+        ("translations/README.md", "# Not a translation file."),
+    ])
+    .expect("Translations to be loaded.");
+
+    assert_eq!(
+        translations.sources(),
+        vec!["translations/base.toml", "translations/vendor/brand.toml"]
+    );
+
+    let replacements = HashMap::from([("name".to_string(), "John".to_string())]);
+    let translate = |language: Language, path_display: &str| {
+        translate_with(&translations, language, &path(path_display), &replacements).ok()
+    };
+
+    // the first file in alphabetical order wins per language.
+    assert_eq!(translate(Language::EN, "greetings::formal"), Some("Hello John".into()));
+    assert_eq!(translate(Language::FR, "greetings::formal"), Some("Bonjour John".into()));
+    assert_eq!(translate(Language::EN, "greetings::brand"), Some("Welcome to Translatable".into()));
+}
+
+#[test]
+pub fn reports_embedded_errors() {
+    let error = load_translations([("a.toml", "[greetings\nen = \"Hi\"")])
+        .err()
+        .expect("Malformed TOML not to be loaded.");
+
+    assert_eq!(error.kind(), ErrorKind::EmbeddedTranslation);
+    assert!(
+        error
+            .cause()
+            .starts_with("The embedded translation file 'a.toml' couldn't be loaded")
+    );
+
+    // the loading error is kept as the source.
+    assert!(matches!(
+        error,
+        Error::EmbeddedTranslation(_, ref source) if matches!(**source, TranslationDataError::File(_))
+    ));
+
+    let error = load_translations([("a.toml", "include = \"b.toml\"")])
+        .err()
+        .expect("Includes not to be resolved.");
+
+    assert_eq!(
+        error.cause(),
+        "The embedded translation file 'a.toml' couldn't be loaded: The include directive in \
+         a.toml can't be resolved, as the file is not read from disk"
+    );
+
+    // only the file with the missing reference is reported.
+    let error = load_translations([
+        ("a.toml", "[greetings.formal]\nen = \"Hello\""),
+        ("b.toml", "[greetings.welcome]\nen = \"@{greetings::informal}\""),
+    ])
+    .err()
+    .expect("Missing references not to be expanded.");
+
+    assert!(matches!(
+        error,
+        Error::EmbeddedTranslation(ref file, ref source)
+            if file == "b.toml" && matches!(**source, TranslationDataError::Node(_))
+    ));
+}
@@ -0,0 +1,59 @@
+use std::env::temp_dir;
+use std::fs::{File, remove_file, write};
+use std::time::{Duration, SystemTime};
+
+use translatable::hot_reload::translations;
+use translatable::shared::data::config::MacroConfig;
+use translatable::shared::data::translations::TranslationDataError;
+use translatable::{Error, Language};
+
+#[test]
+pub fn reloads_modified_files() {
+    let path = temp_dir().join(format!("translatable-hot-reload-{}.toml", std::process::id()));
+    let path_display = path
+        .to_str()
+        .unwrap()
+        .to_string();
+    let config = MacroConfig::from_sources("", &[]).unwrap();
+
+    let greeting = |content: &str| {
+        write(&path, content).unwrap();
+
+        // coarse filesystems may not tell apart quick writes.
+        File::options()
+            .write(true)
+            .open(&path)
+            .unwrap()
+            .set_modified(SystemTime::now() + Duration::from_secs(content.len() as u64))
+            .unwrap();
+
+        translations(&[(&path_display, true)], &config)
+            .unwrap()
+            .find_path(&vec!["greetings", "formal"])
+            .unwrap()
+            .get(&Language::EN)
+            .unwrap()
+            .original()
+            .to_string()
+    };
+
+    assert_eq!(greeting("[greetings.formal]\nen = \"Hello.\""), "Hello.");
+    assert_eq!(greeting("[greetings.formal]\nen = \"Nice to meet you.\""), "Nice to meet you.");
+
+    write(&path, "[greetings.formal]\nen = [[1]]").unwrap();
+    assert!(translations(&[(&path_display, true)], &config).is_err());
+
+    // validated the same way as in compile-time.
+    write(&path, "[greetings.formal]\nen = \"Hello {name}.\"\nes = \"Hola.\"").unwrap();
+    assert!(matches!(
+        translations(&[(&path_display, true)], &config),
+        Err(Error::TranslationReload(_, source))
+            if matches!(*source, TranslationDataError::PlaceholderMismatch(..))
+    ));
+
+    let config = MacroConfig::from_sources("allow_empty = false", &[]).unwrap();
+    write(&path, "[greetings.formal]\nen = \" \"").unwrap();
+    assert!(translations(&[(&path_display, true)], &config).is_err());
+
+    remove_file(&path).unwrap();
+}
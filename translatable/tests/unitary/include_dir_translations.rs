@@ -0,0 +1,27 @@
+use include_dir::{Dir, include_dir};
+use translatable::{Language, load_translations_dir, translate_with};
+
+static TRANSLATIONS: Dir =
+    include_dir!("$CARGO_MANIFEST_DIR/tests/environments/overlapping/translations");
+
+fn translate(language: Language, path: &str) -> Option<String> {
+    let translations = load_translations_dir(&TRANSLATIONS).expect("Translations to be loaded.");
+
+    let path = path
+        .split("::")
+        .map(String::from)
+        .collect::<Vec<_>>();
+
+    translate_with(&translations, language, &path, &Default::default()).ok()
+}
+
+#[test]
+pub fn loads_embedded_directory() {
+    let translations = load_translations_dir(&TRANSLATIONS).expect("Translations to be loaded.");
+
+    // files in subdirectories are collected too.
+    assert_eq!(translations.sources(), vec!["a.toml", "b.toml", "vendor/c.toml"]);
+
+    assert_eq!(translate(Language::ES, "greetings::formal"), Some("Hola desde a.".into()));
+    assert_eq!(translate(Language::EN, "greetings::farewell"), Some("Goodbye from c.".into()));
+}
@@ -1,4 +1,5 @@
 use quote::ToTokens;
+use strum::IntoEnumIterator;
 use translatable::Language;
 
 #[test]
@@ -10,6 +11,66 @@ pub fn language_enum_parsing_case_insensitive() {
     assert!(language_upper.is_ok());
 }
 
+#[test]
+pub fn language_enum_parsing_forms() {
+    for input in ["en", "EN", "eng", "ENG", "english", "English", "ENGLISH"] {
+        assert_eq!(
+            input
+                .parse::<Language>()
+                .ok(),
+            Some(Language::EN),
+            "parsing '{input}'"
+        );
+    }
+
+    assert_eq!(
+        "deu"
+            .parse::<Language>()
+            .ok(),
+        Some(Language::DE)
+    );
+    assert_eq!(
+        "ger"
+            .parse::<Language>()
+            .ok(),
+        Some(Language::DE)
+    );
+    assert_eq!(
+        "Norwegian Bokmål"
+            .parse::<Language>()
+            .ok(),
+        Some(Language::NB)
+    );
+}
+
+#[test]
+pub fn language_enum_parsing_aliases() {
+    assert_eq!(
+        "farsi"
+            .parse::<Language>()
+            .ok(),
+        Some(Language::FA)
+    );
+    assert_eq!(
+        "Castilian"
+            .parse::<Language>()
+            .ok(),
+        Some(Language::ES)
+    );
+}
+
+#[test]
+pub fn language_enum_parsing_invalid() {
+    for input in ["", "e", "engl", "xx", "xxx", " en", "en-US"] {
+        assert!(
+            input
+                .parse::<Language>()
+                .is_err(),
+            "parsing '{input}'"
+        );
+    }
+}
+
 #[test]
 pub fn language_enum_to_tokens() {
     let language_tokens = Language::ES
@@ -24,3 +85,95 @@ pub fn language_enum_to_tokens() {
 pub fn display_matches() {
     assert_eq!(Language::ES.to_string(), "Spanish");
 }
+
+#[test]
+pub fn fallback_parent_lookup() {
+    assert_eq!(Language::NB.fallback_parent(), Some(Language::NO));
+    assert_eq!(Language::NN.fallback_parent(), Some(Language::NO));
+    assert_eq!(Language::NO.fallback_parent(), None);
+    assert_eq!(Language::ES.fallback_parent(), None);
+}
+
+#[test]
+pub fn display_and_native_names() {
+    assert_eq!(Language::ES.display_name(), "Spanish");
+    assert_eq!(Language::ES.native_name(), "Español");
+    assert_eq!(Language::NB.native_name(), "Norsk bokmål");
+}
+
+#[test]
+pub fn language_enum_rtl() {
+    assert!(Language::AR.is_rtl());
+    assert!(Language::HE.is_rtl());
+    assert!(!Language::EN.is_rtl());
+    assert!(!Language::ZH.is_rtl());
+}
+
+#[test]
+pub fn language_enum_custom_tags() {
+    assert_eq!(Language::from_tag("en-xa").ok(), Some(Language::Other("en-XA".into())));
+    assert_eq!(Language::from_tag("x-pirate").ok(), Some(Language::Other("x-pirate".into())));
+    assert_eq!(Language::from_tag("qaa").ok(), Some(Language::Other("qaa".into())));
+    assert_eq!(Language::from_tag("es").ok(), Some(Language::ES));
+
+    for input in ["", "xx", "esp", "en-", "en-x", "en-toolongsubtag"] {
+        assert!(Language::from_tag(input).is_err(), "parsing '{input}'");
+    }
+
+    assert!(
+        "en-XA"
+            .parse::<Language>()
+            .is_err()
+    );
+}
+
+#[test]
+pub fn language_enum_custom_tag_behavior() {
+    let language = Language::Other("en-XA".into());
+
+    assert_eq!(language.to_string(), "en-XA");
+    assert_eq!(language.code(), "en-XA");
+    assert_eq!(language.display_name(), "en-XA");
+    assert_eq!(language.fallback_parent(), Some(Language::EN));
+    assert!(!language.is_rtl());
+    assert!(Language::Other("ar-XB".into()).is_rtl());
+    assert_eq!(Language::Other("x-pirate".into()).fallback_parent(), None);
+
+    let language_tokens = language
+        .into_token_stream()
+        .to_string()
+        .replace(" ", ""); // normalize the path.
+
+    assert!(language_tokens.contains("Language::Other(String::from(\"en-XA\"))"));
+}
+
+#[test]
+pub fn language_enum_all() {
+    let languages = Language::all();
+
+    assert_eq!(languages.len(), 183);
+    assert_eq!(languages.first(), Some(&Language::AB));
+    assert!(languages.contains(&Language::ES));
+    assert!(
+        !languages
+            .iter()
+            .any(|language| matches!(language, Language::Other(_)))
+    );
+    assert!(
+        languages
+            .windows(2)
+            .all(|pair| pair[0] < pair[1])
+    );
+
+    // custom tags are not iterated either.
+    assert!(Language::iter().eq(languages.iter().cloned()));
+
+    for language in languages {
+        assert_eq!(
+            Language::from_tag(&language.code())
+                .ok()
+                .as_ref(),
+            Some(language)
+        );
+    }
+}
@@ -1,6 +1,20 @@
 pub mod collection_generation;
+pub mod embedded_translations;
 pub mod language_enum;
 pub mod runtime_error;
+pub mod suggestion;
 pub mod templating;
 pub mod translation_collection;
+pub mod translation_file;
+pub mod translation_node;
+pub mod translation_resolution;
+pub mod translation_source;
 pub mod display_to_error_tokens;
+#[cfg(feature = "build")]
+pub mod build_generation;
+#[cfg(feature = "hot-reload")]
+pub mod hot_reload;
+#[cfg(feature = "include_dir")]
+pub mod include_dir_translations;
+#[cfg(feature = "serde")]
+pub mod translation_cache;
@@ -1,14 +1,104 @@
-use translatable::{Error, Language};
+use std::error::Error as StdError;
+
+use translatable::shared::misc::templating::TemplateError;
+use translatable::{Error, ErrorKind, Language};
+
+fn path() -> Vec<String> {
+    vec!["path".into(), "to".into(), "translation".into()]
+}
 
 #[test]
 pub fn runtime_error_outputs() {
     assert_eq!(
-        Error::PathNotFound("path::to::translation".into()).cause(),
+        Error::PathNotFound(path(), None).cause(),
         "The path 'path::to::translation' could not be found"
     );
 
     assert_eq!(
-        Error::LanguageNotAvailable(Language::ES, "path::to::translation".into()).cause(),
+        Error::LanguageNotAvailable(Language::ES, path(), None).cause(),
         "The language 'ES' ('Spanish') is not available for the path 'path::to::translation'"
+    );
+
+    assert_eq!(
+        Error::SubtreePath(path(), vec!["formal".into(), "informal".into()]).cause(),
+        "The path 'path::to::translation' leads to a group of translations, available segments: \
+         formal, informal"
+    )
+}
+
+#[test]
+pub fn runtime_error_source_hints() {
+    assert_eq!(
+        Error::PathNotFound(path(), Some("./translations/path.toml".into())).cause(),
+        "The path 'path::to::translation' could not be found, closest match in \
+         ./translations/path.toml"
+    );
+
+    assert_eq!(
+        Error::LanguageNotAvailable(Language::ES, path(), Some("./translations/path.toml".into()))
+            .cause(),
+        "The language 'ES' ('Spanish') is not available for the path 'path::to::translation', \
+         defined in ./translations/path.toml"
     )
 }
+
+#[test]
+pub fn runtime_error_kinds() {
+    assert_eq!(Error::PathNotFound(path(), None).kind(), ErrorKind::PathNotFound);
+
+    assert_eq!(Error::SubtreePath(path(), vec!["formal".into()]).kind(), ErrorKind::SubtreePath);
+
+    assert_eq!(
+        Error::LanguageNotAvailable(Language::ES, path(), None).kind(),
+        ErrorKind::LanguageNotAvailable
+    );
+
+    assert_eq!(
+        Error::ContextFieldParse(path(), "ten".into(), "invalid digit".into()).kind(),
+        ErrorKind::ContextFieldParse
+    );
+
+    assert_eq!(
+        Error::TranslationParse("ten".into(), "invalid digit".into()).kind(),
+        ErrorKind::TranslationParse
+    );
+}
+
+#[test]
+pub fn runtime_error_values() {
+    let error = Error::LanguageNotAvailable(Language::ES, path(), None);
+    assert_eq!(error.path(), Some(path().as_slice()));
+    assert_eq!(error.language(), Some(&Language::ES));
+
+    let error = Error::PathNotFound(path(), None);
+    assert_eq!(error.path(), Some(path().as_slice()));
+    assert_eq!(error.language(), None);
+}
+
+#[test]
+pub fn runtime_error_source() {
+    fn assert_error<E: StdError + Send + Sync + 'static>(_: &E) {}
+
+    let error = Error::Replacement(TemplateError::InvalidIdent("1st".into()), path());
+    assert_error(&error);
+
+    assert_eq!(
+        error
+            .source()
+            .map(ToString::to_string),
+        Some("Found template with key '1st' which is an invalid identifier".into())
+    );
+
+    assert!(
+        Error::PathNotFound(path(), None)
+            .source()
+            .is_none()
+    );
+
+    assert_eq!(
+        Error::ContextFieldParse(path(), "ten".into(), "invalid digit".into())
+            .source()
+            .map(ToString::to_string),
+        Some("invalid digit".into())
+    );
+}
@@ -0,0 +1,16 @@
+use translatable_shared::misc::suggestion::closest_match;
+
+const CANDIDATES: &[&str] = &["path", "seek_mode", "overlap", "embed_languages"];
+
+#[test]
+pub fn suggests_closest_match() {
+    assert_eq!(closest_match("embed_langauges", CANDIDATES), Some("embed_languages"));
+    assert_eq!(closest_match("seekmode", CANDIDATES), Some("seek_mode"));
+    assert_eq!(closest_match("pth", CANDIDATES), Some("path"));
+}
+
+#[test]
+pub fn does_not_suggest_unrelated() {
+    assert_eq!(closest_match("fallback_language", CANDIDATES), None);
+    assert_eq!(closest_match("", CANDIDATES), None);
+}
@@ -1,7 +1,15 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 use std::str::FromStr;
 
-use translatable_shared::misc::templating::FormatString;
+use translatable::Language;
+use translatable_shared::misc::templating::{
+    Args,
+    FormatString,
+    PlaceholderDelimiters,
+    TemplateConstraint,
+    TemplateError,
+};
 
 #[test]
 pub fn does_not_replace_not_found() {
@@ -58,6 +66,38 @@ pub fn escapes_templates() {
     assert_eq!(result, "You write escaped templates like {{ this }}.")
 }
 
+#[test]
+pub fn replaces_custom_delimiters() {
+    let delimiters = PlaceholderDelimiters::new("{{", "}}").expect("Delimiters to be valid.");
+    let result = FormatString::parse_with("Hello {{name}}, {day} is {{ day }}", &delimiters)
+        .expect("Format string to be valid.");
+
+    assert_eq!(result.placeholders(), vec!["name", "day"]);
+    assert_eq!(
+        result.replace_with(&HashMap::from([
+            ("name".into(), "Josh".into()),
+            ("day".into(), "today".into()),
+        ])),
+        "Hello Josh, {day} is today"
+    );
+
+    let delimiters = PlaceholderDelimiters::new("%{", "}").expect("Delimiters to be valid.");
+    let result = FormatString::parse_with("%{%{escaped} and %{name=Guest}", &delimiters)
+        .expect("Format string to be valid.");
+
+    assert_eq!(result.replace_with(&HashMap::new()), "%{%{escaped} and Guest");
+    assert!(matches!(
+        FormatString::parse_with("Hello %{name", &delimiters),
+        Err(TemplateError::Unclosed(6))
+    ));
+}
+
+#[test]
+pub fn rejects_invalid_delimiters() {
+    assert!(PlaceholderDelimiters::new("", "}").is_none());
+    assert!(PlaceholderDelimiters::new("%", "%").is_none());
+}
+
 #[test]
 pub fn gives_original_string() {
     let result = FormatString::from_str("Hello {name} how are you doing {day}?")
@@ -65,3 +105,495 @@ pub fn gives_original_string() {
 
     assert_eq!(result.original(), "Hello {name} how are you doing {day}?");
 }
+
+#[test]
+pub fn lists_placeholders() {
+    let format_string =
+        FormatString::from_str("{b} and {a:int}, {{ escaped }} then {b, number} again")
+            .expect("Format string to be valid.");
+
+    assert_eq!(format_string.placeholders(), vec!["b".to_string(), "a".to_string()]);
+
+    let format_string =
+        FormatString::from_str("No templates {{ here }}").expect("Format string to be valid.");
+
+    assert!(
+        format_string
+            .placeholders()
+            .is_empty()
+    );
+}
+
+#[test]
+pub fn replaces_default_values() {
+    let format_string =
+        FormatString::from_str("Hello {name=Guest}, you have {count:int=0, number} {unit}")
+            .expect("Format string to be valid.");
+
+    assert_eq!(format_string.replace_with(&HashMap::new()), "Hello Guest, you have 0 {unit}");
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([
+            ("name".into(), "Josh".into()),
+            ("count".into(), "1200".into())
+        ])),
+        "Hello Josh, you have 1,200 {unit}"
+    );
+
+    assert_eq!(format_string.placeholders(), vec!["name", "count", "unit"]);
+    assert_eq!(format_string.required_placeholders(), vec!["unit"]);
+}
+
+#[test]
+pub fn fails_strict_missing_values() {
+    let format_string = FormatString::from_str("Hello {name}, welcome to {place=home}")
+        .expect("Format string to be valid.");
+
+    assert!(matches!(
+        format_string.replace_with_strict(&HashMap::new()),
+        Err(TemplateError::MissingValue(key)) if key == "name"
+    ));
+
+    assert_eq!(
+        format_string
+            .replace_with_strict(&HashMap::from([("name".into(), "Josh".into())]))
+            .expect("Every template to have a value."),
+        "Hello Josh, welcome to home"
+    );
+}
+
+#[test]
+pub fn parses_type_constraints() {
+    let format_string =
+        FormatString::from_str("{count:int} items for {name}").expect("Format string to be valid.");
+
+    assert_eq!(format_string.constraints(), &[("count".into(), TemplateConstraint::Int)]);
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([
+            ("count".into(), "2".into()),
+            ("name".into(), "Josh".into())
+        ])),
+        "2 items for Josh"
+    );
+}
+
+#[test]
+pub fn fails_unknown_type_constraint() {
+    let result = FormatString::from_str("{count:integer} items");
+
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn parses_format_specs() {
+    let format_string = FormatString::from_str("{pi:.2} {pi:float:>8.3} {pi} {count:int}")
+        .expect("Format string to be valid.");
+
+    assert_eq!(
+        format_string.format_specs(),
+        vec![("pi".into(), ".2".into()), ("pi".into(), ">8.3".into())]
+    );
+    assert_eq!(
+        format_string.constraints(),
+        &[("pi".into(), TemplateConstraint::Float), ("count".into(), TemplateConstraint::Int)]
+    );
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([
+            ("pi".into(), "3.14159".into()),
+            ("pi:.2".into(), "3.14".into()),
+            ("count".into(), "2".into())
+        ])),
+        "3.14 3.14159 3.14159 2"
+    );
+}
+
+#[test]
+pub fn fails_invalid_format_spec() {
+    assert!(FormatString::from_str("{pi:.} items").is_err());
+    assert!(FormatString::from_str("{pi:>8.2f} items").is_err());
+}
+
+#[test]
+pub fn formats_numbers_by_language() {
+    let format_string =
+        FormatString::from_str("{amount, number}").expect("Format string to be valid.");
+    let values = HashMap::from([("amount".into(), "1234567.5".into())]);
+
+    assert_eq!(
+        format_string
+            .clone()
+            .with_language(Language::EN)
+            .replace_with(&values),
+        "1,234,567.5"
+    );
+    assert_eq!(
+        format_string
+            .with_language(Language::DE)
+            .replace_with(&values),
+        "1.234.567,5"
+    );
+}
+
+#[test]
+pub fn formats_currencies_by_language() {
+    let format_string = FormatString::from_str("{price, currency} {price, currency, eur}")
+        .expect("Format string to be valid.");
+    let values = HashMap::from([("price".into(), "-1234567.5".into())]);
+
+    assert_eq!(
+        format_string
+            .clone()
+            .with_language(Language::EN)
+            .replace_with(&values),
+        "-1,234,567.50 €-1,234,567.50"
+    );
+    assert_eq!(
+        format_string
+            .with_language(Language::DE)
+            .replace_with(&values),
+        "-1.234.567,50 -1.234.567,50\u{a0}€"
+    );
+}
+
+#[test]
+pub fn rounds_currencies_without_losing_precision() {
+    let format_string =
+        FormatString::from_str("{price, currency}").expect("Format string to be valid.");
+    let replaced = |price: &str| {
+        format_string
+            .clone()
+            .with_language(Language::EN)
+            .replace_with(&HashMap::from([("price".into(), price.into())]))
+    };
+
+    assert_eq!(replaced("0.995"), "1.00");
+    assert_eq!(replaced("0.994"), "0.99");
+    assert_eq!(replaced("-2.675"), "-2.68");
+    assert_eq!(replaced("999.999"), "1,000.00");
+    assert_eq!(replaced("-0.001"), "0.00");
+    assert_eq!(replaced("12345678901234567890.125"), "12,345,678,901,234,567,890.13");
+    assert_eq!(replaced("1e3"), "1,000.00");
+
+    let result = FormatString::from_str("{amount, number}")
+        .expect("Format string to be valid.")
+        .replace_with(&HashMap::from([(
+            "amount".into(),
+            "123456789012345678901234567890.50".into(),
+        )]));
+
+    assert_eq!(result, "123,456,789,012,345,678,901,234,567,890.5");
+}
+
+#[test]
+pub fn keeps_non_numeric_values() {
+    let result = FormatString::from_str("{amount, number}")
+        .expect("Format string to be valid.")
+        .replace_with(&HashMap::from([("amount".into(), "many".into())]));
+
+    assert_eq!(result, "many");
+}
+
+#[test]
+pub fn fails_unknown_directive() {
+    let result = FormatString::from_str("{amount, percentage}");
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+pub fn formats_dates_by_language() {
+    let format_string =
+        FormatString::from_str("{when, date, short} | {when, date} | {when, date, long}")
+            .expect("Format string to be valid.");
+    let values = HashMap::from([("when".into(), "2024-01-02T10:00:00Z".into())]);
+
+    assert_eq!(
+        format_string
+            .clone()
+            .with_language(Language::EN)
+            .replace_with(&values),
+        "1/2/2024 | Jan 2, 2024 | January 2, 2024"
+    );
+    assert_eq!(
+        format_string
+            .with_language(Language::ES)
+            .replace_with(&values),
+        "2/1/2024 | 2 ene 2024 | 2 de enero de 2024"
+    );
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+pub fn formats_unix_timestamps() {
+    let result = FormatString::from_str("{when, date, long}")
+        .expect("Format string to be valid.")
+        .with_language(Language::DE)
+        .replace_with(&HashMap::from([("when".into(), "1704189600".into())]));
+
+    assert_eq!(result, "2. Januar 2024");
+}
+
+#[test]
+#[cfg(not(feature = "chrono"))]
+pub fn keeps_preformatted_dates() {
+    let result = FormatString::from_str("{when, date, long}")
+        .expect("Format string to be valid.")
+        .with_language(Language::EN)
+        .replace_with(&HashMap::from([("when".into(), "yesterday".into())]));
+
+    assert_eq!(result, "yesterday");
+}
+
+#[test]
+pub fn fails_unknown_date_style() {
+    let result = FormatString::from_str("{when, date, tiny}");
+
+    assert!(result.is_err());
+}
+
+#[test]
+pub fn isolates_rtl_values() {
+    let values = HashMap::from([("name".into(), "Josh".into())]);
+
+    let isolated = FormatString::from_str("مرحبا {name}")
+        .expect("Format string to be valid.")
+        .with_language(Language::AR)
+        .with_bidi_isolate(true);
+
+    assert_eq!(isolated.replace_with(&values), "مرحبا \u{2068}Josh\u{2069}");
+
+    let not_isolated = FormatString::from_str("مرحبا {name}")
+        .expect("Format string to be valid.")
+        .with_language(Language::AR);
+
+    assert_eq!(not_isolated.replace_with(&values), "مرحبا Josh");
+
+    let ltr = FormatString::from_str("Hello {name}")
+        .expect("Format string to be valid.")
+        .with_language(Language::EN)
+        .with_bidi_isolate(true);
+
+    assert_eq!(ltr.replace_with(&values), "Hello Josh");
+}
+
+#[test]
+pub fn parses_references() {
+    let format_string = FormatString::from_str("Welcome to @{ brand::name }, {user}. @{{escaped}}")
+        .expect("Format string to be valid.");
+
+    assert_eq!(format_string.references(), vec![&["brand".to_string(), "name".to_string()][..]]);
+    assert_eq!(format_string.placeholders(), vec!["user".to_string()]);
+}
+
+#[test]
+pub fn keeps_at_before_templates() {
+    let format_string =
+        FormatString::from_str("Write to support@{domain}").expect("Format string to be valid.");
+
+    assert!(
+        format_string
+            .references()
+            .is_empty()
+    );
+    assert_eq!(format_string.placeholders(), vec!["domain".to_string()]);
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([("domain".into(), "acme.com".into())])),
+        "Write to support@acme.com"
+    );
+}
+
+#[test]
+pub fn fails_empty_reference_segment() {
+    let result = FormatString::from_str("@{brand::}");
+
+    assert!(matches!(result, Err(TemplateError::InvalidReference(_))));
+}
+
+#[test]
+pub fn expands_references() {
+    let expanded = FormatString::from_str("Welcome to @{brand::name}, {user}.")
+        .expect("Format string to be valid.")
+        .expand_references::<TemplateError, _>(&PlaceholderDelimiters::default(), |reference| {
+            Ok(format!("{{{}}}", reference.join("_")))
+        })
+        .expect("References to be expanded.");
+
+    assert!(
+        expanded
+            .references()
+            .is_empty()
+    );
+    assert_eq!(
+        expanded.replace_with(&HashMap::from([
+            ("brand_name".into(), "Acme".into()),
+            ("user".into(), "Ana".into()),
+        ])),
+        "Welcome to Acme, Ana."
+    );
+}
+
+#[test]
+pub fn escapes_replaced_values() {
+    let format_string = FormatString::from_str("<b>{name=<Guest>}</b> {raw!bio} {count, number}")
+        .expect("Format string to be valid.")
+        .with_language(Language::EN);
+
+    let values = HashMap::from([
+        ("name".into(), "Tom & 'Jerry'".into()),
+        ("bio".into(), "<i>Hi</i>".into()),
+        ("count".into(), "1234".into()),
+    ]);
+
+    assert_eq!(
+        format_string.replace_with_escaped(&values),
+        "<b>Tom &amp; &#39;Jerry&#39;</b> <i>Hi</i> 1,234"
+    );
+    assert_eq!(format_string.replace_with(&values), "<b>Tom & 'Jerry'</b> <i>Hi</i> 1,234");
+
+    // defaults are written along the translation.
+    assert_eq!(
+        format_string
+            .with_escape_html(true)
+            .replace_with(&HashMap::new()),
+        "<b><Guest></b> {raw!bio} {count, number}"
+    );
+}
+
+#[test]
+pub fn pseudolocalizes_text() {
+    let format_string = FormatString::from_str("<b>Hi {name=Guest}</b>, {count, number} new")
+        .expect("Format string to be valid.")
+        .with_language(Language::EN)
+        .pseudolocalize();
+
+    assert_eq!(format_string.placeholders(), vec!["name".to_string(), "count".to_string()]);
+
+    // templates and tags are kept, along their defaults and directives.
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([("count".into(), "1234".into())])),
+        "[<b>Ĥî Guest</b>, 1,234 ñéŵ ~~]"
+    );
+
+    assert_eq!(
+        FormatString::from_str("")
+            .expect("Format string to be valid.")
+            .pseudolocalize()
+            .original(),
+        "[]"
+    );
+}
+
+#[test]
+pub fn replaces_with_cow() {
+    let format_string = FormatString::from_data("Hi {name}", vec![("name".into(), 3..9)]);
+
+    assert!(matches!(format_string.replace_with_cow(&HashMap::new()), Cow::Borrowed("Hi {name}")));
+    assert!(matches!(
+        format_string.replace_with_cow(&HashMap::from([("name".into(), "Ana".into())])),
+        Cow::Owned(ref owned) if owned == "Hi Ana"
+    ));
+
+    // defaults are replaced even without values.
+    let format_string =
+        FormatString::from_str("Hi {name=Guest}").expect("Format string to be valid.");
+
+    assert_eq!(format_string.replace_with_cow(&HashMap::new()), "Hi Guest");
+}
+
+#[test]
+pub fn replaces_with_args() {
+    let format_string = FormatString::from_str("{0} sent {count} messages to {1}")
+        .expect("Format string to be valid.");
+
+    assert_eq!(format_string.placeholders(), ["0", "count", "1"]);
+
+    let args = Args::new()
+        .arg("Josh")
+        .arg("Ana")
+        .named("count", 3);
+
+    assert_eq!(format_string.replace_with(&args), "Josh sent 3 messages to Ana");
+
+    // named values take precedence over positional ones.
+    let args = args.named("1", "everyone");
+    assert_eq!(format_string.replace_with(&args), "Josh sent 3 messages to everyone");
+
+    assert!(matches!(
+        format_string.replace_with_strict(&Args::new().arg("Josh")),
+        Err(TemplateError::MissingValue(missing)) if missing == "count"
+    ));
+
+    let mut args = Args::from(HashMap::from([("count".into(), "2".into())]));
+    args.extend([Args::new()
+        .arg("Josh")
+        .arg("Ana")]);
+    assert_eq!(format_string.replace_with(&args), "Josh sent 2 messages to Ana");
+}
+
+#[test]
+pub fn replaces_conditionals() {
+    let format_string =
+        FormatString::from_str("New messages{unread, if { (unread)}}{archived, if {.} else {!}}")
+            .expect("Format string to be valid.");
+
+    assert_eq!(format_string.placeholders(), ["unread", "archived"]);
+
+    let replaced = |unread: &str, archived: &str| {
+        format_string.replace_with(&HashMap::from([
+            ("unread".into(), unread.into()),
+            ("archived".into(), archived.into()),
+        ]))
+    };
+
+    assert_eq!(replaced("true", "1"), "New messages (unread).");
+    assert_eq!(replaced("False", "off"), "New messages!");
+    assert_eq!(replaced("", "yes"), "New messages.");
+
+    // the blocks are neither escaped nor left untouched without a value.
+    let format_string = FormatString::from_str("{bold, if {<b>} else {<i>}}{name}")
+        .expect("Format string to be valid.")
+        .with_escape_html(true);
+
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([
+            ("bold".into(), "true".into()),
+            ("name".into(), "<Ana>".into()),
+        ])),
+        "<b>&lt;Ana&gt;"
+    );
+    assert_eq!(format_string.replace_with(&HashMap::new()), "{bold, if {<b>} else {<i>}}{name}");
+
+    let delimiters = PlaceholderDelimiters::new("%{", "}").expect("Delimiters to be valid.");
+    // the blocks are delimited as the templates are.
+    let format_string = FormatString::parse_with("Hi%{vip, if %{, welcome back}}!", &delimiters)
+        .expect("Format string to be valid.");
+
+    assert_eq!(
+        format_string.replace_with(&HashMap::from([("vip".into(), "true".into())])),
+        "Hi, welcome back!"
+    );
+}
+
+#[test]
+pub fn fails_malformed_conditionals() {
+    for malformed in [
+        "{flag, if}",
+        "{flag, if text}",
+        "{flag, if {text} other}",
+        "{flag, if {text} else}",
+        "{flag, if {text} else {other} more}",
+        // templates aren't replaced inside the blocks.
+        "{flag, if {You have {flag} items}}",
+        "{flag, if {text} else {{other}}}",
+    ] {
+        assert!(
+            matches!(
+                FormatString::from_str(malformed),
+                Err(TemplateError::InvalidConditional(key, _)) if key == "flag"
+            ),
+            "{malformed} should be malformed"
+        );
+    }
+
+    assert!(matches!(FormatString::from_str("{flag, if {text}"), Err(TemplateError::Unclosed(0))));
+}
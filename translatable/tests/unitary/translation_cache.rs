@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use toml_edit::DocumentMut;
+use translatable::Language;
+use translatable_shared::translations::collection::TranslationNodeCollection;
+use translatable_shared::translations::node::TranslationNode;
+
+const FILE: &str = r#"
+[greetings.formal]
+es = "Bueno conocerte."
+en = "Nice to meet you."
+
+[inbox.unread]
+en = "You have {count:int=no} unread messages, {date, date, long}."
+"#;
+
+#[test]
+pub fn round_trips_cache() {
+    let collection = TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        TranslationNode::try_from(
+            FILE.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules."),
+    )]));
+
+    let cache = collection
+        .to_cache()
+        .expect("Collection to be serialized.");
+
+    let cached = TranslationNodeCollection::from_cache(&cache).expect("Cache to be deserialized.");
+
+    let formal = cached
+        .find_path(&vec!["greetings", "formal"])
+        .and_then(|translation| translation.get(&Language::ES))
+        .expect("Translation to be found.");
+
+    assert_eq!(formal.original(), "Bueno conocerte.");
+
+    let original = collection
+        .find_path(&vec!["inbox", "unread"])
+        .and_then(|translation| translation.get(&Language::EN))
+        .expect("Translation to be found.");
+
+    let unread = cached
+        .find_path(&vec!["inbox", "unread"])
+        .and_then(|translation| translation.get(&Language::EN))
+        .expect("Translation to be found.");
+
+    assert_eq!(unread.placeholders(), original.placeholders());
+    assert_eq!(unread.constraints(), original.constraints());
+    assert_eq!(
+        unread.replace_with(&HashMap::from([("date".into(), "2024-01-02".into())])),
+        original.replace_with(&HashMap::from([("date".into(), "2024-01-02".into())]))
+    );
+}
+
+#[test]
+pub fn rejects_invalid_cache() {
+    assert!(TranslationNodeCollection::from_cache(b"not a cache").is_err());
+}
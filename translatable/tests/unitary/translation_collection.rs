@@ -2,8 +2,9 @@ use std::collections::HashMap;
 
 use toml_edit::DocumentMut;
 use translatable::Language;
+use translatable_shared::misc::templating::PlaceholderDelimiters;
 use translatable_shared::translations::collection::TranslationNodeCollection;
-use translatable_shared::translations::node::TranslationNode;
+use translatable_shared::translations::node::{TranslationNode, TranslationNodeError};
 
 const FILE_1: &str = r#"
 [greetings.formal]
@@ -55,3 +56,558 @@ pub fn loads_and_finds_collection() {
 
     assert_eq!(translation, "Hola");
 }
+
+#[test]
+pub fn collects_unique_leaves() {
+    let node = || {
+        TranslationNode::try_from(
+            FILE_1
+                .parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node()),
+        ("b".into(), node()),
+        (
+            "c".into(),
+            TranslationNode::try_from(
+                FILE_2
+                    .parse::<DocumentMut>()
+                    .expect("TOML to be parsed correctly.")
+                    .as_table(),
+            )
+            .expect("TOML to follow the translation rules."),
+        ),
+    ]));
+
+    let mut paths = collection
+        .leaves()
+        .into_iter()
+        .map(|(path, _)| path.join("::"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    assert_eq!(paths, vec!["greetings::formal", "greetings::informal"]);
+}
+
+#[test]
+pub fn retains_languages() {
+    let mut collection = TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        TranslationNode::try_from(
+            FILE_1
+                .parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules."),
+    )]));
+
+    collection.retain_languages(&[Language::ES]);
+
+    let translation = collection
+        .find_path(
+            &"greetings.formal"
+                .split(".")
+                .collect(),
+        )
+        .expect("Translation to be found.");
+
+    assert!(translation.contains_key(&Language::ES));
+    assert!(!translation.contains_key(&Language::EN));
+}
+
+#[test]
+pub fn retains_languages_with_predicate() {
+    let mut collection = TranslationNodeCollection::new(HashMap::from([
+        (
+            "a".into(),
+            TranslationNode::try_from(
+                FILE_1
+                    .parse::<DocumentMut>()
+                    .expect("TOML to be parsed correctly.")
+                    .as_table(),
+            )
+            .expect("TOML to follow the translation rules."),
+        ),
+        (
+            "b".into(),
+            TranslationNode::try_from(
+                "[greetings.pirate]\nx-pirate = \"Ahoy\"\nen = \"Hi\""
+                    .parse::<DocumentMut>()
+                    .expect("TOML to be parsed correctly.")
+                    .as_table(),
+            )
+            .expect("TOML to follow the translation rules."),
+        ),
+    ]));
+
+    let pirate = Language::from_tag("x-pirate").expect("Tag to be valid.");
+    assert_eq!(collection.languages(), vec![Language::EN, Language::ES, pirate.clone()]);
+
+    collection.retain_languages_with(|language| *language != Language::EN);
+    assert_eq!(collection.languages(), vec![Language::ES, pirate]);
+}
+
+#[test]
+pub fn finds_subtree() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node(FILE_1)),
+        ("b".into(), node(FILE_2)),
+    ]));
+
+    let paths = |prefix: &[&str]| {
+        collection
+            .find_subtree(prefix)
+            .into_iter()
+            .map(|(path, _)| path.join("::"))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(paths(&["greetings"]), vec!["greetings::formal", "greetings::informal"]);
+    assert_eq!(paths(&["greetings", "formal"]), vec!["greetings::formal"]);
+    assert_eq!(paths(&[]), vec!["greetings::formal", "greetings::informal"]);
+    assert!(paths(&["menu"]).is_empty());
+    assert!(paths(&["greetings", "formal", "es"]).is_empty());
+}
+
+#[test]
+pub fn finds_subtree_segments() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node(FILE_1)),
+        ("b".into(), node(FILE_2)),
+    ]));
+
+    assert_eq!(
+        collection.subtree_segments(&["greetings"]),
+        Some(vec!["formal".to_string(), "informal".to_string()])
+    );
+    assert_eq!(collection.subtree_segments(&["greetings", "formal"]), None);
+    assert_eq!(collection.subtree_segments(&["menu"]), None);
+    assert_eq!(collection.subtree_segments::<&str>(&[]), None);
+}
+
+#[test]
+pub fn finds_duplicates() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("b".into(), node(FILE_1)),
+        ("a".into(), node(FILE_1)),
+        ("c".into(), node(FILE_2)),
+    ]));
+
+    let duplicates = collection.duplicates();
+
+    assert_eq!(duplicates.len(), 1);
+    assert_eq!(duplicates[0].0, vec!["greetings", "formal"]);
+    assert_eq!(duplicates[0].1, vec!["a", "b"]);
+}
+
+#[test]
+pub fn finds_shadowed_paths() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node(FILE_1)),
+        ("b".into(), node("[greetings]\nes = \"Hola\"")),
+        ("c".into(), node("[greetings.formal]\nshort.es = [\"Hola\"]")),
+    ]));
+
+    assert_eq!(
+        collection.shadowed_paths(),
+        vec![
+            (vec!["greetings".to_string()], "b", "a"),
+            (vec!["greetings".to_string()], "b", "c"),
+            (vec!["greetings".to_string(), "formal".to_string()], "a", "c"),
+        ]
+    );
+
+    // files defining the same paths don't shadow each other.
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node(FILE_1)),
+        ("b".into(), node(FILE_1)),
+        ("c".into(), node(FILE_2)),
+    ]));
+
+    assert!(
+        collection
+            .shadowed_paths()
+            .is_empty()
+    );
+}
+
+#[test]
+pub fn finds_paths_case_insensitively() {
+    let node = TranslationNode::try_from(
+        r#"
+        [Greetings.Formal]
+        es = "Hola"
+        "#
+        .parse::<DocumentMut>()
+        .expect("TOML to be parsed correctly.")
+        .as_table(),
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let collection = TranslationNodeCollection::new(HashMap::from([("a".into(), node.clone())]));
+    assert!(
+        collection
+            .find_path(&vec!["greetings", "formal"])
+            .is_none()
+    );
+
+    let collection = TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        node.lowercase_paths()
+            .expect("Keys not to collide."),
+    )]))
+    .with_case_insensitive_paths(true);
+
+    assert!(
+        collection
+            .find_path(&vec!["greetings", "formal"])
+            .is_some()
+    );
+    assert!(
+        collection
+            .find_path(&vec!["GREETINGS", "Formal"])
+            .is_some()
+    );
+    assert_eq!(collection.closest_match(&vec!["Greetings", "missing"]), Some("a"));
+}
+
+#[test]
+pub fn reports_case_collisions() {
+    let node = TranslationNode::try_from(
+        r#"
+        [greetings.formal]
+        es = "Hola"
+
+        [Greetings.informal]
+        es = "Que haces?"
+        "#
+        .parse::<DocumentMut>()
+        .expect("TOML to be parsed correctly.")
+        .as_table(),
+    )
+    .expect("TOML to follow the translation rules.");
+
+    assert_eq!(
+        node.lowercase_paths()
+            .err()
+            .map(|error| error.to_string()),
+        Some("The keys 'Greetings' and 'greetings' collide when paths are case-insensitive".into())
+    );
+}
+
+#[test]
+pub fn suggests_similar_paths() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node(FILE_1)),
+        ("b".into(), node(FILE_2)),
+    ]));
+
+    assert_eq!(
+        collection.similar_path(&["greetings", "informl"]),
+        Some(("greetings::informal".into(), "b"))
+    );
+    assert_eq!(collection.similar_path(&["menu", "title"]), None);
+}
+
+#[test]
+pub fn expands_references() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let mut collection = TranslationNodeCollection::new(HashMap::from([
+        ("a".into(), node(FILE_1)),
+        (
+            "b".into(),
+            node(
+                r#"
+                [welcome]
+                es = "@{greetings::formal}, {user}."
+                en = "@{greetings::formal}, {user}."
+                nb = "@{greetings::formal}, {user}."
+                "#,
+            ),
+        ),
+    ]));
+
+    let result = collection.expand_references(&PlaceholderDelimiters::default());
+    assert!(matches!(
+        result,
+        Err((file, TranslationNodeError::ReferenceNotAvailable(..))) if file == "b"
+    ));
+
+    collection.retain_languages(&[Language::ES, Language::EN]);
+    collection
+        .expand_references(&PlaceholderDelimiters::default())
+        .expect("References to be expanded.");
+
+    let translation = collection
+        .find_path(&vec!["welcome"])
+        .expect("Translation to be found.");
+
+    assert_eq!(
+        translation
+            .get(&Language::ES)
+            .expect("Language to be available.")
+            .replace_with(&HashMap::from([("user".into(), "Ana".into())])),
+        "Hola, Ana."
+    );
+}
+
+#[test]
+pub fn reports_missing_references() {
+    let mut collection = TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        TranslationNode::try_from(
+            r#"
+            [welcome]
+            en = "@{greetings::formal}"
+            "#
+            .parse::<DocumentMut>()
+            .expect("TOML to be parsed correctly.")
+            .as_table(),
+        )
+        .expect("TOML to follow the translation rules."),
+    )]));
+
+    let result = collection.expand_references(&PlaceholderDelimiters::default());
+
+    assert!(matches!(
+        result,
+        Err((file, TranslationNodeError::ReferenceNotFound(path, reference)))
+            if file == "a" && path == "welcome" && reference == "greetings::formal"
+    ));
+}
+
+#[test]
+pub fn reports_reference_cycles() {
+    let mut collection = TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        TranslationNode::try_from(
+            r#"
+            [menu.a]
+            en = "@{menu::b}"
+
+            [menu.b]
+            en = "@{menu::a}"
+            "#
+            .parse::<DocumentMut>()
+            .expect("TOML to be parsed correctly.")
+            .as_table(),
+        )
+        .expect("TOML to follow the translation rules."),
+    )]));
+
+    let result = collection.expand_references(&PlaceholderDelimiters::default());
+
+    assert!(matches!(
+        result,
+        Err((_, TranslationNodeError::ReferenceCycle(cycle)))
+            if cycle == "menu::a -> menu::b -> menu::a"
+    ));
+}
+
+#[test]
+pub fn looks_up_in_order() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::from_iter([
+        ("z".to_string(), node(FILE_1)),
+        ("a".to_string(), node("[greetings.formal]\nes = \"Buenas\"")),
+    ]);
+
+    assert_eq!(collection.sources(), vec!["z", "a"]);
+    assert_eq!(
+        collection
+            .find_path_with_source(&vec!["greetings", "formal"])
+            .map(|(source, _)| source),
+        Some("z")
+    );
+
+    let collection = TranslationNodeCollection::new(HashMap::from([
+        ("z".to_string(), node(FILE_1)),
+        ("a".to_string(), node("[greetings.formal]\nes = \"Buenas\"")),
+    ]));
+
+    assert_eq!(collection.sources(), vec!["a", "z"]);
+}
+
+#[test]
+pub fn merges_languages_in_order() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let mut collection = TranslationNodeCollection::from_iter([
+        (
+            "z".to_string(),
+            node("[greetings.formal]\nes = \"Hola\"\n[greetings.short]\nes = [\"Hola\"]"),
+        ),
+        (
+            "a".to_string(),
+            node(
+                "[greetings.formal]\nes = \"Buenas\"\nen = \"Hello\"\n[greetings.short]\nen = \
+                 [\"Hi\"]",
+            ),
+        ),
+        ("b".to_string(), node("[greetings.formal]\nen = \"Hi\"\nfr = \"Salut\"")),
+    ]);
+
+    collection.merge_languages();
+
+    let translation = |language: Language| {
+        collection
+            .find_path(&vec!["greetings", "formal"])
+            .and_then(|translation| translation.get(&language))
+            .map(|translation| translation.replace_with(&HashMap::new()))
+    };
+
+    // the first file defining each language wins.
+    assert_eq!(translation(Language::ES), Some("Hola".into()));
+    assert_eq!(translation(Language::EN), Some("Hello".into()));
+    assert_eq!(translation(Language::FR), Some("Salut".into()));
+
+    let list = collection
+        .find_list(&["greetings", "short"])
+        .expect("List to be found.");
+
+    assert!(list.contains_key(&Language::ES));
+    assert!(list.contains_key(&Language::EN));
+
+    let path = vec!["greetings".to_string(), "formal".to_string()];
+    assert_eq!(collection.inherited_source(&path, &Language::ES), None);
+    assert_eq!(collection.inherited_source(&path, &Language::EN), Some("a"));
+    assert_eq!(collection.inherited_source(&path, &Language::FR), Some("b"));
+}
+
+#[test]
+pub fn counts_language_coverage() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::from_iter([
+        ("a".to_string(), node(FILE_1)),
+        ("b".to_string(), node(FILE_2)),
+        // only the resolved translation is counted.
+        ("c".to_string(), node("[greetings.formal]\nfr = \"Bonjour\"")),
+        ("d".to_string(), node("[farewell]\nfr = \"Au revoir\"")),
+    ]);
+
+    // sorted as the languages are declared.
+    assert_eq!(
+        collection.language_coverage(),
+        vec![(Language::EN, 2), (Language::FR, 1), (Language::ES, 2)]
+    );
+}
+
+#[test]
+pub fn hashes_content_regardless_of_order() {
+    let node = |file: &str| {
+        TranslationNode::try_from(
+            file.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules.")
+    };
+
+    let collection = TranslationNodeCollection::from_iter([
+        ("a".to_string(), node(FILE_1)),
+        ("b".to_string(), node(FILE_2)),
+    ]);
+
+    let reordered = TranslationNodeCollection::from_iter([
+        ("./translations/b.toml".to_string(), node(FILE_2)),
+        ("./translations/a.toml".to_string(), node(FILE_1)),
+    ]);
+
+    let changed = TranslationNodeCollection::from_iter([
+        ("a".to_string(), node(FILE_1)),
+        ("b".to_string(), node(&FILE_2.replace("Wyd?", "What's up?"))),
+    ]);
+
+    assert_eq!(collection.content_hash(), reordered.content_hash());
+    assert_ne!(collection.content_hash(), changed.content_hash());
+
+    // the same text in another language is different content.
+    assert_ne!(
+        TranslationNodeCollection::from_iter([("a".to_string(), node("[a]\nen = \"Hi\""))])
+            .content_hash(),
+        TranslationNodeCollection::from_iter([("a".to_string(), node("[a]\nes = \"Hi\""))])
+            .content_hash()
+    );
+}
@@ -0,0 +1,76 @@
+use std::env::temp_dir;
+use std::fs::{create_dir_all, remove_dir_all, write};
+
+use translatable::shared::translations::file::{TranslationFileError, read_translation_file};
+
+#[test]
+pub fn resolves_includes() {
+    let directory = temp_dir().join(format!("translatable-includes-{}", std::process::id()));
+    create_dir_all(directory.join("common")).unwrap();
+
+    write(
+        directory.join("main.toml"),
+        "[menu]\ninclude = [\"common/first.toml\", \"common/second.toml\"]\n\n[menu.file]\nen = \
+         \"Main file\"\n",
+    )
+    .unwrap();
+    write(
+        directory.join("common/first.toml"),
+        "[file]\nen = \"First file\"\nes = \"Archivo\"\n\n[edit]\nen = \"First edit\"\n",
+    )
+    .unwrap();
+    write(directory.join("common/second.toml"), "[edit]\nen = \"Second edit\"\n").unwrap();
+
+    let path = directory
+        .join("main.toml")
+        .to_string_lossy()
+        .to_string();
+
+    let value = |overwrite: bool, key: &str, language: &str| {
+        let (table, _) = read_translation_file(&path, overwrite).unwrap();
+
+        table["menu"][key][language]
+            .as_str()
+            .map(String::from)
+    };
+
+    assert_eq!(value(true, "file", "en").as_deref(), Some("Main file"));
+    assert_eq!(value(true, "file", "es").as_deref(), Some("Archivo"));
+    assert_eq!(value(true, "edit", "en").as_deref(), Some("Second edit"));
+
+    assert_eq!(value(false, "file", "en").as_deref(), Some("First file"));
+    assert_eq!(value(false, "edit", "en").as_deref(), Some("First edit"));
+
+    let (_, includes) = read_translation_file(&path, true).unwrap();
+    assert_eq!(includes.len(), 2);
+
+    remove_dir_all(&directory).unwrap();
+}
+
+#[test]
+pub fn detects_circular_includes() {
+    let directory = temp_dir().join(format!("translatable-circular-{}", std::process::id()));
+    create_dir_all(&directory).unwrap();
+
+    write(directory.join("a.toml"), "include = \"b.toml\"\n").unwrap();
+    write(directory.join("b.toml"), "[nested]\ninclude = \"a.toml\"\n").unwrap();
+
+    let path = directory
+        .join("a.toml")
+        .to_string_lossy()
+        .to_string();
+
+    assert!(matches!(
+        read_translation_file(&path, true),
+        Err(TranslationFileError::CircularInclude(chain)) if chain.len() == 3
+    ));
+
+    write(directory.join("a.toml"), "include = [1]\n").unwrap();
+
+    assert!(matches!(
+        read_translation_file(&path, true),
+        Err(TranslationFileError::InvalidInclude(_))
+    ));
+
+    remove_dir_all(&directory).unwrap();
+}
@@ -0,0 +1,325 @@
+use toml_edit::DocumentMut;
+use translatable::{Args, Language};
+use translatable_shared::translations::node::{
+    TranslationNode,
+    TranslationNodeError,
+    TranslationObjectExt,
+};
+
+const SCALAR_FILE: &str = r#"
+[release.year]
+en = 2024
+
+[release.version]
+en = 1.10
+
+[release.stable]
+en = true
+
+[release.date]
+en = 2024-05-01
+"#;
+
+fn parse(content: &str) -> Result<TranslationNode, TranslationNodeError> {
+    TranslationNode::try_from(
+        content
+            .parse::<DocumentMut>()
+            .expect("TOML to be parsed correctly.")
+            .as_table(),
+    )
+}
+
+#[test]
+pub fn coerces_scalar_values() {
+    let node = parse(SCALAR_FILE).expect("TOML to follow the translation rules.");
+
+    for (key, expected) in
+        [("year", "2024"), ("version", "1.10"), ("stable", "true"), ("date", "2024-05-01")]
+    {
+        let translation = node
+            .find_path(&vec!["release", key])
+            .and_then(|translation| translation.get(&Language::EN))
+            .expect("Translation to be found.")
+            .replace_with(&Args::new());
+
+        assert_eq!(translation, expected);
+    }
+}
+
+#[test]
+pub fn rejects_unsupported_values() {
+    let array = parse("[release.year]\nen = [[2024]]");
+    let inline_table = parse("[release]\nyear = { en = 2024 }");
+
+    assert!(
+        matches!(array, Err(TranslationNodeError::UnsupportedValue(key, "an array")) if key == "en[0]")
+    );
+    assert!(matches!(
+        inline_table,
+        Err(TranslationNodeError::UnsupportedValue(key, "an inline table")) if key == "year"
+    ));
+}
+
+#[test]
+pub fn parses_lists() {
+    let node = parse(
+        "[onboarding.steps]\n\"@desc\" = \"Onboarding steps\"\nen = [\"Sign up\", 2]\nes = []",
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let list = node
+        .find_list(&["onboarding", "steps"])
+        .expect("List to be found.");
+
+    let steps = list
+        .get(&Language::EN)
+        .expect("List to be available.")
+        .iter()
+        .map(|step| step.replace_with(&Args::new()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(steps, vec!["Sign up", "2"]);
+    assert_eq!(list.description(), Some("Onboarding steps"));
+    assert_eq!(
+        list.get(&Language::ES)
+            .map(Vec::len),
+        Some(0)
+    );
+    assert!(
+        node.find_path(&vec!["onboarding", "steps"])
+            .is_none()
+    );
+}
+
+#[test]
+pub fn rejects_mixed_lists() {
+    let node = parse("[onboarding.steps]\nen = [\"Sign up\"]\nes = \"Regístrate\"");
+
+    assert!(matches!(node, Err(TranslationNodeError::MixedLists)));
+
+    let node = parse("[onboarding.steps]\nen = \"Sign up\"\nes = [\"Regístrate\"]");
+
+    assert!(matches!(node, Err(TranslationNodeError::MixedLists)));
+}
+
+#[test]
+pub fn parses_lists_with_comments_and_trailing_metadata() {
+    let node = parse(
+        "[onboarding.steps]\nen = [\n    # the first step\n    \"Sign up\", # inline\n    \
+         \"Verify\",\n]\n\"@desc\" = \"Onboarding steps\"",
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let list = node
+        .find_list(&["onboarding", "steps"])
+        .expect("List to be found.");
+
+    assert_eq!(
+        list.get(&Language::EN)
+            .map(Vec::len),
+        Some(2)
+    );
+    assert_eq!(list.description(), Some("Onboarding steps"));
+}
+
+#[test]
+pub fn rejects_mixed_tables() {
+    let node = parse("[greetings]\nen = \"Hi\"\n\n[greetings.formal]\nen = \"Hello\"");
+
+    assert!(matches!(
+        node,
+        Err(TranslationNodeError::MixedValues(value, table)) if value == "en" && table == "formal"
+    ));
+
+    // the nested table is declared first with a dotted key.
+    let node = parse("[greetings]\nformal.en = \"Hello\"\nen = \"Hi\"");
+
+    assert!(matches!(
+        node,
+        Err(TranslationNodeError::MixedValues(value, table)) if value == "en" && table == "formal"
+    ));
+
+    let node =
+        parse("[greetings]\n\"@desc\" = \"Greetings\"\n\n[greetings.formal]\nen = \"Hello\"");
+
+    assert!(matches!(
+        node,
+        Err(TranslationNodeError::MixedValues(value, table)) if value == "@desc" && table == "formal"
+    ));
+}
+
+#[test]
+pub fn rejects_non_language_keys() {
+    let node = parse("[errors]\npage_title = \"Page not found.\"");
+
+    assert!(matches!(
+        node,
+        Err(TranslationNodeError::LanguageParsing(key, _)) if key == "page_title"
+    ));
+}
+
+#[test]
+pub fn nests_tables_named_as_languages() {
+    let node = parse("[actions.to.confirm]\nen = \"Confirm\"\n\n[actions.es]\nen = \"Spanish\"")
+        .expect("TOML to follow the translation rules.");
+
+    for (path, expected) in
+        [(vec!["actions", "to", "confirm"], "Confirm"), (vec!["actions", "es"], "Spanish")]
+    {
+        let translation = node
+            .find_path(&path)
+            .and_then(|translation| translation.get(&Language::EN))
+            .expect("Translation to be found.")
+            .replace_with(&Args::new());
+
+        assert_eq!(translation, expected);
+    }
+}
+
+#[test]
+pub fn captures_description_metadata() {
+    let node = parse("[buttons.save]\n\"@desc\" = \"Button label, keep it short\"\nen = \"Save\"")
+        .expect("TOML to follow the translation rules.");
+
+    let translation = node
+        .find_path(&vec!["buttons", "save"])
+        .expect("Translation to be found.");
+
+    assert_eq!(translation.description(), Some("Button label, keep it short"));
+    assert_eq!(translation.available_languages(), vec![Language::EN]);
+
+    let undescribed =
+        parse("[buttons.cancel]\nen = \"Cancel\"").expect("TOML to follow the translation rules.");
+
+    assert_eq!(
+        undescribed
+            .find_path(&vec!["buttons", "cancel"])
+            .and_then(|translation| translation.description()),
+        None
+    );
+}
+
+#[test]
+pub fn rejects_unknown_metadata() {
+    let node = parse("[buttons.save]\n\"@note\" = \"Unused\"\nen = \"Save\"");
+
+    assert!(matches!(node, Err(TranslationNodeError::UnknownMetadata(key)) if key == "@note"));
+}
+
+#[test]
+pub fn keeps_raw_translations_verbatim() {
+    let node = parse(
+        "[legacy.printf]\nen = \"%s has {count} items {{\"\n\"@raw\" = \
+         true\n\n[legacy.lines]\n\"@raw\" = true\nen = [\"{first}\", \"{second}\"]",
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let translation = node
+        .find_path(&vec!["legacy", "printf"])
+        .and_then(|translation| translation.get(&Language::EN))
+        .expect("Translation to be found.");
+
+    assert!(!translation.has_templates());
+    assert_eq!(translation.replace_with(&Args::new().named("count", 3)), "%s has {count} items {{");
+
+    let lines = node
+        .find_list(&["legacy", "lines"])
+        .and_then(|list| list.get(&Language::EN))
+        .expect("List to be found.")
+        .iter()
+        .map(|line| line.replace_with(&Args::new()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(lines, vec!["{first}", "{second}"]);
+}
+
+#[test]
+pub fn rejects_invalid_raw_metadata() {
+    let node = parse("[legacy.printf]\n\"@raw\" = \"yes\"\nen = \"%s\"");
+
+    assert!(matches!(node, Err(TranslationNodeError::InvalidRaw)));
+}
+
+#[test]
+pub fn reports_placeholder_mismatches() {
+    let node = parse(
+        "[greetings.informal]\nen = \"Hi {name}, you have {count}.\"\nes = \"Hola {nombre}, \
+         tienes {count}.\"\nfr = \"Salut {name}, tu as {count}.\"",
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let mismatches = node
+        .find_path(&vec!["greetings", "informal"])
+        .expect("Translation to be found.")
+        .placeholder_mismatches();
+
+    assert_eq!(
+        mismatches,
+        vec![
+            (Language::EN, vec!["nombre".to_string()]),
+            (Language::ES, vec!["name".to_string()]),
+            (Language::FR, vec!["nombre".to_string()]),
+        ]
+    );
+}
+
+#[test]
+pub fn exempts_defaulted_placeholders() {
+    let node = parse("[welcome.guest]\nen = \"Welcome, {name=Guest}.\"\nes = \"Bienvenido.\"")
+        .expect("TOML to follow the translation rules.");
+
+    let mismatches = node
+        .find_path(&vec!["welcome", "guest"])
+        .expect("Translation to be found.")
+        .placeholder_mismatches();
+
+    assert!(mismatches.is_empty());
+}
+
+#[test]
+pub fn parses_dotted_keys() {
+    let dotted = parse(
+        r#"
+        greetings.formal.en = "Nice to meet you."
+        greetings.formal.es = "Bueno conocerte."
+
+        [greetings.informal]
+        en = "What's good?"
+
+        [farewells]
+        formal.en = "Goodbye."
+        "#,
+    )
+    .expect("TOML to follow the translation rules.");
+
+    let nested = parse(
+        r#"
+        [greetings.formal]
+        en = "Nice to meet you."
+        es = "Bueno conocerte."
+
+        [greetings.informal]
+        en = "What's good?"
+
+        [farewells.formal]
+        en = "Goodbye."
+        "#,
+    )
+    .expect("TOML to follow the translation rules.");
+
+    for (path, language) in [
+        (vec!["greetings", "formal"], Language::EN),
+        (vec!["greetings", "formal"], Language::ES),
+        (vec!["greetings", "informal"], Language::EN),
+        (vec!["farewells", "formal"], Language::EN),
+    ] {
+        let translation = |node: &TranslationNode| {
+            node.find_path(&path)
+                .and_then(|translation| translation.get(&language))
+                .map(|translation| translation.replace_with(&Args::new()))
+        };
+
+        assert!(translation(&dotted).is_some());
+        assert_eq!(translation(&dotted), translation(&nested));
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use toml_edit::DocumentMut;
+use translatable::shared::translations::collection::TranslationNodeCollection;
+use translatable::shared::translations::node::TranslationNode;
+use translatable::shared::translations::resolution::{
+    Fallback,
+    ResolutionError,
+    matches_language_pattern,
+    resolve,
+    split_path,
+};
+use translatable::{Error, Language};
+
+const FILE: &str = r#"
+[greetings.formal]
+es = "Hola {name}"
+no = "Hei {name}"
+"#;
+
+fn collection() -> TranslationNodeCollection {
+    TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        TranslationNode::try_from(
+            FILE.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules."),
+    )]))
+}
+
+#[test]
+pub fn resolves_with_fallbacks() {
+    let collection = collection();
+    let replacements = HashMap::from([("name".into(), "Josh".into())]);
+
+    let resolved = |language: Language, fallback: Fallback| {
+        resolve(&collection, &language, &["greetings", "formal"], fallback, &replacements)
+    };
+
+    assert_eq!(resolved(Language::ES, Fallback::Parents).unwrap(), "Hola Josh");
+    assert_eq!(resolved(Language::NB, Fallback::Parents).unwrap(), "Hei Josh");
+    assert_eq!(resolved(Language::EN, Fallback::Language(&Language::ES)).unwrap(), "Hola Josh");
+
+    assert!(matches!(
+        resolved(Language::NB, Fallback::Disabled),
+        Err(ResolutionError::LanguageNotAvailable(Language::NB, _, Some(_)))
+    ));
+}
+
+#[test]
+pub fn resolves_with_fallback_rules() {
+    let collection = collection();
+    let replacements = HashMap::from([("name".into(), "Josh".into())]);
+    let rules: &[(&str, &[Language])] =
+        &[("n*", &[Language::ES]), ("f*", &[Language::DE, Language::NO])];
+
+    let resolved = |language: Language| {
+        resolve(
+            &collection,
+            &language,
+            &["greetings", "formal"],
+            Fallback::Rules(rules),
+            &replacements,
+        )
+    };
+
+    // the rule replaces the `no` parent.
+    assert_eq!(resolved(Language::NB).unwrap(), "Hola Josh");
+    assert_eq!(resolved(Language::FR).unwrap(), "Hei Josh");
+    assert_eq!(resolved(Language::ES).unwrap(), "Hola Josh");
+
+    assert!(matches!(
+        resolved(Language::EN),
+        Err(ResolutionError::LanguageNotAvailable(Language::EN, _, Some(_)))
+    ));
+}
+
+#[test]
+pub fn matches_language_patterns() {
+    assert!(matches_language_pattern("pt-*", "pt-BR"));
+    assert!(matches_language_pattern("PT-*", "pt-br"));
+    assert!(matches_language_pattern("*", "en"));
+    assert!(matches_language_pattern("zh-*-cn", "zh-Hans-CN"));
+    assert!(matches_language_pattern("en", "en"));
+
+    assert!(!matches_language_pattern("pt-*", "pt"));
+    assert!(!matches_language_pattern("zh-*-cn", "zh-Hans-TW"));
+    assert!(!matches_language_pattern("en", "en-GB"));
+}
+
+#[test]
+pub fn converts_resolution_errors() {
+    let error = resolve(
+        &collection(),
+        &Language::ES,
+        &["greetings", "missing"],
+        Fallback::Parents,
+        &HashMap::new(),
+    )
+    .map_err(Error::from);
+
+    assert!(matches!(
+        error,
+        Err(Error::PathNotFound(path, Some(source))) if path == ["greetings", "missing"] && source == "a"
+    ));
+}
+
+#[test]
+pub fn splits_paths() {
+    assert_eq!(split_path(&["greetings", "formal"], "::"), ["greetings", "formal"]);
+    assert_eq!(split_path(&["greetings::formal"], "::"), ["greetings", "formal"]);
+    assert_eq!(split_path(&[" greetings . formal "], "."), ["greetings", "formal"]);
+    assert_eq!(split_path(&["errors", "sign/in"], "/"), ["errors", "sign", "in"]);
+}
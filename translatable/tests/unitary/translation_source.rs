@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use toml_edit::DocumentMut;
+use translatable::shared::misc::templating::FormatString;
+use translatable::shared::translations::collection::TranslationNodeCollection;
+use translatable::shared::translations::node::TranslationNode;
+use translatable::{Error, Language, TranslationSource, translate_with};
+
+const FILE: &str = r#"
+[greetings.formal]
+es = "Hola {name}"
+en = "Hello {name}"
+"#;
+
+struct DatabaseSource(HashMap<(Language, String), FormatString>);
+
+impl TranslationSource for DatabaseSource {
+    fn resolve(&self, lang: Language, path: &[String]) -> Option<&FormatString> {
+        self.0
+            .get(&(lang, path.join("::")))
+    }
+}
+
+fn path(path: &str) -> Vec<String> {
+    path.split("::")
+        .map(String::from)
+        .collect()
+}
+
+#[test]
+pub fn translates_with_collection() {
+    let collection = TranslationNodeCollection::new(HashMap::from([(
+        "a".into(),
+        TranslationNode::try_from(
+            FILE.parse::<DocumentMut>()
+                .expect("TOML to be parsed correctly.")
+                .as_table(),
+        )
+        .expect("TOML to follow the translation rules."),
+    )]));
+
+    let replacements = HashMap::from([("name".to_string(), "john".to_string())]);
+
+    assert_eq!(
+        translate_with(&collection, Language::ES, &path("greetings::formal"), &replacements)
+            .unwrap(),
+        "Hola john"
+    );
+
+    assert!(matches!(
+        translate_with(&collection, Language::FR, &path("greetings::formal"), &replacements),
+        Err(Error::LanguageNotAvailable(Language::FR, _, None))
+    ));
+}
+
+#[test]
+pub fn translates_with_custom_source() {
+    let source: Box<dyn TranslationSource> = Box::new(DatabaseSource(HashMap::from([(
+        (Language::EN, "greetings::formal".to_string()),
+        "Welcome back {name}"
+            .parse::<FormatString>()
+            .unwrap(),
+    )])));
+
+    let replacements = HashMap::from([("name".to_string(), "john".to_string())]);
+
+    assert_eq!(
+        translate_with(&*source, Language::EN, &path("greetings::formal"), &replacements).unwrap(),
+        "Welcome back john"
+    );
+
+    assert!(
+        translate_with(&*source, Language::EN, &path("greetings::missing"), &replacements).is_err()
+    );
+}
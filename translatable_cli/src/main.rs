@@ -0,0 +1,467 @@
+//! Translation command line tool.
+//!
+//! This binary loads the configuration and the translations
+//! the same way the macros do, reading `./translatable.toml`
+//! and the environment from the working directory, so the
+//! translations can be validated in CI without a full build.
+//!
+//! **Subcommands**
+//! * `check` - Reports translations missing languages and paths defined in more
+//!   than one file.
+//! * `list` - Prints every translation path along its description, if any.
+//! * `unused <src-dir>` - Reports the translation paths no static invocation in
+//!   the source directory refers to.
+
+use std::collections::BTreeSet;
+use std::env::args;
+use std::fs::{read_dir, read_to_string};
+use std::io::Error as IoError;
+use std::path::Path;
+use std::process::ExitCode;
+
+use translatable_shared::data::translations::load_translations;
+use translatable_shared::misc::language::Language;
+use translatable_shared::translations::collection::TranslationNodeCollection;
+use translatable_shared::translations::node::TranslationObjectExt;
+
+/// Command line usage.
+///
+/// Printed when the arguments don't match any subcommand.
+const USAGE: &str = "Usage: translatable-cli <check | list | unused <src-dir>>";
+
+/// Macros whose static paths count as a usage.
+///
+/// A `subtree!()` prefix counts as a usage of every
+/// translation beneath it.
+const MACROS: &[&str] = &[
+    "translation!",
+    "try_translation!",
+    "translation_with_lang!",
+    "available_languages!",
+    "subtree!",
+    "translation_list!",
+    "translation_exists!",
+    "translation_into!",
+];
+
+/// Macros whose path is written without the `static` keyword.
+///
+/// A `translation_module!()` prefix counts as a usage of
+/// every translation beneath it.
+const PATH_MACROS: &[&str] = &["translation_module!", "localized!"];
+
+/// Reports translations missing languages and duplicate paths.
+///
+/// A translation is considered missing a language if any
+/// other translation is available in it. Parent language
+/// fallbacks are not considered.
+///
+/// **Arguments**
+/// * `translations` - The loaded translations.
+///
+/// **Returns**
+/// Whether no issue was found.
+fn check(translations: &TranslationNodeCollection) -> bool {
+    let mut leaves = translations.leaves();
+    leaves.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    let languages = leaves
+        .iter()
+        .flat_map(|(_, translation)| translation.keys())
+        .map(Language::code)
+        .collect::<BTreeSet<_>>();
+
+    let mut valid = true;
+
+    for (path, translation) in &leaves {
+        let available = translation
+            .available_languages()
+            .iter()
+            .map(Language::code)
+            .collect::<BTreeSet<_>>();
+
+        let missing = languages
+            .difference(&available)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !missing.is_empty() {
+            valid = false;
+            println!("missing languages: {} lacks {}", path.join("::"), missing.join(", "));
+        }
+    }
+
+    for (path, sources) in translations.duplicates() {
+        valid = false;
+        println!("duplicate path: {} is defined in {}", path.join("::"), sources.join(", "));
+    }
+
+    valid
+}
+
+/// Prints every translation path.
+///
+/// Translations declaring a `@desc` metadata key
+/// are followed by their description, tab separated.
+///
+/// **Arguments**
+/// * `translations` - The loaded translations.
+fn list(translations: &TranslationNodeCollection) {
+    let mut paths = translations
+        .leaves()
+        .into_iter()
+        .map(|(path, translation)| (path.join("::"), translation.description()))
+        .collect::<Vec<_>>();
+
+    paths.sort();
+
+    for (path, description) in paths {
+        match description {
+            Some(description) => println!("{path}\t{description}"),
+            None => println!("{path}"),
+        }
+    }
+}
+
+/// Parses the static path an invocation fragment starts with.
+///
+/// Each segment is either an identifier or a string
+/// literal, such as `errors::"404"`, whose contents are
+/// taken as the segment.
+///
+/// **Arguments**
+/// * `source` - The source following the `static` keyword.
+///
+/// **Returns**
+/// The path segments, empty if the fragment doesn't start with a path.
+fn parse_static_path(mut source: &str) -> Vec<String> {
+    let mut path = Vec::new();
+
+    loop {
+        source = source.trim_start();
+
+        let segment = if let Some(literal) = source.strip_prefix('"') {
+            let Some(end) = literal.find('"') else {
+                break;
+            };
+
+            source = &literal[end + 1..];
+            &literal[..end]
+        } else {
+            let end = source
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(source.len());
+
+            let segment = &source[..end];
+            source = &source[end..];
+            segment
+        };
+
+        if segment.is_empty() {
+            break;
+        }
+
+        path.push(segment.to_string());
+
+        match source
+            .trim_start()
+            .strip_prefix("::")
+        {
+            Some(rest) => source = rest,
+            None => break,
+        }
+    }
+
+    path
+}
+
+/// Strips the keyword an invocation fragment starts with.
+///
+/// **Arguments**
+/// * `source` - The invocation fragment.
+/// * `keyword` - The keyword to strip.
+///
+/// **Returns**
+/// The fragment following the keyword, or the whole fragment if it doesn't
+/// start with the keyword.
+fn strip_keyword<'a>(source: &'a str, keyword: &str) -> &'a str {
+    let source = source.trim_start();
+
+    match source.strip_prefix(keyword) {
+        Some(rest) if !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_') => rest,
+        _ => source,
+    }
+}
+
+/// Collects the static paths used in a source file.
+///
+/// Each invocation of the macros in [`MACROS`] is searched
+/// for a `static` keyword followed by a path, dynamic paths
+/// can't be known without running the code. The invocations
+/// of the macros in [`PATH_MACROS`] are parsed as a path,
+/// after their visibility or `static` keyword if any.
+///
+/// **Arguments**
+/// * `source` - The source file contents.
+/// * `paths` - Where the found path segments are collected.
+fn collect_static_paths(source: &str, paths: &mut BTreeSet<Vec<String>>) {
+    for name in MACROS
+        .iter()
+        .chain(PATH_MACROS)
+    {
+        for (start, _) in source.match_indices(name) {
+            let preceded_by_ident = source[..start]
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_alphanumeric() || c == '_');
+
+            if preceded_by_ident {
+                continue;
+            }
+
+            let invocation = &source[start + name.len()..];
+            let Some(open) = invocation.find(['(', '[', '{']) else {
+                continue;
+            };
+
+            let mut depth = 0usize;
+            let end = invocation[open..]
+                .char_indices()
+                .find(|(_, c)| {
+                    match c {
+                        '(' | '[' | '{' => depth += 1,
+                        ')' | ']' | '}' => depth -= 1,
+                        _ => {},
+                    }
+
+                    depth == 0
+                })
+                .map(|(index, _)| open + index)
+                .unwrap_or(invocation.len());
+
+            if PATH_MACROS.contains(name) {
+                let mut arguments = strip_keyword(&invocation[open + 1..end], "pub");
+
+                if let Some(restriction) = arguments
+                    .trim_start()
+                    .strip_prefix('(')
+                {
+                    arguments = restriction
+                        .split_once(')')
+                        .map_or(restriction, |(_, rest)| rest);
+                }
+
+                let path = parse_static_path(strip_keyword(arguments, "static"));
+
+                if !path.is_empty() {
+                    paths.insert(path);
+                }
+
+                continue;
+            }
+
+            for (index, _) in invocation[open..end].match_indices("static") {
+                let path = parse_static_path(&invocation[open + index + "static".len()..end]);
+
+                if !path.is_empty() {
+                    paths.insert(path);
+                }
+            }
+        }
+    }
+}
+
+/// Collects the static paths used in a source directory.
+///
+/// **Arguments**
+/// * `directory` - The directory to walk for `.rs` files.
+/// * `paths` - Where the found path segments are collected.
+///
+/// **Returns**
+/// An empty result or the first IO error found.
+fn walk_sources(directory: &Path, paths: &mut BTreeSet<Vec<String>>) -> Result<(), IoError> {
+    for entry in read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            walk_sources(&path, paths)?;
+        } else if path
+            .extension()
+            .is_some_and(|extension| extension == "rs")
+        {
+            collect_static_paths(&read_to_string(&path)?, paths);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reports the translation paths no static invocation uses.
+///
+/// Translations only used with dynamic paths or trough
+/// translation contexts are reported as well, so the result
+/// is a list of candidates to review.
+///
+/// **Arguments**
+/// * `translations` - The loaded translations.
+/// * `directory` - The source directory to search.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(bool)` - Whether every translation is used.
+/// * `Err(IoError)` - If the source directory couldn't be read.
+fn unused(translations: &TranslationNodeCollection, directory: &Path) -> Result<bool, IoError> {
+    let mut used = BTreeSet::new();
+    walk_sources(directory, &mut used)?;
+
+    let mut unused = translations
+        .leaves()
+        .into_iter()
+        .map(|(path, _)| path)
+        .filter(|path| {
+            !used
+                .iter()
+                .any(|prefix| path.starts_with(prefix))
+        })
+        .map(|path| path.join("::"))
+        .collect::<Vec<_>>();
+
+    unused.sort();
+
+    for path in &unused {
+        println!("unused path: {path}");
+    }
+
+    Ok(unused.is_empty())
+}
+
+fn main() -> ExitCode {
+    let args = args()
+        .skip(1)
+        .collect::<Vec<_>>();
+
+    let args = args
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+
+    if !matches!(args.as_slice(), ["check"] | ["list"] | ["unused", _]) {
+        eprintln!("{USAGE}");
+        return ExitCode::from(2);
+    }
+
+    let translations = match load_translations() {
+        Ok(translations) => translations,
+        Err(err) => {
+            eprintln!("{err:#}");
+            return ExitCode::FAILURE;
+        },
+    };
+
+    let valid = match args.as_slice() {
+        ["check"] => check(&translations),
+
+        ["list"] => {
+            list(&translations);
+            true
+        },
+
+        ["unused", directory] => match unused(&translations, Path::new(directory)) {
+            Ok(valid) => valid,
+            Err(err) => {
+                eprintln!("Couldn't read {directory}: {err:#}");
+                return ExitCode::FAILURE;
+            },
+        },
+
+        _ => unreachable!(),
+    };
+
+    if valid { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::collect_static_paths;
+
+    fn static_paths(source: &str) -> Vec<String> {
+        let mut paths = BTreeSet::new();
+        collect_static_paths(source, &mut paths);
+
+        paths
+            .into_iter()
+            .map(|path| path.join("::"))
+            .collect()
+    }
+
+    #[test]
+    fn collects_literal_segments() {
+        assert_eq!(
+            static_paths(
+                r#"
+                let not_found = translation!("en", static errors::"404");
+                let sign_in = translation!("es", static "errors" :: "sign-in");
+                let formal = translation!(lang, static greetings::formal, user = name);
+                "#
+            ),
+            ["errors::404", "errors::sign-in", "greetings::formal"]
+        );
+    }
+
+    #[test]
+    fn collects_list_paths() {
+        assert_eq!(
+            static_paths(
+                r#"let steps = translation_list!("en", static onboarding::steps, email);"#
+            ),
+            ["onboarding::steps"]
+        );
+    }
+
+    #[test]
+    fn collects_existence_paths() {
+        assert_eq!(
+            static_paths(r#"if translation_exists!(language, static checkout::express) {}"#),
+            ["checkout::express"]
+        );
+    }
+
+    #[test]
+    fn collects_module_prefixes() {
+        assert_eq!(
+            static_paths(
+                r#"
+                translation_module!(pub ui::buttons);
+                translation_module!(pub(crate) errors);
+                translation_module!(inbox);
+                "#
+            ),
+            ["errors", "inbox", "ui::buttons"]
+        );
+    }
+
+    #[test]
+    fn collects_localized_paths() {
+        assert_eq!(
+            static_paths(
+                r#"
+                const FORMAL: fn(&Language) -> Option<&'static str> = localized!(static greetings::formal);
+                let norwegian = localized!(greetings::norwegian);
+                "#
+            ),
+            ["greetings::formal", "greetings::norwegian"]
+        );
+    }
+
+    #[test]
+    fn collects_parsed_paths() {
+        assert_eq!(
+            static_paths(
+                r#"let max_retries: u32 = translation_into!(language, static config::max_retries)?;"#
+            ),
+            ["config::max_retries"]
+        );
+    }
+}
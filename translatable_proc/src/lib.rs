@@ -9,14 +9,33 @@
 
 #![warn(missing_docs)]
 
+use macro_generation::available_languages::available_languages_macro;
 use macro_generation::context::context_macro;
-use macro_generation::translation::translation_macro;
+use macro_generation::localized::localized_macro;
+use macro_generation::replacements::replacements_macro;
+use macro_generation::subtree::subtree_macro;
+use macro_generation::translatable_enum::translatable_enum_macro;
+use macro_generation::translation::{translation_into_macro, translation_macro};
+use macro_generation::translation_exists::translation_exists_macro;
+use macro_generation::translation_hash::translation_hash_macro;
+use macro_generation::translation_list::translation_list_macro;
+use macro_generation::translation_module::translation_module_macro;
+use macro_generation::translation_paths::translation_paths_macro;
 use macro_input::context::{ContextMacroArgs, ContextMacroStruct};
+use macro_input::localized::LocalizedMacroArgs;
+use macro_input::replacements::ReplacementsInput;
+use macro_input::subtree::SubtreeMacroArgs;
+use macro_input::translatable_enum::TranslatableEnumInput;
 use macro_input::translation::TranslationMacroArgs;
+use macro_input::translation_exists::TranslationExistsMacroArgs;
+use macro_input::translation_list::TranslationListMacroArgs;
+use macro_input::translation_module::TranslationModuleMacroArgs;
+use macro_input::utils::input_type::InputType;
+use macro_input::utils::translation_path::TranslationPath;
 use proc_macro::TokenStream;
+use syn::parse::Nothing;
 use syn::parse_macro_input;
 
-mod data;
 mod macro_generation;
 mod macro_input;
 
@@ -28,12 +47,21 @@ mod macro_input;
 ///
 /// **Parameters**
 /// * `language` - A string literal for static inference or an instance of
-/// `translatable::Language` for dynamic inference.
-/// * `path` - A pat prefixed with `static` for static inference or a `Vec<impl
-///   ToString>`
-/// for dynamic inference.
+///   `translatable::Language` for dynamic inference. It may be omitted before a
+///   `static`, `unchecked` or `inline` path if a `default_language` is
+///   configured. A `const` prefixed constant such as `const LANG` is resolved
+///   in compile time along static and inline paths.
+/// * `path` - A pat prefixed with `static` for static inference, a `Vec<impl
+///   ToString>` or a pat prefixed with `unchecked` for dynamic inference or an
+///   `inline { en = "..." }` table to declare the translation in place.
 /// * `replacements` - Arguments similar to python's `kwargs` for the
-///   translation replacements.
+///   translation replacements, optionally ending with a `..replacements` spread
+///   of a struct deriving `Replacements`.
+/// * `fallback` - An optional `fallback = none` to disable the language
+///   fallback or `fallback = language` to try that language last.
+/// * `replacements` - An optional `replacements = map` with a map computed in
+///   runtime, such as a `HashMap<String, String>`, merged below the explicit
+///   replacements.
 ///
 /// This macro provides optimizations depending on the dynamism
 /// of the parameters while calling the macro.
@@ -48,6 +76,14 @@ mod macro_input;
 ///   the `Err` branch.
 ///
 /// - If both are dynamic a single [`String`] will be generated.
+/// - If both are static and the translation has neither templates nor
+///   replacements, a `&'static str` literal will be generated, which may
+///   initialize `const` and `static` items, unless `literal_output` is
+///   disabled. A language written as a variant path such as `Language::EN` is
+///   static in these invocations.
+/// - If `cow_output` is configured, a `Cow<'static, str>` is generated instead
+///   of a `String` or `&'static str`, borrowing the translation when nothing is
+///   replaced.
 ///
 /// Independently of any other parameter, the `replacements` parameter
 /// is always dynamic (context based).
@@ -64,7 +100,288 @@ mod macro_input;
 /// * `Err(translatable::Error)` - If the invocation fails with a runtime error.
 #[proc_macro]
 pub fn translation(input: TokenStream) -> TokenStream {
-    translation_macro(parse_macro_input!(input as TranslationMacroArgs).into()).into()
+    translation_macro(parse_macro_input!(input as TranslationMacroArgs), false, false).into()
+}
+
+/// # Translation obtention macro with the resolved language.
+///
+/// This macro takes the same parameters and applies the
+/// same optimizations as [`translation!()`], but also returns
+/// the language that actually served the translation, which
+/// differs from the requested one when a fallback kicks in.
+///
+/// **Returns**
+/// A `(String, Language)` tuple if both the language and the path
+/// are static, otherwise a `Result` containing either:
+/// * `Ok((String, Language))` - If the invocation is successful.
+/// * `Err(translatable::Error)` - If the invocation fails with a runtime error.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn translation_with_lang(input: TokenStream) -> TokenStream {
+    translation_macro(parse_macro_input!(input as TranslationMacroArgs), true, false).into()
+}
+
+/// # Fallible translation obtention macro.
+///
+/// This macro takes the same parameters and applies the
+/// same optimizations as [`translation!()`], but it always
+/// evaluates to a `Result`, fully static invocations are
+/// resolved in compile time and wrapped in `Ok`.
+///
+/// This way static and dynamic invocations can be handled
+/// uniformly with `?`.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(String)` - If the invocation is successful.
+/// * `Err(translatable::Error)` - If the invocation fails with a runtime error.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn try_translation(input: TokenStream) -> TokenStream {
+    translation_macro(parse_macro_input!(input as TranslationMacroArgs), false, true).into()
+}
+
+/// # Parsed translation obtention macro.
+///
+/// This macro takes the same parameters and applies the
+/// same optimizations as [`translation!()`], then parses the
+/// resolved translation into the type expected where it's
+/// invoked, such as `let n: u32 = translation_into!("en", static
+/// max_retries)?`.
+///
+/// The target type must implement `FromStr` with an error
+/// implementing `std::error::Error + Send + Sync`, or a `String`
+/// error, the same as parsed fields in `#[translation_context]`
+/// structs. The error is kept as the `Error::TranslationParse` source.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(T)` - If the translation is obtained and parsed.
+/// * `Err(translatable::Error)` - If the invocation fails with a runtime error,
+///   or `Error::TranslationParse` if the translation couldn't be parsed.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn translation_into(input: TokenStream) -> TokenStream {
+    translation_into_macro(parse_macro_input!(input as TranslationMacroArgs)).into()
+}
+
+/// # Translation list obtention macro.
+///
+/// This macro obtains a translation list, declared in the
+/// translation files as a translation object whose values
+/// are arrays of strings, such as the steps of an onboarding.
+///
+/// **Parameters**
+/// * `language` - A string literal for static inference or an instance of
+///   `translatable::Language` for dynamic inference.
+/// * `path` - A pat prefixed with `static`, lists can't be obtained from
+///   dynamic paths.
+/// * `replacements` - Arguments similar to python's `kwargs` for the
+///   replacements, applied to each translation of the list.
+///
+/// The path is validated to lead to a list in compile time, if the
+/// language is static too, the list is resolved in compile time. The
+/// requested language falls back to its parents the same way
+/// [`translation!()`] does.
+///
+/// **Returns**
+/// A `Vec<String>` if the language is static, otherwise a `Result`
+/// containing either:
+/// * `Ok(Vec<String>)` - If the invocation is successful.
+/// * `Err(translatable::Error)` - If the invocation fails with a runtime error.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn translation_list(input: TokenStream) -> TokenStream {
+    translation_list_macro(parse_macro_input!(input as TranslationListMacroArgs)).into()
+}
+
+/// # Translation existence macro.
+///
+/// This macro checks whether a translation is available
+/// in a language without obtaining it, useful to branch on
+/// partially translated features.
+///
+/// **Parameters**
+/// * `language` - A string literal for static inference or an instance of
+///   `translatable::Language` for dynamic inference.
+/// * `path` - A pat prefixed with `static` for static inference or a `Vec<impl
+///   ToString>` for dynamic inference.
+///
+/// The requested language falls back to its parents the same way
+/// [`translation!()`] does. A path that doesn't lead to a translation
+/// is not reported, neither in compile time nor in runtime.
+///
+/// If both the language and the path are static, the check is
+/// resolved in compile time, so it can be used in `const` items.
+///
+/// **Returns**
+/// A `bool`, whether the translation is available in the language.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn translation_exists(input: TokenStream) -> TokenStream {
+    translation_exists_macro(parse_macro_input!(input as TranslationExistsMacroArgs)).into()
+}
+
+/// # Available languages macro.
+///
+/// This macro obtains the languages a translation
+/// is available in at runtime, useful for diagnostics
+/// or language selectors.
+///
+/// **Parameters**
+/// * `path` - A pat prefixed with `static` for static inference or a `Vec<impl
+///   ToString>` for dynamic inference.
+///
+/// If the path is static, its existence is validated in compile time
+/// the same way [`translation!()`] does.
+///
+/// Only the languages embedded for runtime resolution are
+/// reported, which are all of them unless `embed_languages`
+/// is configured.
+///
+/// **Returns**
+/// An `Option<Vec<Language>>` containing either:
+/// * `Some(Vec<Language>)` - The languages sorted by their ISO 639-1 code.
+/// * `None` - If the path doesn't lead to a translation.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn available_languages(input: TokenStream) -> TokenStream {
+    available_languages_macro(parse_macro_input!(input as InputType<TranslationPath>)).into()
+}
+
+/// # Translation subtree macro
+///
+/// This macro obtains every translation beneath a path
+/// prefix in a language, such as all the entries of a menu.
+///
+/// **Parameters**
+/// * `language` - A string literal for static inference or an instance of
+///   `translatable::Language` for dynamic inference.
+/// * `prefix` - A pat prefixed with `static` for static inference or a
+///   `Vec<impl ToString>` for dynamic inference.
+///
+/// If the prefix is static, it's validated to contain at least
+/// one translation in compile time and only the translations
+/// beneath it are embedded. If the language is static too, the
+/// whole result is resolved in compile time.
+///
+/// Translations not available in the language are skipped, the
+/// templates are not replaced.
+///
+/// **Returns**
+/// A `Vec<(String, String)>` of `::` joined paths and their translations,
+/// sorted by path.
+#[proc_macro]
+pub fn subtree(input: TokenStream) -> TokenStream {
+    subtree_macro(parse_macro_input!(input as SubtreeMacroArgs)).into()
+}
+
+/// # Translation paths macro
+///
+/// This macro lists every path that leads to a translation,
+/// for example to assert in a test that every translation
+/// is used somewhere.
+///
+/// The list is resolved in compile time, so it can be
+/// used in `const` and `static` items.
+///
+/// **Parameters**
+/// This macro doesn't take any parameter.
+///
+/// **Returns**
+/// A `&'static [&'static str]` of `::` joined paths sorted alphabetically.
+#[proc_macro]
+pub fn translation_paths(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as Nothing);
+
+    translation_paths_macro().into()
+}
+
+/// # Translation hash macro
+///
+/// This macro computes a hash of all the loaded translations,
+/// for example to embed a version string a web frontend can use
+/// to bust its cache when the translations change.
+///
+/// The hash only depends on the translations content, so the same
+/// translations always hash the same regardless of the file paths,
+/// the order files are found in or the `seek_mode`. It's resolved in
+/// compile time so it can be used in `const` and `static` items.
+///
+/// **Parameters**
+/// This macro doesn't take any parameter.
+///
+/// **Returns**
+/// A `&'static str` with the hash as 16 lowercase hexadecimal digits.
+#[proc_macro]
+pub fn translation_hash(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as Nothing);
+
+    translation_hash_macro().into()
+}
+
+/// # Translation module macro
+///
+/// This macro generates a module with a function per
+/// translation beneath a path prefix, so translations are
+/// obtained trough type-safe accessors instead of paths.
+///
+/// **Parameters**
+/// * `visibility` - An optional visibility for the module, such as `pub`.
+/// * `prefix` - A path, optionally prefixed with `static`, that leads to a
+///   group of translations.
+///
+/// The module is named after the prefix segments joined with
+/// underscores and each function after the rest of its path the
+/// same way, so `ui::buttons::save` becomes `ui_buttons::save`.
+/// Segments that aren't valid identifiers are sanitized, for example
+/// `errors::404` becomes `errors::_404`.
+///
+/// Each function takes a `&Language` and a value per template key
+/// in alphabetical order, then evaluates to the same as [`translation!()`]
+/// with a static path and a dynamic language would. The paths are
+/// validated in compile time and typed templates constrain their
+/// arguments as well.
+///
+/// **Returns**
+/// A module declaration with functions returning a
+/// `Result<String, translatable::Error>`.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn translation_module(input: TokenStream) -> TokenStream {
+    translation_module_macro(parse_macro_input!(input as TranslationModuleMacroArgs)).into()
+}
+
+/// # Localized macro
+///
+/// This macro generates a function mapping any language to
+/// a translation, so matching a `Language` by hand to pick a
+/// text can't miss any variant.
+///
+/// **Parameters**
+/// * `path` - A path, optionally prefixed with `static`, that leads to a
+///   translation without templates.
+///
+/// The translation is resolved in compile time for every language
+/// the same way a static [`translation!()`] invocation would, falling
+/// back as configured, so the function can be used in `const` items.
+/// The path is validated in compile time.
+///
+/// **Returns**
+/// A `fn(&Language) -> Option<&'static str>` evaluating to `None` for
+/// the languages the translation can't be resolved in.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro]
+pub fn localized(input: TokenStream) -> TokenStream {
+    localized_macro(parse_macro_input!(input as LocalizedMacroArgs)).into()
 }
 
 /// # Translation context macro
@@ -74,19 +391,37 @@ pub fn translation(input: TokenStream) -> TokenStream {
 /// By definition that struct shouldn't be used for anything else,
 /// but nothing stops you from doing so.
 ///
-/// This macro applies a rule to the struct. All fields must be
-/// a `String` or `&str`.
+/// This macro applies a rule to the struct. Fields are usually
+/// a `String` or `&str`. A `&str` field is rendered as `&'static str`
+/// and borrows the translation inlined in the binary without allocating,
+/// thus it's only allowed for translations without templates. Fields of
+/// any other owned type, such as `u32` or a custom enum, are loaded as a
+/// `String` and then parsed with their `FromStr` implementation, failing
+/// in runtime with `Error::ContextFieldParse`, with the parse error as
+/// its source, if the translation can't be parsed. Parsed fields are not
+/// allowed along a `fallback_language`.
 ///
 /// You can configure some parameters as a punctuated [`MetaNameValue`],
 /// these are
 /// - `base_path`: A path that gets prepended to all fields.
-/// - `fallback_language`: A language that must be available for all
-/// paths and changes the return type of the `load_translations` method.
+/// - `fallback_language`: A language that must be available for all paths and
+///   changes the return type of the `load_translations` method.
 ///
 /// All the fields on the struct now point to paths in your translation
 /// files, you can extend these paths applying the `#[path()]` attribute
 /// with a [`TranslationPath`]. Otherwise the path will be appended as
-/// the field identifier.
+/// the field identifier. A field may replace the struct `base_path`
+/// with its own `#[base_path()]` attribute, so a single context can
+/// gather translations from multiple subtrees.
+///
+/// A `String` field may declare the placeholders it expects with the
+/// `#[placeholders()]` attribute, each of them must be used by the
+/// translation, otherwise the macro fails to compile.
+///
+/// A field may be loaded in a fixed language with the `#[language()]`
+/// attribute, which takes a language literal, checked against the
+/// translation, or an expression evaluating to a `Language`, instead
+/// of the language the context is loaded with.
 ///
 /// The field and struct visibility are kept as original.
 ///
@@ -95,6 +430,11 @@ pub fn translation(input: TokenStream) -> TokenStream {
 /// optionally wrapped on a result depending on the `fallback_language`
 /// parameter value.
 ///
+/// For finer control a `builder(language)` method is generated, returning
+/// a builder with a setter per field to override its value and a `*_with`
+/// setter per `String` or parsed field to pass that field's replacements,
+/// `build()` loads the remaining fields the same way `load_translations` does.
+///
 /// [`MetaNameValue`]: syn::MetaNameValue
 /// [`TranslationPath`]: macro_input::utils::translation_path::TranslationPath
 #[proc_macro_attribute]
@@ -105,3 +445,50 @@ pub fn translation_context(attr: TokenStream, item: TokenStream) -> TokenStream
     )
     .into()
 }
+
+/// # Translatable enum derive macro
+///
+/// This macro implements `TranslatableEnum` for an enum,
+/// mapping each variant to a translation path so it can be
+/// passed as a replacement and rendered as a localized label.
+///
+/// You can configure the enum with the `#[translatable()]`
+/// attribute as a punctuated [`MetaNameValue`], the only
+/// parameter is `base_path`, a path that gets prepended to
+/// all variants.
+///
+/// Each variant points to the variant name in snake case, so
+/// `InTransit` points to `in_transit`, you can change it applying
+/// `#[translatable(path = ...)]` with a [`TranslationPath`] to
+/// the variant. Variant fields are ignored.
+///
+/// Every variant path is validated to exist in compile time.
+///
+/// [`MetaNameValue`]: syn::MetaNameValue
+/// [`TranslationPath`]: macro_input::utils::translation_path::TranslationPath
+#[proc_macro_derive(TranslatableEnum, attributes(translatable))]
+pub fn translatable_enum(input: TokenStream) -> TokenStream {
+    translatable_enum_macro(parse_macro_input!(input as TranslatableEnumInput)).into()
+}
+
+/// # Replacements derive macro
+///
+/// This macro implements `Replacements` for a struct with
+/// named fields, each field being the replacement for the
+/// template with its name, so the struct can be spread into
+/// a [`translation!()`] invocation as `..replacements`.
+///
+/// Fields are rendered the same way as explicit replacements,
+/// thus `TranslatableEnum` fields are replaced with their label.
+///
+/// If the translation is known in compile time, every field is
+/// validated to be a template of the translation, and with
+/// `missing_replacements` configured as `error` every template
+/// without a default must be a field or an explicit replacement.
+/// Template type constraints are not checked for fields.
+///
+/// [`translation!()`]: crate::translation
+#[proc_macro_derive(Replacements)]
+pub fn replacements(input: TokenStream) -> TokenStream {
+    replacements_macro(parse_macro_input!(input as ReplacementsInput)).into()
+}
@@ -0,0 +1,121 @@
+//! [`available_languages!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`available_languages!()`] macro tokens
+//! from a translation path.
+//!
+//! [`available_languages!()`]: crate::available_languages
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use thiserror::Error;
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::{
+    embedded_translation,
+    embedded_translations,
+    load_translations,
+};
+use translatable_shared::handle_macro_result;
+use translatable_shared::translations::node::TranslationObjectExt;
+
+use super::translation::{dynamic_lookup, similar_hint};
+use crate::macro_input::utils::input_type::InputType;
+use crate::macro_input::utils::translation_path::TranslationPath;
+
+/// Macro compile-time available languages resolution error.
+///
+/// Represents errors that can occur while compiling the
+/// [`available_languages!()`] macro.
+///
+/// [`available_languages!()`]: crate::available_languages
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// The requested translation path could not be found.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — A similar existing path along the translation file it's defined
+    ///   in, if any.
+    #[error("The path '{0}' could not be found{hint}", hint = similar_hint(_1))]
+    PathNotFound(String, Option<(String, String)>),
+}
+
+/// [`available_languages!()`] macro output generation.
+///
+/// Expands into an expression that evaluates to the languages
+/// a translation is available in at runtime, which are the
+/// embedded languages for that translation.
+///
+/// If the path is static the languages are resolved during macro
+/// expansion and the path existence is validated, otherwise the
+/// embedded translations are searched in runtime.
+///
+/// **Arguments**
+/// * `path` — The translation path to look up.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to an `Option<Vec<Language>>`.
+///
+/// [`available_languages!()`]: crate::available_languages
+pub fn available_languages_macro(path: InputType<TranslationPath>) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+    let config = handle_macro_result!(load_config());
+    let embed_languages = config.embed_languages();
+    let path_separator = config.path_separator();
+
+    match path {
+        InputType::Static(path) => {
+            let path_segments = path.segments();
+
+            let translation = handle_macro_result!(
+                translations
+                    .find_path(path_segments)
+                    .ok_or_else(|| MacroCompileError::PathNotFound(
+                        path_segments.join("::"),
+                        translations
+                            .similar_path(path_segments)
+                            .map(|(path, source)| (path, source.to_string()))
+                    ))
+            );
+
+            let languages =
+                embedded_translation(translation, embed_languages).available_languages();
+
+            quote! {
+                std::option::Option::Some(vec![#(#languages),*])
+            }
+        },
+
+        InputType::Dynamic(path) => {
+            let lookup = handle_macro_result!(dynamic_lookup(&embedded_translations(
+                &translations,
+                embed_languages
+            )));
+
+            quote! {
+                (|| -> Result<
+                    Vec<translatable::shared::misc::language::Language>,
+                    translatable::Error
+                > {
+                    #[doc(hidden)]
+                    use translatable::shared::translations::node::TranslationObjectExt as _;
+
+                    #[doc(hidden)]
+                    let path: Vec<_> = #path;
+
+                    #[doc(hidden)]
+                    let path = translatable::shared::translations::resolution::split_path(
+                        &path,
+                        #path_separator
+                    );
+
+                    #lookup
+
+                    let _ = source;
+                    std::result::Result::Ok(translation_object.available_languages())
+                })()
+                .ok()
+            }
+        },
+    }
+}
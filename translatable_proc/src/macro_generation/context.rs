@@ -7,13 +7,28 @@
 //! [`#\[translation_context\]`]: crate::translation_context
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::{ToTokens, quote};
+use quote::{format_ident, quote, quote_spanned};
+use strum::IntoEnumIterator;
+use syn::Error as SynError;
+use syn::spanned::Spanned;
 use thiserror::Error;
+use translatable_shared::data::config::{MissingReplacements, load_config};
+use translatable_shared::data::translations::{embedded_translation, load_translations};
 use translatable_shared::handle_macro_result;
 use translatable_shared::macros::collections::map_to_tokens;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::language::Language;
+use translatable_shared::misc::templating::FormatString;
+use translatable_shared::translations::node::TranslationObjectExt;
 
-use crate::data::translations::load_translations;
-use crate::macro_input::context::{ContextMacroArgs, ContextMacroStruct};
+use super::translation::similar_hint;
+use crate::macro_input::context::{
+    ContextFieldType,
+    ContextMacroArgs,
+    ContextMacroField,
+    ContextMacroStruct,
+};
+use crate::macro_input::utils::input_type::InputType;
 
 /// Macro compile-time translation resolution error.
 ///
@@ -33,16 +48,64 @@ enum MacroCompileError {
     ///
     /// **Parameters**
     /// * `0` — The translation path, displayed in `::` notation.
-    #[error("A translation with the path '{0}' could not be found")]
-    TranslationNotFound(String),
+    /// * `1` — A similar existing path along the translation file it's defined
+    ///   in, if any.
+    #[error("A translation with the path '{0}' could not be found{hint}", hint = similar_hint(_1))]
+    TranslationNotFound(String, Option<(String, String)>),
+
+    /// The struct base path doesn't lead to a group of translations.
+    ///
+    /// Reported once instead of an error per field.
+    ///
+    /// **Parameters**
+    /// * `0` — The base path, displayed in `::` notation.
+    #[error("The base path '{0}' doesn't lead to a group of translations")]
+    BasePathNotFound(String),
 
     /// A fallback is not available for a specified translation path.
     #[error("One of the translations doesn't have the fallback language available")]
     FallbackNotAvailable,
 
-    /// One of the fields type is not a &str or String.
-    #[error("Only String' and '&str' is allowed for translation contexts")]
-    TypeNotAllowed,
+    /// A `&str` field points to a translation with templates.
+    ///
+    /// Templates are replaced in runtime, so the translation
+    /// can't be borrowed from the binary.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    #[error(
+        "The translation '{0}' contains templates, it can only be loaded into a 'String' field"
+    )]
+    BorrowedTemplate(String),
+
+    /// A field declares a placeholder its translation doesn't use.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — The unused placeholder.
+    #[error("The translation '{0}' doesn't use the placeholder '{1}'")]
+    UnusedPlaceholder(String, String),
+
+    /// A parsed field in a context with a fallback language.
+    ///
+    /// Parsing may fail in runtime, while loading a context
+    /// with a fallback language is infallible.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    #[error(
+        "The translation '{0}' is parsed, which may fail, so it can't be loaded with a fallback \
+         language"
+    )]
+    ParsedWithFallback(String),
+
+    /// A field language override is not available for its translation.
+    ///
+    /// **Parameters**
+    /// * `0` — The overriding language.
+    /// * `1` — The translation path, displayed in `::` notation.
+    #[error("The language '{0:?}' ('{0:#}') is not available for the translation '{1}'")]
+    LanguageNotAvailable(Language, String),
 }
 
 /// [`#\[translation_context\]`] macro output generation.
@@ -54,6 +117,25 @@ enum MacroCompileError {
 /// with all the paths and then the `load_translations` generated
 /// method will return the same structure instead of a Result.
 ///
+/// A builder is generated along the struct, each field has a setter
+/// overriding its value and `String` fields also have a `*_with`
+/// setter taking the replacements for that field only, as positional
+/// arguments if the field declares its `#[placeholders()]`, the same
+/// applies to parsed fields, whose value is parsed after replacing. The
+/// `load_translations` method is a shortcut that applies the same
+/// replacements to all the fields.
+///
+/// A non empty base path must lead to a group of translations, otherwise
+/// a single error is reported at the base path instead of one per field.
+///
+/// A field with a `#[language()]` attribute is loaded in that language
+/// instead of the one passed to the builder, a static language is checked
+/// against the translation unless there is a fallback language.
+///
+/// If `missing_replacements` is configured as `error`, templates
+/// without a value make the loading fail, unless there is a fallback
+/// language, as loading is infallible then.
+///
 /// **Arguments**
 /// * `macro_args` - The parsed arguments for the macro invocation.
 /// * `macro_input` - The parsed macro tokens themselves.
@@ -67,90 +149,383 @@ pub fn context_macro(
     macro_input: ContextMacroStruct,
 ) -> TokenStream2 {
     let translations = handle_macro_result!(out load_translations());
+    let config = handle_macro_result!(out load_config());
+    let embed_languages = config.embed_languages();
+    let strict = config.missing_replacements() == MissingReplacements::Error;
     let base_path = macro_args.base_path();
 
+    if !base_path
+        .segments()
+        .is_empty()
+        && translations
+            .subtree_segments(base_path.segments())
+            .is_none()
+    {
+        let error = MacroCompileError::BasePathNotFound(
+            base_path
+                .segments()
+                .join("::"),
+        );
+
+        return SynError::new(base_path.span(), error).into_compile_error();
+    }
+
     let struct_pub = macro_input.visibility();
     let struct_ident = macro_input.ident();
 
-    let struct_fields = handle_macro_result!(out
-        macro_input
-            .fields()
+    let struct_fields = macro_input.fields();
+
+    // each field error is spanned to the field it belongs to.
+    let load_value = |field: &ContextMacroField| -> Result<TokenStream2, MacroCompileError> {
+        let path_segments = field.full_path(base_path);
+
+        let path_segments_display = path_segments.join("::");
+
+        let (source, translation) = translations
+            .find_path_with_source(&path_segments)
+            .ok_or_else(|| {
+                MacroCompileError::TranslationNotFound(
+                    path_segments.join("::"),
+                    translations
+                        .similar_path(&path_segments)
+                        .map(|(path, source)| (path, source.to_string())),
+                )
+            })?;
+
+        let ident = field.ident();
+
+        let path_tokens = quote! {
+            vec![#(#path_segments.to_string()),*]
+        };
+
+        let not_available = quote! {
+            translatable::Error::LanguageNotAvailable(
+                language.clone(),
+                #path_tokens,
+                Some(#source.to_string())
+            )
+        };
+
+        let fallback = macro_args
+            .fallback_language()
+            .map(|fallback_language| {
+                translation
+                    .get(&fallback_language)
+                    .ok_or(MacroCompileError::FallbackNotAvailable)
+            })
+            .transpose()?;
+
+        if let Some(placeholder) = field
+            .placeholders()
+            .unwrap_or_default()
             .iter()
-            .map(|field| {
-                let field_ty = field.ty().to_token_stream().to_string();
-                if matches!(field_ty.as_str(), "String" | "&str") {
-                    Ok(field)
-                } else {
-                    Err(MacroCompileError::TypeNotAllowed)
-                }
+            .map(|placeholder| placeholder.to_string())
+            .find(|placeholder| {
+                !translation
+                    .values()
+                    .any(|translation| translation.uses_template(placeholder))
             })
-            .collect::<Result<Vec<_>, _>>()
-    );
+        {
+            return Err(MacroCompileError::UnusedPlaceholder(path_segments_display, placeholder));
+        }
 
-    let loadable_translations = handle_macro_result!(out
+        let translation = embedded_translation(translation, embed_languages);
+
+        let unavailable_language = match field.language() {
+            Some(InputType::Static(language)) if fallback.is_none() => translation
+                .resolve(language)
+                .is_none()
+                .then(|| language.clone()),
+            _ => None,
+        };
+
+        if let Some(language) = unavailable_language {
+            return Err(MacroCompileError::LanguageNotAvailable(language, path_segments_display));
+        }
+
+        if matches!(field.ty(), ContextFieldType::Str) {
+            if translation
+                .values()
+                .any(FormatString::has_templates)
+            {
+                return Err(MacroCompileError::BorrowedTemplate(path_segments_display));
+            }
+
+            // custom tags can't be matched as patterns, and fall back in runtime.
+            let arms = Language::iter()
+                .filter_map(|language| {
+                    let (_, translation) = translation.resolve(&language)?;
+                    let translation = translation.original();
+
+                    Some(quote! { Some(#language) => break #translation })
+                });
+
+            let custom_arms = translation
+                .iter()
+                .filter_map(|(language, translation)| {
+                    let Language::Other(tag) = language else {
+                        return None;
+                    };
+                    let translation = translation.original();
+
+                    Some(quote! {
+                        Some(translatable::shared::misc::language::Language::Other(tag))
+                            if tag == #tag => break #translation
+                    })
+                });
+
+            let fallback_arm = match fallback {
+                Some(fallback) => {
+                    let fallback = fallback.original();
+                    quote! { None => break #fallback }
+                },
+                None => quote! { None => return Err(#not_available) },
+            };
+
+            return Ok(quote! {
+                match self.#ident {
+                    Some(value) => value,
+                    None => {
+                        #[doc(hidden)]
+                        let mut current = Some(language.clone());
+
+                        loop {
+                            match &current {
+                                #(#arms,)*
+                                #(#custom_arms,)*
+                                #fallback_arm,
+                                Some(other) => current = other.fallback_parent(),
+                            }
+                        }
+                    },
+                }
+            });
+        }
+
+        let translation_tokens = map_to_tokens(&translation);
+
+        let replacements = format_ident!("{ident}_replacements");
+
+        let handler = match fallback {
+            Some(fallback) => quote! {
+                .get(&language)
+                .unwrap_or(&#fallback)
+                .replace_with(&self.#replacements)
+            },
+            None if strict => quote! {
+                .get_or_parent(&language)
+                .ok_or_else(|| #not_available)?
+                .replace_with_strict(&self.#replacements)
+                .map_err(|error| {
+                    translatable::Error::Replacement(
+                        error,
+                        #path_tokens
+                    )
+                })?
+            },
+            None => quote! {
+                .get_or_parent(&language)
+                .ok_or_else(|| #not_available)?
+                .replace_with(&self.#replacements)
+            },
+        };
+
+        let ContextFieldType::Parsed(ty) = field.ty() else {
+            return Ok(quote! {
+                match self.#ident {
+                    Some(value) => value,
+                    None => #translation_tokens
+                        #handler,
+                }
+            });
+        };
+
+        if fallback.is_some() {
+            return Err(MacroCompileError::ParsedWithFallback(path_segments_display));
+        }
+
+        let parse = quote_spanned! { ty.span() =>
+            <#ty as translatable::ContextFieldValue>::parse_field
+        };
+
+        Ok(quote! {
+            match self.#ident {
+                Some(value) => value,
+                None => {
+                    let value = #translation_tokens
+                        #handler;
+
+                    #parse(&value)
+                        .map_err(|error| {
+                            translatable::Error::ContextFieldParse(
+                                #path_tokens,
+                                value,
+                                error
+                            )
+                        })?
+                },
+            }
+        })
+    };
+
+    let load_field = |field: &ContextMacroField| -> Result<TokenStream2, MacroCompileError> {
+        let ident = field.ident();
+        let value = load_value(field)?;
+
+        // the override shadows the context language for this field only.
+        Ok(match field.language() {
+            Some(InputType::Static(language)) => quote! {
+                #ident: {
+                    #[doc(hidden)]
+                    let language = #language;
+
+                    #value
+                }
+            },
+            Some(InputType::Dynamic(language)) => quote! {
+                #ident: {
+                    #[doc(hidden)]
+                    let language: translatable::Language = #language;
+
+                    #value
+                }
+            },
+            None => quote! { #ident: #value },
+        })
+    };
+
+    let loadable_translations = handle_macro_result!(collect spanned
         macro_input
             .fields()
             .iter()
             .map(|field| {
-                let path_segments = base_path
-                    .merge(&field.path());
+                load_field(field).map_err(|error| error.to_syn_error(field.ident()))
+            })
+    );
 
-                let path_segments_display = path_segments
-                    .join("::");
+    let builder_ident = format_ident!("{struct_ident}Builder");
 
-                let translation = translations
-                    .find_path(&path_segments)
-                    .ok_or(MacroCompileError::TranslationNotFound(path_segments.join("::")))?;
+    let builder_fields = struct_fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident();
+            let ty = field.ty();
 
-                let translation_tokens = map_to_tokens(translation);
-                let ident = field.ident();
+            match ty {
+                ContextFieldType::String | ContextFieldType::Parsed(_) => {
+                    let replacements = format_ident!("{ident}_replacements");
 
-                let handler = if let Some(fallback_language) = macro_args.fallback_language() {
-                    if let Some(translation) = translation.get(&fallback_language) {
-                        quote! {
-                            .unwrap_or(&#translation)
-                        }
-                    } else {
-                        return Err(MacroCompileError::FallbackNotAvailable);
+                    quote! {
+                        #ident: Option<#ty>,
+                        #replacements: std::collections::HashMap<String, String>,
                     }
-                } else {
+                },
+                ContextFieldType::Str => quote! { #ident: Option<#ty>, },
+            }
+        });
+
+    let builder_defaults = struct_fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident();
+
+            match field.ty() {
+                ContextFieldType::String | ContextFieldType::Parsed(_) => {
+                    let replacements = format_ident!("{ident}_replacements");
+
                     quote! {
-                        .ok_or_else(|| translatable::Error::LanguageNotAvailable(
-                            language.clone(),
-                            #path_segments_display.to_string()
-                        ))?
+                        #ident: None,
+                        #replacements: std::collections::HashMap::new(),
                     }
-                };
-
-                Ok(quote! {
-                    #ident: #translation_tokens
-                        .get(&language)
-                        #handler
-                        .replace_with(&replacements)
-                })
-            })
-            .collect::<Result<Vec<TokenStream2>, MacroCompileError>>()
-    );
+                },
+                ContextFieldType::Str => quote! { #ident: None, },
+            }
+        });
+
+    let builder_setters = struct_fields
+        .iter()
+        .map(|field| {
+            let ident = field.ident();
+            let ty = field.ty();
+
+            let setter = quote! {
+                #struct_pub fn #ident(mut self, value: #ty) -> Self {
+                    self.#ident = Some(value);
+                    self
+                }
+            };
+
+            match ty {
+                ContextFieldType::String | ContextFieldType::Parsed(_) => {
+                    let replacements = format_ident!("{ident}_replacements");
+                    let setter_with = format_ident!("{ident}_with");
+
+                    let setter_with = match field.placeholders() {
+                        Some(placeholders) => {
+                            let keys = placeholders
+                                .iter()
+                                .map(|placeholder| placeholder.to_string());
+
+                            quote! {
+                                #struct_pub fn #setter_with(
+                                    mut self,
+                                    #(#placeholders: impl ToString),*
+                                ) -> Self {
+                                    self.#replacements = std::collections::HashMap::from([
+                                        #((#keys.to_string(), #placeholders.to_string())),*
+                                    ]);
+                                    self
+                                }
+                            }
+                        },
+                        None => quote! {
+                            #struct_pub fn #setter_with<K: ToString, V: ToString>(
+                                mut self,
+                                replacements: &std::collections::HashMap<K, V>
+                            ) -> Self {
+                                self.#replacements = replacements
+                                    .iter()
+                                    .map(|(key, value)| (key.to_string(), value.to_string()))
+                                    .collect();
+                                self
+                            }
+                        },
+                    };
+
+                    quote! {
+                        #setter
+
+                        #setter_with
+                    }
+                },
+                ContextFieldType::Str => setter,
+            }
+        });
+
+    let replaced_fields = struct_fields
+        .iter()
+        .filter(|field| !matches!(field.ty(), ContextFieldType::Str))
+        .map(|field| format_ident!("{}_replacements", field.ident()));
 
     let is_lang_some = macro_args
         .fallback_language()
         .is_some();
 
     let load_ret_ty = if is_lang_some {
-        quote! { Self }
+        quote! { #struct_ident }
     } else {
-        quote! { Result<Self, translatable::Error> }
+        quote! { Result<#struct_ident, translatable::Error> }
     };
 
     let load_ret_stmnt = if is_lang_some {
         quote! {
-            Self {
+            #struct_ident {
                 #(#loadable_translations),*
             }
         }
     } else {
         quote! {
-            Ok(Self {
+            Ok(#struct_ident {
                 #(#loadable_translations),*
             })
         }
@@ -161,16 +536,45 @@ pub fn context_macro(
             #(#struct_fields),*
         }
 
+        #struct_pub struct #builder_ident {
+            language: translatable::Language,
+            #(#builder_fields)*
+        }
+
         impl #struct_ident {
+            #struct_pub fn builder(language: translatable::Language) -> #builder_ident {
+                #builder_ident {
+                    language,
+                    #(#builder_defaults)*
+                }
+            }
+
             #struct_pub fn load_translations<K: ToString, V: ToString>(
                 language: translatable::Language,
                 replacements: &std::collections::HashMap<K, V>
             ) -> #load_ret_ty {
+                #[allow(unused_variables)]
                 let replacements = replacements
                     .iter()
                     .map(|(key, value)| (key.to_string(), value.to_string()))
                     .collect::<std::collections::HashMap<String, String>>();
 
+                #[allow(unused_mut)]
+                let mut builder = Self::builder(language);
+                #(builder.#replaced_fields = replacements.clone();)*
+                builder.build()
+            }
+        }
+
+        impl #builder_ident {
+            #(#builder_setters)*
+
+            #struct_pub fn build(self) -> #load_ret_ty {
+                #[doc(hidden)]
+                use translatable::shared::translations::node::TranslationObjectExt as _;
+
+                let language = self.language;
+
                 #load_ret_stmnt
             }
         }
@@ -0,0 +1,171 @@
+//! [`localized!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`localized!()`] macro tokens
+//! with intrinsics from [`macro_input::localized`].
+//!
+//! [`localized!()`]: crate::localized
+//! [`macro_input::localized`]: super::super::macro_input::localized
+
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use strum::IntoEnumIterator;
+use thiserror::Error;
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::{language_enabled, load_translations};
+use translatable_shared::handle_macro_result;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::language::Language;
+use translatable_shared::translations::node::TranslationObjectExt;
+use translatable_shared::translations::resolution::{Fallback, resolve_object};
+
+use super::translation::similar_hint;
+use crate::macro_input::localized::LocalizedMacroArgs;
+
+/// Macro compile-time localized resolution error.
+///
+/// Represents errors that can occur while compiling the
+/// [`localized!()`] macro.
+///
+/// [`localized!()`]: crate::localized
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// The requested translation path could not be found.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — A similar existing path along the translation file it's defined
+    ///   in, if any.
+    #[error("The path '{0}' could not be found{hint}", hint = similar_hint(_1))]
+    PathNotFound(String, Option<(String, String)>),
+
+    /// The translation has templates, which can't be
+    /// replaced in a `&'static str`.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    #[error("The translation '{0}' contains templates, it can't be localized as a static string")]
+    TemplatedTranslation(String),
+}
+
+/// [`localized!()`] macro output generation.
+///
+/// Expands into a function pointer that maps any language to
+/// the translation it resolves to, as a static invocation of
+/// [`translation!()`] would, with the configured fallback.
+///
+/// The translation is resolved for every enabled language during
+/// macro expansion, and every private use language it's defined
+/// in. Languages resolving to the same translation share a match
+/// arm, other private use languages are resolved as their parent
+/// and the rest evaluate to `None`.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a
+/// `fn(&Language) -> Option<&'static str>`.
+///
+/// [`localized!()`]: crate::localized
+/// [`translation!()`]: crate::translation
+pub fn localized_macro(input: LocalizedMacroArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+    let config = handle_macro_result!(load_config());
+    let fallback_rules = config.fallback_rules();
+    let path_segments = input
+        .path()
+        .segments();
+    let path_display = path_segments.join("::");
+
+    let translation_object = handle_macro_result!(
+        translations
+            .find_path(path_segments)
+            .ok_or_else(|| MacroCompileError::PathNotFound(
+                path_display.clone(),
+                translations
+                    .similar_path(path_segments)
+                    .map(|(path, source)| (path, source.to_string()))
+            ))
+    );
+
+    let fallback = if fallback_rules.is_empty() {
+        Fallback::Parents
+    } else {
+        Fallback::Rules(&fallback_rules)
+    };
+
+    let private_use = translation_object
+        .available_languages()
+        .into_iter()
+        .filter(|language| matches!(language, Language::Other(_)));
+
+    // languages resolving to the same translation share an arm,
+    // except private use languages which are matched by a guard.
+    let mut arms = Vec::<(Vec<TokenStream2>, String, bool)>::new();
+
+    for language in Language::iter()
+        .filter(language_enabled)
+        .chain(private_use)
+    {
+        let Ok((_, translation)) =
+            resolve_object(translation_object, None, &language, &[&path_display], fallback)
+        else {
+            continue;
+        };
+
+        if !translation
+            .placeholders()
+            .is_empty()
+        {
+            return MacroCompileError::TemplatedTranslation(path_display).to_compile_error();
+        }
+
+        let literal = translation.replace_with(&HashMap::new());
+
+        if let Language::Other(tag) = &language {
+            let pattern = quote! {
+                translatable::shared::misc::language::Language::Other(tag) if tag == #tag
+            };
+
+            arms.push((vec![pattern], literal, true));
+            continue;
+        }
+
+        match arms
+            .iter_mut()
+            .find(|(_, arm_literal, guarded)| !*guarded && *arm_literal == literal)
+        {
+            Some((patterns, ..)) => patterns.push(quote! { #language }),
+            None => arms.push((vec![quote! { #language }], literal, false)),
+        }
+    }
+
+    let arms = arms
+        .iter()
+        .map(|(patterns, literal, _)| {
+            quote! { #(#patterns)|* => std::option::Option::Some(#literal), }
+        });
+
+    quote! {
+        {
+            #[doc(hidden)]
+            fn localized(
+                language: &translatable::shared::misc::language::Language
+            ) -> std::option::Option<&'static str> {
+                match language {
+                    #(#arms)*
+                    translatable::shared::misc::language::Language::Other(_) => language
+                        .fallback_parent()
+                        .and_then(|parent| localized(&parent)),
+                    _ => std::option::Option::None,
+                }
+            }
+
+            localized
+                as fn(&translatable::shared::misc::language::Language) -> std::option::Option<&'static str>
+        }
+    }
+}
@@ -10,5 +10,15 @@
 //!
 //! [`macro_input`]: crate::macro_input
 
+pub mod available_languages;
 pub mod context;
+pub mod localized;
+pub mod replacements;
+pub mod subtree;
+pub mod translatable_enum;
 pub mod translation;
+pub mod translation_exists;
+pub mod translation_hash;
+pub mod translation_list;
+pub mod translation_module;
+pub mod translation_paths;
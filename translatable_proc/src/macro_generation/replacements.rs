@@ -0,0 +1,103 @@
+//! [`#\[derive(Replacements)\]`] output module.
+//!
+//! This module contains the required for
+//! the generation of the `Replacements` implementation
+//! with intrinsics from [`macro_input::replacements`].
+//!
+//! [`#\[derive(Replacements)\]`]: crate::Replacements
+//! [`macro_input::replacements`]: super::super::macro_input::replacements
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::ext::IdentExt;
+
+use crate::macro_input::replacements::ReplacementsInput;
+
+/// Replacement key type generation.
+///
+/// **Arguments**
+/// * `key` — The replacement key.
+///
+/// **Returns**
+/// The `Key` type identifying the key in compile time.
+pub fn key_type(key: &str) -> TokenStream2 {
+    quote! {
+        translatable::replacement_keys::Key<{ translatable::replacement_keys::key_hash(#key) }>
+    }
+}
+
+/// [`#\[derive(Replacements)\]`] macro output generation.
+///
+/// Implements `Replacements` mapping each field to the
+/// template with its name, rendered the same way as an
+/// explicit replacement, so `TranslatableEnum` fields are
+/// replaced with their label.
+///
+/// The field names are also declared as a type-level list,
+/// for [`translation!()`] to validate them against the
+/// templates of static paths in compile time.
+///
+/// **Arguments**
+/// * `input` — The parsed struct.
+///
+/// **Returns**
+/// Generated `TokenStream2` with the trait implementation.
+///
+/// [`#\[derive(Replacements)\]`]: crate::Replacements
+/// [`translation!()`]: crate::translation
+pub fn replacements_macro(input: ReplacementsInput) -> TokenStream2 {
+    let ident = input.ident();
+    let (impl_generics, ty_generics, where_clause) = input
+        .generics()
+        .split_for_impl();
+
+    let keys = input
+        .fields()
+        .iter()
+        .rev()
+        .fold(quote! { () }, |rest, field| {
+            let key = key_type(
+                &field
+                    .unraw()
+                    .to_string(),
+            );
+            quote! { (#key, #rest) }
+        });
+
+    let entries = input
+        .fields()
+        .iter()
+        .map(|field| {
+            let key = field
+                .unraw()
+                .to_string();
+
+            quote! {
+                (
+                    #key.to_string(),
+                    {
+                        #[doc(hidden)]
+                        use translatable::replacement::{DisplayReplacement as _, EnumReplacement as _};
+
+                        (&translatable::replacement::Replacement(&self.#field)).to_replacement(language)
+                    }
+                )
+            }
+        });
+
+    quote! {
+        impl #impl_generics translatable::Replacements for #ident #ty_generics #where_clause {
+            #[doc(hidden)]
+            type Keys = #keys;
+
+            fn to_replacements(
+                &self,
+                language: &translatable::Language,
+            ) -> std::collections::HashMap<String, String> {
+                vec![#(#entries),*]
+                    .into_iter()
+                    .collect::<std::collections::HashMap<_, _>>()
+            }
+        }
+    }
+}
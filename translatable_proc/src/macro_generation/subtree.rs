@@ -0,0 +1,138 @@
+//! [`subtree!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`subtree!()`] macro tokens
+//! with intrinsics from [`macro_input::subtree`].
+//!
+//! [`subtree!()`]: crate::subtree
+//! [`macro_input::subtree`]: super::super::macro_input::subtree
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use thiserror::Error;
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::{embedded_translations, load_translations};
+use translatable_shared::handle_macro_result;
+use translatable_shared::macros::collections::map_to_tokens;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::translations::node::TranslationObjectExt;
+
+use crate::macro_input::subtree::SubtreeMacroArgs;
+use crate::macro_input::utils::input_type::InputType;
+
+/// Macro compile-time subtree resolution error.
+///
+/// Represents errors that can occur while compiling the
+/// [`subtree!()`] macro.
+///
+/// [`subtree!()`]: crate::subtree
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// The requested path prefix doesn't lead to any translation.
+    ///
+    /// **Parameters**
+    /// * `0` — The path prefix, displayed in `::` notation.
+    #[error("The path '{0}' doesn't contain any translation")]
+    PathNotFound(String),
+}
+
+/// [`subtree!()`] macro output generation.
+///
+/// Expands into an expression that evaluates to every translation
+/// beneath a path prefix in the requested language, translations
+/// not available in that language are skipped.
+///
+/// If both the language and the prefix are static the translations
+/// are resolved during macro expansion. If only the prefix is static,
+/// its existence is validated and only the translations beneath it are
+/// embedded, otherwise all the embedded translations are searched in
+/// runtime.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `Vec<(String, String)>` of
+/// `::` joined paths and their translations, sorted by path.
+///
+/// [`subtree!()`]: crate::subtree
+pub fn subtree_macro(input: SubtreeMacroArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+    let embed_languages = handle_macro_result!(load_config()).embed_languages();
+    let translations = embedded_translations(&translations, embed_languages);
+
+    let language = match input.language() {
+        InputType::Static(language) => quote! { &#language },
+        InputType::Dynamic(language) => quote! { &(#language) },
+    };
+
+    let leaves = match input.prefix() {
+        InputType::Static(prefix) => {
+            let prefix = prefix.segments();
+            let subtree = translations.find_subtree(prefix);
+
+            if subtree.is_empty() {
+                return MacroCompileError::PathNotFound(prefix.join("::")).to_compile_error();
+            }
+
+            if let InputType::Static(language) = input.language() {
+                let translations = subtree
+                    .iter()
+                    .filter_map(|(path, translation)| {
+                        let path = path.join("::");
+                        let translation = translation
+                            .get_or_parent(language)?
+                            .original();
+
+                        Some(quote! { (#path.to_string(), #translation.to_string()) })
+                    });
+
+                return quote! { vec![#(#translations),*] };
+            }
+
+            let leaves = subtree
+                .iter()
+                .map(|(path, translation)| {
+                    let translation = map_to_tokens(translation);
+                    quote! { (vec![#(#path.to_string()),*], #translation) }
+                });
+
+            quote! {
+                #[doc(hidden)]
+                let leaves: Vec<(Vec<String>, _)> = vec![#(#leaves),*];
+            }
+        },
+
+        InputType::Dynamic(prefix) => quote! {
+            #[doc(hidden)]
+            let translations = #translations;
+
+            #[doc(hidden)]
+            let prefix: Vec<_> = #prefix;
+
+            #[doc(hidden)]
+            let leaves = translations.find_subtree(&prefix);
+        },
+    };
+
+    quote! {
+        {
+            #[doc(hidden)]
+            use translatable::shared::translations::node::TranslationObjectExt as _;
+
+            #[doc(hidden)]
+            let language: &translatable::shared::misc::language::Language = #language;
+
+            #leaves
+
+            leaves
+                .iter()
+                .filter_map(|(path, translation)| {
+                    translation
+                        .get_or_parent(language)
+                        .map(|translation| (path.join("::"), translation.original().to_string()))
+                })
+                .collect::<Vec<(String, String)>>()
+        }
+    }
+}
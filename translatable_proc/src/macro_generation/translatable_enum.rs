@@ -0,0 +1,127 @@
+//! [`#\[derive(TranslatableEnum)\]`] output module.
+//!
+//! This module contains the required for
+//! the generation of the `TranslatableEnum` implementation
+//! with intrinsics from [`macro_input::translatable_enum`].
+//!
+//! [`#\[derive(TranslatableEnum)\]`]: crate::TranslatableEnum
+//! [`macro_input::translatable_enum`]: super::super::macro_input::translatable_enum
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Path, parse_str};
+use thiserror::Error;
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::load_translations;
+use translatable_shared::handle_macro_result;
+
+use super::translation::similar_hint;
+use crate::macro_input::translatable_enum::TranslatableEnumInput;
+
+/// Macro compile-time translatable enum resolution error.
+///
+/// Represents errors that can occur while deriving
+/// `TranslatableEnum`.
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// A variant translation path could not be found.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — A similar existing path along the translation file it's defined
+    ///   in, if any.
+    #[error("The path '{0}' could not be found{hint}", hint = similar_hint(_1))]
+    PathNotFound(String, Option<(String, String)>),
+}
+
+/// [`#\[derive(TranslatableEnum)\]`] macro output generation.
+///
+/// Implements `TranslatableEnum` mapping each variant to
+/// its translation path, validated in compile time, the
+/// labels are loaded with a [`translation!()`] invocation
+/// per variant taking the language in runtime, converted
+/// into a `String` if `cow_output` is configured.
+///
+/// **Arguments**
+/// * `input` — The parsed enum.
+///
+/// **Returns**
+/// Generated `TokenStream2` with the trait implementation, or
+/// a compile error for each variant whose path could not be found.
+///
+/// [`#\[derive(TranslatableEnum)\]`]: crate::TranslatableEnum
+/// [`translation!()`]: crate::translation
+pub fn translatable_enum_macro(input: TranslatableEnumInput) -> TokenStream2 {
+    let config = handle_macro_result!(out load_config());
+    let translations = handle_macro_result!(out load_translations());
+
+    let into_string = config
+        .cow_output()
+        .then(|| quote! { .map(String::from) });
+
+    let ident = input.ident();
+    let (impl_generics, ty_generics, where_clause) = input
+        .generics()
+        .split_for_impl();
+
+    let arms = handle_macro_result!(collect out
+        input
+            .variants()
+            .iter()
+            .map(|variant| -> Result<_, MacroCompileError> {
+                let path_segments = input
+                    .base_path()
+                    .merge(variant.path());
+
+                let path_segments_display = path_segments.join("::");
+
+                translations
+                    .find_path(&path_segments)
+                    .ok_or_else(|| {
+                        MacroCompileError::PathNotFound(
+                            path_segments_display.clone(),
+                            translations
+                                .similar_path(&path_segments)
+                                .map(|(path, source)| (path, source.to_string())),
+                        )
+                    })?;
+
+                let path = parse_str::<Path>(&path_segments_display).map_err(|_| {
+                    MacroCompileError::PathNotFound(path_segments_display.clone(), None)
+                })?;
+
+                let variant_ident = variant.ident();
+
+                Ok((
+                    quote! { Self::#variant_ident { .. } => #path_segments_display },
+                    quote! {
+                        Self::#variant_ident { .. } =>
+                            translatable::translation!(language, static #path)#into_string
+                    },
+                ))
+            })
+    );
+
+    let (paths, labels) = arms
+        .into_iter()
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    quote! {
+        impl #impl_generics translatable::TranslatableEnum for #ident #ty_generics #where_clause {
+            fn translation_path(&self) -> &'static str {
+                match *self {
+                    #(#paths,)*
+                }
+            }
+
+            fn translate(
+                &self,
+                language: &translatable::Language,
+            ) -> std::result::Result<String, translatable::Error> {
+                match *self {
+                    #(#labels,)*
+                }
+            }
+        }
+    }
+}
@@ -7,17 +7,49 @@
 //! [`translation!()`]: crate::translation
 //! [`macro_input::translation`]: super::super::macro_input::translation
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::{ToTokens, quote};
+use std::collections::HashMap;
+#[cfg(all(feature = "phf", not(all(feature = "hot-reload", debug_assertions))))]
+use std::collections::HashSet;
+
+#[cfg(not(all(feature = "hot-reload", debug_assertions)))]
+use proc_macro2::LexError;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote, quote_spanned};
+use strum::IntoEnumIterator;
+use syn::spanned::Spanned;
+use syn::{Expr, Ident};
 use thiserror::Error;
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+use translatable_shared::data::config::config_sources;
+use translatable_shared::data::config::{MissingReplacements, MissingTranslations, load_config};
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+use translatable_shared::data::translations::{TranslationDataError, hot_reload_paths};
+use translatable_shared::data::translations::{
+    embedded_translation,
+    embedded_translations,
+    language_enabled,
+    language_feature,
+    load_translations,
+};
 use translatable_shared::handle_macro_result;
 use translatable_shared::macros::collections::{map_to_tokens, map_transform_to_tokens};
+use translatable_shared::macros::errors::IntoCompileError;
 use translatable_shared::misc::language::Language;
+use translatable_shared::misc::templating::TemplateConstraint;
+use translatable_shared::translations::collection::TranslationNodeCollection;
+use translatable_shared::translations::node::TranslationObject;
+use translatable_shared::translations::resolution::{Fallback, missing_marker, resolve_object};
 
-use crate::data::translations::load_translations;
-use crate::macro_input::translation::TranslationMacroArgs;
+use super::replacements::key_type;
+use crate::macro_input::translation::{FallbackOverride, TranslationMacroArgs, TranslationSource};
 use crate::macro_input::utils::input_type::InputType;
 
+/// Inline translation path.
+///
+/// How inline translations are referred to in
+/// errors, as they don't have a path.
+const INLINE_PATH: &str = "inline";
+
 /// Macro compile-time translation resolution error.
 ///
 /// Represents errors that can occur while compiling the [`translation!()`]
@@ -34,8 +66,28 @@ enum MacroCompileError {
     ///
     /// **Parameters**
     /// * `0` — The translation path, displayed in `::` notation.
-    #[error("The path '{0}' could not be found")]
-    PathNotFound(String),
+    /// * `1` — A similar existing path along the translation file it's defined
+    ///   in, if any.
+    #[error("The path '{0}' could not be found{hint}", hint = similar_hint(_1))]
+    PathNotFound(String, Option<(String, String)>),
+
+    /// The requested path leads to a translation list.
+    ///
+    /// **Parameters**
+    /// * `0` — The path, displayed in `::` notation.
+    #[error("The path '{0}' is a list, use translation_list!() to obtain it")]
+    ListPath(String),
+
+    /// The requested path leads to a group of translations.
+    ///
+    /// **Parameters**
+    /// * `0` — The path, displayed in `::` notation.
+    /// * `1` — The segments nested beneath the path.
+    #[error(
+        "The path '{0}' leads to a group of translations, available segments: {segments}",
+        segments = _1.join(", ")
+    )]
+    SubtreePath(String, Vec<String>),
 
     /// The requested language is not available for the provided translation
     /// path.
@@ -45,6 +97,1266 @@ enum MacroCompileError {
     /// * `1` — The translation path where the language was expected.
     #[error("The language '{0:?}' ('{0:#}') is not available for the path '{1}'")]
     LanguageNotAvailable(Language, String),
+
+    /// A template has neither a replacement nor a default
+    /// with `missing_replacements` configured as `error`.
+    ///
+    /// **Parameters**
+    /// * `0` — The template key.
+    /// * `1` — The translation path, displayed in `::` notation.
+    #[error("The template '{0}' has no value in the path '{1}'")]
+    MissingReplacement(String, String),
+
+    /// The requested language is not enabled by the `lang-*` features.
+    ///
+    /// **Parameters**
+    /// * `0` — The requested `Language`.
+    /// * `1` — The feature that enables it, if any.
+    #[error(
+        "The language '{0:?}' ('{0:#}') is not enabled{hint}",
+        hint = _1
+            .as_ref()
+            .map(|feature| format!(", enable the '{feature}' feature"))
+            .unwrap_or_default()
+    )]
+    LanguageDisabled(Language, Option<String>),
+
+    /// The `const` language is not available for the provided
+    /// translation path.
+    ///
+    /// Reported while evaluating the `const` language, as
+    /// its value is only known to the compiler.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path where the language was expected.
+    #[error("The const language is not available for the path '{0}'")]
+    ConstLanguageNotAvailable(String),
+}
+
+/// Similar path hint formatting.
+///
+/// **Arguments**
+/// * `similar` — The similar path along the file it's defined in, if any.
+///
+/// **Returns**
+/// The hint to append to the error message, or an
+/// empty string if there is no similar path.
+pub fn similar_hint(similar: &Option<(String, String)>) -> String {
+    similar
+        .as_ref()
+        .map(|(path, source)| format!(", did you mean '{path}' defined in {source}?"))
+        .unwrap_or_default()
+}
+
+/// Path not found error construction.
+///
+/// **Arguments**
+/// * `translations` — The loaded translations, to look for a similar path.
+/// * `path_segments` — The sections of the path that could not be found.
+///
+/// **Returns**
+/// A [`MacroCompileError::PathNotFound`] suggesting the most
+/// similar existing path, if any, a [`MacroCompileError::ListPath`]
+/// if the path leads to a translation list or a
+/// [`MacroCompileError::SubtreePath`] if it leads to a group of
+/// translations.
+fn path_not_found(
+    translations: &TranslationNodeCollection,
+    path_segments: &[String],
+) -> MacroCompileError {
+    if translations
+        .find_list(path_segments)
+        .is_some()
+    {
+        return MacroCompileError::ListPath(path_segments.join("::"));
+    }
+
+    if let Some(segments) = translations.subtree_segments(path_segments) {
+        return MacroCompileError::SubtreePath(path_segments.join("::"), segments);
+    }
+
+    MacroCompileError::PathNotFound(
+        path_segments.join("::"),
+        translations
+            .similar_path(path_segments)
+            .map(|(path, source)| (path, source.to_string())),
+    )
+}
+
+/// Dynamic path lookup generation.
+///
+/// Generates the statements that look up the `path` variable
+/// in scope trough the embedded translations, binding the found
+/// translation object to `translation_object` and the file it
+/// was defined in to `source`.
+///
+/// If the path is not found, a [`PathNotFound`] error is returned
+/// with `?` along the closest match, thus the statements must be
+/// placed in a function returning a `Result<_, translatable::Error>`.
+///
+/// The translations are embedded as a [`TranslationNodeCollection`]
+/// and searched with the shared resolution `lookup` function.
+///
+/// **Arguments**
+/// * `translations` — The loaded translations to embed.
+///
+/// **Returns**
+/// The lookup statements tokens.
+///
+/// [`PathNotFound`]: translatable::Error::PathNotFound
+#[cfg(not(any(feature = "phf", all(feature = "hot-reload", debug_assertions))))]
+pub fn dynamic_lookup(translations: &TranslationNodeCollection) -> Result<TokenStream2, LexError> {
+    Ok(quote! {
+        #[doc(hidden)]
+        let translations = #translations;
+
+        #[doc(hidden)]
+        let (source, translation_object) =
+            translatable::shared::translations::resolution::lookup(&translations, &path)?;
+    })
+}
+
+/// Dynamic path lookup generation.
+///
+/// Generates the statements that look up the `path` variable
+/// in scope trough the embedded translations, binding the found
+/// translation object to `translation_object` and the file it
+/// was defined in to `source`.
+///
+/// If the path is not found, a [`PathNotFound`] error is returned
+/// with `?` along the closest match, thus the statements must be
+/// placed in a function returning a `Result<_, translatable::Error>`.
+///
+/// The translations are embedded as a perfect hash map keyed by
+/// their `::` joined path, each value being the source file and a
+/// function that constructs the translation object, so only the
+/// requested translation is constructed in runtime. The closest
+/// matches are embedded as another map keyed by each path prefix,
+/// and the segments beneath each group of translations as a third
+/// one, to tell a path leading to a group apart from a missing one.
+/// With `case_insensitive_paths` the path is lowercased before
+/// looking it up.
+///
+/// **Arguments**
+/// * `translations` — The loaded translations to embed.
+///
+/// **Returns**
+/// The lookup statements tokens, or a [`LexError`] if the generated
+/// maps couldn't be tokenized.
+///
+/// [`PathNotFound`]: translatable::Error::PathNotFound
+#[cfg(all(feature = "phf", not(all(feature = "hot-reload", debug_assertions))))]
+pub fn dynamic_lookup(translations: &TranslationNodeCollection) -> Result<TokenStream2, LexError> {
+    let leaves = translations.leaves();
+    let mut map = phf_codegen::Map::new();
+    map.phf_path("translatable::shared::phf");
+
+    let mut prefixes = phf_codegen::Map::new();
+    prefixes.phf_path("translatable::shared::phf");
+    let mut seen_prefixes = HashSet::new();
+
+    let mut subtrees = phf_codegen::Map::new();
+    subtrees.phf_path("translatable::shared::phf");
+
+    let constructors = leaves
+        .iter()
+        .enumerate()
+        .map(|(index, (path, translation))| {
+            let ident = format_ident!("__translation_{index}");
+            let source = translations
+                .find_path_with_source(path)
+                .map(|(source, _)| source)
+                .unwrap_or_default();
+
+            map.entry(
+                path.join("::"),
+                format!(
+                    "({source:?}, {ident} as fn() -> \
+                     translatable::shared::translations::node::TranslationObject)"
+                ),
+            );
+
+            for length in 1..=path.len() {
+                let prefix = path[..length].to_vec();
+
+                if !seen_prefixes.insert(prefix.join("::")) {
+                    continue;
+                }
+
+                if let Some(source) = translations.closest_match(&prefix) {
+                    prefixes.entry(prefix.join("::"), format!("{source:?}"));
+                }
+
+                if let Some(segments) = translations.subtree_segments(&prefix) {
+                    subtrees.entry(prefix.join("::"), format!("&{segments:?}"));
+                }
+            }
+
+            quote! {
+                #[doc(hidden)]
+                fn #ident() -> translatable::shared::translations::node::TranslationObject {
+                    #translation
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let map = map
+        .build()
+        .to_string()
+        .parse::<TokenStream2>()?;
+
+    let prefixes = prefixes
+        .build()
+        .to_string()
+        .parse::<TokenStream2>()?;
+
+    let subtrees = subtrees
+        .build()
+        .to_string()
+        .parse::<TokenStream2>()?;
+
+    // the embedded keys are already lowercased.
+    let normalize = translations
+        .case_insensitive_paths()
+        .then(|| quote! { .to_lowercase() });
+
+    Ok(quote! {
+        #(#constructors)*
+
+        #[doc(hidden)]
+        static TRANSLATIONS: translatable::shared::phf::Map<
+            &'static str,
+            (&'static str, fn() -> translatable::shared::translations::node::TranslationObject)
+        > = #map;
+
+        #[doc(hidden)]
+        static PREFIXES: translatable::shared::phf::Map<&'static str, &'static str> = #prefixes;
+
+        #[doc(hidden)]
+        static SUBTREES: translatable::shared::phf::Map<&'static str, &'static [&'static str]> =
+            #subtrees;
+
+        #[doc(hidden)]
+        let (source, translation_object) = TRANSLATIONS
+            .get(path.join("::")#normalize.as_str())
+            .map(|(source, translation)| (*source, translation()))
+            .ok_or_else(|| {
+                if let Some(segments) = SUBTREES.get(path.join("::")#normalize.as_str()) {
+                    return translatable::Error::SubtreePath(
+                        path.iter().map(|segment| segment.to_string()).collect(),
+                        segments.iter().map(|segment| segment.to_string()).collect(),
+                    );
+                }
+
+                translatable::Error::PathNotFound(
+                    path.iter().map(|segment| segment.to_string()).collect(),
+                    (1..=path.len())
+                        .rev()
+                        .find_map(|length| PREFIXES.get(path[..length].join("::")#normalize.as_str()))
+                        .map(|source| source.to_string()),
+                )
+            })?;
+    })
+}
+
+/// Dynamic path lookup generation.
+///
+/// Generates the statements that look up the `path` variable
+/// in scope trough the translations read in runtime, binding the
+/// found translation object to `translation_object` and the file
+/// it was defined in to `source`.
+///
+/// If the path is not found, a [`PathNotFound`] error is returned
+/// with `?` along the closest match, thus the statements must be
+/// placed in a function returning a `Result<_, translatable::Error>`.
+///
+/// Nothing is embedded, the absolute paths of the translation files,
+/// whether their `overlap` strategy is `overwrite`, and the configuration
+/// sources, so the configuration is rebuilt in runtime as it was loaded
+/// in compile-time, are passed to [`hot_reload::translations`] which
+/// parses them on first use and again whenever any of them is modified.
+///
+/// Only used if the macros are compiled with debug assertions,
+/// so release builds embed the translations as usual.
+///
+/// **Arguments**
+/// * `_translations` — The loaded translations, unused as these are read in
+///   runtime.
+///
+/// **Returns**
+/// The lookup statements tokens, or a [`TranslationDataError`] if the
+/// translation files couldn't be collected.
+///
+/// [`PathNotFound`]: translatable::Error::PathNotFound
+/// [`hot_reload::translations`]: translatable::hot_reload::translations
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+pub fn dynamic_lookup(
+    _translations: &TranslationNodeCollection,
+) -> Result<TokenStream2, TranslationDataError> {
+    let paths = hot_reload_paths()?
+        .into_iter()
+        .map(|(path, overwrite)| quote! { (#path, #overwrite) });
+    let (content, env_vars) = config_sources();
+    let env_vars = env_vars
+        .iter()
+        .map(|(key, value)| quote! { (#key, #value) });
+
+    Ok(quote! {
+        #[doc(hidden)]
+        static CONFIG: std::sync::LazyLock<translatable::shared::data::config::MacroConfig> =
+            std::sync::LazyLock::new(|| {
+                translatable::shared::data::config::MacroConfig::from_sources(
+                    #content,
+                    &[#(#env_vars),*]
+                )
+                .expect("Configuration to be valid as it was loaded in compile-time.")
+            });
+
+        #[doc(hidden)]
+        let translations = translatable::hot_reload::translations(&[#(#paths),*], &CONFIG)?;
+
+        #[doc(hidden)]
+        let (source, translation_object) =
+            translatable::shared::translations::resolution::lookup(&translations, &path)?;
+    })
+}
+
+/// Template replacements generation.
+///
+/// Generates the expression that collects the replacements
+/// into the named values of an `Args`, if a replacement key is
+/// type constrained in the translation, the value is checked
+/// to implement the constraint marker trait.
+///
+/// Values implementing `TranslatableEnum` are replaced with
+/// their label in the provided language, any other value with
+/// its `Display` implementation.
+///
+/// If a replacement key has format specs in the translation
+/// such as `{pi:.2}`, the value is also formatted with each of
+/// them with `format!()` and keyed as `pi:.2`.
+///
+/// **Arguments**
+/// * `replacements` — The replacements passed to the macro.
+/// * `constraints` — The template type constraints known in compile-time.
+/// * `specs` — The template format specs known in compile-time.
+/// * `language` — An expression evaluating to the `&Language` the translation
+///   was resolved in.
+///
+/// **Returns**
+/// The replacements `Args` expression tokens.
+pub fn template_replacements(
+    replacements: &HashMap<Ident, TokenStream2>,
+    constraints: &[(String, TemplateConstraint)],
+    specs: &[(String, String)],
+    language: &TokenStream2,
+) -> TokenStream2 {
+    let mut keys = replacements
+        .keys()
+        .collect::<Vec<_>>();
+    keys.sort();
+
+    // values with format specs are bound beforehand,
+    // so these are evaluated only once.
+    let mut replacements = replacements.clone();
+    let mut bindings = Vec::new();
+    let mut formatted = Vec::new();
+
+    for key in keys {
+        let key_specs = specs
+            .iter()
+            .filter(|(spec_key, _)| key == spec_key)
+            .map(|(_, spec)| spec)
+            .collect::<Vec<_>>();
+
+        if key_specs.is_empty() {
+            continue;
+        }
+
+        let value = &replacements[key];
+        let span = value.span();
+        let binding = format_ident!("value_{}", key, span = Span::mixed_site());
+
+        bindings.push(quote! {
+            #[doc(hidden)]
+            let #binding = &(#value);
+        });
+
+        for spec in key_specs {
+            let entry = format!("{key}:{spec}");
+            let literal = format!("{{:{spec}}}");
+
+            formatted.push(quote_spanned! { span =>
+                (#entry.to_string(), format!(#literal, #binding))
+            });
+        }
+
+        let deref = quote_spanned! { span => * };
+        replacements.insert(key.clone(), quote! { #deref #binding });
+    }
+
+    let render = |value: TokenStream2| {
+        quote! {
+            {
+                #[doc(hidden)]
+                use translatable::replacement::{DisplayReplacement as _, EnumReplacement as _};
+
+                (&translatable::replacement::Replacement(#value)).to_replacement(#language)
+            }
+        }
+    };
+
+    let map = map_transform_to_tokens(&replacements, |key, value| {
+        let mut key_constraints = Vec::new();
+        for (constraint_key, constraint) in constraints {
+            if key == constraint_key && !key_constraints.contains(constraint) {
+                key_constraints.push(*constraint);
+            }
+        }
+
+        if key_constraints.is_empty() {
+            let value = render(quote! { &(#value) });
+            return quote! { (stringify!(#key).to_string(), #value) };
+        }
+
+        let checks = key_constraints
+            .iter()
+            .map(|constraint| {
+                let trait_path = constraint.trait_path();
+
+                quote_spanned! { value.span() =>
+                    {
+                        #[doc(hidden)]
+                        fn check<T: #trait_path + ?Sized>(_: &T) {}
+                        check(value);
+                    }
+                }
+            });
+
+        let rendered = render(quote! { value });
+
+        quote! {
+            (
+                stringify!(#key).to_string(),
+                {
+                    #[doc(hidden)]
+                    let value = &(#value);
+
+                    #(#checks)*
+
+                    #rendered
+                }
+            )
+        }
+    });
+
+    let map = quote! { translatable::shared::misc::templating::Args::from(#map) };
+
+    if formatted.is_empty() {
+        return map;
+    }
+
+    quote! {
+        {
+            #(#bindings)*
+
+            #[doc(hidden)]
+            let mut replacements = #map;
+            replacements.extend([#(#formatted),*]);
+
+            replacements
+        }
+    }
+}
+
+/// Replacements spread generation.
+///
+/// Wraps the replacements `Args` expression so the values
+/// collected from the `..replacements` spread are extended
+/// with the `replacements = ...` map entries, stringified,
+/// and then with the explicit replacements, so the explicit
+/// ones take precedence.
+///
+/// **Arguments**
+/// * `spread` — The spread expression, if any.
+/// * `map` — The replacements map expression, if any.
+/// * `replacements` — The explicit replacements `Args` expression.
+/// * `checks` — The spread validation statements, see [`spread_checks`].
+/// * `language` — An expression evaluating to the `&Language` the translation
+///   was resolved in.
+///
+/// **Returns**
+/// The replacements `Args` expression tokens, the explicit
+/// replacements as they are if there is no spread nor map.
+fn spread_replacements(
+    spread: Option<&Expr>,
+    map: Option<&Expr>,
+    replacements: TokenStream2,
+    checks: &TokenStream2,
+    language: &TokenStream2,
+) -> TokenStream2 {
+    if spread.is_none() && map.is_none() {
+        return replacements;
+    }
+
+    // the bindings can't be seen from the user expressions.
+    let replacements_binding = Ident::new("replacements", Span::mixed_site());
+    let spread_binding = Ident::new("spread", Span::mixed_site());
+    let map_binding = Ident::new("map", Span::mixed_site());
+
+    let spread = match spread {
+        Some(spread) => quote! {
+            #[doc(hidden)]
+            let #spread_binding = &(#spread);
+
+            {
+                #[doc(hidden)]
+                let spread = #spread_binding;
+
+                #checks
+            }
+
+            #[doc(hidden)]
+            let mut #replacements_binding = translatable::shared::misc::templating::Args::from(
+                translatable::Replacements::to_replacements(#spread_binding, #language),
+            );
+        },
+
+        None => quote! {
+            #[doc(hidden)]
+            let mut #replacements_binding = translatable::shared::misc::templating::Args::new();
+        },
+    };
+
+    let map = map.map(|map| {
+        let entries = quote_spanned! { map.span() =>
+            #map_binding
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+        };
+
+        quote! {
+            #[doc(hidden)]
+            let #map_binding = &(#map);
+
+            #replacements_binding.extend(#entries);
+        }
+    });
+
+    quote! {
+        {
+            #spread
+
+            #map
+
+            #replacements_binding.extend([#replacements]);
+            #replacements_binding
+        }
+    }
+}
+
+/// Replacements spread validation generation.
+///
+/// Generates the statements that check the `spread` variable
+/// in scope in compile time, as the spread struct fields are
+/// not known while expanding the macro. Every field must be
+/// a template of the translation object in some language, and
+/// each `required` template must be a field.
+///
+/// The checks are trait bounds over the type-level list of
+/// field names declared by `Replacements`, reported with the
+/// same messages as their expansion-time counterparts.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+/// * `translation_object` — The translation object the fields are checked
+///   against.
+/// * `required` — The templates that must be a field.
+/// * `path_display` — How the translation is referred to in errors.
+///
+/// **Returns**
+/// The validation statements tokens, empty if there is no spread.
+fn spread_checks(
+    input: &TranslationMacroArgs,
+    translation_object: &TranslationObject,
+    required: &[String],
+    path_display: &str,
+) -> TokenStream2 {
+    let Some(spread) = input.spread() else {
+        return TokenStream2::new();
+    };
+
+    let mut placeholders = translation_object
+        .values()
+        .flat_map(|translation| translation.placeholders())
+        .collect::<Vec<_>>();
+    placeholders.sort();
+    placeholders.dedup();
+
+    let placeholder_keys = placeholders
+        .iter()
+        .map(|placeholder| key_type(placeholder));
+
+    let fields_message =
+        format!("The replacements have a field that is not a template of '{path_display}'");
+
+    let required_checks = required
+        .iter()
+        .map(|placeholder| {
+            let key = key_type(placeholder);
+            let message =
+                format!("The template '{placeholder}' has no value in the path '{path_display}'");
+
+            quote_spanned! { spread.span() =>
+                {
+                    #[doc(hidden)]
+                    #[diagnostic::on_unimplemented(
+                        message = #message,
+                        label = "add it as a field or as a replacement"
+                    )]
+                    trait HasTemplate<I> {}
+
+                    #[diagnostic::do_not_recommend]
+                    impl<R> HasTemplate<translatable::replacement_keys::Here> for (#key, R) {}
+
+                    #[diagnostic::do_not_recommend]
+                    impl<K, R: HasTemplate<I>, I>
+                        HasTemplate<translatable::replacement_keys::There<I>> for (K, R) {}
+
+                    #[doc(hidden)]
+                    fn check<T: translatable::Replacements<Keys = L> + ?Sized, L: HasTemplate<I>, I>(_: &T) {}
+                    check(spread);
+                }
+            }
+        });
+
+    quote_spanned! { spread.span() =>
+        {
+            #[doc(hidden)]
+            #[diagnostic::on_unimplemented(
+                message = #fields_message,
+                label = "every field must be a template of the translation"
+            )]
+            trait Templates {}
+
+            impl Templates for () {}
+
+            #(
+                #[diagnostic::do_not_recommend]
+                impl<R: Templates> Templates for (#placeholder_keys, R) {}
+            )*
+
+            #[doc(hidden)]
+            fn check<T: translatable::Replacements + ?Sized>(_: &T)
+            where
+                T::Keys: Templates,
+            {
+            }
+            check(spread);
+        }
+
+        #(#required_checks)*
+    }
+}
+
+/// Translation output options.
+///
+/// Groups the flags that decide the shape of the
+/// [`translation!()`] macro family output.
+///
+/// [`translation!()`]: crate::translation
+#[derive(Clone, Copy)]
+struct OutputOptions<'a> {
+    /// Whether to output the resolved language along the string.
+    with_language: bool,
+
+    /// Whether to output a `Result` for fully static invocations.
+    always_result: bool,
+
+    /// Whether templates without a value fail.
+    strict: bool,
+
+    /// What missing translations evaluate to.
+    missing: MissingTranslations,
+
+    /// Whether to output a `Cow<'static, str>` instead of a `String`.
+    cow: bool,
+
+    /// Whether translations without replacements may be
+    /// output as a `&'static str` literal.
+    literal: bool,
+
+    /// The configured language fallback rules.
+    fallback_rules: &'a [(&'a str, &'a [Language])],
+}
+
+/// Compile-time translation resolution.
+///
+/// Generates the output for a static language and a translation
+/// object known in compile time, the translation is resolved during
+/// macro expansion and only the replacements happen in runtime.
+///
+/// With `literal`, translations without templates nor replacements
+/// are emitted as a `&'static str` literal unless the output shape is
+/// fixed by `with_language` or `always_result`, avoiding any allocation.
+/// With `cow`, every translation without replacements is emitted
+/// as a borrowed literal.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+/// * `language` — The static language.
+/// * `translation_object` — The translation object to resolve.
+/// * `path_display` — How the translation is referred to in errors.
+/// * `options` — The output options.
+///
+/// **Returns**
+/// The resolved translation tokens, or a compile error if the language
+/// is not available or a template has no value in strict mode.
+fn static_resolution(
+    input: &TranslationMacroArgs,
+    language: &Language,
+    translation_object: &TranslationObject,
+    path_display: String,
+    options: OutputOptions<'_>,
+) -> TokenStream2 {
+    let resolved = resolve_object(
+        translation_object,
+        None,
+        language,
+        &[&path_display],
+        static_fallback(input, options.fallback_rules),
+    );
+
+    let (resolved_language, translation) = match (resolved, options.missing) {
+        (Ok(resolved), _) => resolved,
+
+        (Err(_), MissingTranslations::Error) => {
+            return MacroCompileError::LanguageNotAvailable(language.clone(), path_display)
+                .to_compile_error();
+        },
+
+        (Err(_), missing) => {
+            let placeholder = match missing {
+                MissingTranslations::Key => path_display,
+                _ => missing_marker(&[&path_display], language),
+            };
+
+            return static_missing(input, language, placeholder, options);
+        },
+    };
+
+    let unreplaced = if options.strict {
+        translation
+            .required_placeholders()
+            .into_iter()
+            .filter(|placeholder| {
+                !input
+                    .replacements()
+                    .keys()
+                    .any(|key| key == placeholder)
+            })
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+
+    // spread fields are only known by the type checker,
+    // and map keys are only known in runtime.
+    if input
+        .spread()
+        .is_none()
+        && input
+            .replacement_map()
+            .is_none()
+        && let Some(missing) = unreplaced.first()
+    {
+        return MacroCompileError::MissingReplacement(missing.clone(), path_display)
+            .to_compile_error();
+    }
+
+    let checks = spread_checks(input, translation_object, &unreplaced, &path_display);
+
+    if options.literal
+        && !options.with_language
+        && !options.always_result
+        && !options.cow
+        && input
+            .replacements()
+            .is_empty()
+        && input
+            .spread()
+            .is_none()
+        && input
+            .replacement_map()
+            .is_none()
+        && translation
+            .placeholders()
+            .is_empty()
+    {
+        let literal = translation.replace_with(&HashMap::new());
+        return quote! { #literal };
+    }
+
+    let output = if options.cow
+        && input
+            .replacements()
+            .is_empty()
+        && input
+            .spread()
+            .is_none()
+        && input
+            .replacement_map()
+            .is_none()
+    {
+        let literal = translation.replace_with(&HashMap::new());
+        quote! { std::borrow::Cow::<'static, str>::Borrowed(#literal) }
+    } else {
+        let language = quote! { &#resolved_language };
+        let template_replacements = spread_replacements(
+            input.spread(),
+            input.replacement_map(),
+            template_replacements(
+                input.replacements(),
+                translation.constraints(),
+                &translation.format_specs(),
+                &language,
+            ),
+            &checks,
+            &language,
+        );
+
+        if options.cow {
+            quote! {
+                #translation
+                    .replace_with_cow(&#template_replacements)
+            }
+        } else {
+            quote! {
+                #translation
+                    .replace_with(&#template_replacements)
+            }
+        }
+    };
+
+    let output = if options.with_language {
+        quote! { (#output, #resolved_language) }
+    } else {
+        output
+    };
+
+    if options.always_result {
+        quote! { std::result::Result::<_, translatable::Error>::Ok(#output) }
+    } else {
+        output
+    }
+}
+
+/// Compile-time fallback strategy.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+/// * `fallback_rules` — The configured language fallback rules.
+///
+/// **Returns**
+/// The fallback strategy for a static resolution, a dynamic
+/// fallback language must be resolved in runtime instead.
+fn static_fallback<'a>(
+    input: &'a TranslationMacroArgs,
+    fallback_rules: &'a [(&'a str, &'a [Language])],
+) -> Fallback<'a> {
+    match input.fallback() {
+        Some(FallbackOverride::Disabled) => Fallback::Disabled,
+        Some(FallbackOverride::Language(InputType::Static(fallback))) => {
+            Fallback::Language(fallback)
+        },
+        _ if !fallback_rules.is_empty() => Fallback::Rules(fallback_rules),
+        _ => Fallback::Parents,
+    }
+}
+
+/// Runtime fallback rules.
+///
+/// **Arguments**
+/// * `fallback_rules` — The configured language fallback rules.
+///
+/// **Returns**
+/// The tokens evaluating to a `Fallback::Rules` with the
+/// configured rules, or to `Fallback::Parents` without rules.
+pub fn fallback_rules_tokens(fallback_rules: &[(&str, &[Language])]) -> TokenStream2 {
+    if fallback_rules.is_empty() {
+        return quote! { translatable::shared::translations::resolution::Fallback::Parents };
+    }
+
+    let rules = fallback_rules
+        .iter()
+        .map(|(pattern, languages)| quote! { (#pattern, &[#(#languages),*]) });
+
+    quote! {
+        translatable::shared::translations::resolution::Fallback::Rules(&[#(#rules),*])
+    }
+}
+
+/// Compile-time resolution for a `const` language.
+///
+/// The value of a `const` language is only known to the compiler,
+/// so the translation is resolved for every enabled language during
+/// macro expansion, and the language is matched in a `const` item
+/// selecting the output of the language it resolves to. Languages
+/// that aren't available fail the constant evaluation, unless missing
+/// translations are not configured as errors.
+///
+/// With `literal`, the outputs are `&'static str` literals only if none
+/// of the resolved translations has templates, so every arm has the same
+/// type.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+/// * `language` — The `const` wrapped language expression.
+/// * `translation_object` — The translation object to resolve.
+/// * `path_display` — How the translation is referred to in errors.
+/// * `options` — The output options.
+///
+/// **Returns**
+/// A block selecting the resolved translation tokens.
+fn const_resolution(
+    input: &TranslationMacroArgs,
+    language: &TokenStream2,
+    translation_object: &TranslationObject,
+    path_display: String,
+    mut options: OutputOptions<'_>,
+) -> TokenStream2 {
+    let resolved = Language::iter()
+        .filter(language_enabled)
+        .map(|language| {
+            let translation = resolve_object(
+                translation_object,
+                None,
+                &language,
+                &[&path_display],
+                static_fallback(input, options.fallback_rules),
+            )
+            .ok()
+            .map(|(_, translation)| translation);
+
+            (language, translation)
+        })
+        .collect::<Vec<_>>();
+
+    options.literal &= resolved
+        .iter()
+        .filter_map(|(_, translation)| *translation)
+        .all(|translation| {
+            translation
+                .placeholders()
+                .is_empty()
+        });
+
+    // languages resolving to the same output share an arm.
+    let mut arms = Vec::<(Vec<Language>, String, TokenStream2)>::new();
+
+    for (language, translation) in resolved {
+        if translation.is_none() && options.missing == MissingTranslations::Error {
+            continue;
+        }
+
+        let output =
+            static_resolution(input, &language, translation_object, path_display.clone(), options);
+        let key = output.to_string();
+
+        match arms
+            .iter_mut()
+            .find(|(_, arm_key, _)| *arm_key == key)
+        {
+            Some((languages, ..)) => languages.push(language),
+            None => arms.push((vec![language], key, output)),
+        }
+    }
+
+    let message = MacroCompileError::ConstLanguageNotAvailable(path_display).to_string();
+    let indices = 0..arms.len();
+    let patterns = arms
+        .iter()
+        .map(|(languages, ..)| quote! { #(#languages)|* });
+    let outputs = arms
+        .iter()
+        .map(|(.., output)| output);
+    let output_indices = indices.clone();
+
+    quote! {
+        {
+            #[doc(hidden)]
+            const TRANSLATION_ARM: usize = match #language {
+                #(#patterns => #indices,)*
+                _ => std::panic!(#message),
+            };
+
+            match TRANSLATION_ARM {
+                #(#output_indices => #outputs,)*
+                _ => std::unreachable!(),
+            }
+        }
+    }
+}
+
+/// Compile-time missing translation output.
+///
+/// Generates the output for a static invocation whose
+/// language is not available when missing translations
+/// are not configured as errors, following the same output
+/// shape as [`static_resolution`].
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+/// * `language` — The requested static language.
+/// * `placeholder` — The marker or path rendered instead of the translation.
+/// * `options` — The output options.
+///
+/// **Returns**
+/// The placeholder tokens, a `&'static str` literal with `literal`
+/// unless the output shape is fixed by `with_language`, `always_result`,
+/// replacements or `cow`.
+fn static_missing(
+    input: &TranslationMacroArgs,
+    language: &Language,
+    placeholder: String,
+    options: OutputOptions<'_>,
+) -> TokenStream2 {
+    if options.literal
+        && !options.with_language
+        && !options.always_result
+        && !options.cow
+        && input
+            .replacements()
+            .is_empty()
+        && input
+            .spread()
+            .is_none()
+        && input
+            .replacement_map()
+            .is_none()
+    {
+        return quote! { #placeholder };
+    }
+
+    let placeholder = if options.cow {
+        quote! { std::borrow::Cow::<'static, str>::Borrowed(#placeholder) }
+    } else {
+        quote! { #placeholder.to_string() }
+    };
+
+    let output = if options.with_language {
+        quote! { (#placeholder, #language) }
+    } else {
+        placeholder
+    };
+
+    if options.always_result {
+        quote! { std::result::Result::<_, translatable::Error>::Ok(#output) }
+    } else {
+        output
+    }
+}
+
+/// Language argument to runtime tokens.
+///
+/// **Arguments**
+/// * `language` — The static or dynamic language argument.
+///
+/// **Returns**
+/// An expression borrowing the language, to be coerced into a
+/// `&Language`, so a dynamic language may be passed by value or
+/// by reference without cloning it.
+pub fn language_tokens(language: &InputType<Language>) -> TokenStream2 {
+    match language {
+        InputType::Static(language) => quote! { &#language },
+        InputType::Dynamic(language) => quote! { &(#language) },
+    }
+}
+
+/// Runtime translation resolution.
+///
+/// Generates a closure invocation that resolves the translation
+/// object bound by `lookup` in runtime, the `lookup` statements must
+/// bind the translation object to `translation_object` and its path
+/// segments to `path`.
+///
+/// Unless missing translations are configured as errors, a
+/// path or language that is not available evaluates to the
+/// configured placeholder instead of an error.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+/// * `lookup` — The translation object lookup statements.
+/// * `constraints` — The template type constraints known in compile-time.
+/// * `checks` — The spread validation statements known in compile-time, see
+///   [`spread_checks`].
+/// * `source` — An `Option<String>` expression with the file the translation
+///   was defined in, reported if the language is not available.
+/// * `options` — The output options.
+///
+/// **Returns**
+/// The runtime resolution tokens evaluating to a `Result`.
+fn runtime_resolution(
+    input: &TranslationMacroArgs,
+    lookup: TokenStream2,
+    constraints: &[(String, TemplateConstraint)],
+    specs: &[(String, String)],
+    checks: TokenStream2,
+    source: TokenStream2,
+    options: OutputOptions<'_>,
+) -> TokenStream2 {
+    let language = language_tokens(input.language());
+
+    let fallback = match input.fallback() {
+        None => fallback_rules_tokens(options.fallback_rules),
+
+        Some(FallbackOverride::Disabled) => {
+            quote! { translatable::shared::translations::resolution::Fallback::Disabled }
+        },
+
+        Some(FallbackOverride::Language(fallback)) => {
+            let fallback = language_tokens(fallback);
+
+            quote! { translatable::shared::translations::resolution::Fallback::Language(#fallback) }
+        },
+    };
+
+    let language_tokens = quote! { &resolved_language };
+    let template_replacements = spread_replacements(
+        input.spread(),
+        input.replacement_map(),
+        template_replacements(input.replacements(), constraints, specs, &language_tokens),
+        &checks,
+        &language_tokens,
+    );
+
+    let replaced = match (options.strict, options.cow) {
+        (true, false) => quote! {
+            translation
+                .replace_with_strict(&#template_replacements)
+                .map_err(|error| {
+                    translatable::Error::Replacement(
+                        error,
+                        path.iter().map(|segment| segment.to_string()).collect(),
+                    )
+                })?
+        },
+
+        // the strict check is done apart so nothing is allocated.
+        (true, true) => quote! {
+            {
+                #[doc(hidden)]
+                let replacements = #template_replacements;
+
+                if let Some(missing) = translation
+                    .required_placeholders()
+                    .into_iter()
+                    .find(|placeholder| !replacements.contains_key(placeholder))
+                {
+                    return Err(translatable::Error::Replacement(
+                        translatable::shared::misc::templating::TemplateError::MissingValue(missing),
+                        path.iter().map(|segment| segment.to_string()).collect(),
+                    ));
+                }
+
+                translation.replace_with_cow(&replacements)
+            }
+        },
+
+        (false, false) => quote! {
+            translation
+                .replace_with(&#template_replacements)
+        },
+
+        (false, true) => quote! {
+            translation
+                .replace_with_cow(&#template_replacements)
+        },
+    };
+
+    let string_ty = if options.cow {
+        quote! { std::borrow::Cow<'static, str> }
+    } else {
+        quote! { String }
+    };
+
+    let (output_ty, output) = if options.with_language {
+        (
+            quote! { (#string_ty, translatable::shared::misc::language::Language) },
+            quote! { (#replaced, resolved_language) },
+        )
+    } else {
+        (string_ty, replaced)
+    };
+
+    let placeholder = match options.missing {
+        MissingTranslations::Error => None,
+        MissingTranslations::Marker => Some(quote! {
+            translatable::shared::translations::resolution::missing_marker(&path, language)
+        }),
+        MissingTranslations::Key => Some(quote! { path.join("::") }),
+    };
+
+    let placeholder = match placeholder {
+        Some(placeholder) if options.cow => {
+            Some(quote! { std::borrow::Cow::<'static, str>::Owned(#placeholder) })
+        },
+        placeholder => placeholder,
+    };
+
+    let resolution = quote! {
+        (|| -> Result<#output_ty, translatable::Error> {
+            std::result::Result::Ok({
+                #lookup
+
+                #[doc(hidden)]
+                #[allow(unused_variables)]
+                let (resolved_language, translation) =
+                    translatable::shared::translations::resolution::resolve_object(
+                        &translation_object,
+                        #source,
+                        language,
+                        &path,
+                        #fallback,
+                    )?;
+
+                #output
+            })
+        })()
+    };
+
+    let resolution = match placeholder {
+        None => resolution,
+
+        Some(placeholder) => {
+            let placeholder = if options.with_language {
+                quote! { (#placeholder, language.clone()) }
+            } else {
+                placeholder
+            };
+
+            quote! {
+                #resolution.or_else(|error| match error {
+                    translatable::Error::PathNotFound(path, _)
+                    | translatable::Error::SubtreePath(path, _)
+                    | translatable::Error::LanguageNotAvailable(_, path, _) => {
+                        std::result::Result::Ok(#placeholder)
+                    },
+                    error => std::result::Result::Err(error),
+                })
+            }
+        },
+    };
+
+    quote! {
+        (|| -> Result<#output_ty, translatable::Error> {
+            #[doc(hidden)]
+            let language: &translatable::shared::misc::language::Language = #language;
+
+            #resolution
+        })()
+    }
 }
 
 /// [`translation!()`] macro output generation.
@@ -57,12 +1369,38 @@ enum MacroCompileError {
 /// during macro expansion. Otherwise, the generated code will include runtime
 /// resolution logic.
 ///
+/// Inline translations are handled as a static path whose translation
+/// object is declared in the macro call, so the translation files are
+/// not loaded at all.
+///
 /// If the path or language is invalid at compile time, an appropriate
 /// `MacroCompileError` will be reported.
 ///
+/// If `with_language` is set, the language that actually served the
+/// translation is returned along the string, which may differ from
+/// the requested one when falling back to a parent language.
+///
+/// If a `fallback` argument is specified it overrides how the requested
+/// language falls back, a dynamic fallback language forces runtime resolution.
+///
+/// If `always_result` is set, the fully static resolution is wrapped
+/// in `Ok` so every invocation evaluates to a `Result`.
+///
+/// If `cow_output` is configured, the string is output as a
+/// `Cow<'static, str>` that borrows the embedded translation
+/// when nothing is replaced. Unless `literal_output` is disabled,
+/// fully static translations without templates nor replacements
+/// are output as a `&'static str` literal, languages written as
+/// a variant path such as `Language::EN` count as static then.
+///
 /// **Arguments**
 /// * `input` — Structured arguments defining the translation path, language,
-/// and any placeholder replacements obtained from [`macro_input::translation`].
+///   and any placeholder replacements obtained from
+///   [`macro_input::translation`].
+/// * `with_language` — Whether to output a `(String, Language)` tuple instead
+///   of the string alone, used by [`translation_with_lang!()`].
+/// * `always_result` — Whether to output a `Result` for fully static
+///   invocations too, used by [`try_translation!()`].
 ///
 /// **Returns**
 /// Generated `TokenStream2` representing the resolved translation string or
@@ -70,94 +1408,290 @@ enum MacroCompileError {
 ///
 /// [`macro_input::translation`]: super::super::macro_input::translation
 /// [`translation!()`]: crate::translation
-pub fn translation_macro(input: TranslationMacroArgs) -> TokenStream2 {
-    let translations = handle_macro_result!(load_translations());
-
-    let template_replacements = map_transform_to_tokens(
-        input.replacements(),
-        |key, value| quote! { (stringify!(#key).to_string(), #value.to_string()) },
-    );
+/// [`translation_with_lang!()`]: crate::translation_with_lang
+/// [`try_translation!()`]: crate::try_translation
+pub fn translation_macro(
+    mut input: TranslationMacroArgs,
+    with_language: bool,
+    always_result: bool,
+) -> TokenStream2 {
+    let config = handle_macro_result!(load_config());
+    let embed_languages = config.embed_languages();
+    let path_separator = config.path_separator();
+    let fallback_rules = config.fallback_rules();
 
-    if let InputType::Static(language) = input.language() {
-        if let InputType::Static(path) = input.path() {
-            let path_segments = path.segments();
-            let static_path_display = path_segments.join("::");
+    let options = OutputOptions {
+        with_language,
+        always_result,
+        strict: config.missing_replacements() == MissingReplacements::Error,
+        missing: config.missing(),
+        cow: config.cow_output(),
+        literal: config.literal_output(),
+        fallback_rules: &fallback_rules,
+    };
 
-            let translation_object = translations
-                .find_path(path_segments)
-                .ok_or_else(|| MacroCompileError::PathNotFound(static_path_display.clone()));
-
-            let translation = handle_macro_result!(
-                handle_macro_result!(translation_object)
-                    .get(language)
-                    .ok_or_else(|| {
-                        MacroCompileError::LanguageNotAvailable(
-                            language.clone(),
-                            static_path_display.clone(),
-                        )
-                    })
-            );
+    // a variant path is as static as a literal,
+    // so it's output as a literal as well.
+    if options.literal
+        && !with_language
+        && !always_result
+        && !options.cow
+        && input
+            .replacements()
+            .is_empty()
+        && input
+            .spread()
+            .is_none()
+        && input
+            .replacement_map()
+            .is_none()
+    {
+        input.static_language_variant();
+    }
 
-            return quote! {
-                #translation
-                    .replace_with(&#template_replacements)
-            };
-        }
+    if let InputType::Static(language) = input.language()
+        && !language_enabled(language)
+    {
+        return MacroCompileError::LanguageDisabled(language.clone(), language_feature(language))
+            .to_compile_error();
     }
 
-    let language = match input.language() {
-        InputType::Static(language) => language
-            .clone()
-            .to_token_stream(),
-        InputType::Dynamic(language) => quote! {
-            translatable::shared::misc::language::Language::from(#language)
+    // a dynamic fallback language can only be evaluated in runtime.
+    let dynamic_fallback =
+        matches!(input.fallback(), Some(FallbackOverride::Language(InputType::Dynamic(_))));
+
+    let path = match input.source() {
+        TranslationSource::Path(path) => path,
+
+        TranslationSource::Inline(translation_object) => {
+            if let InputType::Static(language) = input.language()
+                && !dynamic_fallback
+            {
+                return static_resolution(
+                    &input,
+                    language,
+                    translation_object,
+                    INLINE_PATH.into(),
+                    options,
+                );
+            }
+
+            if let InputType::Dynamic(language) = input.language()
+                && input.const_language()
+                && !dynamic_fallback
+            {
+                return const_resolution(
+                    &input,
+                    language,
+                    translation_object,
+                    INLINE_PATH.into(),
+                    options,
+                );
+            }
+
+            let constraints = translation_object
+                .values()
+                .flat_map(|translation| translation.constraints())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            let specs = translation_object
+                .values()
+                .flat_map(|translation| translation.format_specs())
+                .collect::<Vec<_>>();
+
+            let translation_tokens = map_to_tokens(translation_object);
+            let checks = spread_checks(&input, translation_object, &[], INLINE_PATH);
+
+            return runtime_resolution(
+                &input,
+                quote! {
+                    #[doc(hidden)]
+                    let path: Vec<String> = vec![#INLINE_PATH.to_string()];
+
+                    #[doc(hidden)]
+                    let translation_object = #translation_tokens;
+                },
+                &constraints,
+                &specs,
+                checks,
+                quote! { None },
+                options,
+            );
         },
     };
 
-    let translation_object = match input.path() {
+    let translations = handle_macro_result!(load_translations());
+
+    if let InputType::Static(language) = input.language()
+        && let InputType::Static(path) = path
+        && !dynamic_fallback
+    {
+        let path_segments = path.segments();
+        let static_path_display = path_segments.join("::");
+
+        let translation_object = handle_macro_result!(
+            translations
+                .find_path(path_segments)
+                .ok_or_else(|| path_not_found(&translations, path_segments))
+        );
+
+        return static_resolution(
+            &input,
+            language,
+            translation_object,
+            static_path_display,
+            options,
+        );
+    }
+
+    if let InputType::Dynamic(language) = input.language()
+        && input.const_language()
+        && let InputType::Static(path) = path
+        && !dynamic_fallback
+    {
+        let path_segments = path.segments();
+
+        let translation_object = handle_macro_result!(
+            translations
+                .find_path(path_segments)
+                .ok_or_else(|| path_not_found(&translations, path_segments))
+        );
+
+        return const_resolution(
+            &input,
+            language,
+            translation_object,
+            path_segments.join("::"),
+            options,
+        );
+    }
+
+    let (translation_lookup, constraints, specs, checks) = match path {
         InputType::Static(path) => {
             let path_segments = path.segments();
-            let static_path_display = path_segments.join("::");
 
             let translation_object = translations
                 .find_path(path_segments)
-                .ok_or_else(|| MacroCompileError::PathNotFound(static_path_display.clone()));
+                .ok_or_else(|| path_not_found(&translations, path_segments));
 
-            let translations_tokens = map_to_tokens(handle_macro_result!(translation_object));
+            let translation_object = handle_macro_result!(translation_object);
+            let translations_tokens =
+                map_to_tokens(&embedded_translation(translation_object, embed_languages));
 
-            quote! {
-                #[doc(hidden)]
-                let path: Vec<_> = vec![#(#path_segments.to_string()),*];
+            let constraints = translation_object
+                .values()
+                .flat_map(|translation| translation.constraints())
+                .cloned()
+                .collect::<Vec<_>>();
 
-                #translations_tokens
-            }
+            let specs = translation_object
+                .values()
+                .flat_map(|translation| translation.format_specs())
+                .collect::<Vec<_>>();
+
+            let checks = spread_checks(&input, translation_object, &[], &path_segments.join("::"));
+
+            let source = handle_macro_result!(
+                translations
+                    .find_path_with_source(path_segments)
+                    .map(|(source, _)| source)
+                    .ok_or_else(|| path_not_found(&translations, path_segments))
+            );
+
+            // the path is still validated, but hot reloaded
+            // translations are looked up in runtime.
+            let lookup = if cfg!(all(feature = "hot-reload", debug_assertions)) {
+                handle_macro_result!(dynamic_lookup(&translations))
+            } else {
+                quote! {
+                    #[doc(hidden)]
+                    let source = #source;
+
+                    #[doc(hidden)]
+                    let translation_object = #translations_tokens;
+                }
+            };
+
+            (
+                quote! {
+                    #[doc(hidden)]
+                    let path: Vec<_> = vec![#(#path_segments.to_string()),*];
+
+                    #lookup
+                },
+                constraints,
+                specs,
+                checks,
+            )
         },
 
         InputType::Dynamic(path) => {
-            let translations_tokens = translations.to_token_stream();
+            let lookup = handle_macro_result!(dynamic_lookup(&embedded_translations(
+                &translations,
+                embed_languages
+            )));
 
-            quote! {
-                #[doc(hidden)]
-                let path: Vec<_> = #path;
+            (
+                quote! {
+                    #[doc(hidden)]
+                    let path: Vec<_> = #path;
 
-                #translations_tokens
-                    .find_path(&path)
-                    .ok_or_else(|| translatable::Error::PathNotFound(path.join("::")))?
-            }
+                    #[doc(hidden)]
+                    let path = translatable::shared::translations::resolution::split_path(
+                        &path,
+                        #path_separator
+                    );
+
+                    #lookup
+                },
+                Vec::new(),
+                Vec::new(),
+                TokenStream2::new(),
+            )
         },
     };
 
-    quote! {
-        (|| -> Result<String, translatable::Error> {
-            std::result::Result::Ok({
-                #[doc(hidden)]
-                let language = #language;
+    runtime_resolution(
+        &input,
+        translation_lookup,
+        &constraints,
+        &specs,
+        checks,
+        quote! { Some(source) },
+        options,
+    )
+}
 
-                #translation_object
-                    .get(&language)
-                    .ok_or_else(|| translatable::Error::LanguageNotAvailable(language, path.join("::")))?
-                    .replace_with(&#template_replacements)
+/// [`translation_into!()`] macro output generation.
+///
+/// Resolves the translation the same way [`try_translation!()`]
+/// does and parses the result trough the `ContextFieldValue`
+/// trait, so the target type is inferred from the invocation
+/// context and must implement `FromStr`.
+///
+/// **Arguments**
+/// * `input` — Structured arguments defining the translation path, language,
+///   and any placeholder replacements obtained from
+///   [`macro_input::translation`].
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `Result` with the parsed
+/// translation or a `TranslationParse` error if it couldn't be parsed.
+///
+/// [`macro_input::translation`]: super::super::macro_input::translation
+/// [`translation_into!()`]: crate::translation_into
+/// [`try_translation!()`]: crate::try_translation
+pub fn translation_into_macro(input: TranslationMacroArgs) -> TokenStream2 {
+    let translation = translation_macro(input, false, true);
+
+    quote! {
+        (#translation)
+            .and_then(|value| {
+                translatable::ContextFieldValue::parse_field(&value)
+                    .map_err(|error| {
+                        translatable::Error::TranslationParse(value.to_string(), error)
+                    })
             })
-        })()
     }
 }
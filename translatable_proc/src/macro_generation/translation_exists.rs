@@ -0,0 +1,124 @@
+//! [`translation_exists!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`translation_exists!()`] macro tokens
+//! with intrinsics from [`macro_input::translation_exists`].
+//!
+//! [`translation_exists!()`]: crate::translation_exists
+//! [`macro_input::translation_exists`]: super::super::macro_input::translation_exists
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::{
+    embedded_translation,
+    embedded_translations,
+    load_translations,
+};
+use translatable_shared::handle_macro_result;
+use translatable_shared::translations::node::TranslationObjectExt;
+
+use super::translation::dynamic_lookup;
+use crate::macro_input::translation_exists::TranslationExistsMacroArgs;
+use crate::macro_input::utils::input_type::InputType;
+
+/// [`translation_exists!()`] macro output generation.
+///
+/// Expands into an expression that evaluates to whether a
+/// translation is available in a language, falling back to
+/// its parents the same way [`translation!()`] does. A path
+/// that doesn't lead to a translation is not an error, the
+/// expression simply evaluates to `false`.
+///
+/// If both the language and the path are static the check is
+/// resolved during macro expansion into a `bool` literal. If only
+/// the path is static, only that translation is embedded, otherwise
+/// the embedded translations are searched in runtime.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `bool`.
+///
+/// [`translation_exists!()`]: crate::translation_exists
+/// [`translation!()`]: crate::translation
+pub fn translation_exists_macro(input: TranslationExistsMacroArgs) -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+    let config = handle_macro_result!(load_config());
+    let embed_languages = config.embed_languages();
+    let path_separator = config.path_separator();
+
+    let language = match input.language() {
+        InputType::Static(language) => quote! { &#language },
+        InputType::Dynamic(language) => quote! { &(#language) },
+    };
+
+    match input.path() {
+        InputType::Static(path) => {
+            let Some(translation) = translations.find_path(path.segments()) else {
+                return quote! { false };
+            };
+
+            if let InputType::Static(language) = input.language() {
+                let exists = translation
+                    .get_or_parent(language)
+                    .is_some();
+
+                return quote! { #exists };
+            }
+
+            let translation = embedded_translation(translation, embed_languages);
+
+            quote! {
+                {
+                    #[doc(hidden)]
+                    use translatable::shared::translations::node::TranslationObjectExt as _;
+
+                    #[doc(hidden)]
+                    let language: &translatable::shared::misc::language::Language = #language;
+
+                    #translation
+                        .get_or_parent(language)
+                        .is_some()
+                }
+            }
+        },
+
+        InputType::Dynamic(path) => {
+            let lookup = handle_macro_result!(dynamic_lookup(&embedded_translations(
+                &translations,
+                embed_languages
+            )));
+
+            quote! {
+                (|| -> Result<bool, translatable::Error> {
+                    #[doc(hidden)]
+                    use translatable::shared::translations::node::TranslationObjectExt as _;
+
+                    #[doc(hidden)]
+                    let language: &translatable::shared::misc::language::Language = #language;
+
+                    #[doc(hidden)]
+                    let path: Vec<_> = #path;
+
+                    #[doc(hidden)]
+                    let path = translatable::shared::translations::resolution::split_path(
+                        &path,
+                        #path_separator
+                    );
+
+                    #lookup
+
+                    let _ = source;
+                    std::result::Result::Ok(
+                        translation_object
+                            .get_or_parent(language)
+                            .is_some()
+                    )
+                })()
+                .unwrap_or(false)
+            }
+        },
+    }
+}
@@ -0,0 +1,31 @@
+//! [`translation_hash!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`translation_hash!()`] macro tokens
+//! from the loaded translations.
+//!
+//! [`translation_hash!()`]: crate::translation_hash
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use translatable_shared::data::translations::load_translations;
+use translatable_shared::handle_macro_result;
+
+/// [`translation_hash!()`] macro output generation.
+///
+/// Expands into a string literal with the content hash of
+/// the loaded translations, computed during macro expansion
+/// so it can be used in `const` items.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `&'static str` with
+/// the 16 digit lowercase hexadecimal hash.
+///
+/// [`translation_hash!()`]: crate::translation_hash
+pub fn translation_hash_macro() -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+
+    let hash = format!("{:016x}", translations.content_hash());
+
+    quote! { #hash }
+}
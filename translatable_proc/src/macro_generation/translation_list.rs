@@ -0,0 +1,241 @@
+//! [`translation_list!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`translation_list!()`] macro tokens
+//! with intrinsics from [`macro_input::translation_list`].
+//!
+//! [`translation_list!()`]: crate::translation_list
+//! [`macro_input::translation_list`]: super::super::macro_input::translation_list
+
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use thiserror::Error;
+use translatable_shared::data::config::{MissingReplacements, load_config};
+use translatable_shared::data::translations::{embedded_translation, load_translations};
+use translatable_shared::handle_macro_result;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::language::Language;
+use translatable_shared::translations::resolution::{Fallback, resolve_object};
+
+use super::translation::{fallback_rules_tokens, language_tokens, template_replacements};
+use crate::macro_input::translation_list::TranslationListMacroArgs;
+use crate::macro_input::utils::input_type::InputType;
+
+/// Macro compile-time list resolution error.
+///
+/// Represents errors that can occur while compiling the
+/// [`translation_list!()`] macro.
+///
+/// [`translation_list!()`]: crate::translation_list
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// The requested list path could not be found.
+    ///
+    /// **Parameters**
+    /// * `0` — The list path, displayed in `::` notation.
+    #[error("The list '{0}' could not be found")]
+    PathNotFound(String),
+
+    /// The requested path leads to a single translation.
+    ///
+    /// **Parameters**
+    /// * `0` — The path, displayed in `::` notation.
+    #[error("The path '{0}' is not a list, use translation!() to obtain it")]
+    NotAList(String),
+
+    /// The requested language is not available for the list.
+    ///
+    /// **Parameters**
+    /// * `0` — The requested `Language`.
+    /// * `1` — The list path where the language was expected.
+    #[error("The language '{0:?}' ('{0:#}') is not available for the list '{1}'")]
+    LanguageNotAvailable(Language, String),
+
+    /// A template has neither a replacement nor a default
+    /// with `missing_replacements` configured as `error`.
+    ///
+    /// **Parameters**
+    /// * `0` — The template key.
+    /// * `1` — The list path, displayed in `::` notation.
+    #[error("The template '{0}' has no value in the list '{1}'")]
+    MissingReplacement(String, String),
+}
+
+/// [`translation_list!()`] macro output generation.
+///
+/// Expands into an expression that evaluates to the translations
+/// of a list in the requested language, each of them with its
+/// templates replaced the same way [`translation!()`] does.
+///
+/// The path is always static, so the list is validated and
+/// embedded in compile time. If the language is static too, the
+/// list is resolved during macro expansion and only the replacements
+/// happen in runtime, otherwise the language is resolved in runtime
+/// falling back to its parents.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `Vec<String>` if the language
+/// is static, otherwise to a `Result<Vec<String>, translatable::Error>`.
+///
+/// [`translation_list!()`]: crate::translation_list
+/// [`translation!()`]: crate::translation
+pub fn translation_list_macro(input: TranslationListMacroArgs) -> TokenStream2 {
+    let config = handle_macro_result!(load_config());
+    let strict = config.missing_replacements() == MissingReplacements::Error;
+    let fallback_rules = config.fallback_rules();
+    let translations = handle_macro_result!(load_translations());
+
+    let path_segments = input
+        .path()
+        .segments();
+    let path_display = path_segments.join("::");
+
+    let Some(list) = translations.find_list(path_segments) else {
+        return match translations.find_path(path_segments) {
+            Some(_) => MacroCompileError::NotAList(path_display),
+            None => MacroCompileError::PathNotFound(path_display),
+        }
+        .to_compile_error();
+    };
+
+    let constraints = list
+        .values()
+        .flatten()
+        .flat_map(|translation| translation.constraints())
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let specs = list
+        .values()
+        .flatten()
+        .flat_map(|translation| translation.format_specs())
+        .collect::<Vec<_>>();
+
+    if let InputType::Static(language) = input.language() {
+        let fallback = if fallback_rules.is_empty() {
+            Fallback::Parents
+        } else {
+            Fallback::Rules(&fallback_rules)
+        };
+
+        let (resolved_language, list) = handle_macro_result!(
+            resolve_object(list, None, language, &[&path_display], fallback).map_err(|_| {
+                MacroCompileError::LanguageNotAvailable(language.clone(), path_display.clone())
+            })
+        );
+
+        if strict
+            && let Some(missing) = list
+                .iter()
+                .flat_map(|translation| translation.required_placeholders())
+                .find(|placeholder| {
+                    !input
+                        .replacements()
+                        .keys()
+                        .any(|key| key == placeholder)
+                })
+        {
+            return MacroCompileError::MissingReplacement(missing, path_display).to_compile_error();
+        }
+
+        if input
+            .replacements()
+            .is_empty()
+            && list
+                .iter()
+                .all(|translation| {
+                    translation
+                        .placeholders()
+                        .is_empty()
+                })
+        {
+            let literals = list
+                .iter()
+                .map(|translation| translation.replace_with(&HashMap::new()));
+
+            return quote! { vec![#(#literals.to_string()),*] };
+        }
+
+        let template_replacements = template_replacements(
+            input.replacements(),
+            &constraints,
+            &specs,
+            &quote! { &#resolved_language },
+        );
+
+        return quote! {
+            {
+                #[doc(hidden)]
+                let replacements = #template_replacements;
+
+                vec![#(#list.replace_with(&replacements)),*]
+            }
+        };
+    }
+
+    let language = language_tokens(input.language());
+    let fallback = fallback_rules_tokens(&fallback_rules);
+    let list = embedded_translation(list, config.embed_languages());
+
+    let template_replacements = template_replacements(
+        input.replacements(),
+        &constraints,
+        &specs,
+        &quote! { &resolved_language },
+    );
+
+    let replaced = if strict {
+        quote! {
+            list
+                .iter()
+                .map(|translation| {
+                    translation
+                        .replace_with_strict(&replacements)
+                        .map_err(|error| translatable::Error::Replacement(error, path.clone()))
+                })
+                .collect::<Result<Vec<String>, translatable::Error>>()?
+        }
+    } else {
+        quote! {
+            list
+                .iter()
+                .map(|translation| translation.replace_with(&replacements))
+                .collect::<Vec<String>>()
+        }
+    };
+
+    quote! {
+        (|| -> Result<Vec<String>, translatable::Error> {
+            std::result::Result::Ok({
+                #[doc(hidden)]
+                let language: &translatable::shared::misc::language::Language = #language;
+
+                #[doc(hidden)]
+                let path: Vec<String> = vec![#(#path_segments.to_string()),*];
+
+                #[doc(hidden)]
+                let list = #list;
+
+                #[doc(hidden)]
+                let (resolved_language, list) =
+                    translatable::shared::translations::resolution::resolve_object(
+                        &list,
+                        None,
+                        language,
+                        &path,
+                        #fallback,
+                    )?;
+
+                #[doc(hidden)]
+                let replacements = #template_replacements;
+
+                #replaced
+            })
+        })()
+    }
+}
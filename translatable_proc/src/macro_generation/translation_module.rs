@@ -0,0 +1,225 @@
+//! [`translation_module!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`translation_module!()`] macro tokens
+//! with intrinsics from [`macro_input::translation_module`].
+//!
+//! [`translation_module!()`]: crate::translation_module
+//! [`macro_input::translation_module`]: super::super::macro_input::translation_module
+
+use std::collections::HashMap;
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::parse_str;
+use thiserror::Error;
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::load_translations;
+use translatable_shared::handle_macro_result;
+use translatable_shared::macros::errors::IntoCompileError;
+
+use crate::macro_input::translation_module::TranslationModuleMacroArgs;
+
+/// Macro compile-time module generation error.
+///
+/// Represents errors that can occur while compiling the
+/// [`translation_module!()`] macro.
+///
+/// [`translation_module!()`]: crate::translation_module
+#[derive(Error, Debug)]
+enum MacroCompileError {
+    /// The requested path prefix doesn't lead to any translation.
+    ///
+    /// **Parameters**
+    /// * `0` — The path prefix, displayed in `::` notation.
+    #[error("The path '{0}' doesn't contain any translation")]
+    PathNotFound(String),
+
+    /// The requested path prefix leads to a single translation.
+    ///
+    /// **Parameters**
+    /// * `0` — The path prefix, displayed in `::` notation.
+    #[error("The path '{0}' is a translation, use translation!() to obtain it")]
+    NotAGroup(String),
+
+    /// Two paths result in the same function name.
+    ///
+    /// **Parameters**
+    /// * `0` — The function name.
+    /// * `1` — The first path, displayed in `::` notation.
+    /// * `2` — The second path, displayed in `::` notation.
+    #[error("The paths '{1}' and '{2}' would both generate the function '{0}'")]
+    DuplicateFunction(String, String, String),
+
+    /// A template key can't be used as an argument name.
+    ///
+    /// **Parameters**
+    /// * `0` — The template key.
+    /// * `1` — The translation path, displayed in `::` notation.
+    #[error("The template '{0}' in '{1}' can't be used as an argument name")]
+    InvalidArgument(String, String),
+}
+
+/// Path segments to identifier conversion.
+///
+/// The segments are joined with underscores and lowercased,
+/// characters that can't be part of an identifier are replaced
+/// with underscores, identifiers starting with a digit are
+/// prefixed with one and keywords are suffixed with one, so
+/// `errors::404` becomes `errors_404` and `type` becomes `type_`.
+///
+/// **Arguments**
+/// * `segments` — The path segments the identifier is made of.
+///
+/// **Returns**
+/// A valid identifier for the segments.
+fn segments_ident(segments: &[String]) -> Ident {
+    let mut name = segments
+        .join("_")
+        .to_lowercase()
+        .chars()
+        .map(|char| if char.is_ascii_alphanumeric() { char } else { '_' })
+        .collect::<String>();
+
+    if name.starts_with(|char: char| char.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+
+    parse_str::<Ident>(&name).unwrap_or_else(|_| format_ident!("{name}_"))
+}
+
+/// [`translation_module!()`] macro output generation.
+///
+/// Expands into a module named after the path prefix with a
+/// function per translation beneath it, named after the rest
+/// of its path, so `ui::buttons::save` becomes `ui_buttons::save`.
+///
+/// Every function takes the language and a value per template
+/// key in alphabetical order, and evaluates to the same as a
+/// [`translation!()`] invocation with a static path and a dynamic
+/// language. Type constrained templates constrain their arguments
+/// as well. The functions return a `String` even if `cow_output`
+/// is configured.
+///
+/// **Arguments**
+/// * `input` — The parsed macro arguments.
+///
+/// **Returns**
+/// Generated `TokenStream2` declaring the module.
+///
+/// [`translation_module!()`]: crate::translation_module
+/// [`translation!()`]: crate::translation
+pub fn translation_module_macro(input: TranslationModuleMacroArgs) -> TokenStream2 {
+    let config = handle_macro_result!(out load_config());
+    let translations = handle_macro_result!(out load_translations());
+
+    let into_string = config
+        .cow_output()
+        .then(|| quote! { .map(String::from) });
+
+    let prefix = input
+        .prefix()
+        .segments();
+    let prefix_display = prefix.join("::");
+
+    let subtree = translations.find_subtree(prefix);
+
+    if subtree.is_empty() {
+        return MacroCompileError::PathNotFound(prefix_display).to_out_compile_error();
+    }
+
+    if subtree
+        .iter()
+        .any(|(path, _)| path.len() == prefix.len())
+    {
+        return MacroCompileError::NotAGroup(prefix_display).to_out_compile_error();
+    }
+
+    let mut names = HashMap::<String, String>::new();
+
+    let functions = handle_macro_result!(collect out
+        subtree
+            .iter()
+            .map(|(path, translation)| {
+                let path_display = path.join("::");
+
+                let name = segments_ident(&path[prefix.len()..]);
+                if let Some(other) = names.insert(name.to_string(), path_display.clone()) {
+                    return Err(MacroCompileError::DuplicateFunction(
+                        name.to_string(),
+                        other,
+                        path_display,
+                    ));
+                }
+
+                let mut keys = translation
+                    .values()
+                    .flat_map(|translation| translation.placeholders())
+                    .collect::<Vec<_>>();
+                keys.sort();
+                keys.dedup();
+
+                let arguments = keys
+                    .iter()
+                    .map(|key| {
+                        let ident = parse_str::<Ident>(key)
+                            .ok()
+                            .filter(|ident| ident != "language")
+                            .ok_or_else(|| {
+                                MacroCompileError::InvalidArgument(key.clone(), path_display.clone())
+                            })?;
+
+                        let mut bounds = Vec::new();
+                        for (constraint_key, constraint) in translation
+                            .values()
+                            .flat_map(|translation| translation.constraints())
+                        {
+                            if constraint_key == key && !bounds.contains(constraint) {
+                                bounds.push(*constraint);
+                            }
+                        }
+
+                        let bounds = bounds
+                            .iter()
+                            .map(|constraint| constraint.trait_path());
+
+                        Ok((ident, quote! { impl std::fmt::Display #(+ #bounds)* }))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let doc = format!("Obtains the `{path_display}` translation in a language.");
+                let idents = arguments
+                    .iter()
+                    .map(|(ident, _)| ident);
+                let types = arguments
+                    .iter()
+                    .map(|(_, ty)| ty);
+                let replacements = idents.clone();
+
+                Ok(quote! {
+                    #[doc = #doc]
+                    pub fn #name(
+                        language: &translatable::Language,
+                        #(#idents: #types),*
+                    ) -> std::result::Result<String, translatable::Error> {
+                        translatable::translation!(
+                            language,
+                            static #(#path)::*,
+                            #(#replacements = #replacements),*
+                        )#into_string
+                    }
+                })
+            })
+    );
+
+    let visibility = input.visibility();
+    let module = segments_ident(prefix);
+    let doc = format!("Accessors for the translations beneath `{prefix_display}`.");
+
+    quote! {
+        #[doc = #doc]
+        #visibility mod #module {
+            #(#functions)*
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! [`translation_paths!()`] macro output module.
+//!
+//! This module contains the required for
+//! the generation of the [`translation_paths!()`] macro tokens
+//! from the loaded translations.
+//!
+//! [`translation_paths!()`]: crate::translation_paths
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use translatable_shared::data::translations::load_translations;
+use translatable_shared::handle_macro_result;
+
+/// [`translation_paths!()`] macro output generation.
+///
+/// Expands into a constant expression listing every path
+/// that leads to a translation, resolved during macro
+/// expansion so it can be used in `const` items.
+///
+/// **Returns**
+/// Generated `TokenStream2` evaluating to a `&'static [&'static str]`
+/// of `::` joined paths, sorted alphabetically.
+///
+/// [`translation_paths!()`]: crate::translation_paths
+pub fn translation_paths_macro() -> TokenStream2 {
+    let translations = handle_macro_result!(load_translations());
+
+    let mut paths = translations
+        .leaves()
+        .into_iter()
+        .map(|(path, _)| path.join("::"))
+        .collect::<Vec<_>>();
+
+    paths.sort();
+
+    quote! {
+        {
+            #[doc(hidden)]
+            const PATHS: &[&str] = &[#(#paths),*];
+            PATHS
+        }
+    }
+}
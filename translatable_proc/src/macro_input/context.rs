@@ -6,11 +6,10 @@
 //! [`#\[translation_context\]`]: crate::translation_context
 //! [`parse_macro_input`]: syn::parse_macro_input
 
-use std::str::FromStr;
-
 use proc_macro2::TokenStream;
 use quote::{ToTokens, TokenStreamExt, quote};
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::{
     Error as SynError,
     Expr,
@@ -23,6 +22,8 @@ use syn::{
     Result as SynResult,
     Token,
     Type,
+    TypePath,
+    TypeReference,
     Visibility,
     parse2,
 };
@@ -30,6 +31,7 @@ use thiserror::Error;
 use translatable_shared::macros::errors::IntoCompileError;
 use translatable_shared::misc::language::Language;
 
+use super::utils::input_type::InputType;
 use super::utils::translation_path::TranslationPath;
 
 /// Parse error for [`ContextMacroArgs`] and [`ContextMacroStruct`].
@@ -73,6 +75,43 @@ enum MacroArgsError {
     /// * `0` - The unknown parameter key.
     #[error("Unknown key '{0}', allowed keys are 'fallback_language' and 'base_path'")]
     UnknownKey(String),
+
+    /// Invalid field type.
+    ///
+    /// Translation context fields can't be references
+    /// other than `&'static str`, as any other type is
+    /// parsed into an owned value.
+    #[error("Only '&str' references are allowed for translation contexts, use an owned type")]
+    TypeNotAllowed,
+}
+
+/// The type of a translation context field.
+///
+/// Fields are classified while parsing, references
+/// other than `&str` are rejected with
+/// [`MacroArgsError::TypeNotAllowed`].
+#[derive(Clone)]
+pub enum ContextFieldType {
+    /// An owned `String`.
+    ///
+    /// The translation is loaded with its
+    /// templates replaced in runtime.
+    String,
+
+    /// A `&str` or `&'static str`.
+    ///
+    /// The translation is borrowed from the
+    /// strings inlined in the binary, so it's
+    /// only allowed for translations without
+    /// templates. Always rendered as `&'static str`.
+    Str,
+
+    /// Any other owned type.
+    ///
+    /// The translation is loaded as a `String`
+    /// field would, then parsed into the type
+    /// with its `FromStr` implementation.
+    Parsed(Box<Type>),
 }
 
 /// The arguments passed to the context macro.
@@ -110,6 +149,13 @@ pub struct ContextMacroField {
     /// path passed to the struct configuration.
     path: Option<TranslationPath>,
 
+    /// The field base path.
+    ///
+    /// Declared with the `#[base_path()]`
+    /// attribute, replaces the struct
+    /// base path for this field.
+    base_path: Option<TranslationPath>,
+
     /// The field visibility.
     ///
     /// This gets literally rendered as is.
@@ -122,8 +168,23 @@ pub struct ContextMacroField {
 
     /// The field type.
     ///
-    /// Validated but rendered as is.
-    ty: Type,
+    /// Validated while parsing, rendered
+    /// as specified in [`ContextFieldType`].
+    ty: ContextFieldType,
+
+    /// The placeholders this field expects.
+    ///
+    /// Declared with the `#[placeholders()]`
+    /// attribute, each of these must be used
+    /// by the translation.
+    placeholders: Option<Vec<Ident>>,
+
+    /// The field language override.
+    ///
+    /// Declared with the `#[language()]`
+    /// attribute, the field is loaded in this
+    /// language instead of the context one.
+    language: Option<InputType<Language>>,
 }
 
 /// Translation context struct data.
@@ -201,16 +262,11 @@ impl Parse for ContextMacroArgs {
 
                 "fallback_language" => {
                     if let Expr::Lit(ExprLit { lit: Lit::Str(lit), .. }) = kvp.value {
-                        fallback_language = Some(
-                            Language::from_str(
-                                lit.value()
-                                    .as_str(),
-                            )
-                            .map_err(|_| {
+                        fallback_language =
+                            Some(Language::from_tag(&lit.value()).map_err(|_| {
                                 MacroArgsError::InvalidLanguageLiteral(lit.value())
                                     .to_syn_error(lit)
-                            })?,
-                        );
+                            })?);
                     } else {
                         return Err(MacroArgsError::OnlyLangLiteralAllowed.to_syn_error(kvp.value));
                     }
@@ -222,7 +278,7 @@ impl Parse for ContextMacroArgs {
             }
         }
 
-        let base_path = base_path.unwrap_or_else(|| TranslationPath::default());
+        let base_path = base_path.unwrap_or_default();
 
         Ok(Self { base_path, fallback_language })
     }
@@ -255,6 +311,28 @@ impl ContextMacroField {
             })
     }
 
+    /// Full path getter.
+    ///
+    /// The field [`path`] prefixed with the base path
+    /// declared in the field attribute, otherwise with
+    /// the struct base path.
+    ///
+    /// **Arguments**
+    /// * `struct_base_path` - The base path declared in the struct arguments.
+    ///
+    /// **Returns**
+    /// The segments of the path the field is loaded from.
+    ///
+    /// [`path`]: ContextMacroField::path
+    #[inline]
+    #[allow(unused)]
+    pub fn full_path(&self, struct_base_path: &TranslationPath) -> Vec<String> {
+        self.base_path
+            .as_ref()
+            .unwrap_or(struct_base_path)
+            .merge(&self.path())
+    }
+
     /// Visibility getter.
     ///
     /// **Returns**
@@ -281,9 +359,80 @@ impl ContextMacroField {
     /// A reference to this field's type.
     #[inline]
     #[allow(unused)]
-    pub fn ty(&self) -> &Type {
+    pub fn ty(&self) -> &ContextFieldType {
         &self.ty
     }
+
+    /// Placeholders getter.
+    ///
+    /// **Returns**
+    /// The placeholders declared for this field, if any.
+    #[inline]
+    #[allow(unused)]
+    pub fn placeholders(&self) -> Option<&[Ident]> {
+        self.placeholders
+            .as_deref()
+    }
+
+    /// Language override getter.
+    ///
+    /// **Returns**
+    /// The language declared for this field, if any.
+    #[inline]
+    #[allow(unused)]
+    pub fn language(&self) -> Option<&InputType<Language>> {
+        self.language
+            .as_ref()
+    }
+}
+
+/// [`ToTokens`] implementation for [`ContextFieldType`].
+///
+/// Borrowed fields are always rendered with a
+/// `'static` lifetime, as they point to the
+/// translations inlined in the binary.
+impl ToTokens for ContextFieldType {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.append_all(match self {
+            Self::String => quote! { String },
+            Self::Str => quote! { &'static str },
+            Self::Parsed(ty) => quote! { #ty },
+        });
+    }
+}
+
+/// [`TryFrom<&Type>`] implementation for [`ContextFieldType`].
+///
+/// This implementation classifies a field type,
+/// `String`, `&str` and `&'static str` are loaded
+/// as they are, other references are not allowed
+/// and any other type is parsed.
+impl TryFrom<&Type> for ContextFieldType {
+    type Error = SynError;
+
+    fn try_from(ty: &Type) -> Result<Self, Self::Error> {
+        match ty {
+            Type::Path(TypePath { qself: None, path }) if path.is_ident("String") => {
+                Ok(Self::String)
+            },
+
+            Type::Reference(TypeReference { lifetime, mutability: None, elem, .. })
+                if lifetime
+                    .as_ref()
+                    .is_none_or(|lifetime| lifetime.ident == "static")
+                    && matches!(
+                        elem.as_ref(),
+                        Type::Path(TypePath { qself: None, path }) if path.is_ident("str")
+                    ) =>
+            {
+                Ok(Self::Str)
+            },
+
+            Type::Reference(_) => Err(MacroArgsError::TypeNotAllowed.to_syn_error(ty)),
+
+            ty => Ok(Self::Parsed(Box::new(ty.clone()))),
+        }
+    }
 }
 
 /// [`ToTokens`] implementation for [`ContextMacroField`].
@@ -322,6 +471,44 @@ impl TryFrom<Field> for ContextMacroField {
             .map(|field| field.parse_args::<TranslationPath>())
             .transpose()?;
 
+        let base_path = field
+            .attrs
+            .iter()
+            .find(|field| {
+                field
+                    .path()
+                    .is_ident("base_path")
+            })
+            .map(|field| field.parse_args::<TranslationPath>())
+            .transpose()?;
+
+        let placeholders = field
+            .attrs
+            .iter()
+            .find(|field| {
+                field
+                    .path()
+                    .is_ident("placeholders")
+            })
+            .map(|field| field.parse_args_with(Punctuated::<Ident, Token![,]>::parse_terminated))
+            .transpose()?
+            .map(|placeholders| {
+                placeholders
+                    .into_iter()
+                    .collect()
+            });
+
+        let language = field
+            .attrs
+            .iter()
+            .find(|field| {
+                field
+                    .path()
+                    .is_ident("language")
+            })
+            .map(|field| field.parse_args::<InputType<Language>>())
+            .transpose()?;
+
         let is_pub = field
             .vis
             .clone();
@@ -331,9 +518,17 @@ impl TryFrom<Field> for ContextMacroField {
             .clone()
             .ok_or(MacroArgsError::InvalidFieldType.to_syn_error(&field))?;
 
-        let ty = field.ty;
-
-        Ok(Self { path, visibility: is_pub, ident, ty })
+        let ty = ContextFieldType::try_from(&field.ty)?;
+
+        Ok(Self {
+            path,
+            base_path,
+            visibility: is_pub,
+            ident,
+            ty,
+            placeholders,
+            language,
+        })
     }
 }
 
@@ -382,11 +577,21 @@ impl Parse for ContextMacroStruct {
         let is_pub = structure.vis;
         let ident = structure.ident;
 
-        let fields = structure
-            .fields
-            .into_iter()
-            .map(|field| ContextMacroField::try_from(field))
-            .collect::<Result<Vec<_>, _>>()?;
+        // every invalid field is reported at once.
+        let mut fields = Vec::new();
+        let mut error = None::<SynError>;
+
+        for field in structure.fields {
+            match (ContextMacroField::try_from(field), error.as_mut()) {
+                (Ok(field), _) => fields.push(field),
+                (Err(field_error), Some(error)) => error.combine(field_error),
+                (Err(field_error), None) => error = Some(field_error),
+            }
+        }
+
+        if let Some(error) = error {
+            return Err(error);
+        }
 
         Ok(Self { visibility: is_pub, ident, fields })
     }
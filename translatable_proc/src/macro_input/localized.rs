@@ -0,0 +1,66 @@
+//! [`localized!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`localized!()`]: crate::localized
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::token::Static;
+use syn::{Result as SynResult, Token};
+
+use super::utils::translation_path::TranslationPath;
+
+/// [`localized!()`] macro input arguments.
+///
+/// This structure implements [`Parse`] to parse
+/// [`localized!()`] macro arguments using
+/// [`parse_macro_input`], to later be used
+/// in the [`localized_macro`] function.
+///
+/// [`localized!()`]: crate::localized
+/// [`parse_macro_input`]: syn::parse_macro_input
+/// [`localized_macro`]: crate::macro_generation::localized::localized_macro
+pub struct LocalizedMacroArgs {
+    /// The path of the localized translation,
+    /// optionally prefixed with `static` for consistency
+    /// with the other macros, as it's always static.
+    path: TranslationPath,
+}
+
+/// [`localized!()`] macro args parsing implementation.
+///
+/// This implementation's purpose is to parse [`TokenStream`]
+/// with the [`parse_macro_input`] macro.
+///
+/// [`localized!()`]: crate::localized
+/// [`TokenStream`]: proc_macro::TokenStream
+/// [`parse_macro_input`]: syn::parse_macro_input
+impl Parse for LocalizedMacroArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if input.peek(Static) {
+            input.parse::<Static>()?;
+        }
+
+        let path = input.parse::<TranslationPath>()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { path })
+    }
+}
+
+impl LocalizedMacroArgs {
+    /// `self.path` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.path` as [`TranslationPath`].
+    #[inline]
+    #[allow(unused)]
+    pub fn path(&self) -> &TranslationPath {
+        &self.path
+    }
+}
@@ -11,5 +11,12 @@
 //! [`macro_generation`]: crate::macro_generation
 
 pub mod context;
+pub mod localized;
+pub mod replacements;
+pub mod subtree;
+pub mod translatable_enum;
 pub mod translation;
+pub mod translation_exists;
+pub mod translation_list;
+pub mod translation_module;
 pub mod utils;
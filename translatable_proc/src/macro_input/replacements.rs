@@ -0,0 +1,108 @@
+//! [`#\[derive(Replacements)\]`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`#\[derive(Replacements)\]`]: crate::Replacements
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::{Data, DeriveInput, Fields, Generics, Ident, Result as SynResult};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+
+/// Parse error for [`ReplacementsInput`].
+///
+/// Represents errors that can occur while parsing the
+/// [`#\[derive(Replacements)\]`] macro input.
+///
+/// [`#\[derive(Replacements)\]`]: crate::Replacements
+#[derive(Error, Debug)]
+enum MacroArgsError {
+    /// The derive was applied to an enum, a union
+    /// or a struct without named fields.
+    #[error("Only structs with named fields can derive 'Replacements'")]
+    NotAStruct,
+}
+
+/// Replacements struct data.
+///
+/// The parsed struct, each field being
+/// the replacement for the template with
+/// its name.
+pub struct ReplacementsInput {
+    /// The struct name.
+    ident: Ident,
+
+    /// The struct generics.
+    ///
+    /// Rendered as is in the generated implementation.
+    generics: Generics,
+
+    /// The struct field names.
+    fields: Vec<Ident>,
+}
+
+impl ReplacementsInput {
+    /// Identifier getter.
+    ///
+    /// **Returns**
+    /// A reference to the struct identifier.
+    #[inline]
+    #[allow(unused)]
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Generics getter.
+    ///
+    /// **Returns**
+    /// A reference to the struct generics.
+    #[inline]
+    #[allow(unused)]
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
+    /// Fields getter.
+    ///
+    /// **Returns**
+    /// A slice to the field names of this struct.
+    #[inline]
+    #[allow(unused)]
+    pub fn fields(&self) -> &[Ident] {
+        &self.fields
+    }
+}
+
+/// [`Parse`] implementation for [`ReplacementsInput`].
+///
+/// This implementation is used to parse the struct
+/// trough [`parse_macro_input!()`].
+///
+/// [`parse_macro_input!()`]: syn::parse_macro_input
+impl Parse for ReplacementsInput {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let input = input.parse::<DeriveInput>()?;
+
+        let Data::Struct(data) = input.data else {
+            return Err(MacroArgsError::NotAStruct.to_syn_error(&input.ident));
+        };
+
+        let Fields::Named(fields) = data.fields else {
+            return Err(MacroArgsError::NotAStruct.to_syn_error(&input.ident));
+        };
+
+        let fields = fields
+            .named
+            .into_iter()
+            .filter_map(|field| field.ident)
+            .collect();
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics,
+            fields,
+        })
+    }
+}
@@ -0,0 +1,84 @@
+//! [`subtree!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`subtree!()`]: crate::subtree
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::{Result as SynResult, Token};
+use translatable_shared::misc::language::Language;
+
+use super::utils::input_type::InputType;
+use super::utils::translation_path::TranslationPath;
+
+/// [`subtree!()`] macro input arguments.
+///
+/// This structure implements [`Parse`] to parse
+/// [`subtree!()`] macro arguments using
+/// [`parse_macro_input`], to later be used
+/// in the [`subtree_macro`] function.
+///
+/// [`subtree!()`]: crate::subtree
+/// [`parse_macro_input`]: syn::parse_macro_input
+/// [`subtree_macro`]: crate::macro_generation::subtree::subtree_macro
+pub struct SubtreeMacroArgs {
+    /// The language the translations are obtained in,
+    /// parsed the same way as the [`translation!()`]
+    /// language argument.
+    ///
+    /// [`translation!()`]: crate::translation
+    language: InputType<Language>,
+
+    /// The path prefix the translations must start with,
+    /// parsed the same way as the [`translation!()`]
+    /// path argument.
+    ///
+    /// [`translation!()`]: crate::translation
+    prefix: InputType<TranslationPath>,
+}
+
+/// [`subtree!()`] macro args parsing implementation.
+///
+/// This implementation's purpose is to parse [`TokenStream`]
+/// with the [`parse_macro_input`] macro.
+///
+/// [`subtree!()`]: crate::subtree
+/// [`TokenStream`]: proc_macro::TokenStream
+/// [`parse_macro_input`]: syn::parse_macro_input
+impl Parse for SubtreeMacroArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let language = input.parse::<InputType<Language>>()?;
+        input.parse::<Token![,]>()?;
+        let prefix = input.parse::<InputType<TranslationPath>>()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { language, prefix })
+    }
+}
+
+impl SubtreeMacroArgs {
+    /// `self.language` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.language` as [`InputType<Language>`].
+    #[inline]
+    #[allow(unused)]
+    pub fn language(&self) -> &InputType<Language> {
+        &self.language
+    }
+
+    /// `self.prefix` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.prefix` as [`InputType<TranslationPath>`].
+    #[inline]
+    #[allow(unused)]
+    pub fn prefix(&self) -> &InputType<TranslationPath> {
+        &self.prefix
+    }
+}
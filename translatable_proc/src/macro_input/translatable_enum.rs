@@ -0,0 +1,285 @@
+//! [`#\[derive(TranslatableEnum)\]`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`#\[derive(TranslatableEnum)\]`]: crate::TranslatableEnum
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    Attribute,
+    Data,
+    DeriveInput,
+    Error as SynError,
+    Generics,
+    Ident,
+    MetaNameValue,
+    Result as SynResult,
+    Token,
+    Variant,
+    parse2,
+};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+
+use super::utils::translation_path::TranslationPath;
+
+/// Parse error for [`TranslatableEnumInput`].
+///
+/// Represents errors that can occur while parsing the
+/// [`#\[derive(TranslatableEnum)\]`] macro input.
+///
+/// [`#\[derive(TranslatableEnum)\]`]: crate::TranslatableEnum
+#[derive(Error, Debug)]
+enum MacroArgsError {
+    /// The derive was applied to a struct or union.
+    #[error("Only enums can derive 'TranslatableEnum'")]
+    NotAnEnum,
+
+    /// Invalid attribute parameter.
+    ///
+    /// **Parameters**
+    /// * `0` - The unknown parameter key.
+    /// * `1` - The only key allowed in that position.
+    #[error("Unknown key '{0}', the only allowed key is '{1}'")]
+    UnknownKey(String, &'static str),
+}
+
+/// A variant inside a translatable enum.
+///
+/// Holds the identifier to match the variant
+/// and the path its translation is found in.
+pub struct TranslatableEnumVariant {
+    /// The variant name.
+    ///
+    /// Matched ignoring any field.
+    ident: Ident,
+
+    /// The translation path.
+    ///
+    /// Appended to the enum `base_path`, declared
+    /// with `#[translatable(path = ...)]`, otherwise
+    /// the variant name in snake case.
+    path: TranslationPath,
+}
+
+/// Translatable enum data.
+///
+/// The parsed enum along its `base_path`, declared with
+/// the `#[translatable(base_path = ...)]` attribute.
+pub struct TranslatableEnumInput {
+    /// The enum name.
+    ident: Ident,
+
+    /// The enum generics.
+    ///
+    /// Rendered as is in the generated implementation.
+    generics: Generics,
+
+    /// Variant base path.
+    ///
+    /// A base path to be prepended to all
+    /// variant paths.
+    base_path: TranslationPath,
+
+    /// The enum variants.
+    variants: Vec<TranslatableEnumVariant>,
+}
+
+/// Converts an identifier to snake case.
+///
+/// Each uppercase letter that isn't the first
+/// character starts a new word, so `InTransit`
+/// becomes `in_transit`.
+///
+/// **Arguments**
+/// * `ident` - The identifier to convert.
+///
+/// **Returns**
+/// The snake case identifier.
+fn snake_case(ident: &str) -> String {
+    let mut snake = String::with_capacity(ident.len());
+
+    for (index, c) in ident
+        .chars()
+        .enumerate()
+    {
+        if c.is_uppercase() && index > 0 && !snake.ends_with('_') {
+            snake.push('_');
+        }
+
+        snake.extend(c.to_lowercase());
+    }
+
+    snake
+}
+
+/// Parses the `#[translatable()]` attributes.
+///
+/// **Arguments**
+/// * `attrs` - The attributes of the enum or variant.
+/// * `allowed` - The only key allowed in that position.
+///
+/// **Returns**
+/// The path of the allowed key, if declared.
+fn translatable_attribute(
+    attrs: &[Attribute],
+    allowed: &'static str,
+) -> SynResult<Option<TranslationPath>> {
+    let mut path = None;
+
+    for attr in attrs
+        .iter()
+        .filter(|attr| {
+            attr.path()
+                .is_ident("translatable")
+        })
+    {
+        let values =
+            attr.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)?;
+
+        for kvp in values {
+            if !kvp
+                .path
+                .is_ident(allowed)
+            {
+                let key = kvp
+                    .path
+                    .to_token_stream()
+                    .to_string();
+
+                return Err(MacroArgsError::UnknownKey(key, allowed).to_syn_error(kvp.path));
+            }
+
+            path = Some(parse2::<TranslationPath>(
+                kvp.value
+                    .to_token_stream(),
+            )?);
+        }
+    }
+
+    Ok(path)
+}
+
+impl TranslatableEnumVariant {
+    /// Identifier getter.
+    ///
+    /// **Returns**
+    /// A reference to this variant's identifier.
+    #[inline]
+    #[allow(unused)]
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Path getter.
+    ///
+    /// **Returns**
+    /// A reference to this variant's path, relative to the `base_path`.
+    #[inline]
+    #[allow(unused)]
+    pub fn path(&self) -> &TranslationPath {
+        &self.path
+    }
+}
+
+/// [`TryFrom<Variant>`] implementation for [`TranslatableEnumVariant`].
+///
+/// This implementation is used to parse
+/// the custom metadata from an enum variant.
+impl TryFrom<Variant> for TranslatableEnumVariant {
+    type Error = SynError;
+
+    fn try_from(variant: Variant) -> Result<Self, Self::Error> {
+        let path = translatable_attribute(&variant.attrs, "path")?.unwrap_or_else(|| {
+            TranslationPath::new(
+                vec![snake_case(
+                    &variant
+                        .ident
+                        .to_string(),
+                )],
+                variant
+                    .ident
+                    .span(),
+            )
+        });
+
+        Ok(Self { ident: variant.ident, path })
+    }
+}
+
+impl TranslatableEnumInput {
+    /// Identifier getter.
+    ///
+    /// **Returns**
+    /// A reference to the enum identifier.
+    #[inline]
+    #[allow(unused)]
+    pub fn ident(&self) -> &Ident {
+        &self.ident
+    }
+
+    /// Generics getter.
+    ///
+    /// **Returns**
+    /// A reference to the enum generics.
+    #[inline]
+    #[allow(unused)]
+    pub fn generics(&self) -> &Generics {
+        &self.generics
+    }
+
+    /// Base path getter.
+    ///
+    /// **Returns**
+    /// A reference to the `base_path`.
+    #[inline]
+    #[allow(unused)]
+    pub fn base_path(&self) -> &TranslationPath {
+        &self.base_path
+    }
+
+    /// Variants getter.
+    ///
+    /// **Returns**
+    /// A slice to all the variants in this enum.
+    #[inline]
+    #[allow(unused)]
+    pub fn variants(&self) -> &[TranslatableEnumVariant] {
+        &self.variants
+    }
+}
+
+/// [`Parse`] implementation for [`TranslatableEnumInput`].
+///
+/// This implementation is used to parse the enum
+/// trough [`parse_macro_input!()`].
+///
+/// [`parse_macro_input!()`]: syn::parse_macro_input
+impl Parse for TranslatableEnumInput {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let input = input.parse::<DeriveInput>()?;
+
+        let Data::Enum(data) = input.data else {
+            return Err(MacroArgsError::NotAnEnum.to_syn_error(&input.ident));
+        };
+
+        let base_path = translatable_attribute(&input.attrs, "base_path")?.unwrap_or_default();
+
+        let variants = data
+            .variants
+            .into_iter()
+            .map(TranslatableEnumVariant::try_from)
+            .collect::<SynResult<_>>()?;
+
+        Ok(Self {
+            ident: input.ident,
+            generics: input.generics,
+            base_path,
+            variants,
+        })
+    }
+}
@@ -9,30 +9,246 @@
 use std::collections::HashMap;
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::ToTokens;
+use quote::{ToTokens, quote_spanned};
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
-use syn::token::Static;
-use syn::{Expr, ExprLit, Ident, Lit, Result as SynResult, Token};
+use syn::spanned::Spanned;
+use syn::token::{Brace, Static};
+use syn::{Error as SynError, Expr, Ident, LitStr, Result as SynResult, Token, braced, parse2};
 use thiserror::Error;
+use translatable_shared::data::config::load_config;
 use translatable_shared::macros::errors::IntoCompileError;
 use translatable_shared::misc::language::Language;
+use translatable_shared::misc::templating::{FormatString, TemplateError};
+use translatable_shared::translations::node::TranslationObject;
 
 use super::utils::input_type::InputType;
 use super::utils::translation_path::TranslationPath;
 
-/// Parse error for [`TranslationMacroArgs`].
+/// Inline translation parsing errors.
 ///
-/// Represents errors that can occur while parsing the [`translation!()`]
-/// macro input. This error is only used while parsing compile-time input,
-/// as runtime input is validated in runtime.
+/// Represents errors that can occur while parsing
+/// an inline translation table, these are reported
+/// on the offending entry.
+#[derive(Error, Debug)]
+enum InlineTranslationError {
+    /// An entry key is not a valid language.
+    #[error("The key '{0}' is an invalid ISO 639-1 string, and cannot be parsed")]
+    InvalidLanguage(String),
+
+    /// An entry value is not a valid template string.
+    #[error("Template validation failed: {0:#}")]
+    InvalidTemplate(TemplateError),
+
+    /// An entry value references another translation,
+    /// which is only supported in translation files.
+    #[error("Inline translations cannot reference other translations, found a reference to '{0}'")]
+    Reference(String),
+
+    /// The same language was declared more than once.
+    #[error("The language '{0:?}' is declared more than once")]
+    DuplicateLanguage(Language),
+
+    /// The table doesn't declare any language.
+    #[error("An inline translation must declare at least one language")]
+    Empty,
+}
+
+/// [`translation!()`] arguments parsing errors.
 ///
 /// [`translation!()`]: crate::translation
 #[derive(Error, Debug)]
-enum MacroArgsError {
-    /// An error while parsing a compile-time String value
-    /// was found.
-    #[error("The literal '{0}' is an invalid ISO 639-1 string, and cannot be parsed")]
-    InvalidIsoLiteral(String),
+enum TranslationInputError {
+    /// An argument was found after the replacements spread.
+    #[error("The replacements spread must be the last argument")]
+    SpreadNotLast,
+
+    /// The language was omitted without a
+    /// `default_language` configured.
+    #[error(
+        "A language must be passed unless a `default_language` is configured in \
+         ./translatable.toml"
+    )]
+    MissingLanguage,
+}
+
+/// Omitted language detection.
+///
+/// The language may only be omitted before a source that
+/// can't be mistaken for a language, which are static,
+/// `unchecked` and inline translations.
+///
+/// **Arguments**
+/// * `input` — The macro input, not advanced.
+///
+/// **Returns**
+/// Whether the input starts with the translation source.
+fn omits_language(input: ParseStream) -> bool {
+    if input.peek(Static) {
+        return true;
+    }
+
+    let fork = input.fork();
+    match fork.parse::<Ident>() {
+        Ok(ident) if ident == "inline" => fork.peek(Brace),
+        Ok(ident) if ident == "unchecked" => {
+            fork.peek(Static) || fork.peek(Ident) || fork.peek(LitStr)
+        },
+        _ => false,
+    }
+}
+
+/// Translation source.
+///
+/// Represents where the translation object is obtained
+/// from, either the translation files or the macro call
+/// itself.
+pub enum TranslationSource {
+    /// Translation file path.
+    ///
+    /// **Parameters**
+    /// * `0` — The static or dynamic path to the translation.
+    Path(InputType<TranslationPath>),
+
+    /// Inline translation.
+    ///
+    /// Declared as `inline { en = "Hi {name}", es = "Hola {name}" }`,
+    /// the translation object is parsed from the macro call so
+    /// the translation files are not read.
+    ///
+    /// **Parameters**
+    /// * `0` — The parsed translation object.
+    Inline(TranslationObject),
+}
+
+/// Translation source parsing implementation.
+///
+/// An `inline` identifier followed by braces is parsed as an
+/// inline translation, anything else as a translation path.
+impl Parse for TranslationSource {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        if !(input.peek(Ident) && input.peek2(Brace))
+            || input
+                .fork()
+                .parse::<Ident>()?
+                != "inline"
+        {
+            return Ok(Self::Path(input.parse()?));
+        }
+
+        input.parse::<Ident>()?;
+
+        let content;
+        let braces = braced!(content in input);
+
+        let mut translation = TranslationObject::new();
+
+        while !content.is_empty() {
+            let (key, key_tokens) = if content.peek(LitStr) {
+                let key = content.parse::<LitStr>()?;
+                (key.value(), key.into_token_stream())
+            } else {
+                let key = content.call(Ident::parse_any)?;
+                (key.to_string(), key.into_token_stream())
+            };
+
+            let language = Language::from_tag(&key).map_err(|_| {
+                InlineTranslationError::InvalidLanguage(key).to_syn_error(&key_tokens)
+            })?;
+
+            content.parse::<Token![=]>()?;
+
+            let value = content.parse::<LitStr>()?;
+            let config = load_config().map_err(|err| err.to_syn_error(&value))?;
+
+            let mut format_string =
+                FormatString::parse_with(&value.value(), config.placeholder_delimiters())
+                    .map_err(|err| {
+                        InlineTranslationError::InvalidTemplate(err).to_syn_error(&value)
+                    })?
+                    .with_language(language.clone())
+                    .with_escape_html(config.escape_html());
+
+            if let Some(reference) = format_string
+                .references()
+                .first()
+            {
+                return Err(
+                    InlineTranslationError::Reference(reference.join("::")).to_syn_error(&value)
+                );
+            }
+
+            if config.pseudolocale() {
+                format_string = format_string.pseudolocalize();
+            }
+
+            if translation.contains_key(&language) {
+                return Err(
+                    InlineTranslationError::DuplicateLanguage(language).to_syn_error(key_tokens)
+                );
+            }
+
+            translation.insert(language, format_string);
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        if translation.is_empty() {
+            return Err(SynError::new(
+                braces
+                    .span
+                    .join(),
+                InlineTranslationError::Empty.to_string(),
+            ));
+        }
+
+        Ok(Self::Inline(translation))
+    }
+}
+
+/// Fallback language override.
+///
+/// Parsed from the `fallback` argument, it overrides
+/// how a translation is resolved when the requested
+/// language is not available for a single invocation.
+pub enum FallbackOverride {
+    /// Fallback disabled.
+    ///
+    /// Declared as `fallback = none`, only the requested
+    /// language is accepted, not even its parents.
+    Disabled,
+
+    /// Fallback language.
+    ///
+    /// Declared as `fallback = "en"` or any expression
+    /// evaluating to a `Language`, tried when neither the
+    /// requested language nor its parents are available.
+    ///
+    /// **Parameters**
+    /// * `0` — The static or dynamic fallback language.
+    Language(InputType<Language>),
+}
+
+/// Fallback override parsing implementation.
+///
+/// The `none` identifier disables the fallback, anything
+/// else is parsed the same way as the language argument.
+impl Parse for FallbackOverride {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let value = input.parse::<Expr>()?;
+
+        if let Expr::Path(path) = &value
+            && path
+                .path
+                .is_ident("none")
+        {
+            return Ok(Self::Disabled);
+        }
+
+        Ok(Self::Language(parse2(value.to_token_stream())?))
+    }
 }
 
 /// [`translation!()`] macro input arguments.
@@ -51,22 +267,67 @@ pub struct TranslationMacroArgs {
     /// is a string literal or a `Language` enum tagged
     /// union instance, otherwise dynamic and represented
     /// as a `TokenStream`.
+    ///
+    /// If omitted, the configured `default_language`
+    /// is used as a static language.
     language: InputType<Language>,
 
+    /// Whether the language was declared as `const`,
+    /// such as `translation!(const LANG, static path)`.
+    ///
+    /// A `const` language is wrapped in a `const` block,
+    /// so it fails to compile if it isn't a constant,
+    /// and it's resolved in compile time along static
+    /// paths and inline translations.
+    const_language: bool,
+
     /// Represents a toml path to find the translation
     /// object in the previously parsed TOML from the
     /// translation files, this can be static if specified
     /// as `static path::to::translation` or dynamic if
     /// it's another expression, this way represented as a
     /// [`TokenStream2`].
-    path: InputType<TranslationPath>,
+    ///
+    /// The translation object may also be declared inline,
+    /// in which case no path is involved.
+    source: TranslationSource,
 
     /// Stores the replacement arguments for the translation
     /// templates such as `Hello {name}` if found on a translation.
     ///
     /// If a call such as `a` is found, it will be implicitly
-    /// converted to `a = a` thus stored like so in the hash map.
+    /// converted to `a = a` thus stored like so in the hash map,
+    /// the same way shorthand field initialization works, so
+    /// `name, count` binds each template to the variable in scope
+    /// with the same name.
     replacements: HashMap<Ident, TokenStream2>,
+
+    /// Stores the fallback override if specified
+    /// as `fallback = ...`, otherwise the translation
+    /// falls back to the requested language parents.
+    ///
+    /// The shorthand `fallback` is still considered
+    /// a replacement for a `{fallback}` template.
+    fallback: Option<FallbackOverride>,
+
+    /// Stores the replacements map if specified
+    /// as `replacements = ...`, an expression evaluating
+    /// to a map whose keys and values implement `Display`,
+    /// such as a `HashMap<String, String>` or a reference to it.
+    ///
+    /// Explicit replacements take precedence over the
+    /// map ones, and these over the spread ones. The
+    /// shorthand `replacements` is still considered a
+    /// replacement for a `{replacements}` template.
+    replacement_map: Option<Expr>,
+
+    /// Stores the replacements spread if specified
+    /// as `..replacements`, an expression evaluating
+    /// to a value implementing `Replacements`.
+    ///
+    /// Explicit replacements take precedence over
+    /// the spread ones.
+    spread: Option<Expr>,
 }
 
 /// [`translation!()`] macro args parsing implementation.
@@ -75,45 +336,89 @@ pub struct TranslationMacroArgs {
 /// with the [`parse_macro_input`] macro.
 impl Parse for TranslationMacroArgs {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let parsed_language_arg =
-            match input.parse::<Expr>()? {
-                Expr::Lit(ExprLit { lit: Lit::Str(literal), .. }) => {
-                    match literal
-                        .value()
-                        .parse::<Language>()
-                    {
-                        Ok(language) => InputType::Static(language),
-
-                        Err(_) => Err(MacroArgsError::InvalidIsoLiteral(literal.value())
-                            .to_syn_error(literal))?,
-                    }
-                },
+        let is_const = input
+            .parse::<Option<Token![const]>>()?
+            .is_some();
 
-                other => InputType::Dynamic(other.into_token_stream()),
-            };
+        let parsed_language_arg = if !is_const && omits_language(input) {
+            let config = load_config().map_err(|err| {
+                err.to_syn_error(
+                    input
+                        .cursor()
+                        .token_stream(),
+                )
+            })?;
 
-        input.parse::<Token![,]>()?;
-
-        let parsed_path_arg = match input.parse::<Static>() {
-            Ok(_) => InputType::Static(input.parse::<TranslationPath>()?),
+            match config.default_language() {
+                Some(language) => InputType::Static(language.clone()),
+                None => {
+                    return Err(TranslationInputError::MissingLanguage.to_syn_error(
+                        input
+                            .cursor()
+                            .token_stream(),
+                    ));
+                },
+            }
+        } else {
+            let language = input.parse::<InputType<Language>>()?;
+            input.parse::<Token![,]>()?;
+            language
+        };
 
-            Err(_) => InputType::Dynamic(
-                input
-                    .parse::<Expr>()?
-                    .to_token_stream(),
+        let (parsed_language_arg, const_language) = match parsed_language_arg {
+            InputType::Dynamic(language) if is_const => (
+                InputType::Dynamic(quote_spanned! { language.span() => const { #language } }),
+                true,
             ),
+
+            language => (language, false),
         };
 
+        let parsed_source_arg = input.parse::<TranslationSource>()?;
+
         let mut replacements = HashMap::new();
+        let mut fallback = None;
+        let mut replacement_map = None;
+        let mut spread = None;
         if input.peek(Token![,]) {
             while !input.is_empty() {
                 input.parse::<Token![,]>()?;
 
-                if input.is_empty() {
+                // empty replacement sections such as `, ,` are skipped.
+                if input.is_empty() || input.peek(Token![,]) {
+                    continue;
+                }
+
+                if input.peek(Token![..]) {
+                    input.parse::<Token![..]>()?;
+                    spread = Some(input.parse::<Expr>()?);
+
+                    while input.peek(Token![,]) {
+                        input.parse::<Token![,]>()?;
+                    }
+
+                    if !input.is_empty() {
+                        let rest = input.parse::<TokenStream2>()?;
+                        return Err(TranslationInputError::SpreadNotLast.to_syn_error(rest));
+                    }
+
                     break;
                 }
 
                 let key = input.parse::<Ident>()?;
+
+                if key == "fallback" && input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    fallback = Some(input.parse::<FallbackOverride>()?);
+                    continue;
+                }
+
+                if key == "replacements" && input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    replacement_map = Some(input.parse::<Expr>()?);
+                    continue;
+                }
+
                 let value = match input.parse::<Token![=]>() {
                     Ok(_) => input
                         .parse::<Expr>()?
@@ -130,8 +435,12 @@ impl Parse for TranslationMacroArgs {
 
         Ok(Self {
             language: parsed_language_arg,
-            path: parsed_path_arg,
+            const_language,
+            source: parsed_source_arg,
             replacements,
+            fallback,
+            replacement_map,
+            spread,
         })
     }
 }
@@ -147,14 +456,38 @@ impl TranslationMacroArgs {
         &self.language
     }
 
-    /// `self.path` reference getter.
+    /// `self.const_language` getter.
+    ///
+    /// **Returns**
+    /// Whether the language was declared as `const`.
+    #[inline]
+    #[allow(unused)]
+    pub fn const_language(&self) -> bool {
+        self.const_language
+    }
+
+    /// Language variant path normalization.
+    ///
+    /// Converts a language written as a variant path, such
+    /// as `Language::EN`, into a static language, so it's
+    /// resolved in compile time as `"en"` would be.
+    pub fn static_language_variant(&mut self) {
+        if let Some(language) = self
+            .language
+            .variant()
+        {
+            self.language = InputType::Static(language);
+        }
+    }
+
+    /// `self.source` reference getter.
     ///
     /// **Returns**
-    /// A reference to `self.path` as [`InputType<Vec<String>>`]
+    /// A reference to `self.source` as [`TranslationSource`]
     #[inline]
     #[allow(unused)]
-    pub fn path(&self) -> &InputType<TranslationPath> {
-        &self.path
+    pub fn source(&self) -> &TranslationSource {
+        &self.source
     }
 
     /// `self.replacements` reference getter.
@@ -166,4 +499,37 @@ impl TranslationMacroArgs {
     pub fn replacements(&self) -> &HashMap<Ident, TokenStream2> {
         &self.replacements
     }
+
+    /// `self.fallback` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.fallback` as [`Option<FallbackOverride>`]
+    #[inline]
+    #[allow(unused)]
+    pub fn fallback(&self) -> Option<&FallbackOverride> {
+        self.fallback
+            .as_ref()
+    }
+
+    /// `self.replacement_map` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.replacement_map` as [`Option<Expr>`]
+    #[inline]
+    #[allow(unused)]
+    pub fn replacement_map(&self) -> Option<&Expr> {
+        self.replacement_map
+            .as_ref()
+    }
+
+    /// `self.spread` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.spread` as [`Option<Expr>`]
+    #[inline]
+    #[allow(unused)]
+    pub fn spread(&self) -> Option<&Expr> {
+        self.spread
+            .as_ref()
+    }
 }
@@ -0,0 +1,84 @@
+//! [`translation_exists!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`translation_exists!()`]: crate::translation_exists
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::{Result as SynResult, Token};
+use translatable_shared::misc::language::Language;
+
+use super::utils::input_type::InputType;
+use super::utils::translation_path::TranslationPath;
+
+/// [`translation_exists!()`] macro input arguments.
+///
+/// This structure implements [`Parse`] to parse
+/// [`translation_exists!()`] macro arguments using
+/// [`parse_macro_input`], to later be used
+/// in the [`translation_exists_macro`] function.
+///
+/// [`translation_exists!()`]: crate::translation_exists
+/// [`parse_macro_input`]: syn::parse_macro_input
+/// [`translation_exists_macro`]: crate::macro_generation::translation_exists::translation_exists_macro
+pub struct TranslationExistsMacroArgs {
+    /// The language the translation is checked in,
+    /// parsed the same way as the [`translation!()`]
+    /// language argument.
+    ///
+    /// [`translation!()`]: crate::translation
+    language: InputType<Language>,
+
+    /// The path to the translation, parsed the
+    /// same way as the [`translation!()`] path
+    /// argument.
+    ///
+    /// [`translation!()`]: crate::translation
+    path: InputType<TranslationPath>,
+}
+
+/// [`translation_exists!()`] macro args parsing implementation.
+///
+/// This implementation's purpose is to parse [`TokenStream`]
+/// with the [`parse_macro_input`] macro.
+///
+/// [`translation_exists!()`]: crate::translation_exists
+/// [`TokenStream`]: proc_macro::TokenStream
+/// [`parse_macro_input`]: syn::parse_macro_input
+impl Parse for TranslationExistsMacroArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let language = input.parse::<InputType<Language>>()?;
+        input.parse::<Token![,]>()?;
+        let path = input.parse::<InputType<TranslationPath>>()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { language, path })
+    }
+}
+
+impl TranslationExistsMacroArgs {
+    /// `self.language` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.language` as [`InputType<Language>`].
+    #[inline]
+    #[allow(unused)]
+    pub fn language(&self) -> &InputType<Language> {
+        &self.language
+    }
+
+    /// `self.path` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.path` as [`InputType<TranslationPath>`].
+    #[inline]
+    #[allow(unused)]
+    pub fn path(&self) -> &InputType<TranslationPath> {
+        &self.path
+    }
+}
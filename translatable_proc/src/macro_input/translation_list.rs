@@ -0,0 +1,145 @@
+//! [`translation_list!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`translation_list!()`]: crate::translation_list
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::token::Static;
+use syn::{Expr, Ident, Result as SynResult, Token};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::language::Language;
+
+use super::utils::input_type::InputType;
+use super::utils::translation_path::TranslationPath;
+
+/// [`translation_list!()`] input parsing errors.
+///
+/// [`translation_list!()`]: crate::translation_list
+#[derive(Error, Debug)]
+enum TranslationListInputError {
+    /// The path is not prefixed with `static`.
+    #[error(
+        "Translation lists can only be obtained from static paths, such as `static path::to::list`"
+    )]
+    DynamicPath,
+}
+
+/// [`translation_list!()`] macro input arguments.
+///
+/// This structure implements [`Parse`] to parse
+/// [`translation_list!()`] macro arguments using
+/// [`parse_macro_input`], to later be used
+/// in the [`translation_list_macro`] function.
+///
+/// [`translation_list!()`]: crate::translation_list
+/// [`parse_macro_input`]: syn::parse_macro_input
+/// [`translation_list_macro`]: crate::macro_generation::translation_list::translation_list_macro
+pub struct TranslationListMacroArgs {
+    /// The language the list is obtained in,
+    /// parsed the same way as the [`translation!()`]
+    /// language argument.
+    ///
+    /// [`translation!()`]: crate::translation
+    language: InputType<Language>,
+
+    /// The static path to the translation list,
+    /// declared as `static path::to::list`.
+    path: TranslationPath,
+
+    /// Stores the replacement arguments for the list
+    /// templates, parsed the same way as the [`translation!()`]
+    /// replacements.
+    ///
+    /// [`translation!()`]: crate::translation
+    replacements: HashMap<Ident, TokenStream2>,
+}
+
+/// [`translation_list!()`] macro args parsing implementation.
+///
+/// This implementation's purpose is to parse [`TokenStream`]
+/// with the [`parse_macro_input`] macro.
+///
+/// [`translation_list!()`]: crate::translation_list
+/// [`TokenStream`]: proc_macro::TokenStream
+/// [`parse_macro_input`]: syn::parse_macro_input
+impl Parse for TranslationListMacroArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let language = input.parse::<InputType<Language>>()?;
+        input.parse::<Token![,]>()?;
+
+        if input
+            .parse::<Static>()
+            .is_err()
+        {
+            let path = input.parse::<Expr>()?;
+            return Err(TranslationListInputError::DynamicPath.to_syn_error(path));
+        }
+
+        let path = input.parse::<TranslationPath>()?;
+
+        let mut replacements = HashMap::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+
+            if input.is_empty() {
+                break;
+            }
+
+            let key = input.parse::<Ident>()?;
+
+            let value = match input.parse::<Token![=]>() {
+                Ok(_) => input
+                    .parse::<Expr>()?
+                    .to_token_stream(),
+
+                Err(_) => key
+                    .clone()
+                    .into_token_stream(),
+            };
+
+            replacements.insert(key, value);
+        }
+
+        Ok(Self { language, path, replacements })
+    }
+}
+
+impl TranslationListMacroArgs {
+    /// `self.language` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.language` as [`InputType<Language>`].
+    #[inline]
+    #[allow(unused)]
+    pub fn language(&self) -> &InputType<Language> {
+        &self.language
+    }
+
+    /// `self.path` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.path` as [`TranslationPath`].
+    #[inline]
+    #[allow(unused)]
+    pub fn path(&self) -> &TranslationPath {
+        &self.path
+    }
+
+    /// `self.replacements` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.replacements` as [`HashMap<Ident, TokenStream2>`]
+    #[inline]
+    #[allow(unused)]
+    pub fn replacements(&self) -> &HashMap<Ident, TokenStream2> {
+        &self.replacements
+    }
+}
@@ -0,0 +1,83 @@
+//! [`translation_module!()`] input parsing module.
+//!
+//! This module declares a structure that implements
+//! [`Parse`] for it to be used with [`parse_macro_input`].
+//!
+//! [`translation_module!()`]: crate::translation_module
+//! [`parse_macro_input`]: syn::parse_macro_input
+
+use syn::parse::{Parse, ParseStream};
+use syn::token::Static;
+use syn::{Result as SynResult, Token, Visibility};
+
+use super::utils::translation_path::TranslationPath;
+
+/// [`translation_module!()`] macro input arguments.
+///
+/// This structure implements [`Parse`] to parse
+/// [`translation_module!()`] macro arguments using
+/// [`parse_macro_input`], to later be used
+/// in the [`translation_module_macro`] function.
+///
+/// [`translation_module!()`]: crate::translation_module
+/// [`parse_macro_input`]: syn::parse_macro_input
+/// [`translation_module_macro`]: crate::macro_generation::translation_module::translation_module_macro
+pub struct TranslationModuleMacroArgs {
+    /// The generated module visibility.
+    ///
+    /// This gets literally rendered as is.
+    visibility: Visibility,
+
+    /// The path prefix the module is generated for,
+    /// optionally prefixed with `static` for consistency
+    /// with the other macros, as it's always static.
+    prefix: TranslationPath,
+}
+
+/// [`translation_module!()`] macro args parsing implementation.
+///
+/// This implementation's purpose is to parse [`TokenStream`]
+/// with the [`parse_macro_input`] macro.
+///
+/// [`translation_module!()`]: crate::translation_module
+/// [`TokenStream`]: proc_macro::TokenStream
+/// [`parse_macro_input`]: syn::parse_macro_input
+impl Parse for TranslationModuleMacroArgs {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let visibility = input.parse::<Visibility>()?;
+
+        if input.peek(Static) {
+            input.parse::<Static>()?;
+        }
+
+        let prefix = input.parse::<TranslationPath>()?;
+
+        if input.peek(Token![,]) {
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(Self { visibility, prefix })
+    }
+}
+
+impl TranslationModuleMacroArgs {
+    /// `self.visibility` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.visibility` as [`Visibility`].
+    #[inline]
+    #[allow(unused)]
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    /// `self.prefix` reference getter.
+    ///
+    /// **Returns**
+    /// A reference to `self.prefix` as [`TranslationPath`].
+    #[inline]
+    #[allow(unused)]
+    pub fn prefix(&self) -> &TranslationPath {
+        &self.prefix
+    }
+}
@@ -5,7 +5,29 @@
 //! and dynamic values during macro input parsing.
 
 use proc_macro2::TokenStream as TokenStream2;
-use quote::ToTokens;
+use quote::{ToTokens, quote};
+use strum::IntoEnumIterator;
+use syn::parse::{Parse, ParseStream};
+use syn::token::Static;
+use syn::{Expr, ExprLit, ExprPath, Ident, Lit, LitStr, Result as SynResult, parse2};
+use thiserror::Error;
+use translatable_shared::macros::errors::IntoCompileError;
+use translatable_shared::misc::language::Language;
+
+use super::translation_path::TranslationPath;
+
+/// Parse error for [`InputType`].
+///
+/// Represents errors that can occur while parsing
+/// a static input. This error is only used while parsing
+/// compile-time input, as runtime input is validated in runtime.
+#[derive(Error, Debug)]
+enum InputTypeError {
+    /// An error while parsing a compile-time String value
+    /// was found.
+    #[error("The literal '{0}' is an invalid ISO 639-1 string, and cannot be parsed")]
+    InvalidIsoLiteral(String),
+}
 
 /// Input type differentiation enum.
 ///
@@ -59,3 +81,109 @@ impl<T: ToTokens> InputType<T> {
         }
     }
 }
+
+/// Language input helpers.
+impl InputType<Language> {
+    /// Language variant path getter.
+    ///
+    /// A dynamic language written as a path to a [`Language`]
+    /// variant, such as `Language::EN` or `translatable::Language::EN`,
+    /// is known in compile time even if it's not a literal.
+    ///
+    /// **Returns**
+    /// The variant, if the language is a variant path.
+    pub fn variant(&self) -> Option<Language> {
+        let Self::Dynamic(tokens) = self else {
+            return None;
+        };
+
+        let Ok(Expr::Path(ExprPath { qself: None, path, .. })) = parse2::<Expr>(tokens.clone())
+        else {
+            return None;
+        };
+
+        let mut segments = path
+            .segments
+            .iter()
+            .rev();
+        let variant = segments.next()?;
+        let language = segments.next()?;
+
+        if language.ident != "Language"
+            || !language
+                .arguments
+                .is_none()
+            || !variant
+                .arguments
+                .is_none()
+        {
+            return None;
+        }
+
+        Language::iter().find(|language| variant.ident == format!("{language:?}"))
+    }
+}
+
+/// Translation path input parsing implementation.
+///
+/// A path prefixed with `static` is parsed as a static
+/// [`TranslationPath`], any other expression is kept as
+/// a dynamic path.
+///
+/// A path prefixed with `unchecked`, such as `unchecked plugin::greeting`,
+/// is written as a static path but kept as a dynamic one, so it's
+/// not validated in compile time, for paths that only exist in runtime.
+impl Parse for InputType<TranslationPath> {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        let fork = input.fork();
+        if fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == "unchecked")
+            && (fork.peek(Static) || fork.peek(Ident) || fork.peek(LitStr))
+        {
+            input.parse::<Ident>()?;
+            input.parse::<Option<Static>>()?;
+
+            let segments = input
+                .parse::<TranslationPath>()?
+                .segments()
+                .clone();
+
+            return Ok(Self::Dynamic(quote! { vec![#(#segments),*] }));
+        }
+
+        match input.parse::<Static>() {
+            Ok(_) => Ok(Self::Static(input.parse::<TranslationPath>()?)),
+
+            Err(_) => Ok(Self::Dynamic(
+                input
+                    .parse::<Expr>()?
+                    .to_token_stream(),
+            )),
+        }
+    }
+}
+
+/// Language input parsing implementation.
+///
+/// A string literal is parsed as a static [`Language`],
+/// failing if it's not a valid language, any other
+/// expression is kept as a dynamic language.
+impl Parse for InputType<Language> {
+    fn parse(input: ParseStream) -> SynResult<Self> {
+        match input.parse::<Expr>()? {
+            Expr::Lit(ExprLit { lit: Lit::Str(literal), .. }) => {
+                match Language::from_tag(&literal.value()) {
+                    Ok(language) => Ok(Self::Static(language)),
+
+                    Err(_) => {
+                        Err(InputTypeError::InvalidIsoLiteral(literal.value())
+                            .to_syn_error(literal))
+                    },
+                }
+            },
+
+            other => Ok(Self::Dynamic(other.into_token_stream())),
+        }
+    }
+}
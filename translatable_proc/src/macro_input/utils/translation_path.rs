@@ -2,7 +2,8 @@
 //!
 //! This module declares an abstraction
 //! to parse [`syn::Path`] disallowing
-//! generic type arguments.
+//! generic type arguments and allowing
+//! string literal segments.
 //!
 //! This module doesn't have anything
 //! to do with [`std::path`].
@@ -10,7 +11,7 @@
 use proc_macro2::Span;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Error as SynError, Path, PathArguments, Result as SynResult};
+use syn::{Error as SynError, LitStr, PathArguments, PathSegment, Result as SynResult, Token};
 
 /// Static translation path parser.
 ///
@@ -18,6 +19,10 @@ use syn::{Error as SynError, Path, PathArguments, Result as SynResult};
 /// of [`syn::Path`] but disallowing generic
 /// types.
 ///
+/// Segments that aren't valid identifiers, such
+/// as `404` or `sign-in`, may be written as string
+/// literals, e.g. `errors::"404"`.
+///
 /// The structure is spanned preserving
 /// the original path unless defaulted, otherwise
 /// the span is callsite.
@@ -29,7 +34,7 @@ pub struct TranslationPath {
     ///
     /// The segments are translated
     /// from a `syn::Path` as
-    /// x::y -> vec!["x", "y"].
+    /// x::"y" -> vec!["x", "y"].
     segments: Vec<String>,
 
     /// The path original span
@@ -46,23 +51,57 @@ pub struct TranslationPath {
 /// [`parse_macro_input!`]: syn::parse_macro_input
 impl Parse for TranslationPath {
     fn parse(input: ParseStream) -> SynResult<Self> {
-        let path = input.parse::<Path>()?;
-
-        let span = path.span();
-        let segments = path
-            .segments
-            .into_iter()
-            .map(|segment| match segment.arguments {
-                PathArguments::None => Ok(segment
-                    .ident
-                    .to_string()),
-
-                error => Err(SynError::new_spanned(
-                    error,
-                    "A translation path can't contain generic arguments.",
-                )),
-            })
-            .collect::<Result<_, _>>()?;
+        let mut span = input.span();
+        let mut segments = Vec::new();
+
+        if input.peek(Token![::]) {
+            input.parse::<Token![::]>()?;
+        }
+
+        loop {
+            let segment_span = if input.peek(LitStr) {
+                let segment = input.parse::<LitStr>()?;
+
+                if segment
+                    .value()
+                    .is_empty()
+                {
+                    return Err(SynError::new_spanned(
+                        segment,
+                        "A translation path segment can't be empty.",
+                    ));
+                }
+
+                segments.push(segment.value());
+                segment.span()
+            } else {
+                let segment = input.parse::<PathSegment>()?;
+
+                if !matches!(segment.arguments, PathArguments::None) {
+                    return Err(SynError::new_spanned(
+                        segment.arguments,
+                        "A translation path can't contain generic arguments.",
+                    ));
+                }
+
+                segments.push(
+                    segment
+                        .ident
+                        .to_string(),
+                );
+                segment.span()
+            };
+
+            span = span
+                .join(segment_span)
+                .unwrap_or(span);
+
+            if !input.peek(Token![::]) {
+                break;
+            }
+
+            input.parse::<Token![::]>()?;
+        }
 
         Ok(Self { segments, span })
     }
@@ -94,7 +133,7 @@ impl TranslationPath {
     /// **Arguments**
     /// * `segments` - The segments this path is made of x::y -> vec!["x", "y"].
     /// * `span` - The original location or where this path should return errors
-    /// if it may.
+    ///   if it may.
     ///
     /// **Returns**
     /// A constructed instance of [`TranslationPath`].
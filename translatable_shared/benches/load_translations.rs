@@ -0,0 +1,91 @@
+//! Translation loading benchmark.
+//!
+//! Generates a large translation tree and compares parsing its
+//! files one after another with [`parse_translation_files`], which
+//! parses them in a thread pool with the `parallel` feature.
+//!
+//! ```sh
+//! cargo bench -p translatable_shared --features parallel
+//! ```
+
+use std::env::temp_dir;
+use std::fs::{create_dir_all, write};
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use translatable_shared::data::config::load_config;
+use translatable_shared::data::translations::{
+    TranslationFile,
+    parse_translation_file,
+    parse_translation_files,
+};
+
+/// Amount of generated translation files.
+const FILES: usize = 2000;
+
+/// Amount of translations per generated file.
+const KEYS: usize = 50;
+
+/// Generates the translation tree.
+///
+/// The files are spread in directories, each of them
+/// containing translations with templates in a few languages.
+///
+/// **Returns**
+/// The generated translation file paths.
+fn translation_tree() -> Vec<String> {
+    let root = temp_dir().join("translatable_bench");
+
+    (0..FILES)
+        .map(|file| {
+            let directory = root.join(format!("section_{}", file % 20));
+            create_dir_all(&directory).expect("Directory to be created.");
+
+            let content = (0..KEYS)
+                .map(|key| {
+                    format!(
+                        "[file_{file}.key_{key}]\nen = \"Hello {{name}}, you have {{count:int}} \
+                         messages.\"\nes = \"Hola {{name}}, tienes {{count:int}} mensajes.\"\nde \
+                         = \"Hallo {{name}}, du hast {{count:int}} Nachrichten.\"\n\n"
+                    )
+                })
+                .collect::<String>();
+
+            let path = directory.join(format!("file_{file}.toml"));
+            write(&path, content).expect("File to be written.");
+
+            path.to_string_lossy()
+                .to_string()
+        })
+        .collect()
+}
+
+fn load_translations(c: &mut Criterion) {
+    let config = load_config().expect("Configuration to be loaded.");
+    let paths = translation_tree();
+    let files = paths
+        .iter()
+        .map(|path| TranslationFile::Path(path, false))
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("load_translations");
+    group.sample_size(10);
+
+    group.bench_function("sequential", |b| {
+        b.iter(|| {
+            files
+                .iter()
+                .map(|file| parse_translation_file(file, config))
+                .collect::<Result<Vec<_>, _>>()
+                .expect("Translations to be parsed.")
+        })
+    });
+
+    group.bench_function("parse_translation_files", |b| {
+        b.iter(|| parse_translation_files(&files, config).expect("Translations to be parsed."))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, load_translations);
+criterion_main!(benches);
@@ -0,0 +1,90 @@
+//! Language data generation.
+//!
+//! This build script reads `data/languages.tsv` and
+//! generates the lookup tables used by `Language::from_str`,
+//! `Language::all`, `Language::display_name` and `Language::native_name`, so
+//! the codes and names are kept as data instead of being
+//! hardcoded in the enum.
+//!
+//! It also collects the enabled `lang-*` features, so
+//! the translations are only loaded in those languages,
+//! as features can't be enumerated with `cfg!` without
+//! listing every language.
+
+use std::env::{var, vars};
+use std::fs::{read_to_string, write};
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=data/languages.tsv");
+
+    let data = read_to_string("data/languages.tsv").expect("data/languages.tsv to be readable.");
+
+    let (name_arms, code_arms, variants) = data
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split('\t');
+
+            let (Some(code), Some(iso639_2), Some(english), Some(native), None) =
+                (fields.next(), fields.next(), fields.next(), fields.next(), fields.next())
+            else {
+                panic!("Invalid line in data/languages.tsv: '{line}'");
+            };
+
+            let variant = code.to_uppercase();
+
+            // some english names are the same as their ISO 639-2 code.
+            let mut patterns = vec![code.to_string(), english.to_lowercase()];
+            for code in iso639_2.split(',') {
+                if !patterns
+                    .iter()
+                    .any(|pattern| pattern == code)
+                {
+                    patterns.push(code.to_string());
+                }
+            }
+
+            let patterns = patterns
+                .iter()
+                .map(|pattern| format!("{pattern:?}"))
+                .collect::<Vec<_>>()
+                .join(" | ");
+
+            (
+                format!("        Language::{variant} => ({english:?}, {native:?}),\n"),
+                format!("        {patterns} => Some(Language::{variant}),\n"),
+                format!("    Language::{variant},\n"),
+            )
+        })
+        .collect::<(String, String, String)>();
+
+    let output =
+        Path::new(&var("OUT_DIR").expect("OUT_DIR to be set by cargo.")).join("language_data.rs");
+
+    write(
+        output,
+        format!(
+            "fn language_names(language: &Language) -> (&str, &str) {{\n    match \
+             language {{\n{name_arms}        Language::Other(tag) => (tag, tag),\n    }}\n}}\n\nfn language_from_code(code: &str) -> \
+             Option<Language> {{\n    match code {{\n{code_arms}        _ => None,\n    }}\n}}\n\nstatic \
+             LANGUAGES: &[Language] = &[\n{variants}];\n"
+        ),
+    )
+    .expect("The generated language data to be written.");
+
+    let mut codes = vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_LANG_")
+                .map(str::to_lowercase)
+        })
+        .collect::<Vec<_>>();
+    codes.sort();
+
+    let output = Path::new(&var("OUT_DIR").expect("OUT_DIR to be set by cargo."))
+        .join("language_features.rs");
+
+    write(output, format!("const LANGUAGE_FEATURES: &[&str] = &{codes:?};\n"))
+        .expect("The enabled language features to be written.");
+}
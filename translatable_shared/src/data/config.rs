@@ -0,0 +1,1546 @@
+//! User configuration module.
+//!
+//! This module defines the structures and
+//! helper functions for parsing and loading
+//! user configuration files.
+
+use std::env::{VarError, var, vars};
+use std::fs::read_to_string;
+use std::io::Error as IoError;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use glob::Pattern;
+use strum::EnumString;
+use thiserror::Error;
+use toml_edit::{DocumentMut, Item, TomlError};
+
+use crate::misc::language::Language;
+use crate::misc::suggestion::closest_match;
+use crate::misc::templating::PlaceholderDelimiters;
+
+/// Configuration error enum.
+///
+/// Used for compile-time configuration
+/// errors, such as errors while opening
+/// files or parsing a file format.
+///
+/// The errors from this enum are directly
+/// shown in rust-analyzer.
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    /// IO error derivations.
+    ///
+    /// Usually errors while interacting
+    /// with the file system.
+    ///
+    /// [`Display`] forwards the inner error [`Display`]
+    /// value with some prefix text.
+    ///
+    /// The enum implements [`From<std::io::Error>`] to
+    /// allow conversion from [`std::io::Error`].
+    ///
+    /// **Parameters**
+    /// * `0` - The IO error derivation.
+    ///
+    /// [`Display`]: std::fmt::Display
+    /// [`From<std::io::Error>`]: std::io::Error
+    #[error("IO error reading configuration: {0:#}")]
+    Io(#[from] IoError),
+
+    /// TOML deserialization error derivations.
+    ///
+    /// The configuration file contents could
+    /// not be parsed as TOML.
+    ///
+    /// The error is formatted displaying
+    /// the file name hardcoded as `./translatable.toml`
+    /// and appended with the line and character.
+    ///
+    /// The enum implements [`From<toml::de::Error>`] to
+    /// allow conversion from [`toml::de::Error`]
+    ///
+    /// **Parameters**
+    /// * `0` - The TOML deserialization error derivation.
+    ///
+    /// [`From<toml::de::Error>`]: toml::de::Error
+    #[error(
+        "TOML parse error '{}'{}",
+        .0.message(),
+        .0.span()
+            .map(|l| format!(" in ./translatable.toml:{}:{}", l.start, l.end))
+            .unwrap_or_else(|| "".into())
+    )]
+    ParseToml(#[from] TomlError),
+
+    /// Parse value error.
+    ///
+    /// There was an error while parsing
+    /// a specific configuration entry,
+    /// since these are mapped to enums in
+    /// most cases.
+    ///
+    /// The error has a custom format
+    /// displaying the key and value
+    /// that should have been parsed.
+    ///
+    /// **Parameters**
+    /// * `0` - The configuration key for which the entry could not be parsed.
+    /// * `1` - The configuration value that couldn't be parsed.
+    #[error("Couldn't parse configuration entry '{1}' for '{0}'")]
+    InvalidValue(String, String),
+
+    /// Unknown configuration key.
+    ///
+    /// The configuration file contains a key that
+    /// is not recognized, usually due to a typo. This
+    /// can be disabled with `allow_unknown_keys`.
+    ///
+    /// **Parameters**
+    /// * `0` - The unknown configuration key.
+    /// * `1` - The closest known configuration key, if any.
+    #[error(
+        "Unknown configuration key '{0}' in ./translatable.toml{hint}",
+        hint = _1
+            .as_ref()
+            .map(|key| format!(", did you mean '{key}'?"))
+            .unwrap_or_default()
+    )]
+    UnknownKey(String, Option<String>),
+}
+
+/// Known configuration keys.
+///
+/// Any other top-level key in `translatable.toml` is
+/// reported as a [`ConfigError::UnknownKey`] unless
+/// `allow_unknown_keys` is set.
+const CONFIG_KEYS: &[&str] = &[
+    "path",
+    "overlay_path",
+    "translations_cache",
+    "seek_mode",
+    "overlap",
+    "embed_languages",
+    "default_language",
+    "fallback_rules",
+    "namespace_by_file",
+    "missing_replacements",
+    "missing",
+    "placeholder_open",
+    "placeholder_close",
+    "path_separator",
+    "trace_merge",
+    "coverage",
+    "print_config",
+    "allow_empty",
+    "allow_missing_locales",
+    "case_insensitive_paths",
+    "bidi_isolate",
+    "escape_html",
+    "pseudolocale",
+    "cow_output",
+    "literal_output",
+    "allow_unknown_keys",
+];
+
+/// Configuration environment variables.
+///
+/// Every configuration key is overridden by its uppercase
+/// name prefixed with `TRANSLATABLE_`, except `path` and
+/// `overlay_path`, overridden by `TRANSLATABLE_LOCALES_PATH`
+/// and `TRANSLATABLE_OVERLAY`.
+///
+/// **Returns**
+/// The names of the environment variables read by [`load_config`].
+pub fn config_env_vars() -> impl Iterator<Item = String> {
+    CONFIG_KEYS
+        .iter()
+        .map(|key| match *key {
+            "path" => "TRANSLATABLE_LOCALES_PATH".into(),
+            "overlay_path" => "TRANSLATABLE_OVERLAY".into(),
+            key => format!("TRANSLATABLE_{}", key.to_uppercase()),
+        })
+}
+
+/// Defines the search strategy for configuration files.
+///
+/// Represents the possible values of the parsed `seek_mode`
+/// field, which determine the order in which file paths
+/// are considered when opening configuration files.
+#[derive(Default, Clone, Copy, Debug, EnumString)]
+pub enum SeekMode {
+    /// Alphabetical order (default)
+    #[default]
+    Alphabetical,
+
+    /// Reverse alphabetical order
+    Unalphabetical,
+}
+
+/// Strategy for resolving translation conflicts.
+///
+/// This enum defines how overlapping translations
+/// are handled when multiple sources provide values
+/// for the same key. The selected strategy determines
+/// whether newer translations replace existing ones or
+/// if the first encountered translation is preserved.
+#[derive(Default, Clone, Copy, Debug, EnumString)]
+#[strum(serialize_all = "lowercase")]
+pub enum TranslationOverlap {
+    /// Last found translation overwrites previous ones
+    Overwrite,
+
+    /// First found translation is preserved (default)
+    #[default]
+    Ignore,
+}
+
+/// Translation overlap rule.
+///
+/// Overrides the overlap strategy for the translation
+/// files whose path matches a glob pattern, declared as
+/// `{ pattern = "vendor/**", strategy = "ignore" }`.
+#[derive(Debug)]
+pub struct OverlapRule {
+    /// The glob pattern matched against the file
+    /// path relative to the translations path.
+    pattern: Pattern,
+
+    /// The strategy for the matching files.
+    strategy: TranslationOverlap,
+}
+
+/// Overlap strategies configuration.
+///
+/// Holds the global overlap strategy along the rules
+/// that override it for specific files, so for example
+/// vendor translations never override the application
+/// ones while these still overwrite each other.
+#[derive(Debug)]
+pub struct OverlapConfig {
+    /// The strategy for the files no rule matches.
+    default: TranslationOverlap,
+
+    /// The rules in declaration order, the first
+    /// matching rule decides the strategy.
+    rules: Vec<OverlapRule>,
+}
+
+impl OverlapConfig {
+    /// Get the overlap strategy for a translation file.
+    ///
+    /// **Arguments**
+    /// * `path` — The translation file path, relative to the translations path.
+    ///
+    /// **Returns**
+    /// The strategy of the first rule whose pattern
+    /// matches the path, or the global strategy.
+    pub fn strategy(&self, path: &Path) -> TranslationOverlap {
+        self.rules
+            .iter()
+            .find(|rule| {
+                rule.pattern
+                    .matches_path(path)
+            })
+            .map(|rule| rule.strategy)
+            .unwrap_or(self.default)
+    }
+}
+
+/// Parses the `overlap` configuration entry.
+///
+/// The entry is either a strategy, setting the global
+/// strategy, or an array of `pattern` and `strategy` tables
+/// declaring the rules.
+///
+/// **Arguments**
+/// * `item` — The `overlap` entry in `translatable.toml`.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok((Option<TranslationOverlap>, Vec<OverlapRule>))` — The global strategy
+///   if declared, along the rules.
+/// * `Err(ConfigError)` — If the entry, any rule or its pattern is invalid.
+fn parse_overlap(
+    item: &Item,
+) -> Result<(Option<TranslationOverlap>, Vec<OverlapRule>), ConfigError> {
+    let invalid = |value: String| ConfigError::InvalidValue("overlap".into(), value);
+
+    if let Some(value) = item.as_str() {
+        return Ok((
+            Some(
+                value
+                    .parse()
+                    .map_err(|_| invalid(value.into()))?,
+            ),
+            Vec::new(),
+        ));
+    }
+
+    let rules = item
+        .clone()
+        .into_array_of_tables()
+        .map_err(|item| invalid(item.to_string()))?
+        .iter()
+        .map(|table| {
+            if table
+                .iter()
+                .any(|(key, _)| key != "pattern" && key != "strategy")
+            {
+                return Err(invalid(table.to_string()));
+            }
+
+            let pattern = table
+                .get("pattern")
+                .and_then(Item::as_str)
+                .ok_or_else(|| invalid(table.to_string()))?;
+
+            let strategy = table
+                .get("strategy")
+                .and_then(Item::as_str)
+                .ok_or_else(|| invalid(table.to_string()))?;
+
+            Ok(OverlapRule {
+                pattern: Pattern::new(pattern).map_err(|_| invalid(pattern.into()))?,
+                strategy: strategy
+                    .parse()
+                    .map_err(|_| invalid(strategy.into()))?,
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok((None, rules))
+}
+
+/// Parse the fallback rules.
+///
+/// The rules are declared as a `fallback_rules` table mapping
+/// a language tag pattern to its ordered fallback languages, or
+/// as `pattern=language,language;pattern=language` through the
+/// environment variable. The declaration order is kept, as the
+/// first matching rule is the one applied.
+///
+/// **Arguments**
+/// * `item` — The `fallback_rules` entry in `translatable.toml`, if any.
+/// * `env` — The `TRANSLATABLE_FALLBACK_RULES` value, if any.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(Vec<(String, Vec<Language>)>)` — The patterns along their fallback
+///   languages.
+/// * `Err(ConfigError)` — If the entry, any pattern or any language is invalid.
+fn parse_fallback_rules(
+    item: Option<&Item>,
+    env: Option<String>,
+) -> Result<Vec<(String, Vec<Language>)>, ConfigError> {
+    let invalid = |value: String| ConfigError::InvalidValue("fallback_rules".into(), value);
+
+    let rules = match (env, item) {
+        (Some(env), _) => env
+            .split(';')
+            .filter(|rule| {
+                !rule
+                    .trim()
+                    .is_empty()
+            })
+            .map(|rule| {
+                let (pattern, languages) = rule
+                    .split_once('=')
+                    .ok_or_else(|| invalid(rule.into()))?;
+
+                Ok::<_, ConfigError>((
+                    pattern
+                        .trim()
+                        .to_string(),
+                    languages
+                        .split(',')
+                        .map(|language| {
+                            language
+                                .trim()
+                                .to_string()
+                        })
+                        .collect::<Vec<_>>(),
+                ))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+
+        (None, Some(item)) => item
+            .as_table_like()
+            .ok_or_else(|| invalid(item.to_string()))?
+            .iter()
+            .map(|(pattern, languages)| {
+                let languages = languages
+                    .as_array()
+                    .ok_or_else(|| invalid(languages.to_string()))?
+                    .iter()
+                    .map(|language| {
+                        language
+                            .as_str()
+                            .map(str::to_string)
+                            .ok_or_else(|| invalid(language.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok::<_, ConfigError>((pattern.to_string(), languages))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+
+        (None, None) => Vec::new(),
+    };
+
+    rules
+        .into_iter()
+        .map(|(pattern, languages)| {
+            if pattern.is_empty() || languages.is_empty() {
+                return Err(invalid(pattern));
+            }
+
+            let languages = languages
+                .into_iter()
+                .map(|language| Language::from_tag(&language).map_err(|_| invalid(language)))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok((pattern, languages))
+        })
+        .collect()
+}
+
+/// Strategy for templates without a value.
+///
+/// This enum defines what the generated code does
+/// when a template has neither a replacement nor a
+/// default value.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum MissingReplacements {
+    /// The template is left as is (default)
+    #[default]
+    Preserve,
+
+    /// The invocation fails
+    Error,
+}
+
+/// Strategy for missing translations.
+///
+/// This enum defines what the generated code evaluates
+/// to when a path or a language is not available in
+/// runtime, or a language is not available for a fully
+/// static invocation.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum MissingTranslations {
+    /// The invocation fails (default)
+    #[default]
+    Error,
+
+    /// A `⟪path@language⟫` marker is emitted
+    Marker,
+
+    /// The `::` joined path is emitted
+    Key,
+}
+
+/// Main configuration structure for the translation system.
+///
+/// Holds all the core parameters used to control how translation files are
+/// located, processed, and how conflicts are resolved between overlapping
+/// translations.
+#[derive(Debug)]
+pub struct MacroConfig {
+    /// Path to the directory containing translation files.
+    ///
+    /// Specifies the base location where the system will search for
+    /// translation files. If the path contains glob metacharacters
+    /// it's expanded as a glob pattern and only the matching files
+    /// are loaded.
+    ///
+    /// # Example
+    /// ```toml
+    /// path = "./locales"
+    /// # or
+    /// path = "./i18n/**/*.locale.toml"
+    /// ```
+    path: String,
+
+    /// Overlay translations path.
+    ///
+    /// A directory or glob pattern whose translations are
+    /// merged after the ones in `path` with `overwrite`
+    /// semantics regardless of `overlap`, usually selected
+    /// per build with the `TRANSLATABLE_OVERLAY` environment
+    /// variable. Default: no overlay.
+    ///
+    /// # Example
+    /// ```toml
+    /// overlay_path = "./overlays/acme"
+    /// ```
+    overlay_path: Option<String>,
+
+    /// Translations cache path.
+    ///
+    /// With the `serde` feature, the parsed translations are
+    /// written to this file and read from it instead of the
+    /// translation files while it matches the configuration
+    /// and the source files. Default: no cache.
+    ///
+    /// # Example
+    /// ```toml
+    /// translations_cache = "./translatable.bin"
+    /// ```
+    translations_cache: Option<String>,
+
+    /// File processing order strategy.
+    ///
+    /// Defines the order in which translation files are processed.
+    /// Default: alphabetical order.
+    seek_mode: SeekMode,
+
+    /// Translation conflict resolution strategy.
+    ///
+    /// Determines the behavior when multiple files contain the same
+    /// translation key, either globally or per file pattern.
+    ///
+    /// # Example
+    /// ```toml
+    /// overlap = "overwrite"
+    /// # or
+    /// overlap = [
+    ///     { pattern = "vendor/**", strategy = "ignore" },
+    ///     { pattern = "**", strategy = "overwrite" },
+    /// ]
+    /// ```
+    overlap: OverlapConfig,
+
+    /// Languages embedded for runtime resolution.
+    ///
+    /// Restricts the translations embedded in the binary
+    /// for dynamic invocations to these languages, requesting
+    /// any other language in runtime results in a
+    /// `LanguageNotAvailable` error. Static invocations are
+    /// not affected. Default: every language is embedded.
+    ///
+    /// # Example
+    /// ```toml
+    /// embed_languages = ["en", "es"]
+    /// ```
+    embed_languages: Option<Vec<Language>>,
+
+    /// Language used when none is passed.
+    ///
+    /// `translation!()` invocations may omit the language
+    /// argument if this is configured, resolving the translation
+    /// in this language as if it was passed as a literal.
+    /// Default: none, the language must always be passed.
+    ///
+    /// # Example
+    /// ```toml
+    /// default_language = "en"
+    /// ```
+    default_language: Option<Language>,
+
+    /// File name namespacing.
+    ///
+    /// Prefixes every path in a translation file with
+    /// the file name up to its first dot, so the `save`
+    /// key in `buttons.toml` becomes `buttons::save`.
+    /// Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// namespace_by_file = true
+    /// ```
+    namespace_by_file: bool,
+
+    /// Missing replacements strategy.
+    ///
+    /// Determines whether templates without a value are
+    /// left as is or reported as an error, in compile-time
+    /// for fully static invocations and in runtime otherwise.
+    /// Default: preserve.
+    ///
+    /// # Example
+    /// ```toml
+    /// missing_replacements = "error"
+    /// ```
+    missing_replacements: MissingReplacements,
+
+    /// Missing translations strategy.
+    ///
+    /// Determines whether a translation that is not available
+    /// is reported as an error or replaced with a visible marker
+    /// or its path, so untranslated text is obvious while the
+    /// application still runs. Paths that don't exist in fully
+    /// static invocations are always reported in compile-time.
+    /// Default: error.
+    ///
+    /// # Example
+    /// ```toml
+    /// missing = "marker"
+    /// ```
+    missing: MissingTranslations,
+
+    /// Language fallback rules.
+    ///
+    /// Language tag patterns, where `*` matches any sequence,
+    /// along the languages tried in order when a matching
+    /// language is not available, in place of its parents.
+    /// The first matching rule applies. Default: none.
+    ///
+    /// # Example
+    /// ```toml
+    /// [fallback_rules]
+    /// "pt-*" = ["pt", "en"]
+    /// "zh-*" = ["zh-Hans", "en"]
+    /// ```
+    fallback_rules: Vec<(String, Vec<Language>)>,
+
+    /// Placeholder delimiters.
+    ///
+    /// The strings that open and close a template in the
+    /// translations, both must be non-empty and different.
+    /// Default: `{` and `}`.
+    ///
+    /// # Example
+    /// ```toml
+    /// placeholder_open = "%{"
+    /// placeholder_close = "}"
+    /// ```
+    placeholder_delimiters: PlaceholderDelimiters,
+
+    /// Dynamic path separator.
+    ///
+    /// The string dynamic path segments are split by, so
+    /// paths read from configuration files such as
+    /// `greetings.formal` can be passed as a single segment.
+    /// The split segments are trimmed. Must be non-empty.
+    /// Default: `::`.
+    ///
+    /// # Example
+    /// ```toml
+    /// path_separator = "."
+    /// ```
+    path_separator: String,
+
+    /// Merge tracing.
+    ///
+    /// Prints a note for every translation defined in more
+    /// than one file while loading the translations, telling
+    /// which file each language was taken from and which
+    /// files it overrode. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// trace_merge = true
+    /// ```
+    trace_merge: bool,
+
+    /// Coverage report.
+    ///
+    /// Whether a note with the amount of translations
+    /// available in each language is printed while
+    /// compiling. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// coverage = true
+    /// ```
+    coverage: bool,
+
+    /// Configuration report.
+    ///
+    /// Whether a note with the resolved configuration, after
+    /// merging the environment, `translatable.toml` and the
+    /// defaults, is printed once while compiling, along the
+    /// environment variables that overrode it. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// print_config = true
+    /// ```
+    print_config: bool,
+
+    /// Empty translations.
+    ///
+    /// Whether translations that are empty or only contain
+    /// whitespace are accepted, otherwise these are reported
+    /// while loading the translations. Default: allowed.
+    ///
+    /// # Example
+    /// ```toml
+    /// allow_empty = false
+    /// ```
+    allow_empty: bool,
+
+    /// Missing translations path.
+    ///
+    /// Whether a translations directory that doesn't exist,
+    /// or a glob pattern that doesn't match any file, is
+    /// loaded as if it had no translations instead of being
+    /// reported. Default: reported.
+    ///
+    /// # Example
+    /// ```toml
+    /// allow_missing_locales = true
+    /// ```
+    allow_missing_locales: bool,
+
+    /// Case-insensitive paths.
+    ///
+    /// Whether translation path segments are matched regardless
+    /// of their case, the translation file keys are lowercased
+    /// while loading the translations. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// case_insensitive_paths = true
+    /// ```
+    case_insensitive_paths: bool,
+
+    /// Directional isolation.
+    ///
+    /// Whether the values replaced in translations of
+    /// right-to-left languages are wrapped in Unicode
+    /// directional isolates. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// bidi_isolate = true
+    /// ```
+    bidi_isolate: bool,
+
+    /// HTML escaping.
+    ///
+    /// Whether the values replaced in the translations are
+    /// HTML-escaped, so user provided values can be safely
+    /// interpolated in HTML. Templates such as `{raw!content}`
+    /// are never escaped. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// escape_html = true
+    /// ```
+    escape_html: bool,
+
+    /// Pseudolocalization.
+    ///
+    /// Whether every translation is pseudolocalized while
+    /// loading, accenting, padding and bracketing its text
+    /// to catch truncated layouts and hardcoded strings.
+    /// The translation files are not modified. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// pseudolocale = true
+    /// ```
+    pseudolocale: bool,
+
+    /// Borrowed output.
+    ///
+    /// Whether the `translation!()` macro family outputs
+    /// a `Cow<'static, str>` instead of a `String`, borrowing
+    /// the embedded translation when nothing is replaced to
+    /// avoid allocating. Default: disabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// cow_output = true
+    /// ```
+    cow_output: bool,
+
+    /// Literal output.
+    ///
+    /// Whether fully static `translation!()` invocations of
+    /// translations without templates nor replacements expand
+    /// to a `&'static str` literal instead of a `String`, so
+    /// these can initialize `const` items, while translations
+    /// with templates fall back to a `String`.
+    /// Default: enabled.
+    ///
+    /// # Example
+    /// ```toml
+    /// literal_output = false
+    /// ```
+    literal_output: bool,
+}
+
+impl MacroConfig {
+    /// Get reference to the configured locales path.
+    ///
+    /// **Returns**
+    /// The path to the directory where translation files are expected
+    /// to be located, or a glob pattern matching them.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Get reference to the configured overlay path.
+    ///
+    /// **Returns**
+    /// The path to the directory or the glob pattern whose
+    /// translations overwrite the ones in [`path`], if any.
+    ///
+    /// [`path`]: MacroConfig::path
+    pub fn overlay_path(&self) -> Option<&str> {
+        self.overlay_path
+            .as_deref()
+    }
+
+    /// Get reference to the configured translations cache path.
+    ///
+    /// **Returns**
+    /// The file the parsed translations are cached in, if any.
+    pub fn translations_cache(&self) -> Option<&str> {
+        self.translations_cache
+            .as_deref()
+    }
+
+    /// Get the current seek mode strategy.
+    ///
+    /// **Returns**
+    /// The configured strategy used to determine the order in which
+    /// translation files are processed.
+    pub fn seek_mode(&self) -> SeekMode {
+        self.seek_mode
+    }
+
+    /// Get the current overlap resolution strategies.
+    ///
+    /// **Returns**
+    /// The configured strategies for resolving translation conflicts
+    /// when multiple files define the same key.
+    pub fn overlap(&self) -> &OverlapConfig {
+        &self.overlap
+    }
+
+    /// Get the languages embedded for runtime resolution.
+    ///
+    /// **Returns**
+    /// The configured languages to embed for dynamic invocations,
+    /// or `None` if every language should be embedded.
+    pub fn embed_languages(&self) -> Option<&[Language]> {
+        self.embed_languages
+            .as_deref()
+    }
+
+    /// Get the language used when none is passed.
+    ///
+    /// **Returns**
+    /// The configured default language, or `None` if
+    /// the language must always be passed.
+    pub fn default_language(&self) -> Option<&Language> {
+        self.default_language
+            .as_ref()
+    }
+
+    /// Get whether paths are namespaced by file name.
+    ///
+    /// **Returns**
+    /// Whether every path in a translation file is prefixed
+    /// with the file name.
+    pub fn namespace_by_file(&self) -> bool {
+        self.namespace_by_file
+    }
+
+    /// Get the missing replacements strategy.
+    ///
+    /// **Returns**
+    /// The configured strategy for templates without a value.
+    pub fn missing_replacements(&self) -> MissingReplacements {
+        self.missing_replacements
+    }
+
+    /// Get the missing translations strategy.
+    ///
+    /// **Returns**
+    /// The configured strategy for translations that
+    /// are not available.
+    pub fn missing(&self) -> MissingTranslations {
+        self.missing
+    }
+
+    /// Get the language fallback rules.
+    ///
+    /// **Returns**
+    /// The configured language tag patterns along their
+    /// fallback languages, in declaration order.
+    pub fn fallback_rules(&self) -> Vec<(&str, &[Language])> {
+        self.fallback_rules
+            .iter()
+            .map(|(pattern, languages)| (pattern.as_str(), languages.as_slice()))
+            .collect()
+    }
+
+    /// Get the placeholder delimiters.
+    ///
+    /// **Returns**
+    /// The configured strings that open and close a template.
+    pub fn placeholder_delimiters(&self) -> &PlaceholderDelimiters {
+        &self.placeholder_delimiters
+    }
+
+    /// Get the dynamic path separator.
+    ///
+    /// **Returns**
+    /// The configured string dynamic path segments are split by.
+    pub fn path_separator(&self) -> &str {
+        &self.path_separator
+    }
+
+    /// Get whether merge conflicts are traced.
+    ///
+    /// **Returns**
+    /// Whether a note is printed for every translation
+    /// defined in more than one file.
+    pub fn trace_merge(&self) -> bool {
+        self.trace_merge
+    }
+
+    /// Get whether the translation coverage is printed.
+    ///
+    /// **Returns**
+    /// Whether a note with the translations available
+    /// in each language is printed while compiling.
+    pub fn coverage(&self) -> bool {
+        self.coverage
+    }
+
+    /// Get whether empty translations are allowed.
+    ///
+    /// **Returns**
+    /// Whether translations that are empty or only
+    /// contain whitespace are accepted.
+    pub fn allow_empty(&self) -> bool {
+        self.allow_empty
+    }
+
+    /// Get whether a missing translations path is allowed.
+    ///
+    /// **Returns**
+    /// Whether a missing translations directory or a glob
+    /// pattern without matches loads no translations.
+    pub fn allow_missing_locales(&self) -> bool {
+        self.allow_missing_locales
+    }
+
+    /// Get whether paths are matched case-insensitively.
+    ///
+    /// **Returns**
+    /// Whether translation path segments are matched
+    /// regardless of their case.
+    pub fn case_insensitive_paths(&self) -> bool {
+        self.case_insensitive_paths
+    }
+
+    /// Get whether replaced values are isolated.
+    ///
+    /// **Returns**
+    /// Whether the values replaced in right-to-left
+    /// translations are wrapped in directional isolates.
+    pub fn bidi_isolate(&self) -> bool {
+        self.bidi_isolate
+    }
+
+    /// Get whether replaced values are HTML-escaped.
+    ///
+    /// **Returns**
+    /// Whether the values replaced in the translations
+    /// are HTML-escaped.
+    pub fn escape_html(&self) -> bool {
+        self.escape_html
+    }
+
+    /// Get whether translations are pseudolocalized.
+    ///
+    /// **Returns**
+    /// Whether every translation is pseudolocalized
+    /// while loading.
+    pub fn pseudolocale(&self) -> bool {
+        self.pseudolocale
+    }
+
+    /// Get whether translations are output as `Cow<'static, str>`.
+    ///
+    /// **Returns**
+    /// Whether the `translation!()` macro family outputs
+    /// a `Cow<'static, str>` instead of a `String`.
+    pub fn cow_output(&self) -> bool {
+        self.cow_output
+    }
+
+    /// Get whether placeholder-free translations are output as literals.
+    ///
+    /// **Returns**
+    /// Whether fully static `translation!()` invocations without
+    /// templates nor replacements output a `&'static str` literal.
+    pub fn literal_output(&self) -> bool {
+        self.literal_output
+    }
+
+    /// Parse a configuration from `translatable.toml` content.
+    ///
+    /// Environment variables still override the content,
+    /// the same way as when the configuration is loaded.
+    ///
+    /// Only available with the `test-util` feature.
+    ///
+    /// **Arguments**
+    /// * `content` — The `translatable.toml` content.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(MacroConfig)` — The parsed configuration.
+    /// * `Err(ConfigError)` — The content is not valid TOML or contains an
+    ///   invalid value.
+    #[cfg(any(test, feature = "test-util"))]
+    #[allow(unused)]
+    pub fn from_toml(content: &str) -> Result<Self, ConfigError> {
+        parse_config(&content.parse::<DocumentMut>()?, |key| var(key))
+    }
+
+    /// Parse a configuration from its sources.
+    ///
+    /// Unlike [`load_config`], the process environment is not
+    /// read, the provided variables override the content instead,
+    /// so a configuration captured in compile-time by [`config_sources`]
+    /// can be rebuilt in runtime.
+    ///
+    /// **Arguments**
+    /// * `content` — The `translatable.toml` content.
+    /// * `env_vars` — The `TRANSLATABLE_` prefixed environment variables along
+    ///   their values.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(MacroConfig)` — The parsed configuration.
+    /// * `Err(ConfigError)` — The content is not valid TOML or contains an
+    ///   invalid value.
+    pub fn from_sources(content: &str, env_vars: &[(&str, &str)]) -> Result<Self, ConfigError> {
+        parse_config(&content.parse::<DocumentMut>()?, |key| {
+            env_vars
+                .iter()
+                .find(|(name, _)| *name == key)
+                .map(|(_, value)| value.to_string())
+                .ok_or(VarError::NotPresent)
+        })
+    }
+}
+
+/// Global configuration cache.
+///
+/// Stores the initialized [`MacroConfig`] instance, which holds the
+/// configuration for the translation system. The [`OnceLock`] ensures the
+/// configuration is initialized only once and can be safely accessed across
+/// multiple threads after that initialization.
+static TRANSLATABLE_CONFIG: OnceLock<MacroConfig> = OnceLock::new();
+
+/// Load the global translation configuration.
+///
+/// Initializes and returns a reference to the shared [`MacroConfig`] instance.
+/// Configuration values are loaded in the following priority order:
+/// environment variables override `translatable.toml`, and missing values fall
+/// back to hardcoded defaults.
+///
+/// Unknown top-level keys in `translatable.toml` are reported as errors unless
+/// `allow_unknown_keys` is set, so typos don't silently fall back to defaults.
+///
+/// The configuration is cached after the first successful load, and reused on
+/// subsequent calls.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * [`Ok(&MacroConfig)`] — The loaded configuration as a reference to the
+///   cached macro configuration.
+/// * [`Err(ConfigError)`] — An error because environment couldn't be read,
+///   `translatable.toml` couldn't be read or it contains an unknown key.
+///
+/// [`Ok(&MacroConfig)`]: MacroConfig
+/// [`Err(ConfigError)`]: ConfigError
+pub fn load_config() -> Result<&'static MacroConfig, ConfigError> {
+    if let Some(config) = TRANSLATABLE_CONFIG.get() {
+        return Ok(config);
+    }
+
+    let toml_content = read_to_string("./translatable.toml")
+        .unwrap_or_default()
+        .parse::<DocumentMut>()?;
+
+    let config = parse_config(&toml_content, |key| var(key))?;
+
+    Ok(TRANSLATABLE_CONFIG.get_or_init(|| trace_config(config)))
+}
+
+/// Get the sources the configuration is loaded from.
+///
+/// **Returns**
+/// The `translatable.toml` content, empty if it couldn't
+/// be read, along the set environment variables listed by
+/// [`config_env_vars`] and their values, see
+/// [`MacroConfig::from_sources`].
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+pub fn config_sources() -> (String, Vec<(String, String)>) {
+    let content = read_to_string("./translatable.toml").unwrap_or_default();
+    let env_vars = config_env_vars()
+        .filter_map(|key| {
+            var(&key)
+                .ok()
+                .map(|value| (key, value))
+        })
+        .collect();
+
+    (content, env_vars)
+}
+
+/// Describes the resolved configuration.
+///
+/// **Arguments**
+/// * `config` — The resolved configuration.
+/// * `overrides` — The `TRANSLATABLE_` prefixed environment variables set while
+///   it was loaded.
+///
+/// **Returns**
+/// The notes with the configuration values and the
+/// environment variables that override `translatable.toml`.
+pub fn config_notes(config: &MacroConfig, overrides: &[String]) -> Vec<String> {
+    let overrides = if overrides.is_empty() {
+        "config not overridden by the environment".into()
+    } else {
+        format!("config overridden by the environment: {}", overrides.join(", "))
+    };
+
+    vec![format!("resolved config: {config:#?}"), overrides]
+}
+
+/// Prints the configuration notes if `print_config` is enabled.
+///
+/// Called only when the configuration is cached, thus
+/// once per compilation, see [`config_notes`].
+///
+/// **Arguments**
+/// * `config` — The resolved configuration.
+///
+/// **Returns**
+/// The same configuration, for chaining.
+fn trace_config(config: MacroConfig) -> MacroConfig {
+    if config.print_config {
+        let mut overrides = vars()
+            .map(|(key, _)| key)
+            .filter(|key| key.starts_with("TRANSLATABLE_"))
+            .collect::<Vec<_>>();
+        overrides.sort();
+
+        for note in config_notes(&config, &overrides) {
+            eprintln!("note: {note}");
+        }
+    }
+
+    config
+}
+
+/// Set the global translation configuration.
+///
+/// Stores the provided configuration in the global cache, so
+/// tests can point at a fixture locales directory without an
+/// environment variable or a `translatable.toml` file. It must
+/// be called before anything loads the configuration.
+///
+/// Only available with the `test-util` feature, meant for
+/// code loading fixture translations, such as unit tests
+/// or benches.
+///
+/// **Arguments**
+/// * `config` — The configuration to use, see [`MacroConfig::from_toml`].
+///
+/// **Returns**
+/// Whether the configuration was set, `false` if
+/// the configuration was already loaded.
+#[cfg(any(test, feature = "test-util"))]
+#[allow(unused)]
+pub fn set_config_for_tests(config: MacroConfig) -> bool {
+    TRANSLATABLE_CONFIG
+        .set(config)
+        .is_ok()
+}
+
+/// Parse the translation configuration.
+///
+/// Environment variables override the provided `translatable.toml`
+/// content, and missing values fall back to hardcoded defaults.
+///
+/// **Arguments**
+/// * `toml_content` — The parsed `translatable.toml` content.
+/// * `env_var` — The environment variable lookup.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(MacroConfig)` — The parsed configuration.
+/// * `Err(ConfigError)` — An error because a value is invalid or the content
+///   contains an unknown key.
+fn parse_config(
+    toml_content: &DocumentMut,
+    env_var: impl Fn(&str) -> Result<String, VarError>,
+) -> Result<MacroConfig, ConfigError> {
+    macro_rules! config_value {
+        ($env_var:expr, $key:expr, $default:expr) => {
+            env_var($env_var)
+                .ok()
+                .or_else(|| {
+                    toml_content
+                        .get($key)
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.to_string())
+                })
+                .unwrap_or_else(|| $default.into())
+        };
+
+        (optional($env_var:expr, $key:expr)) => {
+            env_var($env_var)
+                .ok()
+                .or_else(|| {
+                    toml_content
+                        .get($key)
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.to_string())
+                })
+                .filter(|v| !v.is_empty())
+        };
+
+        (parse($env_var:expr, $key:expr, $default:expr)) => {{
+            let value = env_var($env_var)
+                .ok()
+                .or_else(|| {
+                    toml_content
+                        .get($key)
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.to_string())
+                });
+
+            if let Some(value) = value {
+                value
+                    .parse()
+                    .map_err(|_| ConfigError::InvalidValue($key.into(), value.into()))
+            } else {
+                Ok($default)
+            }
+        }};
+
+        (parse_bool($env_var:expr, $key:expr, $default:expr)) => {{
+            match env_var($env_var).as_deref() {
+                Ok("1") => Ok(true),
+                Ok("0") => Ok(false),
+                Ok(value) => value
+                    .parse::<bool>()
+                    .map_err(|_| ConfigError::InvalidValue($key.into(), value.into())),
+                Err(_) => match toml_content.get($key) {
+                    Some(value) => value
+                        .as_bool()
+                        .ok_or_else(|| ConfigError::InvalidValue($key.into(), value.to_string())),
+                    None => Ok($default),
+                },
+            }
+        }};
+
+        (parse_language($env_var:expr, $key:expr)) => {{
+            let value = env_var($env_var)
+                .ok()
+                .or_else(|| {
+                    toml_content
+                        .get($key)
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.to_string())
+                });
+
+            value
+                .map(|value| {
+                    Language::from_tag(&value)
+                        .map_err(|_| ConfigError::InvalidValue($key.into(), value))
+                })
+                .transpose()
+        }};
+
+        (parse_list($env_var:expr, $key:expr)) => {{
+            let values = match env_var($env_var) {
+                Ok(value) => Some(
+                    value
+                        .split(',')
+                        .map(|v| {
+                            v.trim()
+                                .to_string()
+                        })
+                        .collect::<Vec<_>>(),
+                ),
+                Err(_) => match toml_content.get($key) {
+                    Some(value) => Some(
+                        value
+                            .as_array()
+                            .ok_or_else(|| {
+                                ConfigError::InvalidValue($key.into(), value.to_string())
+                            })?
+                            .iter()
+                            .map(|v| {
+                                v.as_str()
+                                    .map(|v| v.to_string())
+                                    .ok_or_else(|| {
+                                        ConfigError::InvalidValue($key.into(), v.to_string())
+                                    })
+                            })
+                            .collect::<Result<Vec<_>, _>>()?,
+                    ),
+                    None => None,
+                },
+            };
+
+            values
+                .map(|values| {
+                    values
+                        .into_iter()
+                        .map(|value| {
+                            Language::from_tag(&value)
+                                .map_err(|_| ConfigError::InvalidValue($key.into(), value))
+                        })
+                        .collect::<Result<Vec<_>, _>>()
+                })
+                .transpose()
+        }};
+    }
+
+    let allow_unknown_keys =
+        config_value!(parse_bool("TRANSLATABLE_ALLOW_UNKNOWN_KEYS", "allow_unknown_keys", false))?;
+
+    if !allow_unknown_keys
+        && let Some((key, _)) = toml_content
+            .iter()
+            .find(|(key, _)| !CONFIG_KEYS.contains(key))
+    {
+        return Err(ConfigError::UnknownKey(
+            key.to_string(),
+            closest_match(key, CONFIG_KEYS).map(String::from),
+        ));
+    }
+
+    let placeholder_open: String =
+        config_value!("TRANSLATABLE_PLACEHOLDER_OPEN", "placeholder_open", "{");
+    let placeholder_close: String =
+        config_value!("TRANSLATABLE_PLACEHOLDER_CLOSE", "placeholder_close", "}");
+
+    if placeholder_open.is_empty() {
+        return Err(ConfigError::InvalidValue("placeholder_open".into(), placeholder_open));
+    }
+
+    let placeholder_delimiters = PlaceholderDelimiters::new(&placeholder_open, &placeholder_close)
+        .ok_or_else(|| ConfigError::InvalidValue("placeholder_close".into(), placeholder_close))?;
+
+    let path_separator: String =
+        config_value!("TRANSLATABLE_PATH_SEPARATOR", "path_separator", "::");
+
+    if path_separator.is_empty() {
+        return Err(ConfigError::InvalidValue("path_separator".into(), path_separator));
+    }
+
+    let (overlap_default, overlap_rules) = match toml_content.get("overlap") {
+        Some(item) => parse_overlap(item)?,
+        None => (None, Vec::new()),
+    };
+
+    let overlap_default = match env_var("TRANSLATABLE_OVERLAP") {
+        Ok(value) => value
+            .parse()
+            .map_err(|_| ConfigError::InvalidValue("overlap".into(), value))?,
+        Err(_) => overlap_default.unwrap_or_default(),
+    };
+
+    let config = MacroConfig {
+        path: config_value!("TRANSLATABLE_LOCALES_PATH", "path", "./translations"),
+        overlay_path: config_value!(optional("TRANSLATABLE_OVERLAY", "overlay_path")),
+        translations_cache: config_value!(optional(
+            "TRANSLATABLE_TRANSLATIONS_CACHE",
+            "translations_cache"
+        )),
+        overlap: OverlapConfig {
+            default: overlap_default,
+            rules: overlap_rules,
+        },
+        seek_mode: config_value!(parse(
+            "TRANSLATABLE_SEEK_MODE",
+            "seek_mode",
+            SeekMode::Alphabetical
+        ))?,
+        embed_languages: config_value!(parse_list(
+            "TRANSLATABLE_EMBED_LANGUAGES",
+            "embed_languages"
+        ))?,
+        default_language: config_value!(parse_language(
+            "TRANSLATABLE_DEFAULT_LANGUAGE",
+            "default_language"
+        ))?,
+        namespace_by_file: config_value!(parse_bool(
+            "TRANSLATABLE_NAMESPACE_BY_FILE",
+            "namespace_by_file",
+            false
+        ))?,
+        missing_replacements: config_value!(parse(
+            "TRANSLATABLE_MISSING_REPLACEMENTS",
+            "missing_replacements",
+            MissingReplacements::Preserve
+        ))?,
+        missing: config_value!(parse(
+            "TRANSLATABLE_MISSING",
+            "missing",
+            MissingTranslations::Error
+        ))?,
+        fallback_rules: parse_fallback_rules(
+            toml_content.get("fallback_rules"),
+            env_var("TRANSLATABLE_FALLBACK_RULES").ok(),
+        )?,
+        placeholder_delimiters,
+        path_separator,
+        trace_merge: config_value!(parse_bool("TRANSLATABLE_TRACE_MERGE", "trace_merge", false))?,
+        coverage: config_value!(parse_bool("TRANSLATABLE_COVERAGE", "coverage", false))?,
+        print_config: config_value!(parse_bool(
+            "TRANSLATABLE_PRINT_CONFIG",
+            "print_config",
+            false
+        ))?,
+        allow_empty: config_value!(parse_bool("TRANSLATABLE_ALLOW_EMPTY", "allow_empty", true))?,
+        allow_missing_locales: config_value!(parse_bool(
+            "TRANSLATABLE_ALLOW_MISSING_LOCALES",
+            "allow_missing_locales",
+            false
+        ))?,
+        case_insensitive_paths: config_value!(parse_bool(
+            "TRANSLATABLE_CASE_INSENSITIVE_PATHS",
+            "case_insensitive_paths",
+            false
+        ))?,
+        bidi_isolate: config_value!(parse_bool(
+            "TRANSLATABLE_BIDI_ISOLATE",
+            "bidi_isolate",
+            false
+        ))?,
+        escape_html: config_value!(parse_bool("TRANSLATABLE_ESCAPE_HTML", "escape_html", false))?,
+        pseudolocale: config_value!(parse_bool(
+            "TRANSLATABLE_PSEUDOLOCALE",
+            "pseudolocale",
+            false
+        ))?,
+        cow_output: config_value!(parse_bool("TRANSLATABLE_COW_OUTPUT", "cow_output", false))?,
+        literal_output: config_value!(parse_bool(
+            "TRANSLATABLE_LITERAL_OUTPUT",
+            "literal_output",
+            true
+        ))?,
+    };
+
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use toml_edit::DocumentMut;
+
+    use super::{
+        ConfigError,
+        MacroConfig,
+        OverlapConfig,
+        TranslationOverlap,
+        config_env_vars,
+        config_notes,
+        parse_overlap,
+    };
+
+    fn overlap(content: &str) -> Result<OverlapConfig, ConfigError> {
+        let document = content
+            .parse::<DocumentMut>()
+            .expect("TOML to be parsed correctly.");
+
+        let (default, rules) = parse_overlap(&document["overlap"])?;
+
+        Ok(OverlapConfig {
+            default: default.unwrap_or_default(),
+            rules,
+        })
+    }
+
+    #[test]
+    fn lists_every_env_var() {
+        let env_vars = config_env_vars().collect::<Vec<_>>();
+
+        for literal in include_str!("config.rs")
+            .split('"')
+            .skip(1)
+            .step_by(2)
+            .filter(|literal| {
+                literal
+                    .strip_prefix("TRANSLATABLE_")
+                    .is_some_and(|name| {
+                        !name.is_empty()
+                            && name
+                                .chars()
+                                .all(|char| char.is_ascii_uppercase() || char == '_')
+                    })
+            })
+        {
+            assert!(env_vars.contains(&literal.to_string()), "{literal} isn't listed");
+        }
+    }
+
+    #[test]
+    fn matches_overlap_rules() {
+        let config = overlap(
+            r#"
+            overlap = [
+                { pattern = "vendor/**", strategy = "ignore" },
+                { pattern = "**", strategy = "overwrite" },
+            ]
+            "#,
+        )
+        .expect("Overlap rules to be valid.");
+
+        assert!(matches!(
+            config.strategy(Path::new("vendor/ui/buttons.toml")),
+            TranslationOverlap::Ignore
+        ));
+        assert!(matches!(
+            config.strategy(Path::new("app/buttons.toml")),
+            TranslationOverlap::Overwrite
+        ));
+
+        let config = overlap("[[overlap]]\npattern = \"*.toml\"\nstrategy = \"overwrite\"")
+            .expect("Overlap rules to be valid.");
+
+        assert!(matches!(config.strategy(Path::new("a.toml")), TranslationOverlap::Overwrite));
+        assert!(matches!(config.strategy(Path::new("a.json")), TranslationOverlap::Ignore));
+    }
+
+    #[test]
+    fn parses_scalar_overlap() {
+        let config = overlap("overlap = \"overwrite\"").expect("Overlap to be valid.");
+
+        assert!(matches!(config.strategy(Path::new("a.toml")), TranslationOverlap::Overwrite));
+    }
+
+    #[test]
+    fn rejects_invalid_overlap_rules() {
+        assert!(overlap("overlap = [{ pattern = \"vendor/**\" }]").is_err());
+        assert!(overlap("overlap = [{ pattern = \"[\", strategy = \"ignore\" }]").is_err());
+        assert!(overlap("overlap = [{ pattern = \"**\", strategy = \"Keep\" }]").is_err());
+        assert!(overlap("overlap = 1").is_err());
+    }
+
+    #[test]
+    fn describes_config() {
+        let config = MacroConfig::from_toml("seek_mode = \"Unalphabetical\"")
+            .expect("Configuration to be valid.");
+
+        let notes = config_notes(&config, &["TRANSLATABLE_LOCALES_PATH".into()]);
+
+        assert!(notes[0].starts_with("resolved config: MacroConfig {"));
+        assert!(notes[0].contains("seek_mode: Unalphabetical"));
+        assert_eq!(notes[1], "config overridden by the environment: TRANSLATABLE_LOCALES_PATH");
+
+        let notes = config_notes(&config, &[]);
+        assert_eq!(notes[1], "config not overridden by the environment");
+    }
+}
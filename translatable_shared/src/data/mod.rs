@@ -4,6 +4,11 @@
 //! to obtain the translation data and
 //! related configuration.
 //!
+//! The macros, the build-time generation and
+//! the command line tool load the translations
+//! trough this module, so they are loaded the
+//! same way everywhere.
+//!
 //! The only thing that should possibly
 //! be used outside is the [`translations`]
 //! module, as the config is mostly
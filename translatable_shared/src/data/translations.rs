@@ -0,0 +1,1752 @@
+//! Translation obtention module.
+//!
+//! This module is used to obtain
+//! translations from their respective files.
+//!
+//! This module uses `crate::data::config` to
+//! to load the translations and order them
+//! based on the configuration provided
+//! by the module.
+
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+use std::fs::canonicalize;
+use std::fs::{metadata, read_dir};
+#[cfg(feature = "serde")]
+use std::fs::{read, write};
+use std::io::Error as IoError;
+#[cfg(feature = "serde")]
+use std::io::ErrorKind;
+use std::ops::DerefMut;
+use std::path::{Component, Path, PathBuf};
+use std::str::Utf8Error;
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, Once, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+use glob::{Pattern, PatternError, glob};
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use thiserror::Error;
+
+use super::config::{ConfigError, MacroConfig, SeekMode, TranslationOverlap, load_config};
+use crate::misc::language::Language;
+use crate::translations::collection::TranslationNodeCollection;
+use crate::translations::file::{
+    TranslationFileError,
+    namespace_table,
+    parse_translation_file as parse_embedded_translation_file,
+    read_translation_file,
+};
+use crate::translations::node::{TranslationNode, TranslationNodeError, TranslationObjectExt};
+
+/// Translation retrieval error enum.
+///
+/// Represents errors that can occur during compile-time translation
+/// retrieval. This includes I/O issues, configuration loading failures,
+/// TOML deserialization errors, and translation node parsing errors.
+///
+/// The errors from this enum are directly surfaced in `rust-analyzer`
+/// to assist with early detection and debugging.
+#[derive(Error, Debug)]
+pub enum TranslationDataError {
+    /// I/O error derivation.
+    ///
+    /// Raised when an I/O operation fails during translation
+    /// retrieval, typically caused by filesystem-level issues.
+    ///
+    /// [`Display`] will forward the inner [`std::io::Error`]
+    /// representation prefixed with additional context.
+    ///
+    /// The enum implements [`From<std::io::Error>`] to allow
+    /// automatic conversion from `IoError`.
+    ///
+    /// **Parameters**
+    /// * `0` — The underlying I/O error.
+    ///
+    /// [`From<std::io::Error>`]: std::io::Error
+    /// [`Display`]: std::fmt::Display
+    #[error("IO Error: \"{0:#}\". Please check the specified path in your configuration file.")]
+    Io(#[from] IoError),
+
+    /// Configuration loading failure.
+    ///
+    /// Raised when the translation configuration cannot be loaded
+    /// successfully, typically due to invalid values or missing
+    /// configuration data.
+    ///
+    /// [`Display`] will forward the inner [`ConfigError`] message.
+    ///
+    /// The enum implements [`From<ConfigError>`] to allow automatic
+    /// conversion from the underlying error.
+    ///
+    /// **Parameters**
+    /// * `0` — The configuration error encountered.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[error("{0:#}")]
+    LoadConfig(#[from] ConfigError),
+
+    /// Invalid Unicode path.
+    ///
+    /// Raised when a filesystem path cannot be processed due to
+    /// invalid Unicode characters.
+    ///
+    /// This error signals that the translation system cannot proceed
+    /// with a non-Unicode-compatible path.
+    #[error("Couldn't open path, found invalid unicode characters")]
+    InvalidUnicode,
+
+    /// Invalid UTF-8 translation file.
+    ///
+    /// Raised when the contents of a translation file
+    /// embedded in the binary are not valid UTF-8.
+    ///
+    /// **Parameters**
+    /// * `0` — The UTF-8 decoding error.
+    #[error("The file is not valid UTF-8: {0:#}")]
+    InvalidUtf8(#[from] Utf8Error),
+
+    /// Invalid glob pattern.
+    ///
+    /// Raised when the configured translations path contains
+    /// glob metacharacters but can't be compiled as a glob
+    /// pattern.
+    ///
+    /// **Parameters**
+    /// * `0` — The underlying glob pattern error.
+    /// * `1` — The pattern that failed to compile.
+    #[error("Invalid glob pattern '{1}': {0:#}")]
+    InvalidGlob(PatternError, String),
+
+    /// Glob pattern without matches.
+    ///
+    /// Raised when the configured translations path is a glob
+    /// pattern that didn't match any file, this is reported instead
+    /// of silently loading an empty collection.
+    ///
+    /// **Parameters**
+    /// * `0` — The pattern that didn't match any file.
+    #[error("The glob pattern '{0}' did not match any translation file")]
+    GlobNoMatches(String),
+
+    /// Translation file reading failure.
+    ///
+    /// Raised when a translation file or any of the files
+    /// it includes can't be read or parsed, or when the
+    /// includes are circular.
+    ///
+    /// The enum implements [`From<TranslationFileError>`] for
+    /// seamless conversion.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation file error encountered.
+    #[error("{0:#}")]
+    File(#[from] TranslationFileError),
+
+    /// Translation node parsing failure.
+    ///
+    /// Raised when the translation system cannot correctly parse
+    /// a translation node, typically due to invalid formatting
+    /// or missing expected data.
+    ///
+    /// The enum implements [`From<TranslationNodeError>`] for
+    /// seamless conversion.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation node error encountered.
+    #[error("{0:#}")]
+    Node(#[from] TranslationNodeError),
+
+    /// Translation file parsing failure.
+    ///
+    /// Raised by [`parse_translation_files`] when any of the
+    /// files couldn't be parsed, wrapping the error along the
+    /// failing file, so it's known even if the error doesn't
+    /// mention it. Displays the error as is.
+    ///
+    /// **Parameters**
+    /// * `0` — The failing translation file path.
+    /// * `1` — The error encountered.
+    #[error("{1:#}")]
+    FileParse(String, #[source] Box<TranslationDataError>),
+
+    /// Placeholder mismatch between languages.
+    ///
+    /// Raised when a language of a translation doesn't use a
+    /// placeholder required in another language, as its value
+    /// would silently not be replaced in that language.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — The translation file the translation is defined in.
+    /// * `2` — The missing placeholders per language.
+    #[error(
+        "The translation '{0}' in {1} doesn't use the same placeholders in every language: {2}"
+    )]
+    PlaceholderMismatch(String, String, String),
+
+    /// Empty translation.
+    ///
+    /// Raised when `allow_empty` is disabled and a translation
+    /// is empty or only contains whitespace.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation path, displayed in `::` notation.
+    /// * `1` — The translation file the translation is defined in.
+    /// * `2` — The languages the translation is empty in.
+    #[error("The translation '{0}' in {1} is empty in {2}, which is not allowed by `allow_empty`")]
+    EmptyTranslation(String, String, String),
+
+    /// Path shadowed across files.
+    ///
+    /// Raised when a path is a translation or a list in a
+    /// file while another file nests translations beneath it,
+    /// as only one of them could ever be resolved.
+    ///
+    /// **Parameters**
+    /// * `0` — The path, displayed in `::` notation.
+    /// * `1` — The translation file defining the path as a translation.
+    /// * `2` — The translation file nesting translations beneath the path.
+    #[error("The path '{0}' is a translation in {1} but contains other translations in {2}")]
+    ShadowedPath(String, String, String),
+
+    /// Translations cache writing failure.
+    ///
+    /// Raised when the parsed translations can't be
+    /// serialized into the configured `translations_cache`.
+    ///
+    /// **Parameters**
+    /// * `0` — The serialization error message.
+    /// * `1` — The translations cache path.
+    #[cfg(feature = "serde")]
+    #[error("The translations cache {1} couldn't be written: {0}")]
+    Cache(String, String),
+}
+
+/// Translation file source.
+///
+/// Tells [`parse_translation_files`] where each
+/// translation file is read from.
+#[derive(Clone, Copy, Debug)]
+pub enum TranslationFile<'a> {
+    /// A file in the file system.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation file path.
+    /// * `1` — Whether the last found definition of a key wins when resolving
+    ///   its includes.
+    Path(&'a str, bool),
+
+    /// A file embedded in the binary.
+    ///
+    /// Its include directives are rejected, as there
+    /// is no file system to read the included files from.
+    ///
+    /// **Parameters**
+    /// * `0` — The translation file path.
+    /// * `1` — The translation file contents.
+    Embedded(&'a str, &'a str),
+}
+
+impl TranslationFile<'_> {
+    /// Get the translation file path.
+    ///
+    /// **Returns**
+    /// The path the file is identified by.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Path(path, _) | Self::Embedded(path, _) => path,
+        }
+    }
+}
+
+/// Translations cache file.
+///
+/// The contents of the configured `translations_cache`,
+/// holding the parsed translations along everything these
+/// depend on, so the cache is rejected as soon as any of
+/// it changes instead of serving stale translations.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct TranslationsCacheFile<T> {
+    /// The configuration and the enabled languages,
+    /// as obtained with [`cache_key`].
+    key: String,
+
+    /// The translation files in lookup order.
+    translation_paths: Vec<String>,
+
+    /// The translation files followed by the files these
+    /// include, along their modification time.
+    fingerprint: Vec<(String, Option<SystemTime>)>,
+
+    /// The parsed translations.
+    translations: T,
+}
+
+/// Cached translation collection.
+///
+/// Holds a parsed [`TranslationNodeCollection`] along
+/// the fingerprint of the files it was parsed from, so
+/// the cache can be invalidated when any of these files
+/// change.
+struct CachedTranslations {
+    /// Source files fingerprint.
+    ///
+    /// Each translation file path along its last
+    /// modification time, in the order they were
+    /// loaded, followed by the included files.
+    fingerprint: Vec<(String, Option<SystemTime>)>,
+
+    /// Included file paths.
+    ///
+    /// The files included by the translation files,
+    /// which are not known until these are parsed, or
+    /// the translations cache if they were loaded from it.
+    includes: Vec<String>,
+
+    /// The parsed translations.
+    ///
+    /// Shared with every caller that obtained
+    /// them while the fingerprint matched.
+    translations: Arc<TranslationNodeCollection>,
+
+    /// Last fingerprint check.
+    ///
+    /// When the fingerprint was last compared with
+    /// the source files, either by parsing them or
+    /// by revalidating the cached translations.
+    validated_at: Instant,
+}
+
+/// Translations cache.
+///
+/// Holds the last loaded translations, reused without
+/// touching the file system until the revalidation interval
+/// elapses. Only then the translation files are collected
+/// and fingerprinted again, and parsed if they changed.
+struct TranslationsCache {
+    /// How long the cached translations are trusted.
+    revalidation_interval: Duration,
+
+    /// The cached translations, if any were loaded.
+    cached: Mutex<Option<CachedTranslations>>,
+
+    /// How many times the translation files were collected.
+    #[cfg(test)]
+    walks: AtomicUsize,
+
+    /// How many translation files were parsed.
+    #[cfg(test)]
+    parses: AtomicUsize,
+}
+
+impl TranslationsCache {
+    /// Create an empty translations cache.
+    ///
+    /// **Arguments**
+    /// * `revalidation_interval` — How long loaded translations are reused
+    ///   without checking the source files.
+    ///
+    /// **Returns**
+    /// A cache without translations.
+    const fn new(revalidation_interval: Duration) -> Self {
+        Self {
+            revalidation_interval,
+            cached: Mutex::new(None),
+            #[cfg(test)]
+            walks: AtomicUsize::new(0),
+            #[cfg(test)]
+            parses: AtomicUsize::new(0),
+        }
+    }
+}
+
+/// Translations revalidation interval.
+///
+/// A compilation expands every macro within this interval
+/// of the previous one, so these reuse the translations
+/// without collecting nor fingerprinting the translation
+/// files, while a long lived process such as `rust-analyzer`
+/// picks up changes shortly after these are made.
+const REVALIDATION_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Global thread-safe cache for loaded translations.
+///
+/// Stores all parsed translations in memory after the first
+/// successful load. The cache lives as long as the process does,
+/// which for `rust-analyzer` spans many compilations, so it's
+/// replaced whenever the source files fingerprint changes.
+static TRANSLATIONS: TranslationsCache = TranslationsCache::new(REVALIDATION_INTERVAL);
+
+/// Fingerprints a list of files.
+///
+/// **Arguments**
+/// * `paths` — The file paths to fingerprint.
+///
+/// **Returns**
+/// Each file path along its last modification time,
+/// `None` if it couldn't be obtained.
+fn fingerprint<'a>(
+    paths: impl IntoIterator<Item = &'a String>,
+) -> Vec<(String, Option<SystemTime>)> {
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = metadata(path)
+                .and_then(|metadata| metadata.modified())
+                .ok();
+
+            (path.clone(), modified)
+        })
+        .collect()
+}
+
+/// Stores a translation collection in the global cache.
+///
+/// **Arguments**
+/// * `cache` — The locked global cache.
+/// * `translation_paths` — The translation files the collection comes from.
+/// * `includes` — The other files the collection depends on.
+/// * `translations` — The loaded collection.
+///
+/// **Returns**
+/// The collection shared with the global cache.
+fn cache_translations(
+    cache: &mut Option<CachedTranslations>,
+    translation_paths: &[String],
+    includes: Vec<String>,
+    translations: TranslationNodeCollection,
+) -> Arc<TranslationNodeCollection> {
+    let translations = Arc::new(translations);
+
+    *cache = Some(CachedTranslations {
+        fingerprint: fingerprint(
+            translation_paths
+                .iter()
+                .chain(&includes),
+        ),
+        includes,
+        translations: Arc::clone(&translations),
+        validated_at: Instant::now(),
+    });
+
+    translations
+}
+
+/// Describes the merge conflicts between translation files.
+///
+/// For every translation defined in more than one file,
+/// each language of the definition the macros resolve is
+/// reported along the file it was taken from and the other
+/// files also defining it in that language. Conflicts resolved by includes
+/// inside a single file are not reported, as these are merged while reading it.
+///
+/// **Arguments**
+/// * `translations` — The loaded translations.
+///
+/// **Returns**
+/// A note per conflicting path and language, sorted by path.
+pub fn merge_notes(translations: &TranslationNodeCollection) -> Vec<String> {
+    let mut notes = Vec::new();
+
+    for (path, sources) in translations.duplicates() {
+        let Some((winner, translation)) = translations.find_path_with_source(&path) else {
+            continue;
+        };
+
+        for language in translation.available_languages() {
+            let taken_from = translations
+                .inherited_source(&path, &language)
+                .unwrap_or(winner);
+
+            let overridden = sources
+                .iter()
+                .filter(|source| **source != winner && **source != taken_from)
+                .filter(|source| {
+                    translations
+                        .get_node(source)
+                        .and_then(|node| node.find_path(&path))
+                        .is_some_and(|translation| translation.contains_key(&language))
+                })
+                .copied()
+                .collect::<Vec<_>>();
+
+            if !overridden.is_empty() {
+                notes.push(format!(
+                    "key {}: {} taken from {taken_from}, overrode {}",
+                    path.join("::"),
+                    language.code(),
+                    overridden.join(", ")
+                ));
+            }
+        }
+    }
+
+    notes
+}
+
+/// Describes the translation coverage.
+///
+/// Every translation path is counted once, lists are not
+/// counted, so `es: 842/900 keys` means 842 of the 900
+/// translations are available in Spanish.
+///
+/// **Arguments**
+/// * `translations` — The loaded translations.
+///
+/// **Returns**
+/// A note with the translations available in each language,
+/// sorted by language.
+pub fn coverage_note(translations: &TranslationNodeCollection) -> String {
+    let total = translations
+        .leaves()
+        .len();
+
+    let coverage = translations
+        .language_coverage()
+        .into_iter()
+        .map(|(language, count)| format!("{}: {count}/{total} keys", language.code()))
+        .collect::<Vec<_>>();
+
+    format!("translation coverage: {}", coverage.join(", "))
+}
+
+/// Get the translations cache key.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+///
+/// **Returns**
+/// The configuration along the enabled `lang-*` features,
+/// which decide how the translations are parsed.
+#[cfg(feature = "serde")]
+fn cache_key(config: &MacroConfig) -> String {
+    format!("{config:?} {LANGUAGE_FEATURES:?}")
+}
+
+/// Reads the translations cache if it's up to date.
+///
+/// The cache is considered up to date if it was written with
+/// the same configuration and enabled languages, from the same
+/// translation files in the same order, and neither these nor
+/// the files these include were modified since.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `translation_paths` — The translation files the cache replaces.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(Some((TranslationNodeCollection, Vec<String>)))` — The cached
+///   collection along the files the translation files include.
+/// * `Ok(None)` — If no cache is configured, it doesn't exist, it's outdated or
+///   it was written by another version.
+/// * `Err(TranslationDataError)` — If the cache couldn't be read.
+#[cfg(feature = "serde")]
+fn read_translations_cache(
+    config: &MacroConfig,
+    translation_paths: &[String],
+) -> Result<Option<(TranslationNodeCollection, Vec<String>)>, TranslationDataError> {
+    let Some(cache_path) = config.translations_cache() else {
+        return Ok(None);
+    };
+
+    let cache = match read(cache_path) {
+        Ok(cache) => cache,
+        Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err.into()),
+    };
+
+    let Ok(cache) =
+        serde_json::from_slice::<TranslationsCacheFile<TranslationNodeCollection>>(&cache)
+    else {
+        return Ok(None);
+    };
+
+    let up_to_date = cache.key == cache_key(config)
+        && cache.translation_paths == translation_paths
+        && fingerprint(
+            cache
+                .fingerprint
+                .iter()
+                .map(|(path, _)| path),
+        ) == cache.fingerprint;
+
+    if !up_to_date {
+        return Ok(None);
+    }
+
+    let includes = cache
+        .fingerprint
+        .into_iter()
+        .skip(translation_paths.len())
+        .map(|(path, _)| path)
+        .chain([cache_path.to_string()])
+        .collect();
+
+    Ok(Some((cache.translations, includes)))
+}
+
+/// Writes the translations cache if one is configured.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `translation_paths` — The translation files the translations were parsed
+///   from.
+/// * `includes` — The files the translation files include.
+/// * `translations` — The parsed translations.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(())` — If the cache was written or none is configured.
+/// * `Err(TranslationDataError)` — If the cache couldn't be written.
+#[cfg(feature = "serde")]
+fn write_translations_cache(
+    config: &MacroConfig,
+    translation_paths: &[String],
+    includes: &[String],
+    translations: &TranslationNodeCollection,
+) -> Result<(), TranslationDataError> {
+    let Some(cache_path) = config.translations_cache() else {
+        return Ok(());
+    };
+
+    let cache = TranslationsCacheFile {
+        key: cache_key(config),
+        translation_paths: translation_paths.to_vec(),
+        fingerprint: fingerprint(
+            translation_paths
+                .iter()
+                .chain(includes),
+        ),
+        translations,
+    };
+
+    let cache = serde_json::to_vec(&cache)
+        .map_err(|err| TranslationDataError::Cache(err.to_string(), cache_path.into()))?;
+
+    Ok(write(cache_path, cache)?)
+}
+
+/// Recursively walks the target directory to discover all translation files.
+///
+/// Uses an iterative traversal strategy to avoid recursion depth limitations.
+/// Paths are returned as [`String`] values, ready for processing.
+///
+/// Any filesystem errors, invalid paths, or read failures are reported
+/// via `TranslationDataError`.
+///
+/// **Arguments**
+/// * `path` — Root directory to scan for translation files.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * [`Ok(Vec<String>)`] — A flat list of discovered file paths.
+/// * [`Err(TranslationDataError)`] — If traversal fails at any point.
+///
+/// [`Ok(Vec<String>)`]: std::vec::Vec<String>
+/// [`Err(TranslationDataError)`]: TranslationDataError
+fn walk_dir(path: &str) -> Result<Vec<String>, TranslationDataError> {
+    let mut stack = vec![path.to_string()];
+    let mut result = Vec::new();
+
+    while let Some(current_path) = stack.pop() {
+        let directory = read_dir(&current_path)?.collect::<Result<Vec<_>, _>>()?;
+
+        for entry in directory {
+            let path = entry.path();
+            if path.is_dir() {
+                stack.push(
+                    path.to_str()
+                        .ok_or(TranslationDataError::InvalidUnicode)?
+                        .to_string(),
+                );
+            } else {
+                result.push(
+                    path.to_string_lossy()
+                        .to_string(),
+                );
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Expands a glob pattern into the translation files it matches.
+///
+/// Only files are kept, directories matched by the pattern are
+/// ignored, same as they would be if they were walked with [`walk_dir`].
+///
+/// **Arguments**
+/// * `pattern` — The glob pattern to expand, such as `./i18n/**/*.locale.toml`.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * [`Ok(Vec<String>)`] — A flat list of the matched file paths.
+/// * [`Err(TranslationDataError)`] — If the pattern is invalid, a matched path
+///   couldn't be read or nothing matched at all.
+///
+/// [`Ok(Vec<String>)`]: std::vec::Vec<String>
+/// [`Err(TranslationDataError)`]: TranslationDataError
+fn glob_files(pattern: &str) -> Result<Vec<String>, TranslationDataError> {
+    let mut result = Vec::new();
+
+    for entry in
+        glob(pattern).map_err(|err| TranslationDataError::InvalidGlob(err, pattern.to_string()))?
+    {
+        let path = entry.map_err(IoError::from)?;
+
+        if path.is_file() {
+            result.push(
+                path.to_string_lossy()
+                    .to_string(),
+            );
+        }
+    }
+
+    if result.is_empty() {
+        return Err(TranslationDataError::GlobNoMatches(pattern.to_string()));
+    }
+
+    Ok(result)
+}
+
+/// Collects the translation file paths from the configured path.
+///
+/// If the path contains any glob metacharacter (`*`, `?` or `[`) it's
+/// expanded as a glob pattern with [`glob_files`], otherwise it's
+/// considered a directory and walked with [`walk_dir`].
+///
+/// If `allow_missing` is set, a directory that doesn't exist or a glob
+/// pattern without matches result in no files instead of an error.
+///
+/// **Arguments**
+/// * `path` — The configured translations path or glob pattern.
+/// * `allow_missing` — Whether the path may not lead to any file.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * [`Ok(Vec<String>)`] — A flat list of the translation file paths.
+/// * [`Err(TranslationDataError)`] — If the files couldn't be collected.
+///
+/// [`Ok(Vec<String>)`]: std::vec::Vec<String>
+/// [`Err(TranslationDataError)`]: TranslationDataError
+fn collect_translation_paths(
+    path: &str,
+    allow_missing: bool,
+) -> Result<Vec<String>, TranslationDataError> {
+    if path.contains(['*', '?', '[']) {
+        match glob_files(path) {
+            Err(TranslationDataError::GlobNoMatches(_)) if allow_missing => Ok(Vec::new()),
+            result => result,
+        }
+    } else if allow_missing && !Path::new(path).exists() {
+        Ok(Vec::new())
+    } else {
+        walk_dir(path)
+    }
+}
+
+/// Get whether a translation file belongs to the overlay.
+///
+/// The file path is checked to be inside the configured
+/// `overlay_path` directory, or to match it if it's a glob
+/// pattern, ignoring `.` components in both.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `path` — The translation file path.
+///
+/// **Returns**
+/// Whether the file was collected from the overlay path.
+fn is_overlay(config: &MacroConfig, path: &str) -> bool {
+    let Some(overlay) = config.overlay_path() else {
+        return false;
+    };
+
+    let normalize = |path: &str| {
+        Path::new(path)
+            .components()
+            .filter(|component| !matches!(component, Component::CurDir))
+            .collect::<PathBuf>()
+    };
+
+    let path = normalize(path);
+    let overlay = normalize(overlay);
+
+    if overlay
+        .to_string_lossy()
+        .contains(['*', '?', '['])
+    {
+        Pattern::new(&overlay.to_string_lossy()).is_ok_and(|pattern| pattern.matches_path(&path))
+    } else {
+        path.starts_with(overlay)
+    }
+}
+
+/// Get the overlap strategy for a translation file.
+///
+/// Files in the overlay always overwrite, otherwise
+/// the file path is matched against the `overlap` rules
+/// relative to the configured translations path, or to
+/// the leading directories of the glob pattern that don't
+/// contain any glob metacharacter.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `path` — The translation file path.
+///
+/// **Returns**
+/// The strategy for the translation file.
+fn overlap_strategy(config: &MacroConfig, path: &str) -> TranslationOverlap {
+    if is_overlay(config, path) {
+        return TranslationOverlap::Overwrite;
+    }
+
+    let base = Path::new(config.path())
+        .components()
+        .take_while(|component| {
+            !component
+                .as_os_str()
+                .to_string_lossy()
+                .contains(['*', '?', '['])
+        })
+        .collect::<PathBuf>();
+
+    let path = Path::new(path);
+
+    config
+        .overlap()
+        .strategy(
+            path.strip_prefix(&base)
+                .unwrap_or(path),
+        )
+}
+
+/// Collects the translation file paths in lookup order.
+///
+/// The paths are collected with [`collect_translation_paths`] and
+/// sorted according to the configured `seek_mode`, then each file
+/// whose `overlap` strategy is `overwrite` is looked up before the
+/// files found before it, and any other file after them.
+///
+/// The overlay files, if any, are collected the same way and
+/// looked up before every other file, so these overwrite them.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * [`Ok(Vec<String>)`] — The translation file paths in lookup order.
+/// * [`Err(TranslationDataError)`] — If the configuration couldn't be loaded or
+///   the files couldn't be collected.
+///
+/// [`Ok(Vec<String>)`]: std::vec::Vec<String>
+/// [`Err(TranslationDataError)`]: TranslationDataError
+fn sorted_translation_paths(config: &MacroConfig) -> Result<Vec<String>, TranslationDataError> {
+    let mut translation_paths =
+        collect_translation_paths(config.path(), config.allow_missing_locales())?;
+
+    // overlays nested in the translations path are not base files.
+    translation_paths.retain(|path| !is_overlay(config, path));
+
+    if let Some(overlay) = config.overlay_path() {
+        translation_paths
+            .extend(collect_translation_paths(overlay, config.allow_missing_locales())?);
+    }
+
+    // Apply sorting based on configuration
+    translation_paths.sort_by_key(|path| path.to_lowercase());
+    if matches!(config.seek_mode(), SeekMode::Unalphabetical) {
+        translation_paths.reverse();
+    }
+
+    // the overlay is merged last, so it's looked up first.
+    translation_paths.sort_by_key(|path| is_overlay(config, path));
+
+    let mut lookup_order = VecDeque::new();
+    for path in translation_paths {
+        match overlap_strategy(config, &path) {
+            TranslationOverlap::Overwrite => lookup_order.push_front(path),
+            TranslationOverlap::Ignore => lookup_order.push_back(path),
+        }
+    }
+
+    Ok(lookup_order.into())
+}
+
+/// Collects the translation file paths for hot reloading.
+///
+/// Same as [`sorted_translation_paths`] but the paths are
+/// made absolute, so they can be read in runtime regardless
+/// of the working directory.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * [`Ok(Vec<(String, bool)>)`] — The absolute translation file paths in
+///   lookup order, along whether their `overlap` strategy is `overwrite`.
+/// * [`Err(TranslationDataError)`] — If the files couldn't be collected.
+///
+/// [`Ok(Vec<(String, bool)>)`]: std::vec::Vec
+/// [`Err(TranslationDataError)`]: TranslationDataError
+#[cfg(all(feature = "hot-reload", debug_assertions))]
+pub fn hot_reload_paths() -> Result<Vec<(String, bool)>, TranslationDataError> {
+    let config = load_config()?;
+
+    sorted_translation_paths(config)?
+        .iter()
+        .map(|path| {
+            let overwrite = matches!(overlap_strategy(config, path), TranslationOverlap::Overwrite);
+
+            Ok((
+                canonicalize(path)?
+                    .to_str()
+                    .ok_or(TranslationDataError::InvalidUnicode)?
+                    .to_string(),
+                overwrite,
+            ))
+        })
+        .collect()
+}
+
+/// Loads and caches translations from the configured directory.
+///
+/// The cached translations are returned as is while these were
+/// loaded or revalidated less than [`REVALIDATION_INTERVAL`] ago,
+/// without touching the file system. Otherwise, this function:
+/// - Reads the translation directory path from the loaded configuration.
+/// - Recursively walks the directory to discover all translation files, or
+///   expands the path as a glob pattern if it contains glob metacharacters.
+/// - Sorts the file list according to the configured `seek_mode`.
+/// - Fingerprints the file list, along the files they included the last time
+///   they were parsed, with each file modification time.
+/// - Prints the merge conflicts between files if `trace_merge` is set.
+///
+/// Only if the fingerprint differs from the cached one, which is always
+/// the case on the first invocation, each file is parsed and validated,
+/// resolving its includes and nesting it under its file name if
+/// `namespace_by_file` is set, replacing the cached translations.
+///
+/// With the `serde` feature and a `translations_cache` configured, the
+/// cache is deserialized instead of parsing the files while it matches the
+/// configuration and the source files, otherwise it's written after parsing
+/// them.
+///
+/// This way a translation heavy module only collects and parses the
+/// translation files once, while a long lived process such as
+/// `rust-analyzer` picks up changes in the translation files.
+///
+/// **Returns**
+/// A [`Result`] containing either:
+/// * [`Ok(Arc<TranslationNodeCollection>)`] — The parsed and cached
+///   translations.
+/// * [`Err(TranslationDataError)`] — An error because any of the translation
+///   files couldn't be read.
+///
+/// [`Ok(Arc<TranslationNodeCollection>)`]: TranslationNodeCollection
+/// [`Err(TranslationDataError)`]: TranslationDataError
+pub fn load_translations() -> Result<Arc<TranslationNodeCollection>, TranslationDataError> {
+    load_cached_translations(&TRANSLATIONS, load_config()?)
+}
+
+/// Loads translations trough a cache.
+///
+/// See [`load_translations`], which uses the global cache.
+///
+/// **Arguments**
+/// * `cache` — The cache to reuse or replace.
+/// * `config` — The loaded configuration.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(Arc<TranslationNodeCollection>)` — The cached or parsed translations.
+/// * `Err(TranslationDataError)` — If any of the translation files couldn't be
+///   read.
+fn load_cached_translations(
+    translations_cache: &TranslationsCache,
+    config: &MacroConfig,
+) -> Result<Arc<TranslationNodeCollection>, TranslationDataError> {
+    let mut cache = translations_cache
+        .cached
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if let Some(cached) = cache.as_ref()
+        && cached
+            .validated_at
+            .elapsed()
+            < translations_cache.revalidation_interval
+    {
+        return Ok(Arc::clone(&cached.translations));
+    }
+
+    let translation_paths = sorted_translation_paths(config)?;
+
+    #[cfg(test)]
+    translations_cache
+        .walks
+        .fetch_add(1, Ordering::Relaxed);
+
+    if let Some(cached) = cache.as_mut()
+        && cached.fingerprint
+            == fingerprint(
+                translation_paths
+                    .iter()
+                    .chain(&cached.includes),
+            )
+    {
+        cached.validated_at = Instant::now();
+        return Ok(Arc::clone(&cached.translations));
+    }
+
+    #[cfg(feature = "serde")]
+    if let Some((translations, includes)) = read_translations_cache(config, &translation_paths)? {
+        return Ok(trace_coverage(
+            config,
+            trace_merge(
+                config,
+                cache_translations(&mut cache, &translation_paths, includes, translations),
+            ),
+        ));
+    }
+
+    let files = translation_paths
+        .iter()
+        .map(|path| {
+            let overwrite = matches!(overlap_strategy(config, path), TranslationOverlap::Overwrite);
+            TranslationFile::Path(path, overwrite)
+        })
+        .collect::<Vec<_>>();
+
+    let (translations, includes) = parse_translation_files(&files, config)?;
+
+    #[cfg(feature = "serde")]
+    write_translations_cache(config, &translation_paths, &includes, &translations)?;
+
+    #[cfg(test)]
+    translations_cache
+        .parses
+        .fetch_add(translation_paths.len(), Ordering::Relaxed);
+
+    Ok(trace_coverage(
+        config,
+        trace_merge(
+            config,
+            cache_translations(&mut cache, &translation_paths, includes, translations),
+        ),
+    ))
+}
+
+/// Reads and parses a single translation file.
+///
+/// The file includes are resolved, or rejected for embedded
+/// files, and, if `namespace_by_file`
+/// is set, its translations are nested under its file name. If
+/// `case_insensitive_paths` is set, its keys are lowercased, and
+/// if `bidi_isolate` is set, its right-to-left translations isolate
+/// their replaced values.
+/// Translations whose languages don't use the same required
+/// placeholders, or empty translations if `allow_empty` is
+/// disabled, are reported, the first one by path.
+///
+/// **Arguments**
+/// * `file` — The translation file to parse.
+/// * `config` — The loaded configuration.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok((TranslationNode, Vec<String>))` — The parsed file along the files it
+///   included.
+/// * `Err(TranslationDataError)` — If the file couldn't be read, doesn't follow
+///   the translation rules, its placeholders mismatch or it contains a
+///   disallowed empty translation.
+pub fn parse_translation_file(
+    file: &TranslationFile,
+    config: &MacroConfig,
+) -> Result<(TranslationNode, Vec<String>), TranslationDataError> {
+    let path = file.path();
+    let (mut table, includes) = match *file {
+        TranslationFile::Path(path, overwrite) => read_translation_file(path, overwrite)?,
+        TranslationFile::Embedded(path, contents) => {
+            (parse_embedded_translation_file(contents, path)?, Vec::new())
+        },
+    };
+
+    if config.namespace_by_file() {
+        table = namespace_table(table, path);
+    }
+
+    let mut node = TranslationNode::from_table_with(&table, config.placeholder_delimiters())?;
+
+    if config.case_insensitive_paths() {
+        node = node.lowercase_paths()?;
+    }
+
+    if config.bidi_isolate() {
+        node.set_bidi_isolate(true);
+    }
+
+    if config.escape_html() {
+        node.set_escape_html(true);
+    }
+
+    if !config.allow_empty() {
+        let empty = node
+            .leaves()
+            .into_iter()
+            .filter_map(|(path_segments, translation)| {
+                let languages = translation
+                    .available_languages()
+                    .into_iter()
+                    .filter(|language| {
+                        translation
+                            .get(language)
+                            .is_some_and(|translation| {
+                                translation
+                                    .original()
+                                    .trim()
+                                    .is_empty()
+                            })
+                    })
+                    .map(|language| language.code())
+                    .collect::<Vec<_>>();
+
+                (!languages.is_empty()).then(|| (path_segments.join("::"), languages))
+            })
+            .min_by(|(a, _), (b, _)| a.cmp(b));
+
+        if let Some((translation_path, languages)) = empty {
+            return Err(TranslationDataError::EmptyTranslation(
+                translation_path,
+                path.into(),
+                languages.join(", "),
+            ));
+        }
+    }
+
+    let mismatch = node
+        .leaves()
+        .into_iter()
+        .filter_map(|(path_segments, translation)| {
+            let mismatches = translation.placeholder_mismatches();
+            (!mismatches.is_empty()).then(|| (path_segments.join("::"), mismatches))
+        })
+        .min_by(|(a, _), (b, _)| a.cmp(b));
+
+    if let Some((translation_path, mismatches)) = mismatch {
+        let missing = mismatches
+            .iter()
+            .map(|(language, placeholders)| {
+                format!(
+                    "{} is missing {}",
+                    language.code(),
+                    placeholders
+                        .iter()
+                        .map(|placeholder| format!("'{placeholder}'"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        return Err(TranslationDataError::PlaceholderMismatch(
+            translation_path,
+            path.into(),
+            missing,
+        ));
+    }
+
+    Ok((node, includes))
+}
+
+/// Reads and parses the translation files.
+///
+/// This is the loader shared by the compile-time, hot reloaded
+/// and embedded translations, so these are validated the same way.
+///
+/// Each file is parsed with [`parse_translation_file`], with the
+/// `parallel` feature the files are parsed in a thread pool. Either
+/// way the results are merged in the order of `files`, so `seek_mode`
+/// and `overlap` resolve conflicts the same way and the first failing
+/// file in that order is the one reported, as a [`FileParse`] error,
+/// the same as the file nesting translations beneath a path that is a
+/// translation in another file, or the file of a reference that couldn't
+/// be expanded.
+///
+/// The languages are then merged, the references expanded, the
+/// translations pseudolocalized if `pseudolocale` is set and only
+/// the languages enabled by the `lang-*` features are kept.
+///
+/// **Arguments**
+/// * `files` — The translation files in seek order.
+/// * `config` — The loaded configuration.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok((TranslationNodeCollection, Vec<String>))` — The parsed translations
+///   along the files included by any of them.
+/// * `Err(TranslationDataError)` — If any of the files couldn't be parsed, a
+///   path is a translation in a file and nests translations in another or the
+///   references couldn't be expanded.
+///
+/// [`FileParse`]: TranslationDataError::FileParse
+pub fn parse_translation_files(
+    files: &[TranslationFile],
+    config: &MacroConfig,
+) -> Result<(TranslationNodeCollection, Vec<String>), TranslationDataError> {
+    #[cfg(feature = "parallel")]
+    let parsed = files
+        .par_iter()
+        .map(|file| parse_translation_file(file, config))
+        .collect::<Vec<_>>();
+
+    #[cfg(not(feature = "parallel"))]
+    let parsed = files
+        .iter()
+        .map(|file| parse_translation_file(file, config));
+
+    let mut includes = Vec::new();
+
+    let mut translations = files
+        .iter()
+        .zip(parsed)
+        .map(|(file, parsed)| {
+            let (node, file_includes) = parsed.map_err(|err| {
+                TranslationDataError::FileParse(
+                    file.path()
+                        .into(),
+                    Box::new(err),
+                )
+            })?;
+            includes.extend(file_includes);
+
+            Ok((
+                file.path()
+                    .to_string(),
+                node,
+            ))
+        })
+        .collect::<Result<TranslationNodeCollection, TranslationDataError>>()?
+        .with_case_insensitive_paths(config.case_insensitive_paths());
+
+    if let Some((path, leaf_source, nesting_source)) = translations
+        .shadowed_paths()
+        .first()
+    {
+        return Err(TranslationDataError::FileParse(
+            nesting_source.to_string(),
+            Box::new(TranslationDataError::ShadowedPath(
+                path.join("::"),
+                leaf_source.to_string(),
+                nesting_source.to_string(),
+            )),
+        ));
+    }
+
+    translations.merge_languages();
+    translations
+        .expand_references(config.placeholder_delimiters())
+        .map_err(|(file, err)| TranslationDataError::FileParse(file, Box::new(err.into())))?;
+
+    if config.pseudolocale() {
+        translations.pseudolocalize();
+    }
+
+    retain_enabled_languages(&mut translations);
+
+    Ok((translations, includes))
+}
+
+// Generated by the build script from the enabled `lang-*` features.
+include!(concat!(env!("OUT_DIR"), "/language_features.rs"));
+
+/// Get whether the languages are gated by features.
+///
+/// **Returns**
+/// Whether any `lang-*` feature is enabled, in which
+/// case only those languages are loaded.
+pub fn languages_gated() -> bool {
+    !LANGUAGE_FEATURES.is_empty()
+}
+
+/// Get the feature that enables a language.
+///
+/// Custom tags are enabled along the language
+/// they fall back to, so `en-XA` is enabled by `lang-en`.
+///
+/// **Arguments**
+/// * `language` — The language to get the feature of.
+///
+/// **Returns**
+/// The `lang-*` feature name, or `None` for custom
+/// tags that don't fall back to any language.
+pub fn language_feature(language: &Language) -> Option<String> {
+    match language {
+        Language::Other(_) => language
+            .fallback_parent()
+            .and_then(|parent| language_feature(&parent)),
+
+        language => Some(format!("lang-{}", language.code())),
+    }
+}
+
+/// Get whether a language is enabled by the features.
+///
+/// **Arguments**
+/// * `language` — The language to check.
+///
+/// **Returns**
+/// Whether the feature enabling the language is enabled,
+/// always `true` if no `lang-*` feature is enabled.
+pub fn language_enabled(language: &Language) -> bool {
+    !languages_gated()
+        || language_feature(language)
+            .is_some_and(|feature| LANGUAGE_FEATURES.contains(&&feature["lang-".len()..]))
+}
+
+/// Removes the translations in languages not enabled by the features.
+///
+/// Does nothing if no `lang-*` feature is enabled.
+///
+/// **Arguments**
+/// * `translations` — The translations to filter.
+fn retain_enabled_languages(translations: &mut TranslationNodeCollection) {
+    if languages_gated() {
+        translations.retain_languages_with(language_enabled);
+    }
+}
+
+/// Logs the merge notes if `trace_merge` is enabled.
+///
+/// This is plain logging to the standard error, which the compiler
+/// forwards, not a compiler diagnostic, so the notes have no span,
+/// are not shown by `rust-analyzer` and the generated code is not
+/// affected. The notes are only printed the first time the
+/// translations are loaded in the process, so a long lived process
+/// reloading the translation files doesn't repeat them.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `translations` — The loaded translations.
+///
+/// **Returns**
+/// The same translations, for chaining.
+fn trace_merge(
+    config: &MacroConfig,
+    translations: Arc<TranslationNodeCollection>,
+) -> Arc<TranslationNodeCollection> {
+    static TRACED: Once = Once::new();
+
+    if config.trace_merge() {
+        TRACED.call_once(|| {
+            for note in merge_notes(&translations) {
+                eprintln!("note: {note}");
+            }
+        });
+    }
+
+    translations
+}
+
+/// Logs the coverage note if `coverage` is enabled.
+///
+/// Same as [`trace_merge`], this is plain logging to the
+/// standard error rather than a compiler diagnostic, and the
+/// note is only printed the first time the translations are
+/// loaded in the process.
+///
+/// **Arguments**
+/// * `config` — The loaded configuration.
+/// * `translations` — The loaded translations.
+///
+/// **Returns**
+/// The same translations, for chaining.
+fn trace_coverage(
+    config: &MacroConfig,
+    translations: Arc<TranslationNodeCollection>,
+) -> Arc<TranslationNodeCollection> {
+    static TRACED: Once = Once::new();
+
+    if config.coverage() {
+        TRACED.call_once(|| eprintln!("note: {}", coverage_note(&translations)));
+    }
+
+    translations
+}
+
+/// Filters a translation collection to the embedded languages.
+///
+/// Used before embedding the translations for runtime
+/// resolution, so only the configured `embed_languages`
+/// are serialized in the generated code.
+///
+/// **Arguments**
+/// * `translations` — The loaded translations.
+/// * `languages` — The languages to embed, or `None` to embed all of them.
+///
+/// **Returns**
+/// The translations as they should be embedded, only cloned
+/// if there is something to filter.
+pub fn embedded_translations<'a>(
+    translations: &'a TranslationNodeCollection,
+    languages: Option<&[Language]>,
+) -> Cow<'a, TranslationNodeCollection> {
+    match languages {
+        Some(languages) => {
+            let mut translations = translations.clone();
+            translations.retain_languages(languages);
+            Cow::Owned(translations)
+        },
+        None => Cow::Borrowed(translations),
+    }
+}
+
+/// Filters a translation object to the embedded languages.
+///
+/// Same as [`embedded_translations`] but for a single
+/// translation object or list, used when the path is
+/// known in compile-time but the language isn't.
+///
+/// **Arguments**
+/// * `translation` — The translation object or list to embed.
+/// * `languages` — The languages to embed, or `None` to embed all of them.
+///
+/// **Returns**
+/// The translation object or list as it should be embedded.
+pub fn embedded_translation<'a, T, V>(
+    translation: &'a T,
+    languages: Option<&[Language]>,
+) -> Cow<'a, T>
+where
+    T: Clone + DerefMut<Target = HashMap<Language, V>>,
+{
+    match languages {
+        Some(languages) => {
+            let mut translation = translation.clone();
+            translation.retain(|language, _| languages.contains(language));
+
+            Cow::Owned(translation)
+        },
+        None => Cow::Borrowed(translation),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+    use std::fs::{File, create_dir_all, remove_dir_all, write};
+    use std::sync::Arc;
+    use std::sync::atomic::Ordering;
+    use std::time::{Duration, SystemTime};
+
+    use toml_edit::{DocumentMut, Value};
+
+    use super::{
+        LANGUAGE_FEATURES,
+        TranslationsCache,
+        coverage_note,
+        language_enabled,
+        language_feature,
+        languages_gated,
+        load_cached_translations,
+        merge_notes,
+        sorted_translation_paths,
+    };
+    use crate::data::config::MacroConfig;
+    use crate::misc::language::Language;
+    use crate::translations::collection::TranslationNodeCollection;
+    use crate::translations::node::TranslationNode;
+
+    const TRANSLATION_FILE: &str = r#"
+[greetings.formal]
+es = "Bueno conocerte."
+en = "Nice to meet you."
+"#;
+
+    #[test]
+    fn caches_until_files_change() {
+        let directory = temp_dir().join(format!("translatable-cache-{}", std::process::id()));
+        let file = directory.join("test.toml");
+
+        create_dir_all(&directory).expect("Temporary directory to be created.");
+        write(&file, TRANSLATION_FILE).expect("Translation file to be written.");
+
+        let config = MacroConfig::from_toml(&format!(
+            "path = {}",
+            Value::from(
+                directory
+                    .to_string_lossy()
+                    .as_ref()
+            )
+        ))
+        .expect("Configuration to be valid.");
+
+        // caches of their own, so other tests loading translations don't interfere.
+        let load = |cache: &TranslationsCache| {
+            load_cached_translations(cache, &config).expect("Translations to be loaded.")
+        };
+
+        let counts = |cache: &TranslationsCache| {
+            (
+                cache
+                    .walks
+                    .load(Ordering::Relaxed),
+                cache
+                    .parses
+                    .load(Ordering::Relaxed),
+            )
+        };
+
+        // within the interval the file system is not touched at all.
+        let cache = TranslationsCache::new(Duration::MAX);
+        let first = load(&cache);
+
+        for _ in 0..500 {
+            assert!(Arc::ptr_eq(&first, &load(&cache)));
+        }
+
+        assert_eq!(counts(&cache), (1, 1));
+
+        // once it elapses the files are collected again, but only parsed if changed.
+        let cache = TranslationsCache::new(Duration::ZERO);
+        let first = load(&cache);
+
+        for _ in 0..5 {
+            assert!(Arc::ptr_eq(&first, &load(&cache)));
+        }
+
+        assert_eq!(counts(&cache), (6, 1));
+
+        File::options()
+            .write(true)
+            .open(&file)
+            .and_then(|file| file.set_modified(SystemTime::now() + Duration::from_secs(60)))
+            .expect("Translation file modification time to be updated.");
+
+        let reloaded = load(&cache);
+
+        assert!(!Arc::ptr_eq(&first, &reloaded));
+        assert!(Arc::ptr_eq(&reloaded, &load(&cache)));
+        assert_eq!(counts(&cache), (8, 2));
+
+        remove_dir_all(&directory).ok();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn reuses_translations_cache() {
+        let directory = temp_dir().join(format!("translatable-serde-cache-{}", std::process::id()));
+        let included = directory.join("shared.toml");
+        let cache_path = directory.join("translatable.bin");
+
+        create_dir_all(directory.join("translations")).expect("Temporary directory to be created.");
+        write(
+            directory.join("translations/test.toml"),
+            "[farewells]\ninclude = \"../shared.toml\"",
+        )
+        .expect("Translation file to be written.");
+        write(&included, "[formal]\nen = \"See you later.\"")
+            .expect("Included file to be written.");
+
+        let config = |extra: &str| {
+            MacroConfig::from_toml(&format!(
+                "path = {}\ntranslations_cache = {}\n{extra}",
+                Value::from(
+                    directory
+                        .join("translations")
+                        .to_string_lossy()
+                        .as_ref()
+                ),
+                Value::from(
+                    cache_path
+                        .to_string_lossy()
+                        .as_ref()
+                ),
+            ))
+            .expect("Configuration to be valid.")
+        };
+
+        // a fresh cache per load, as a new compilation would have.
+        let parses = |config: &MacroConfig| {
+            let cache = TranslationsCache::new(Duration::MAX);
+            load_cached_translations(&cache, config).expect("Translations to be loaded.");
+
+            cache
+                .parses
+                .load(Ordering::Relaxed)
+        };
+
+        assert_eq!(parses(&config("")), 1);
+        assert!(cache_path.exists());
+        assert_eq!(parses(&config("")), 0);
+
+        // a configuration change invalidates the cache.
+        assert_eq!(parses(&config("namespace_by_file = true")), 1);
+        assert_eq!(parses(&config("namespace_by_file = true")), 0);
+
+        // and so does a change in an included file.
+        File::options()
+            .write(true)
+            .open(&included)
+            .and_then(|file| file.set_modified(SystemTime::now() + Duration::from_secs(60)))
+            .expect("Included file modification time to be updated.");
+
+        assert_eq!(parses(&config("namespace_by_file = true")), 1);
+        assert_eq!(parses(&config("namespace_by_file = true")), 0);
+
+        remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn orders_lookup_by_overlap() {
+        let directory = temp_dir().join(format!("translatable-order-{}", std::process::id()));
+
+        create_dir_all(directory.join("vendor")).expect("Temporary directory to be created.");
+        for file in ["a.toml", "b.toml", "vendor/c.toml"] {
+            write(directory.join(file), TRANSLATION_FILE).expect("Translation file to be written.");
+        }
+
+        let lookup_order = |config: &str| {
+            let config = MacroConfig::from_toml(&format!(
+                "path = {}\n{config}",
+                Value::from(
+                    directory
+                        .to_string_lossy()
+                        .as_ref()
+                )
+            ))
+            .expect("Configuration to be valid.");
+
+            sorted_translation_paths(&config)
+                .expect("Translation files to be collected.")
+                .iter()
+                .map(|path| {
+                    path.strip_prefix(
+                        directory
+                            .to_string_lossy()
+                            .as_ref(),
+                    )
+                    .expect("Path to be in the temporary directory.")
+                    .trim_start_matches(['/', '\\'])
+                    .replace('\\', "/")
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(lookup_order(""), ["a.toml", "b.toml", "vendor/c.toml"]);
+        assert_eq!(lookup_order("overlap = \"overwrite\""), ["vendor/c.toml", "b.toml", "a.toml"]);
+
+        // the last found file wins, which is the first one by name.
+        assert_eq!(
+            lookup_order("overlap = \"overwrite\"\nseek_mode = \"Unalphabetical\""),
+            ["a.toml", "b.toml", "vendor/c.toml"]
+        );
+
+        // ignored files are looked up after every other file.
+        assert_eq!(
+            lookup_order(
+                "overlap = [{ pattern = \"vendor/**\", strategy = \"ignore\" }, { pattern = \
+                 \"**\", strategy = \"overwrite\" }]"
+            ),
+            ["b.toml", "a.toml", "vendor/c.toml"]
+        );
+
+        remove_dir_all(&directory).ok();
+    }
+
+    #[test]
+    fn describes_merge_conflicts() {
+        let node = |content: &str| {
+            TranslationNode::try_from(
+                content
+                    .parse::<DocumentMut>()
+                    .expect("TOML to be parsed correctly.")
+                    .as_table(),
+            )
+            .expect("TOML to follow the translation rules.")
+        };
+
+        let translations = TranslationNodeCollection::from_iter([
+            ("a.toml".to_string(), node(TRANSLATION_FILE)),
+            ("b.toml".to_string(), node("[greetings.formal]\nes = \"Un placer.\"")),
+            ("c.toml".to_string(), node("[greetings.informal]\nes = \"Hola.\"")),
+        ]);
+
+        assert_eq!(
+            merge_notes(&translations),
+            vec!["key greetings::formal: es taken from a.toml, overrode b.toml"]
+        );
+    }
+
+    #[test]
+    fn describes_merged_languages() {
+        let node = |content: &str| {
+            TranslationNode::try_from(
+                content
+                    .parse::<DocumentMut>()
+                    .expect("TOML to be parsed correctly.")
+                    .as_table(),
+            )
+            .expect("TOML to follow the translation rules.")
+        };
+
+        let mut translations = TranslationNodeCollection::from_iter([
+            ("a.toml".to_string(), node("[greetings.formal]\nes = \"Un placer.\"")),
+            ("b.toml".to_string(), node(TRANSLATION_FILE)),
+            ("c.toml".to_string(), node("[greetings.formal]\nen = \"Hi.\"")),
+        ]);
+
+        translations.merge_languages();
+
+        assert_eq!(
+            merge_notes(&translations),
+            vec![
+                "key greetings::formal: en taken from b.toml, overrode c.toml",
+                "key greetings::formal: es taken from a.toml, overrode b.toml",
+            ]
+        );
+    }
+
+    #[test]
+    fn describes_coverage() {
+        let translations = TranslationNodeCollection::from_iter([(
+            "a.toml".to_string(),
+            TranslationNode::try_from(
+                format!("{TRANSLATION_FILE}\n[greetings.informal]\nes = \"Hola.\"")
+                    .parse::<DocumentMut>()
+                    .expect("TOML to be parsed correctly.")
+                    .as_table(),
+            )
+            .expect("TOML to follow the translation rules."),
+        )]);
+
+        assert_eq!(
+            coverage_note(&translations),
+            "translation coverage: en: 1/2 keys, es: 2/2 keys"
+        );
+    }
+
+    #[test]
+    fn maps_languages_to_features() {
+        let tag = |tag: &str| Language::from_tag(tag).expect("Tag to be valid.");
+
+        assert_eq!(language_feature(&Language::ES).as_deref(), Some("lang-es"));
+        assert_eq!(language_feature(&tag("en-XA")).as_deref(), Some("lang-en"));
+        assert_eq!(language_feature(&tag("x-pirate")), None);
+
+        // every language is enabled unless a `lang-*` feature is.
+        assert_eq!(
+            language_enabled(&Language::ES),
+            !languages_gated() || LANGUAGE_FEATURES.contains(&"es")
+        );
+        assert_eq!(language_enabled(&tag("en-XA")), language_enabled(&Language::EN));
+        assert_eq!(language_enabled(&tag("x-pirate")), !languages_gated());
+    }
+}
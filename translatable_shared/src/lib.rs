@@ -10,6 +10,16 @@
 
 #![warn(missing_docs)]
 
+pub mod data;
 pub mod macros;
 pub mod misc;
 pub mod translations;
+
+/// Perfect hash map re-export.
+///
+/// Used by the code generated with the `phf`
+/// feature to declare compile-time hashed
+/// translation tables.
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+pub use phf;
@@ -10,6 +10,22 @@ use std::collections::HashMap;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, quote};
 
+/// [`HashMap<K, V>`] entries sorted by key.
+///
+/// **Parameters**
+/// * `map` - The map to collect the entries from.
+///
+/// **Returns**
+/// The map entries in ascending key order.
+fn sorted_entries<K: Ord, V>(map: &HashMap<K, V>) -> Vec<(&K, &V)> {
+    let mut entries = map
+        .iter()
+        .collect::<Vec<_>>();
+    entries.sort_by_key(|(key, _)| *key);
+
+    entries
+}
+
 /// [`HashMap<K, V>`] runtime conversion.
 ///
 /// This function converts a [`HashMap<K, V>`] into a [`TokenStream2`]
@@ -18,15 +34,18 @@ use quote::{ToTokens, quote};
 ///
 /// The type of the keys and values of the map must implement [`ToTokens`].
 ///
+/// The entries are sorted by key, so the same map always generates
+/// the same tokens regardless of its iteration order.
+///
 /// **Parameters**
 /// * `map` - The map to convert into tokens.
 ///
 /// **Returns**
 /// The provided `map` parameter represented as [`TokenStream2`].
 #[inline]
-pub fn map_to_tokens<K: ToTokens, V: ToTokens>(map: &HashMap<K, V>) -> TokenStream2 {
-    let map = map
-        .iter()
+pub fn map_to_tokens<K: ToTokens + Ord, V: ToTokens>(map: &HashMap<K, V>) -> TokenStream2 {
+    let map = sorted_entries(map)
+        .into_iter()
         .map(|(key, value)| {
             let key = key.into_token_stream();
             let value = value.into_token_stream();
@@ -51,13 +70,13 @@ pub fn map_to_tokens<K: ToTokens, V: ToTokens>(map: &HashMap<K, V>) -> TokenStre
 ///
 /// The predicate must return a [`TokenStream2`] containing tuples, the internal
 /// conversion is as `vec![$($converted),*]` collected into a [`HashMap<K, V>`]
-/// in runtime.
+/// in runtime. The entries are sorted by key as in [`map_to_tokens`].
 ///
 /// **Parameters**
 /// * `map` - The map to convert into tokens.
 /// * `predicate` - A predicate taking a key and a value that should return a
-///   [`TokenStream2`]
-/// containing a tuple of the key and the value transformed in any way.
+///   [`TokenStream2`] containing a tuple of the key and the value transformed
+///   in any way.
 ///
 /// **Returns**
 /// The provided `map` parameter mutated with the `predicate` and converted to a
@@ -65,10 +84,11 @@ pub fn map_to_tokens<K: ToTokens, V: ToTokens>(map: &HashMap<K, V>) -> TokenStre
 #[inline]
 pub fn map_transform_to_tokens<K, V, F>(map: &HashMap<K, V>, predicate: F) -> TokenStream2
 where
+    K: Ord,
     F: Fn(&K, &V) -> TokenStream2,
 {
-    let processed = map
-        .iter()
+    let processed = sorted_entries(map)
+        .into_iter()
         .map(|(key, value)| predicate(key, value));
 
     quote! {
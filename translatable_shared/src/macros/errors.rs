@@ -75,6 +75,100 @@ where
 /// for values that implement [`Display`].
 impl<T: Display> IntoCompileError for T {}
 
+/// Error collection implementations for macro outputs.
+///
+/// Same as [`IntoCompileError`] but for a list of errors,
+/// so every error found while generating a macro output
+/// is reported at once instead of only the first one.
+pub trait IntoCompileErrors {
+    /// Convert the errors to runtime.
+    ///
+    /// **Returns**
+    /// A block containing a [`compile_error!`] invocation
+    /// for each error.
+    ///
+    /// [`compile_error!`]: std::compile_error
+    fn to_compile_error(&self) -> TokenStream2;
+
+    /// Convert the errors to runtime.
+    ///
+    /// Same as [`IntoCompileError::to_out_compile_error`],
+    /// but all the invocations share a single function
+    /// so there are no duplicate definitions.
+    ///
+    /// **Returns**
+    /// A `fn __() {}` wrapping every [`compile_error!`] invocation.
+    ///
+    /// [`compile_error!`]: std::compile_error
+    fn to_out_compile_error(&self) -> TokenStream2 {
+        let invocations = self.to_compile_error();
+        quote! { fn __() { #invocations } }
+    }
+}
+
+/// [`IntoCompileErrors`] implementation for lists
+/// of values that implement [`Display`].
+impl<T: Display> IntoCompileErrors for Vec<T> {
+    #[cold]
+    fn to_compile_error(&self) -> TokenStream2 {
+        let invocations = self
+            .iter()
+            .map(IntoCompileError::to_compile_error);
+
+        quote! { { #(#invocations;)* } }
+    }
+}
+
+/// Collects every value or every error.
+///
+/// Unlike collecting into a [`Result<Vec<T>, E>`] this
+/// doesn't stop at the first error, so all the errors
+/// can be reported together.
+///
+/// **Parameters**
+/// * `results` - The results to collect.
+///
+/// **Returns**
+/// All the values if there were no errors, otherwise
+/// all the errors.
+pub fn collect_results<T, E>(
+    results: impl IntoIterator<Item = Result<T, E>>,
+) -> Result<Vec<T>, Vec<E>> {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    if errors.is_empty() { Ok(values) } else { Err(errors) }
+}
+
+/// Spanned errors conversion to runtime.
+///
+/// Unlike [`IntoCompileErrors`] the errors keep their
+/// span, so each error is reported where it originated
+/// instead of the whole macro invocation.
+///
+/// **Parameters**
+/// * `errors` - The spanned errors to convert.
+///
+/// **Returns**
+/// A braced [`compile_error!`] invocation for each error,
+/// valid both inside and outside functions.
+///
+/// [`compile_error!`]: std::compile_error
+#[cold]
+pub fn spanned_compile_errors(errors: Vec<SynError>) -> TokenStream2 {
+    errors
+        .into_iter()
+        .map(SynError::into_compile_error)
+        .collect()
+}
+
 /// [`to_compile_error`] conversion helper macro.
 ///
 /// This macro takes a [`Result<T, E>`] where
@@ -88,6 +182,13 @@ impl<T: Display> IntoCompileError for T {}
 /// If you prepend `out` to the value this will
 /// call [`to_out_compile_error`] instead.
 ///
+/// If you prepend `collect` to the value, it must be an
+/// iterator of results instead, which is collected with
+/// [`collect_results`] reporting every error at once.
+/// It may be combined with `out` as `collect out`, or with
+/// `spanned` as `collect spanned` for [`SynError`] results,
+/// which are converted with [`spanned_compile_errors`].
+///
 /// [`to_compile_error`]: IntoCompileError::to_compile_error
 /// [`to_out_compile_error`]: IntoCompileError::to_out_compile_error
 #[macro_export]
@@ -108,4 +209,30 @@ macro_rules! handle_macro_result {
     (out $val:expr) => {
         $crate::handle_macro_result!(to_out_compile_error; $val)
     };
+
+    (collect $method:ident; $val:expr) => {{
+        use $crate::macros::errors::IntoCompileErrors;
+
+        match $crate::macros::errors::collect_results($val) {
+            std::result::Result::Ok(values) => values,
+            std::result::Result::Err(errors) => return errors.$method(),
+        }
+    }};
+
+    (collect out $val:expr) => {
+        $crate::handle_macro_result!(collect to_out_compile_error; $val)
+    };
+
+    (collect spanned $val:expr) => {{
+        match $crate::macros::errors::collect_results($val) {
+            std::result::Result::Ok(values) => values,
+            std::result::Result::Err(errors) => {
+                return $crate::macros::errors::spanned_compile_errors(errors);
+            },
+        }
+    }};
+
+    (collect $val:expr) => {
+        $crate::handle_macro_result!(collect to_compile_error; $val)
+    };
 }
@@ -4,9 +4,13 @@
 //! required for parsing and validating ISO-639-1
 //! language strings from user input.
 
+use std::iter::Cloned;
+use std::slice::Iter;
+use std::str::FromStr;
+
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::{ToTokens, TokenStreamExt, quote};
-use strum::{Display, EnumIter, EnumString};
+use strum::{Display, IntoEnumIterator, ParseError};
 use syn::Ident;
 
 /// This implementation converts the tagged union
@@ -16,6 +20,14 @@ use syn::Ident;
 /// macro generation context.
 impl ToTokens for Language {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
+        if let Self::Other(tag) = self {
+            tokens.append_all(quote! {
+                translatable::shared::misc::language::Language::Other(String::from(#tag))
+            });
+
+            return;
+        }
+
         let ident = Ident::new(&format!("{self:?}"), Span::call_site());
 
         tokens.append_all(quote! { translatable::shared::misc::language::Language::#ident })
@@ -26,10 +38,15 @@ impl ToTokens for Language {
 ///
 /// Provides two-way mapping between language codes and names with:
 /// - Case-insensitive parsing
+/// - ISO 639-2 three letter codes and common aliases parsing
 /// - Strict validation
 /// - Complete ISO 639-1 coverage
-#[derive(Debug, Clone, EnumIter, Display, EnumString, Eq, Hash, PartialEq)]
-#[strum(ascii_case_insensitive)]
+/// - Custom BCP 47 tags trough [`Language::from_tag`]
+///
+/// Languages are ordered as declared, custom tags last,
+/// so generated code listing languages is deterministic.
+#[derive(Debug, Clone, Display, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Language {
     #[allow(missing_docs)]
     #[strum(serialize = "Abkhazian", serialize = "ab")]
@@ -580,4 +597,310 @@ pub enum Language {
     #[allow(missing_docs)]
     #[strum(serialize = "Zulu", serialize = "zu")]
     ZU,
+
+    /// A custom language tag.
+    ///
+    /// A tag that is not an ISO 639 code but follows the
+    /// BCP 47 syntax, such as the `en-XA` pseudolocale or
+    /// the `x-pirate` private-use tag. Obtained with
+    /// [`Language::from_tag`] and displayed as the tag.
+    ///
+    /// Iterating the languages doesn't yield this
+    /// variant, as custom tags can't be enumerated.
+    ///
+    /// **Parameters**
+    /// * `0` - The tag in its canonical case.
+    #[strum(transparent)]
+    Other(String),
+}
+
+/// Language fallback parents.
+///
+/// Each entry relates a language with the macrolanguage
+/// it belongs to according to ISO 639-3, only listing the
+/// relationships where both languages have an ISO 639-1
+/// code.
+const FALLBACK_PARENTS: &[(Language, Language)] =
+    &[(Language::NB, Language::NO), (Language::NN, Language::NO), (Language::ID, Language::MS)];
+
+/// Right-to-left languages.
+///
+/// The languages whose script, as used in their
+/// native name, is written from right to left, such
+/// as the Arabic and Hebrew scripts.
+const RTL_LANGUAGES: &[Language] = &[
+    Language::AR,
+    Language::DV,
+    Language::FA,
+    Language::HE,
+    Language::PS,
+    Language::UG,
+    Language::UR,
+    Language::YI,
+];
+
+/// Language name aliases.
+///
+/// Alternative English names for languages that are
+/// commonly used instead of the ISO 639 reference name,
+/// these are accepted when parsing a [`Language`].
+const ALIASES: &[(&str, Language)] = &[
+    ("castilian", Language::ES),
+    ("dhivehi", Language::DV),
+    ("farsi", Language::FA),
+    ("flemish", Language::NL),
+    ("gaelic", Language::GD),
+    ("kirghiz", Language::KY),
+    ("maldivian", Language::DV),
+    ("moldavian", Language::RO),
+    ("moldovan", Language::RO),
+    ("panjabi", Language::PA),
+    ("pushto", Language::PS),
+    ("sinhalese", Language::SI),
+    ("uighur", Language::UG),
+    ("valencian", Language::CA),
+];
+
+/// [`IntoEnumIterator`] implementation for [`Language`].
+///
+/// Iterates [`Language::all`], so custom tags are not
+/// yielded, as these can't be enumerated.
+impl IntoEnumIterator for Language {
+    type Iterator = Cloned<Iter<'static, Language>>;
+
+    fn iter() -> Self::Iterator {
+        LANGUAGES
+            .iter()
+            .cloned()
+    }
+}
+
+/// [`FromStr`] implementation for [`Language`].
+///
+/// Parses ISO 639-1 codes, ISO 639-2 codes, both terminology
+/// and bibliographic, English names and the names listed in
+/// [`ALIASES`], ignoring case. Anything else is rejected.
+impl FromStr for Language {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.to_lowercase();
+
+        language_from_code(&s)
+            .or_else(|| {
+                ALIASES
+                    .iter()
+                    .find(|(alias, _)| *alias == s)
+                    .map(|(_, language)| language.clone())
+            })
+            .ok_or(ParseError::VariantNotFound)
+    }
+}
+
+/// Custom language tag canonicalization.
+///
+/// Accepts tags made of alphanumeric subtags of up to 8
+/// characters separated by `-` or `_`, starting with a 2 or 3
+/// letter language subtag or the `x` private-use singleton,
+/// that either have more than one subtag or are an ISO 639
+/// private-use code, from `qaa` to `qtz`. So typos such as
+/// `esp` are still rejected.
+///
+/// Subtags are cased as BCP 47 recommends, languages in lowercase,
+/// scripts in titlecase and regions in uppercase, everything after
+/// a singleton is lowercased.
+///
+/// **Arguments**
+/// * `tag` - The tag to canonicalize.
+///
+/// **Returns**
+/// The canonical tag, or `None` if it's not a valid custom tag.
+fn canonical_tag(tag: &str) -> Option<String> {
+    let subtags = tag
+        .split(['-', '_'])
+        .collect::<Vec<_>>();
+
+    let valid_subtags = subtags
+        .iter()
+        .all(|subtag| {
+            (1..=8).contains(&subtag.len())
+                && subtag
+                    .chars()
+                    .all(|char| char.is_ascii_alphanumeric())
+        });
+
+    let primary = subtags[0].to_lowercase();
+    let valid_primary = match subtags.len() {
+        1 => primary.len() == 3 && ("qaa"..="qtz").contains(&primary.as_str()),
+        _ => {
+            primary == "x"
+                || ((2..=3).contains(&primary.len())
+                    && primary
+                        .chars()
+                        .all(|char| char.is_ascii_alphabetic()))
+        },
+    };
+
+    if !valid_subtags
+        || !valid_primary
+        || subtags
+            .last()?
+            .len()
+            == 1
+    {
+        return None;
+    }
+
+    let mut singleton = primary == "x";
+    let mut canonical = vec![primary];
+
+    for subtag in &subtags[1..] {
+        let subtag = match subtag.len() {
+            _ if singleton => subtag.to_lowercase(),
+            1 => {
+                singleton = true;
+                subtag.to_lowercase()
+            },
+            2 => subtag.to_uppercase(),
+            4 if subtag
+                .chars()
+                .all(|char| char.is_ascii_alphabetic()) =>
+            {
+                subtag[..1].to_uppercase() + &subtag[1..].to_lowercase()
+            },
+            _ => subtag.to_lowercase(),
+        };
+
+        canonical.push(subtag);
+    }
+
+    Some(canonical.join("-"))
+}
+
+impl Language {
+    /// Parse a language allowing custom tags.
+    ///
+    /// Parses the same as [`FromStr`], otherwise accepts
+    /// BCP 47 tags such as the `en-XA` pseudolocale or the
+    /// `x-pirate` private-use tag as [`Language::Other`], in
+    /// their canonical case. This is how the language keys in
+    /// translation files and the static language literals are
+    /// parsed.
+    ///
+    /// **Arguments**
+    /// * `tag` - The language or tag to parse.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(Language)` - The parsed language.
+    /// * `Err(ParseError)` - If it's neither a language nor a valid tag.
+    pub fn from_tag(tag: &str) -> Result<Self, ParseError> {
+        Self::from_str(tag).or_else(|error| {
+            canonical_tag(tag)
+                .map(Self::Other)
+                .ok_or(error)
+        })
+    }
+
+    /// Get every known language.
+    ///
+    /// The same languages iterated by [`IntoEnumIterator`],
+    /// custom tags are not included, as they can't be enumerated.
+    ///
+    /// **Returns**
+    /// Every ISO 639-1 language in declaration order,
+    /// the same order languages are sorted in.
+    pub fn all() -> &'static [Language] {
+        LANGUAGES
+    }
+
+    /// Get the code of this language.
+    ///
+    /// **Returns**
+    /// The ISO 639-1 code in lowercase, such as `"es"`, or
+    /// the tag itself for custom tags.
+    pub fn code(&self) -> String {
+        match self {
+            Self::Other(tag) => tag.clone(),
+            language => format!("{language:?}").to_lowercase(),
+        }
+    }
+
+    /// Get the natural fallback of this language.
+    ///
+    /// Looks up this language in the fallback parents table,
+    /// which relates individual languages with the macrolanguage
+    /// they belong to, such as `nb` and `nn` with `no`.
+    ///
+    /// Custom tags fall back to the tag without its last subtag,
+    /// so `en-XA` falls back to `en`.
+    ///
+    /// **Returns**
+    /// The parent language if this language has one.
+    pub fn fallback_parent(&self) -> Option<Language> {
+        if let Self::Other(tag) = self {
+            let mut subtags = tag
+                .split('-')
+                .collect::<Vec<_>>();
+
+            subtags.pop();
+            while subtags
+                .last()
+                .is_some_and(|subtag| subtag.len() == 1)
+            {
+                subtags.pop();
+            }
+
+            return Self::from_tag(&subtags.join("-")).ok();
+        }
+
+        FALLBACK_PARENTS
+            .iter()
+            .find(|(language, _)| language == self)
+            .map(|(_, parent)| parent.clone())
+    }
+
+    /// Get whether this language is written right to left.
+    ///
+    /// Looks up this language in the right-to-left languages
+    /// table, which is based on the script each language is
+    /// usually written in. Custom tags are written the same
+    /// way as their fallback parent.
+    ///
+    /// **Returns**
+    /// Whether the language script is written right to left.
+    pub fn is_rtl(&self) -> bool {
+        match self {
+            Self::Other(_) => self
+                .fallback_parent()
+                .is_some_and(|parent| parent.is_rtl()),
+            language => RTL_LANGUAGES.contains(language),
+        }
+    }
+
+    /// Get the English name of this language.
+    ///
+    /// The names are generated from `data/languages.tsv`
+    /// at build time, custom tags are named after the tag.
+    ///
+    /// **Returns**
+    /// The English name of the language, such as `"Spanish"`.
+    pub fn display_name(&self) -> &str {
+        language_names(self).0
+    }
+
+    /// Get the native name of this language.
+    ///
+    /// The names are generated from `data/languages.tsv`
+    /// at build time, custom tags are named after the tag.
+    ///
+    /// **Returns**
+    /// The name of the language in the language itself,
+    /// such as `"Español"`.
+    pub fn native_name(&self) -> &str {
+        language_names(self).1
+    }
 }
+
+// Generated by the build script from `data/languages.tsv`.
+include!(concat!(env!("OUT_DIR"), "/language_data.rs"));
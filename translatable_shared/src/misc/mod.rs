@@ -5,4 +5,5 @@
 //! because there aren’t enough related modules to justify their own group.
 
 pub mod language;
+pub mod suggestion;
 pub mod templating;
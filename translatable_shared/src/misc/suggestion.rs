@@ -0,0 +1,75 @@
+//! Suggestion module.
+//!
+//! This module declares [`closest_match`] which is
+//! used to suggest a known value when an unknown one
+//! is found, usually due to a typo.
+
+/// Computes the edit distance between two strings.
+///
+/// The distance is the minimum amount of single character
+/// insertions, deletions or substitutions required to turn
+/// one string into the other.
+///
+/// **Arguments**
+/// * `left` — The first string.
+/// * `right` — The second string.
+///
+/// **Returns**
+/// The Levenshtein distance between both strings.
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right = right
+        .chars()
+        .collect::<Vec<_>>();
+
+    let mut previous = (0..=right.len()).collect::<Vec<_>>();
+
+    for (i, left_char) in left
+        .chars()
+        .enumerate()
+    {
+        let mut current = vec![i + 1];
+
+        for (j, right_char) in right
+            .iter()
+            .enumerate()
+        {
+            let substitution = previous[j] + usize::from(left_char != *right_char);
+            current.push(
+                substitution
+                    .min(previous[j + 1] + 1)
+                    .min(current[j] + 1),
+            );
+        }
+
+        previous = current;
+    }
+
+    previous[right.len()]
+}
+
+/// Finds the candidate closest to an unknown value.
+///
+/// Only candidates within a third of the value length
+/// in edit distance are considered, so unrelated values
+/// don't produce a suggestion.
+///
+/// **Arguments**
+/// * `value` — The unknown value.
+/// * `candidates` — The known values.
+///
+/// **Returns**
+/// The closest candidate, or `None` if none is close enough.
+pub fn closest_match<'a>(value: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let threshold = (value
+        .chars()
+        .count()
+        / 3)
+    .max(1);
+
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(value, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
@@ -5,15 +5,20 @@
 //! and generate strings of them with replaced
 //! parameters.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::iter::repeat;
 use std::ops::Range;
 use std::str::FromStr;
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, TokenStreamExt, quote};
+use strum::EnumString;
 use syn::{Ident, parse_str};
 use thiserror::Error;
 
+use super::language::Language;
+
 /// Template parsing errors.
 ///
 /// This error is used within [`FormatString`]
@@ -38,6 +43,1334 @@ pub enum TemplateError {
     /// are checked because of macro parsing.
     #[error("Found template with key '{0}' which is an invalid identifier")]
     InvalidIdent(String),
+
+    /// Invalid type constraint error.
+    ///
+    /// This error is returned when a template
+    /// is annotated with a type that is not
+    /// a [`TemplateConstraint`].
+    ///
+    /// **Parameters**
+    /// * `0` - The template key.
+    /// * `1` - The unknown type constraint.
+    #[error("Found template with key '{0}' constrained to the unknown type '{1}'")]
+    InvalidConstraint(String, String),
+
+    /// Invalid directive error.
+    ///
+    /// This error is returned when a template
+    /// has a formatting directive such as
+    /// `{amount, number}` that is unknown or
+    /// has invalid arguments.
+    ///
+    /// **Parameters**
+    /// * `0` - The template key.
+    /// * `1` - The invalid directive, including its arguments.
+    #[error("Found template with key '{0}' with the invalid directive '{1}'")]
+    InvalidDirective(String, String),
+
+    /// Invalid conditional error.
+    ///
+    /// This error is returned when a template
+    /// has a conditional block such as
+    /// `{flag, if {text} else {other}}` that is
+    /// malformed, such as a block without braces,
+    /// a block containing templates or unexpected
+    /// text after the blocks.
+    ///
+    /// **Parameters**
+    /// * `0` - The template key.
+    /// * `1` - The malformed conditional, including its blocks.
+    #[error("Found template with key '{0}' with the malformed conditional '{1}'")]
+    InvalidConditional(String, String),
+
+    /// Missing value error.
+    ///
+    /// This error is returned by [`FormatString::replace_with_strict`]
+    /// when a template has neither a value nor a default.
+    ///
+    /// **Parameters**
+    /// * `0` - The template key.
+    #[error("Found template with key '{0}' without a value")]
+    MissingValue(String),
+
+    /// Invalid reference error.
+    ///
+    /// This error is returned when a reference
+    /// such as `@{brand::name}` doesn't contain
+    /// a valid translation path.
+    ///
+    /// **Parameters**
+    /// * `0` - The reference contents.
+    #[error("Found reference '{0}' which is not a valid translation path")]
+    InvalidReference(String),
+}
+
+/// Template formatting directive.
+///
+/// Templates may have a directive after their key
+/// such as `{amount, number}`, which formats the
+/// replaced value according to the conventions of
+/// the language the translation is written in.
+///
+/// If the value can't be formatted with the directive,
+/// it's replaced as is.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TemplateDirective {
+    /// Number formatting.
+    ///
+    /// Declared as `{key, number}`, groups the integer
+    /// digits and uses the language decimal separator.
+    Number,
+
+    /// Currency formatting.
+    ///
+    /// Declared as `{key, currency}` or `{key, currency, EUR}`,
+    /// formats the value as a number with two fraction digits,
+    /// optionally with the currency symbol placed according to
+    /// the language.
+    ///
+    /// **Parameters**
+    /// * `0` - The ISO 4217 currency code, if any.
+    Currency(Option<String>),
+
+    /// Date formatting.
+    ///
+    /// Declared as `{key, date}` or `{key, date, style}`, formats
+    /// a timestamp as a date. The value may be a unix timestamp
+    /// in seconds or any string starting with an ISO 8601 date,
+    /// such as the `chrono` types `Display` output.
+    ///
+    /// Requires the `chrono` feature, otherwise the value is
+    /// expected to be already formatted and replaced as is.
+    ///
+    /// **Parameters**
+    /// * `0` - The date style, `medium` if not specified.
+    Date(DateStyle),
+
+    /// Conditional text.
+    ///
+    /// Declared as `{key, if {text}}` or `{key, if {text} else {other}}`,
+    /// replaces the template with a text selected by whether the value
+    /// is truthy. Empty values, `false`, `0`, `no` and `off` are falsy,
+    /// any other value is truthy. The blocks are replaced as they are
+    /// written, without escaping nor isolating them, thus these can't
+    /// contain templates.
+    ///
+    /// **Parameters**
+    /// * `0` - The text for truthy values.
+    /// * `1` - The text for falsy values, empty if not specified.
+    Conditional(String, Option<String>),
+}
+
+/// Date formatting style.
+///
+/// The amount of detail used by the
+/// [`TemplateDirective::Date`] directive.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "lowercase")]
+pub enum DateStyle {
+    /// Numeric date, such as `1/2/2024`.
+    Short,
+
+    /// Abbreviated month name, such as `Jan 2, 2024`.
+    #[default]
+    Medium,
+
+    /// Full month name, such as `January 2, 2024`.
+    Long,
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates the equivalent
+/// [`DateStyle`] variant in runtime.
+impl ToTokens for DateStyle {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let style = quote! { translatable::shared::misc::templating::DateStyle };
+
+        tokens.append_all(match self {
+            Self::Short => quote! { #style::Short },
+            Self::Medium => quote! { #style::Medium },
+            Self::Long => quote! { #style::Long },
+        });
+    }
+}
+
+impl TemplateDirective {
+    /// Directive parsing.
+    ///
+    /// **Arguments**
+    /// * `name` - The directive name, such as `number`.
+    /// * `arguments` - The directive arguments after its name.
+    ///
+    /// **Returns**
+    /// The parsed directive, or `None` if the directive is
+    /// unknown or the arguments are invalid.
+    fn parse(name: &str, arguments: &[&str]) -> Option<Self> {
+        match (name, arguments) {
+            ("number", []) => Some(Self::Number),
+            ("currency", []) => Some(Self::Currency(None)),
+            ("currency", [code])
+                if code.len() == 3
+                    && code
+                        .chars()
+                        .all(|c| c.is_ascii_alphabetic()) =>
+            {
+                Some(Self::Currency(Some(code.to_ascii_uppercase())))
+            },
+            ("date", []) => Some(Self::Date(DateStyle::default())),
+            ("date", [style]) => style
+                .parse()
+                .ok()
+                .map(Self::Date),
+            _ => None,
+        }
+    }
+
+    /// Conditional directive parsing.
+    ///
+    /// **Arguments**
+    /// * `directive` - The directive after the template key, such as `if {text}
+    ///   else {other}`.
+    /// * `open` - The string that opens a block.
+    /// * `close` - The string that closes a block.
+    ///
+    /// **Returns**
+    /// The parsed conditional, or `None` if the blocks
+    /// are malformed or contain the delimiters, as the
+    /// blocks are replaced as they are written.
+    fn parse_conditional(directive: &str, open: &str, close: &str) -> Option<Self> {
+        let block = |rest| {
+            split_block(rest, open, close)
+                .filter(|(block, _)| !block.contains(open) && !block.contains(close))
+        };
+
+        let rest = directive
+            .trim_start()
+            .strip_prefix("if")?;
+        let (text, rest) = block(rest.trim_start())?;
+
+        let rest = rest.trim();
+        if rest.is_empty() {
+            return Some(Self::Conditional(text, None));
+        }
+
+        let rest = rest
+            .strip_prefix("else")?
+            .trim_start();
+        let (other, rest) = block(rest)?;
+
+        rest.trim()
+            .is_empty()
+            .then_some(Self::Conditional(text, Some(other)))
+    }
+
+    /// Whether the directive replaces the template with its own
+    /// text instead of the formatted value.
+    ///
+    /// **Returns**
+    /// Whether the directive is a [`Conditional`].
+    ///
+    /// [`Conditional`]: TemplateDirective::Conditional
+    fn is_conditional(&self) -> bool {
+        matches!(self, Self::Conditional(..))
+    }
+
+    /// Applies the directive to a value.
+    ///
+    /// **Arguments**
+    /// * `value` - The stringified template value.
+    /// * `language` - The language the translation is written in.
+    ///
+    /// **Returns**
+    /// The formatted value, or `None` if the value couldn't be
+    /// formatted with this directive.
+    fn apply(&self, value: &str, language: Option<&Language>) -> Option<String> {
+        let symbols = NumberSymbols::for_language(language);
+
+        match self {
+            Self::Number => symbols.format(value, None),
+
+            Self::Currency(code) => {
+                let number = symbols.format(value, Some(2))?;
+
+                Some(match code {
+                    None => number,
+                    Some(code) => {
+                        let symbol = currency_symbol(code);
+
+                        if symbols.currency_suffix {
+                            format!("{number}\u{a0}{symbol}")
+                        } else {
+                            format!("{symbol}{number}")
+                        }
+                    },
+                })
+            },
+
+            #[cfg(feature = "chrono")]
+            Self::Date(style) => DateConventions::for_language(language).format(value, *style),
+
+            #[cfg(not(feature = "chrono"))]
+            Self::Date(_) => None,
+
+            Self::Conditional(text, other) => {
+                let falsy = ["", "false", "0", "no", "off"]
+                    .iter()
+                    .any(|falsy| {
+                        value
+                            .trim()
+                            .eq_ignore_ascii_case(falsy)
+                    });
+
+                Some(match (falsy, other) {
+                    (false, _) => text.clone(),
+                    (true, Some(other)) => other.clone(),
+                    (true, None) => String::new(),
+                })
+            },
+        }
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates the equivalent
+/// [`TemplateDirective`] variant in runtime.
+impl ToTokens for TemplateDirective {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let directive = quote! { translatable::shared::misc::templating::TemplateDirective };
+
+        tokens.append_all(match self {
+            Self::Number => quote! { #directive::Number },
+            Self::Currency(None) => quote! { #directive::Currency(None) },
+            Self::Currency(Some(code)) => quote! { #directive::Currency(Some(#code.to_string())) },
+            Self::Date(style) => quote! { #directive::Date(#style) },
+            Self::Conditional(text, None) => {
+                quote! { #directive::Conditional(#text.to_string(), None) }
+            },
+            Self::Conditional(text, Some(other)) => {
+                quote! { #directive::Conditional(#text.to_string(), Some(#other.to_string())) }
+            },
+        });
+    }
+}
+
+/// Number formatting symbols.
+///
+/// The separators and currency placement
+/// conventions for a language.
+struct NumberSymbols {
+    /// Digit group separator.
+    group: &'static str,
+
+    /// Decimal separator.
+    decimal: &'static str,
+
+    /// Whether the currency symbol goes after the number.
+    currency_suffix: bool,
+}
+
+/// Default number formatting symbols.
+///
+/// Used for the languages not listed in [`NUMBER_SYMBOLS`].
+const DEFAULT_NUMBER_SYMBOLS: NumberSymbols = NumberSymbols {
+    group: ",",
+    decimal: ".",
+    currency_suffix: false,
+};
+
+/// Number formatting symbols by language.
+///
+/// A small built-in table with the most common
+/// conventions, any language not listed here uses
+/// [`DEFAULT_NUMBER_SYMBOLS`].
+const NUMBER_SYMBOLS: &[(Language, NumberSymbols)] = {
+    const DOT_COMMA: NumberSymbols = NumberSymbols {
+        group: ".",
+        decimal: ",",
+        currency_suffix: true,
+    };
+    const SPACE_COMMA: NumberSymbols = NumberSymbols {
+        group: "\u{a0}",
+        decimal: ",",
+        currency_suffix: true,
+    };
+
+    &[
+        (Language::DE, DOT_COMMA),
+        (Language::ES, DOT_COMMA),
+        (Language::IT, DOT_COMMA),
+        (Language::NL, DOT_COMMA),
+        (Language::PT, DOT_COMMA),
+        (Language::DA, DOT_COMMA),
+        (Language::EL, DOT_COMMA),
+        (Language::ID, DOT_COMMA),
+        (Language::TR, DOT_COMMA),
+        (Language::RO, DOT_COMMA),
+        (Language::HR, DOT_COMMA),
+        (Language::SL, DOT_COMMA),
+        (Language::FR, SPACE_COMMA),
+        (Language::RU, SPACE_COMMA),
+        (Language::UK, SPACE_COMMA),
+        (Language::PL, SPACE_COMMA),
+        (Language::CS, SPACE_COMMA),
+        (Language::SK, SPACE_COMMA),
+        (Language::HU, SPACE_COMMA),
+        (Language::BG, SPACE_COMMA),
+        (Language::FI, SPACE_COMMA),
+        (Language::SV, SPACE_COMMA),
+        (Language::NO, SPACE_COMMA),
+        (Language::NB, SPACE_COMMA),
+        (Language::NN, SPACE_COMMA),
+    ]
+};
+
+impl NumberSymbols {
+    /// Get the number formatting symbols for a language.
+    ///
+    /// **Arguments**
+    /// * `language` - The language to get the symbols for.
+    ///
+    /// **Returns**
+    /// The symbols for the language, or the default ones.
+    fn for_language(language: Option<&Language>) -> &'static Self {
+        language
+            .and_then(|language| {
+                NUMBER_SYMBOLS
+                    .iter()
+                    .find(|(candidate, _)| candidate == language)
+            })
+            .map(|(_, symbols)| symbols)
+            .unwrap_or(&DEFAULT_NUMBER_SYMBOLS)
+    }
+
+    /// Formats a stringified number.
+    ///
+    /// Decimal values are formatted as they are written, so
+    /// neither big values nor the rounding lose precision,
+    /// other numbers such as `1e3` are read as a float.
+    ///
+    /// **Arguments**
+    /// * `value` - The stringified number.
+    /// * `fraction_digits` - The fixed amount of fraction digits, rounded half
+    ///   away from zero, if `None` the significant fraction digits are kept as
+    ///   they are.
+    ///
+    /// **Returns**
+    /// The formatted number, or `None` if the value is not a finite number.
+    fn format(&self, value: &str, fraction_digits: Option<usize>) -> Option<String> {
+        let value = value.trim();
+
+        let decimal = if is_decimal(value) {
+            value.to_string()
+        } else {
+            value
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())?
+                .to_string()
+        };
+
+        let (negative, unsigned) = match decimal.strip_prefix('-') {
+            Some(unsigned) => (true, unsigned),
+            None => (false, decimal.trim_start_matches('+')),
+        };
+
+        let (integer, fraction) = unsigned
+            .split_once('.')
+            .unwrap_or((unsigned, ""));
+
+        let (integer, fraction) = match fraction_digits {
+            Some(digits) => round_decimal(integer, fraction, digits),
+            None => (
+                integer.to_string(),
+                fraction
+                    .trim_end_matches('0')
+                    .to_string(),
+            ),
+        };
+
+        let integer = match integer.trim_start_matches('0') {
+            "" => "0",
+            integer => integer,
+        };
+
+        let mut result = String::new();
+        if negative
+            && integer
+                .chars()
+                .chain(fraction.chars())
+                .any(|digit| digit != '0')
+        {
+            result.push('-');
+        }
+
+        for (index, digit) in integer
+            .chars()
+            .enumerate()
+        {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                result.push_str(self.group);
+            }
+
+            result.push(digit);
+        }
+
+        if !fraction.is_empty() {
+            result.push_str(self.decimal);
+            result.push_str(&fraction);
+        }
+
+        Some(result)
+    }
+}
+
+/// Decimal number check.
+///
+/// **Arguments**
+/// * `value` - The stringified number.
+///
+/// **Returns**
+/// Whether the value is a plain decimal number, such as
+/// `-1234.50`, with an optional sign and fraction.
+fn is_decimal(value: &str) -> bool {
+    let unsigned = value
+        .strip_prefix(['+', '-'])
+        .unwrap_or(value);
+
+    let (integer, fraction) = unsigned
+        .split_once('.')
+        .unwrap_or((unsigned, ""));
+
+    !(integer.is_empty() && fraction.is_empty())
+        && integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|char| char.is_ascii_digit())
+}
+
+/// Decimal number rounding.
+///
+/// Rounds the digits of a decimal number half away from
+/// zero without converting it to a float, so `0.995`
+/// rounds to `1.00`.
+///
+/// **Arguments**
+/// * `integer` - The integer digits.
+/// * `fraction` - The fraction digits.
+/// * `digits` - The amount of fraction digits to keep.
+///
+/// **Returns**
+/// The rounded integer and fraction digits, the fraction
+/// padded with zeros up to `digits`.
+fn round_decimal(integer: &str, fraction: &str, digits: usize) -> (String, String) {
+    let mut kept = integer
+        .chars()
+        .chain(
+            fraction
+                .chars()
+                .chain(repeat('0'))
+                .take(digits),
+        )
+        .collect::<Vec<_>>();
+
+    if fraction
+        .chars()
+        .nth(digits)
+        .is_some_and(|digit| digit >= '5')
+    {
+        let mut carry = true;
+
+        for digit in kept
+            .iter_mut()
+            .rev()
+        {
+            if *digit == '9' {
+                *digit = '0';
+            } else {
+                *digit = (*digit as u8 + 1) as char;
+                carry = false;
+                break;
+            }
+        }
+
+        if carry {
+            kept.insert(0, '1');
+        }
+    }
+
+    let fraction = kept.split_off(kept.len() - digits);
+
+    (kept.into_iter().collect(), fraction.into_iter().collect())
+}
+
+/// Date formatting conventions.
+///
+/// The patterns for each [`DateStyle`] and the month
+/// names for a language. Patterns replace `{d}` and `{m}`
+/// with the day and month number, `{dd}` and `{mm}` with
+/// their zero padded version, `{Y}` with the year and `{b}`
+/// and `{B}` with the abbreviated and full month name.
+#[cfg(feature = "chrono")]
+struct DateConventions {
+    /// Pattern for [`DateStyle::Short`].
+    short: &'static str,
+
+    /// Pattern for [`DateStyle::Medium`].
+    medium: &'static str,
+
+    /// Pattern for [`DateStyle::Long`].
+    long: &'static str,
+
+    /// Abbreviated month names.
+    abbreviated_months: [&'static str; 12],
+
+    /// Full month names.
+    months: [&'static str; 12],
+}
+
+/// Default date formatting conventions.
+///
+/// Used for the languages not listed in [`DATE_CONVENTIONS`],
+/// formats every style as an ISO 8601 date.
+#[cfg(feature = "chrono")]
+const DEFAULT_DATE_CONVENTIONS: DateConventions = DateConventions {
+    short: "{Y}-{mm}-{dd}",
+    medium: "{Y}-{mm}-{dd}",
+    long: "{Y}-{mm}-{dd}",
+    abbreviated_months: [""; 12],
+    months: [""; 12],
+};
+
+/// Date formatting conventions by language.
+///
+/// A small built-in table with the most common
+/// conventions, any language not listed here uses
+/// [`DEFAULT_DATE_CONVENTIONS`].
+#[cfg(feature = "chrono")]
+const DATE_CONVENTIONS: &[(Language, DateConventions)] = &[
+    (
+        Language::EN,
+        DateConventions {
+            short: "{m}/{d}/{Y}",
+            medium: "{b} {d}, {Y}",
+            long: "{B} {d}, {Y}",
+            abbreviated_months: [
+                "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+            ],
+            months: [
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+        },
+    ),
+    (
+        Language::ES,
+        DateConventions {
+            short: "{d}/{m}/{Y}",
+            medium: "{d} {b} {Y}",
+            long: "{d} de {B} de {Y}",
+            abbreviated_months: [
+                "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+            ],
+            months: [
+                "enero",
+                "febrero",
+                "marzo",
+                "abril",
+                "mayo",
+                "junio",
+                "julio",
+                "agosto",
+                "septiembre",
+                "octubre",
+                "noviembre",
+                "diciembre",
+            ],
+        },
+    ),
+    (
+        Language::DE,
+        DateConventions {
+            short: "{dd}.{mm}.{Y}",
+            medium: "{d}. {b} {Y}",
+            long: "{d}. {B} {Y}",
+            abbreviated_months: [
+                "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.",
+                "Nov.", "Dez.",
+            ],
+            months: [
+                "Januar",
+                "Februar",
+                "März",
+                "April",
+                "Mai",
+                "Juni",
+                "Juli",
+                "August",
+                "September",
+                "Oktober",
+                "November",
+                "Dezember",
+            ],
+        },
+    ),
+    (
+        Language::FR,
+        DateConventions {
+            short: "{dd}/{mm}/{Y}",
+            medium: "{d} {b} {Y}",
+            long: "{d} {B} {Y}",
+            abbreviated_months: [
+                "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.",
+                "nov.", "déc.",
+            ],
+            months: [
+                "janvier",
+                "février",
+                "mars",
+                "avril",
+                "mai",
+                "juin",
+                "juillet",
+                "août",
+                "septembre",
+                "octobre",
+                "novembre",
+                "décembre",
+            ],
+        },
+    ),
+    (
+        Language::IT,
+        DateConventions {
+            short: "{dd}/{mm}/{Y}",
+            medium: "{d} {b} {Y}",
+            long: "{d} {B} {Y}",
+            abbreviated_months: [
+                "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+            ],
+            months: [
+                "gennaio",
+                "febbraio",
+                "marzo",
+                "aprile",
+                "maggio",
+                "giugno",
+                "luglio",
+                "agosto",
+                "settembre",
+                "ottobre",
+                "novembre",
+                "dicembre",
+            ],
+        },
+    ),
+    (
+        Language::PT,
+        DateConventions {
+            short: "{dd}/{mm}/{Y}",
+            medium: "{d} de {b} de {Y}",
+            long: "{d} de {B} de {Y}",
+            abbreviated_months: [
+                "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.",
+                "nov.", "dez.",
+            ],
+            months: [
+                "janeiro",
+                "fevereiro",
+                "março",
+                "abril",
+                "maio",
+                "junho",
+                "julho",
+                "agosto",
+                "setembro",
+                "outubro",
+                "novembro",
+                "dezembro",
+            ],
+        },
+    ),
+];
+
+#[cfg(feature = "chrono")]
+impl DateConventions {
+    /// Get the date formatting conventions for a language.
+    ///
+    /// **Arguments**
+    /// * `language` - The language to get the conventions for.
+    ///
+    /// **Returns**
+    /// The conventions for the language, or the default ones.
+    fn for_language(language: Option<&Language>) -> &'static Self {
+        language
+            .and_then(|language| {
+                DATE_CONVENTIONS
+                    .iter()
+                    .find(|(candidate, _)| candidate == language)
+            })
+            .map(|(_, conventions)| conventions)
+            .unwrap_or(&DEFAULT_DATE_CONVENTIONS)
+    }
+
+    /// Formats a stringified timestamp as a date.
+    ///
+    /// **Arguments**
+    /// * `value` - A unix timestamp in seconds or a string starting with an ISO
+    ///   8601 date.
+    /// * `style` - The date style to format the value with.
+    ///
+    /// **Returns**
+    /// The formatted date, or `None` if the value is not a timestamp.
+    fn format(&self, value: &str, style: DateStyle) -> Option<String> {
+        use chrono::{DateTime, Datelike, NaiveDate};
+
+        let value = value.trim();
+        let date = match value.parse::<i64>() {
+            Ok(timestamp) => DateTime::from_timestamp(timestamp, 0)?.date_naive(),
+            Err(_) => NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()?,
+        };
+
+        let pattern = match style {
+            DateStyle::Short => self.short,
+            DateStyle::Medium => self.medium,
+            DateStyle::Long => self.long,
+        };
+
+        let month = date.month0() as usize;
+
+        Some(
+            pattern
+                .replace("{dd}", &format!("{:02}", date.day()))
+                .replace("{mm}", &format!("{:02}", date.month()))
+                .replace(
+                    "{d}",
+                    &date
+                        .day()
+                        .to_string(),
+                )
+                .replace(
+                    "{m}",
+                    &date
+                        .month()
+                        .to_string(),
+                )
+                .replace(
+                    "{Y}",
+                    &date
+                        .year()
+                        .to_string(),
+                )
+                .replace("{b}", self.abbreviated_months[month])
+                .replace("{B}", self.months[month]),
+        )
+    }
+}
+
+/// Get the symbol for a currency code.
+///
+/// **Arguments**
+/// * `code` - The ISO 4217 currency code.
+///
+/// **Returns**
+/// The currency symbol if it's well known, otherwise the code itself.
+fn currency_symbol(code: &str) -> &str {
+    match code {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" => "¥",
+        other => other,
+    }
+}
+
+/// Format spec validation.
+///
+/// Checks whether a string is a format spec as written after
+/// the colon in `format!("{:.2}", value)`, with literal width
+/// and precision, which may be used after a template key such
+/// as `{pi:.2}`.
+///
+/// **Parameters**
+/// * `spec` - The format spec to check.
+///
+/// **Returns**
+/// Whether the spec can be used with Rust's formatting machinery.
+fn is_format_spec(spec: &str) -> bool {
+    let is_align = |c: char| matches!(c, '<' | '^' | '>');
+
+    let mut chars = spec.chars();
+    let mut rest = match (chars.next(), chars.next()) {
+        (Some(fill), Some(align)) if is_align(align) => &spec[fill.len_utf8() + 1..],
+        (Some(align), _) if is_align(align) => &spec[1..],
+        _ => spec,
+    };
+
+    rest = rest
+        .strip_prefix(['+', '-'])
+        .unwrap_or(rest);
+    rest = rest
+        .strip_prefix('#')
+        .unwrap_or(rest);
+    rest = rest.trim_start_matches(|c: char| c.is_ascii_digit());
+
+    if let Some(precision) = rest.strip_prefix('.') {
+        rest = precision.trim_start_matches(|c: char| c.is_ascii_digit());
+
+        if rest.len() == precision.len() {
+            return false;
+        }
+    }
+
+    !spec.is_empty() && matches!(rest, "" | "?" | "x?" | "X?" | "x" | "X" | "o" | "b" | "e" | "E")
+}
+
+/// Template type constraint.
+///
+/// Templates may be annotated with a type such as
+/// `{count:int}`, this restricts the values passed
+/// for that template to the types implementing
+/// the respective marker trait, which is checked
+/// in compile-time when the translation path is static.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, EnumString)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[strum(serialize_all = "lowercase")]
+pub enum TemplateConstraint {
+    /// Integer values, see [`IntTemplateValue`].
+    Int,
+
+    /// Floating point values, see [`FloatTemplateValue`].
+    Float,
+
+    /// String values, see [`StrTemplateValue`].
+    Str,
+}
+
+impl TemplateConstraint {
+    /// Marker trait path generation.
+    ///
+    /// **Returns**
+    /// The runtime path to the marker trait a value
+    /// must implement to satisfy this constraint.
+    pub fn trait_path(&self) -> TokenStream2 {
+        match self {
+            Self::Int => quote! { translatable::shared::misc::templating::IntTemplateValue },
+            Self::Float => quote! { translatable::shared::misc::templating::FloatTemplateValue },
+            Self::Str => quote! { translatable::shared::misc::templating::StrTemplateValue },
+        }
+    }
+}
+
+/// Integer template value marker.
+///
+/// Implemented for all the primitive integer types,
+/// values for `{key:int}` templates must implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as an `int` template value",
+    label = "this template is constrained to integers"
+)]
+pub trait IntTemplateValue {}
+
+/// Floating point template value marker.
+///
+/// Implemented for the primitive floating point types,
+/// values for `{key:float}` templates must implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a `float` template value",
+    label = "this template is constrained to floating point numbers"
+)]
+pub trait FloatTemplateValue {}
+
+/// String template value marker.
+///
+/// Implemented for the string types,
+/// values for `{key:str}` templates must implement it.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be used as a `str` template value",
+    label = "this template is constrained to strings"
+)]
+pub trait StrTemplateValue {}
+
+macro_rules! impl_template_value {
+    ($trait:ident: $($ty:ty),*) => {
+        $(impl $trait for $ty {})*
+    };
+}
+
+impl_template_value!(IntTemplateValue: i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_template_value!(FloatTemplateValue: f32, f64);
+impl_template_value!(StrTemplateValue: str, String, char);
+
+impl<T: IntTemplateValue + ?Sized> IntTemplateValue for &T {}
+impl<T: FloatTemplateValue + ?Sized> FloatTemplateValue for &T {}
+impl<T: StrTemplateValue + ?Sized> StrTemplateValue for &T {}
+
+/// Placeholder delimiters.
+///
+/// The strings that open and close a template,
+/// `{` and `}` by default. Writing the opening
+/// delimiter twice in a row escapes it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlaceholderDelimiters {
+    /// The string that opens a template.
+    open: String,
+
+    /// The string that closes a template.
+    close: String,
+}
+
+impl PlaceholderDelimiters {
+    /// Create new [`PlaceholderDelimiters`].
+    ///
+    /// **Parameters**
+    /// * `open` - The string that opens a template.
+    /// * `close` - The string that closes a template.
+    ///
+    /// **Returns**
+    /// The delimiters, or `None` if any of them is empty
+    /// or both are the same, as templates couldn't be told
+    /// apart from the surrounding text.
+    pub fn new(open: &str, close: &str) -> Option<Self> {
+        if open.is_empty() || close.is_empty() || open == close {
+            return None;
+        }
+
+        Some(Self::from_data(open, close))
+    }
+
+    /// Compile-time to runtime transformation function.
+    ///
+    /// Same as [`new`] but without validating the
+    /// delimiters, meant to be used from macro output.
+    ///
+    /// **Parameters**
+    /// * `open` - The string that opens a template.
+    /// * `close` - The string that closes a template.
+    ///
+    /// **Returns**
+    /// The delimiters as provided.
+    ///
+    /// [`new`]: PlaceholderDelimiters::new
+    pub fn from_data(open: &str, close: &str) -> Self {
+        Self {
+            open: open.to_string(),
+            close: close.to_string(),
+        }
+    }
+
+    /// Opening delimiter getter.
+    ///
+    /// **Returns**
+    /// The string that opens a template.
+    pub fn open(&self) -> &str {
+        &self.open
+    }
+
+    /// Closing delimiter getter.
+    ///
+    /// **Returns**
+    /// The string that closes a template.
+    pub fn close(&self) -> &str {
+        &self.close
+    }
+}
+
+/// Default delimiters implementation.
+///
+/// Templates are delimited by braces
+/// such as `{name}` by default.
+impl Default for PlaceholderDelimiters {
+    fn default() -> Self {
+        Self::from_data("{", "}")
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// This implementation generates a call to the [`from_data`]
+/// function in [`PlaceholderDelimiters`].
+///
+/// [`from_data`]: PlaceholderDelimiters::from_data
+impl ToTokens for PlaceholderDelimiters {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let open = &self.open;
+        let close = &self.close;
+
+        tokens.append_all(quote! {
+            translatable::shared::misc::templating::PlaceholderDelimiters::from_data(
+                #open,
+                #close
+            )
+        });
+    }
+}
+
+/// Template values lookup.
+///
+/// Implemented by the values the templates of a
+/// [`FormatString`] can be replaced with, such as
+/// a `HashMap<String, String>` or [`Args`].
+pub trait TemplateValues {
+    /// Template value getter.
+    ///
+    /// **Parameters**
+    /// * `key` - The template key, a name or a positional index such as `0`.
+    ///
+    /// **Returns**
+    /// The value for the template if there is any.
+    fn value(&self, key: &str) -> Option<&String>;
+}
+
+/// Named values implementation.
+///
+/// Every template is looked up by its key,
+/// positional templates by their index as is.
+impl TemplateValues for HashMap<String, String> {
+    fn value(&self, key: &str) -> Option<&String> {
+        self.get(key)
+    }
+}
+
+/// Template replacement arguments.
+///
+/// Holds both positional values, which replace the
+/// templates keyed by their index such as `{0}`, and
+/// named values, which replace the templates keyed by
+/// their name such as `{user}`, for example
+/// `Args::new().arg(user).named("count", count)`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Args {
+    /// The values replacing the templates by index.
+    positional: Vec<String>,
+
+    /// The values replacing the templates by name.
+    named: HashMap<String, String>,
+}
+
+impl Args {
+    /// Create empty [`Args`].
+    ///
+    /// **Returns**
+    /// Arguments without any value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a positional value.
+    ///
+    /// The value replaces the template keyed by the
+    /// amount of positional values added before it.
+    ///
+    /// **Parameters**
+    /// * `value` - The value to add.
+    ///
+    /// **Returns**
+    /// The same arguments along the value.
+    pub fn arg(mut self, value: impl ToString) -> Self {
+        self.positional
+            .push(value.to_string());
+        self
+    }
+
+    /// Add a named value.
+    ///
+    /// **Parameters**
+    /// * `key` - The template key the value replaces.
+    /// * `value` - The value to add.
+    ///
+    /// **Returns**
+    /// The same arguments along the value.
+    pub fn named(mut self, key: impl ToString, value: impl ToString) -> Self {
+        self.named
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Positional values getter.
+    ///
+    /// **Returns**
+    /// The values replacing the templates by index.
+    pub fn positional(&self) -> &[String] {
+        &self.positional
+    }
+
+    /// Named values getter.
+    ///
+    /// **Returns**
+    /// The values replacing the templates by name.
+    pub fn named_values(&self) -> &HashMap<String, String> {
+        &self.named
+    }
+
+    /// Checks whether a template has a value.
+    ///
+    /// **Parameters**
+    /// * `key` - The template key, a name or a positional index such as `0`.
+    ///
+    /// **Returns**
+    /// Whether there is a value for the template.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.value(key)
+            .is_some()
+    }
+}
+
+/// Positional and named values implementation.
+///
+/// Named values are looked up first, then templates
+/// keyed by an index are looked up in the positional
+/// values.
+impl TemplateValues for Args {
+    fn value(&self, key: &str) -> Option<&String> {
+        self.named
+            .get(key)
+            .or_else(|| {
+                Some(key)
+                    .filter(|key| is_positional_key(key))
+                    .and_then(|key| {
+                        key.parse::<usize>()
+                            .ok()
+                    })
+                    .and_then(|index| {
+                        self.positional
+                            .get(index)
+                    })
+            })
+    }
+}
+
+/// Named values conversion implementation.
+impl From<HashMap<String, String>> for Args {
+    fn from(named: HashMap<String, String>) -> Self {
+        Self { positional: Vec::new(), named }
+    }
+}
+
+/// Named values collection implementation.
+impl FromIterator<(String, String)> for Args {
+    fn from_iter<T: IntoIterator<Item = (String, String)>>(iter: T) -> Self {
+        Self::from(
+            iter.into_iter()
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+}
+
+/// Named values extension implementation.
+///
+/// The provided values take precedence over
+/// the existing values with the same key.
+impl Extend<(String, String)> for Args {
+    fn extend<T: IntoIterator<Item = (String, String)>>(&mut self, iter: T) {
+        self.named
+            .extend(iter);
+    }
+}
+
+/// Arguments extension implementation.
+///
+/// The positional values are appended after
+/// the existing ones, and the named values take
+/// precedence over the existing values with the
+/// same key.
+impl Extend<Args> for Args {
+    fn extend<T: IntoIterator<Item = Args>>(&mut self, iter: T) {
+        for args in iter {
+            self.positional
+                .extend(args.positional);
+            self.named
+                .extend(args.named);
+        }
+    }
+}
+
+/// Conditional directive check.
+///
+/// **Arguments**
+/// * `directive` - The template text after the key.
+/// * `open` - The string that opens a block.
+///
+/// **Returns**
+/// Whether the directive is an `if` conditional, whose
+/// blocks are delimited as the templates are.
+fn is_conditional_directive(directive: &str, open: &str) -> bool {
+    directive
+        .trim_start()
+        .strip_prefix("if")
+        .is_some_and(|rest| {
+            rest.is_empty() || rest.starts_with(char::is_whitespace) || rest.starts_with(open)
+        })
+}
+
+/// Splits a delimited block.
+///
+/// **Arguments**
+/// * `s` - The text starting with the block.
+/// * `open` - The string that opens a block.
+/// * `close` - The string that closes a block.
+///
+/// **Returns**
+/// The block contents and the text after it, or `None`
+/// if the text doesn't start with a balanced block.
+fn split_block<'a>(s: &'a str, open: &str, close: &str) -> Option<(String, &'a str)> {
+    let mut rest = s.strip_prefix(open)?;
+    let mut depth = 1usize;
+    let mut contents = String::new();
+
+    while let Some(c) = rest
+        .chars()
+        .next()
+    {
+        if rest.starts_with(close) {
+            depth -= 1;
+
+            if depth == 0 {
+                return Some((contents, &rest[close.len()..]));
+            }
+
+            contents.push_str(close);
+            rest = &rest[close.len()..];
+        } else if rest.starts_with(open) {
+            depth += 1;
+            contents.push_str(open);
+            rest = &rest[open.len()..];
+        } else {
+            contents.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+
+    None
+}
+
+/// Positional template key check.
+///
+/// **Arguments**
+/// * `key` - The template key.
+///
+/// **Returns**
+/// Whether the key is an index such as `0`, which
+/// are replaced by the positional values of [`Args`].
+fn is_positional_key(key: &str) -> bool {
+    !key.is_empty()
+        && key
+            .chars()
+            .all(|char| char.is_ascii_digit())
 }
 
 /// Format string wrapper struct.
@@ -47,6 +1380,8 @@ pub enum TemplateError {
 /// with each respective position for
 /// the sake of replacing these positions
 /// with read data.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FormatString {
     /// Original templated string.
     ///
@@ -56,7 +1391,10 @@ pub struct FormatString {
     ///
     /// This should never be mutated for the sake
     /// of keeping the alignment with `self.spans`.
-    original: String,
+    ///
+    /// Strings constructed from the macro output borrow
+    /// the embedded literal instead of allocating.
+    original: Cow<'static, str>,
 
     /// Template spans.
     ///
@@ -68,6 +1406,132 @@ pub struct FormatString {
     /// want to allow multiple templates with
     /// the same key.
     spans: Vec<(String, Range<usize>)>,
+
+    /// Template type constraints.
+    ///
+    /// This vector contains the type each
+    /// annotated template is constrained to.
+    ///
+    /// This is only used for compile-time
+    /// validation, thus it's not kept when
+    /// converted to runtime.
+    constraints: Vec<(String, TemplateConstraint)>,
+
+    /// Template formatting directives.
+    ///
+    /// This vector contains the directive for each
+    /// template that has one, keyed by the start of
+    /// its span in `self.spans`.
+    directives: Vec<(usize, TemplateDirective)>,
+
+    /// Template default values.
+    ///
+    /// This vector contains the default value for each
+    /// template that declares one such as `{name=Guest}`,
+    /// keyed by the start of its span in `self.spans`.
+    defaults: Vec<(usize, String)>,
+
+    /// Template format specs.
+    ///
+    /// This vector contains the format spec for each
+    /// template that declares one such as `{pi:.2}`,
+    /// keyed by the start of its span in `self.spans`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    formats: Vec<(usize, String)>,
+
+    /// Translation references.
+    ///
+    /// This vector contains the path segments of
+    /// each reference to another translation such as
+    /// `@{brand::name}` along its span in the original
+    /// string, including the `@`.
+    ///
+    /// References are expanded while loading the
+    /// translations, thus these are not kept when
+    /// converted to runtime.
+    #[cfg_attr(feature = "serde", serde(default))]
+    references: Vec<(Vec<String>, Range<usize>)>,
+
+    /// The language this string is written in.
+    ///
+    /// Used by the formatting directives, if unknown
+    /// the default formatting conventions are used.
+    language: Option<Language>,
+
+    /// Whether replaced values are isolated.
+    ///
+    /// If set and the language is written right to
+    /// left, each replaced value is wrapped in Unicode
+    /// directional isolates.
+    #[cfg_attr(feature = "serde", serde(default))]
+    bidi_isolate: bool,
+
+    /// Raw templates.
+    ///
+    /// This vector contains the start of the span of
+    /// each template opted out of HTML escaping such
+    /// as `{raw!content}`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    raw: Vec<usize>,
+
+    /// Whether replaced values are HTML-escaped.
+    ///
+    /// If set, [`replace_with`] behaves as [`replace_with_escaped`].
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    /// [`replace_with_escaped`]: FormatString::replace_with_escaped
+    #[cfg_attr(feature = "serde", serde(default))]
+    escape_html: bool,
+}
+
+/// Escapes the HTML special characters of a value.
+///
+/// **Parameters**
+/// * `value` - The value to escape.
+///
+/// **Returns**
+/// The value with `&`, `<`, `>`, `"` and `'` replaced
+/// by their character references.
+fn escape_html(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Accents a character for pseudolocalization.
+///
+/// **Parameters**
+/// * `c` - The character to accent.
+///
+/// **Returns**
+/// An accented look-alike of the ASCII letters,
+/// any other character is returned as is.
+fn pseudo_char(c: char) -> char {
+    const LOWERCASE: [char; 26] = [
+        'á', 'ƀ', 'ç', 'ð', 'é', 'ƒ', 'ĝ', 'ĥ', 'î', 'ĵ', 'ķ', 'ļ', 'ɱ', 'ñ', 'ö', 'þ', 'ǫ', 'ŕ',
+        'š', 'ţ', 'û', 'ṽ', 'ŵ', 'ẋ', 'ý', 'ž',
+    ];
+    const UPPERCASE: [char; 26] = [
+        'Å', 'Ɓ', 'Ç', 'Ð', 'É', 'Ƒ', 'Ĝ', 'Ĥ', 'Î', 'Ĵ', 'Ķ', 'Ļ', 'Ṁ', 'Ñ', 'Ö', 'Þ', 'Ǫ', 'Ŕ',
+        'Š', 'Ţ', 'Û', 'Ṽ', 'Ŵ', 'Ẋ', 'Ý', 'Ž',
+    ];
+
+    match c {
+        'a'..='z' => LOWERCASE[c as usize - 'a' as usize],
+        'A'..='Z' => UPPERCASE[c as usize - 'A' as usize],
+        c => c,
+    }
 }
 
 impl FormatString {
@@ -84,30 +1548,267 @@ impl FormatString {
     /// to work.
     ///
     /// **Parameters**
-    /// * `original` - What belongs to the `original` field.
-    /// * `spans` - What belongs to the `spans` field.
+    /// * `original` - What belongs to the `original` field.
+    /// * `spans` - What belongs to the `spans` field.
+    ///
+    /// **Returns**
+    /// An instance of self based on the provided parameters.
+    pub fn from_data(original: &'static str, spans: Vec<(String, Range<usize>)>) -> Self {
+        Self {
+            original: Cow::Borrowed(original),
+            spans,
+            constraints: Vec::new(),
+            directives: Vec::new(),
+            defaults: Vec::new(),
+            formats: Vec::new(),
+            references: Vec::new(),
+            language: None,
+            bidi_isolate: false,
+            raw: Vec::new(),
+            escape_html: false,
+        }
+    }
+
+    /// Creates a string without templates.
+    ///
+    /// The string is kept verbatim, delimiters included,
+    /// so [`replace_with`] always returns it as is. Used for
+    /// translations declared with the `@raw` metadata key.
+    ///
+    /// **Parameters**
+    /// * `s` - The string to keep.
+    ///
+    /// **Returns**
+    /// An instance of self without templates nor references.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn literal(s: &str) -> Self {
+        Self {
+            original: Cow::Owned(s.to_string()),
+            ..Self::from_data("", Vec::new())
+        }
+    }
+
+    /// Language setter.
+    ///
+    /// Sets the language this string is written in,
+    /// which is used to apply the formatting directives.
+    ///
+    /// **Parameters**
+    /// * `language` - The language this string is written in.
+    ///
+    /// **Returns**
+    /// The same instance with the language set.
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Directional isolation setter.
+    ///
+    /// Sets whether the replaced values are wrapped in
+    /// Unicode directional isolates, only if the language
+    /// of this string is written right to left.
+    ///
+    /// **Parameters**
+    /// * `bidi_isolate` - Whether the replaced values are isolated.
+    ///
+    /// **Returns**
+    /// The same instance with the directional isolation set.
+    pub fn with_bidi_isolate(mut self, bidi_isolate: bool) -> Self {
+        self.set_bidi_isolate(bidi_isolate);
+        self
+    }
+
+    /// Directional isolation mutable setter.
+    ///
+    /// Same as [`with_bidi_isolate`] but in place, used
+    /// while loading the translations.
+    ///
+    /// **Parameters**
+    /// * `bidi_isolate` - Whether the replaced values are isolated.
+    ///
+    /// [`with_bidi_isolate`]: FormatString::with_bidi_isolate
+    pub fn set_bidi_isolate(&mut self, bidi_isolate: bool) {
+        self.bidi_isolate = bidi_isolate;
+    }
+
+    /// HTML escaping setter.
+    ///
+    /// Sets whether the replaced values are HTML-escaped
+    /// by [`replace_with`], except for raw templates.
+    ///
+    /// **Parameters**
+    /// * `escape_html` - Whether the replaced values are escaped.
+    ///
+    /// **Returns**
+    /// The same instance with the HTML escaping set.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn with_escape_html(mut self, escape_html: bool) -> Self {
+        self.set_escape_html(escape_html);
+        self
+    }
+
+    /// HTML escaping mutable setter.
+    ///
+    /// Same as [`with_escape_html`] but in place, used
+    /// while loading the translations.
+    ///
+    /// **Parameters**
+    /// * `escape_html` - Whether the replaced values are escaped.
+    ///
+    /// [`with_escape_html`]: FormatString::with_escape_html
+    pub fn set_escape_html(&mut self, escape_html: bool) {
+        self.escape_html = escape_html;
+    }
+
+    /// Raw templates setter.
+    ///
+    /// This function is meant to be used from macro output
+    /// along [`from_data`], the same alignment rules apply.
+    ///
+    /// **Parameters**
+    /// * `raw` - What belongs to the `raw` field.
+    ///
+    /// **Returns**
+    /// The same instance with the raw templates set.
+    ///
+    /// [`from_data`]: FormatString::from_data
+    pub fn with_raw(mut self, raw: Vec<usize>) -> Self {
+        self.raw = raw;
+        self
+    }
+
+    /// Formatting directives setter.
+    ///
+    /// This function is meant to be used from macro output
+    /// along [`from_data`], the same alignment rules apply.
+    ///
+    /// **Parameters**
+    /// * `directives` - What belongs to the `directives` field.
+    ///
+    /// **Returns**
+    /// The same instance with the directives set.
+    ///
+    /// [`from_data`]: FormatString::from_data
+    pub fn with_directives(mut self, directives: Vec<(usize, TemplateDirective)>) -> Self {
+        self.directives = directives;
+        self
+    }
+
+    /// Default values setter.
+    ///
+    /// This function is meant to be used from macro output
+    /// along [`from_data`], the same alignment rules apply.
+    ///
+    /// **Parameters**
+    /// * `defaults` - What belongs to the `defaults` field.
+    ///
+    /// **Returns**
+    /// The same instance with the defaults set.
+    ///
+    /// [`from_data`]: FormatString::from_data
+    pub fn with_defaults(mut self, defaults: Vec<(usize, String)>) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Format specs setter.
+    ///
+    /// This function is meant to be used from macro output
+    /// along [`from_data`], the same alignment rules apply.
+    ///
+    /// **Parameters**
+    /// * `formats` - What belongs to the `formats` field.
     ///
     /// **Returns**
-    /// An instance of self based on the provided parameters.
-    pub fn from_data(original: &str, spans: Vec<(String, Range<usize>)>) -> Self {
-        Self { original: original.to_string(), spans }
+    /// The same instance with the format specs set.
+    ///
+    /// [`from_data`]: FormatString::from_data
+    pub fn with_formats(mut self, formats: Vec<(usize, String)>) -> Self {
+        self.formats = formats;
+        self
     }
 
     /// Creates replaced original string copy.
     ///
     /// This method takes the original string, and replaces
     /// it's templates with the values of the values provided
-    /// as a hashmap.
+    /// as a hashmap or as [`Args`], which also holds the
+    /// values of positional templates such as `{0}`.
+    ///
+    /// If a template has a formatting directive, the value
+    /// is formatted according to the language of this string.
+    ///
+    /// If a template has no value but declares a default,
+    /// the default is used as its value.
+    ///
+    /// If a template declares a format spec such as `{pi:.2}`,
+    /// the value keyed as `pi:.2` is preferred over the one
+    /// keyed as `pi`, the macros provide it already formatted.
+    ///
+    /// If directional isolation is set and this string is
+    /// written in a right-to-left language, each value is
+    /// wrapped between a first strong isolate (U+2068) and
+    /// a pop directional isolate (U+2069), so left-to-right
+    /// values such as names or numbers don't reorder the
+    /// surrounding text.
+    ///
+    /// If HTML escaping is set, the values are escaped
+    /// the same way [`replace_with_escaped`] does.
+    ///
+    /// **Parameters**
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// A copy of the original string with it's templates replaced.
+    ///
+    /// [`replace_with_escaped`]: FormatString::replace_with_escaped
+    pub fn replace_with(&self, values: &impl TemplateValues) -> String {
+        self.replace(values, self.escape_html)
+    }
+
+    /// Creates replaced original string copy, HTML-escaping the values.
+    ///
+    /// Same as [`replace_with`] but the provided values are
+    /// HTML-escaped, so user provided values can be safely
+    /// interpolated in HTML. The string itself and the template
+    /// defaults are not escaped, neither are the values of raw
+    /// templates such as `{raw!content}`.
+    ///
+    /// **Parameters**
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// A copy of the original string with it's templates replaced.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn replace_with_escaped(&self, values: &impl TemplateValues) -> String {
+        self.replace(values, true)
+    }
+
+    /// [`replace_with`] and [`replace_with_escaped`] implementation.
     ///
     /// **Parameters**
     /// * `values` - The values to replace the templates with.
+    /// * `escape` - Whether the provided values are HTML-escaped.
     ///
     /// **Returns**
     /// A copy of the original string with it's templates replaced.
-    pub fn replace_with(&self, values: &HashMap<String, String>) -> String {
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    /// [`replace_with_escaped`]: FormatString::replace_with_escaped
+    fn replace(&self, values: &impl TemplateValues, escape: bool) -> String {
+        let isolate = self.bidi_isolate
+            && self
+                .language
+                .as_ref()
+                .is_some_and(Language::is_rtl);
+
         let mut original = self
             .original
-            .clone();
+            .to_string();
 
         let mut spans = self
             .spans
@@ -117,11 +1818,58 @@ impl FormatString {
         let mut offset = 0isize;
 
         for (key, range) in spans {
-            if let Some(value) = values.get(&key) {
+            let directive = self
+                .directives
+                .iter()
+                .find(|(start, _)| *start == range.start)
+                .map(|(_, directive)| directive);
+
+            // conditional blocks are written along the translation.
+            let literal = directive.is_some_and(TemplateDirective::is_conditional);
+
+            // only the provided values are escaped, the defaults are
+            // written along the translation.
+            let escape = escape
+                && !literal
+                && values
+                    .value(&key)
+                    .is_some()
+                && !self
+                    .raw
+                    .contains(&range.start);
+
+            let value = self
+                .formats
+                .iter()
+                .find(|(start, _)| *start == range.start)
+                .and_then(|(_, format)| values.value(&format!("{key}:{format}")))
+                .or_else(|| values.value(&key))
+                .or_else(|| {
+                    self.defaults
+                        .iter()
+                        .find(|(start, _)| *start == range.start)
+                        .map(|(_, default)| default)
+                });
+
+            if let Some(value) = value {
+                let value = directive
+                    .and_then(|directive| {
+                        directive.apply(
+                            value,
+                            self.language
+                                .as_ref(),
+                        )
+                    })
+                    .unwrap_or_else(|| value.clone());
+
+                let value = if escape { escape_html(&value) } else { value };
+                let value =
+                    if isolate && !literal { format!("\u{2068}{value}\u{2069}") } else { value };
+
                 let start = (range.start as isize + offset) as usize;
                 let end = (range.end as isize + offset) as usize;
 
-                original.replace_range(start..end, value);
+                original.replace_range(start..end, &value);
 
                 offset += value.len() as isize - (range.end - range.start) as isize;
             }
@@ -130,6 +1878,78 @@ impl FormatString {
         original
     }
 
+    /// Creates replaced original string copy, requiring every value.
+    ///
+    /// Same as [`replace_with`] but instead of leaving the
+    /// templates without a value untouched, it fails.
+    ///
+    /// **Parameters**
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(String)` - A copy of the original string with it's templates
+    ///   replaced.
+    /// * `Err(TemplateError::MissingValue)` - If a template has neither a value
+    ///   nor a default.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn replace_with_strict(
+        &self,
+        values: &impl TemplateValues,
+    ) -> Result<String, TemplateError> {
+        if let Some(missing) = self
+            .required_placeholders()
+            .into_iter()
+            .find(|placeholder| {
+                values
+                    .value(placeholder)
+                    .is_none()
+            })
+        {
+            return Err(TemplateError::MissingValue(missing));
+        }
+
+        Ok(self.replace_with(values))
+    }
+
+    /// Creates replaced original string, borrowing if possible.
+    ///
+    /// Same as [`replace_with`] but if no template has
+    /// a value nor a default the original string is returned
+    /// as is, which doesn't allocate for strings embedded by
+    /// the macros, such as translations without templates.
+    ///
+    /// **Parameters**
+    /// * `values` - The values to replace the templates with.
+    ///
+    /// **Returns**
+    /// The original string if nothing was replaced, otherwise
+    /// an owned copy with it's templates replaced.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn replace_with_cow(&self, values: &impl TemplateValues) -> Cow<'static, str> {
+        let replaced = self
+            .spans
+            .iter()
+            .any(|(key, range)| {
+                values
+                    .value(key)
+                    .is_some()
+                    || self
+                        .defaults
+                        .iter()
+                        .any(|(start, _)| *start == range.start)
+            });
+
+        if replaced {
+            Cow::Owned(self.replace_with(values))
+        } else {
+            self.original
+                .clone()
+        }
+    }
+
     /// Original string getter.
     ///
     /// **Returns**
@@ -137,75 +1957,639 @@ impl FormatString {
     pub fn original(&self) -> &str {
         &self.original
     }
-}
 
-/// Parse method implementation.
-///
-/// This implementation leads to the implementation
-/// of the `parse` method for [`FormatString`] which
-/// parses all the templates on the string and stores
-/// them in a structure along the original string for
-/// future replacement.
-impl FromStr for FormatString {
-    type Err = TemplateError;
+    /// Template presence check.
+    ///
+    /// **Returns**
+    /// Whether this string contains any template, if it
+    /// doesn't, [`replace_with`] always returns the original.
+    ///
+    /// [`replace_with`]: FormatString::replace_with
+    pub fn has_templates(&self) -> bool {
+        !self
+            .spans
+            .is_empty()
+    }
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
+    /// Template usage check.
+    ///
+    /// **Parameters**
+    /// * `key` - The template key to look for.
+    ///
+    /// **Returns**
+    /// Whether this string contains a template keyed with `key`.
+    pub fn uses_template(&self, key: &str) -> bool {
+        self.spans
+            .iter()
+            .any(|(template, _)| template == key)
+    }
+
+    /// Template keys getter.
+    ///
+    /// Escaped braces are not templates, thus these
+    /// are never listed.
+    ///
+    /// **Returns**
+    /// The distinct keys of the templates in this string,
+    /// in the order they first appear.
+    pub fn placeholders(&self) -> Vec<String> {
+        let mut spans = self
+            .spans
+            .iter()
+            .collect::<Vec<_>>();
+        spans.sort_by_key(|(_key, range)| range.start);
+
+        let mut placeholders = Vec::<String>::new();
+
+        for (key, _) in spans {
+            if !placeholders.contains(key) {
+                placeholders.push(key.clone());
+            }
+        }
+
+        placeholders
+    }
+
+    /// Required template keys getter.
+    ///
+    /// Same as [`placeholders`] but without the keys that
+    /// declare a default in every template they appear in,
+    /// as these are optional.
+    ///
+    /// **Returns**
+    /// The distinct keys of the templates that require a value,
+    /// in the order they first appear.
+    ///
+    /// [`placeholders`]: FormatString::placeholders
+    pub fn required_placeholders(&self) -> Vec<String> {
+        self.placeholders()
+            .into_iter()
+            .filter(|placeholder| {
+                self.spans
+                    .iter()
+                    .filter(|(key, _)| key == placeholder)
+                    .any(|(_, range)| {
+                        !self
+                            .defaults
+                            .iter()
+                            .any(|(start, _)| *start == range.start)
+                    })
+            })
+            .collect()
+    }
+
+    /// Language getter.
+    ///
+    /// **Returns**
+    /// The language this string is written in, if known.
+    pub fn language(&self) -> Option<&Language> {
+        self.language
+            .as_ref()
+    }
+
+    /// Translation references getter.
+    ///
+    /// **Returns**
+    /// The path segments of each translation this
+    /// string references, in the order they appear.
+    pub fn references(&self) -> Vec<&[String]> {
+        let mut references = self
+            .references
+            .iter()
+            .collect::<Vec<_>>();
+        references.sort_by_key(|(_path, range)| range.start);
+
+        references
+            .into_iter()
+            .map(|(path, _)| path.as_slice())
+            .collect()
+    }
+
+    /// Expands the translation references.
+    ///
+    /// Each reference is replaced with the original string
+    /// obtained from `resolve`, then the result is parsed
+    /// again so the templates of the referenced strings are
+    /// templates of the expanded string too. The language,
+    /// directional isolation and HTML escaping are kept.
+    ///
+    /// **Parameters**
+    /// * `delimiters` - The placeholder delimiters to parse the result with.
+    /// * `resolve` - Obtains the original string of a referenced path.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(FormatString)` - The string with its references expanded.
+    /// * `Err(E)` - The first error returned by `resolve`, or a
+    ///   [`TemplateError`] if the expanded string is not valid.
+    pub fn expand_references<E, F>(
+        &self,
+        delimiters: &PlaceholderDelimiters,
+        mut resolve: F,
+    ) -> Result<Self, E>
+    where
+        E: From<TemplateError>,
+        F: FnMut(&[String]) -> Result<String, E>,
+    {
+        if self
+            .references
+            .is_empty()
+        {
+            return Ok(self.clone());
+        }
+
+        let mut references = self
+            .references
+            .clone();
+        references.sort_by_key(|(_path, range)| range.start);
+
+        let mut expanded = String::new();
+        let mut last = 0;
+
+        for (path, range) in references {
+            expanded.push_str(&self.original[last..range.start]);
+            expanded.push_str(&resolve(&path)?);
+            last = range.end;
+        }
+
+        expanded.push_str(&self.original[last..]);
+
+        let mut format_string = Self::parse_with(&expanded, delimiters)?;
+        format_string.language = self
+            .language
+            .clone();
+        format_string.bidi_isolate = self.bidi_isolate;
+        format_string.escape_html = self.escape_html;
+
+        Ok(format_string)
+    }
+
+    /// Pseudolocalized copy.
+    ///
+    /// Transforms the text of this string to test layouts
+    /// with longer, accented translations, so `Save {name}`
+    /// becomes `[Šáṽé {name} ~~]`. The letters are accented,
+    /// the text is padded by about 30% of its letters with `~`
+    /// and the result is wrapped in brackets.
+    ///
+    /// Templates, references and HTML tags are kept as is,
+    /// along their defaults and formatting directives.
+    ///
+    /// **Returns**
+    /// The pseudolocalized string.
+    pub fn pseudolocalize(&self) -> Self {
+        let mut protected = self
+            .spans
+            .iter()
+            .map(|(_, range)| range.clone())
+            .chain(
+                self.references
+                    .iter()
+                    .map(|(_, range)| range.clone()),
+            )
+            .collect::<Vec<_>>();
+        protected.sort_by_key(|range| range.start);
+
+        let mut original = String::from("[");
+        let mut starts = Vec::new();
+        let mut letters = 0usize;
+        let mut in_tag = false;
+        let mut last = 0;
+
+        let mut push_text = |original: &mut String, text: &str| {
+            for c in text.chars() {
+                match c {
+                    '<' => in_tag = true,
+                    '>' => in_tag = false,
+                    c if !in_tag && c.is_ascii_alphabetic() => letters += 1,
+                    _ => {},
+                }
+
+                original.push(if in_tag { c } else { pseudo_char(c) });
+            }
+        };
+
+        for range in protected {
+            push_text(&mut original, &self.original[last..range.start]);
+            starts.push((range.start, original.len()));
+            original.push_str(&self.original[range.clone()]);
+            last = range.end;
+        }
+
+        push_text(&mut original, &self.original[last..]);
+
+        let padding = (letters * 3).div_ceil(10);
+        if padding > 0 {
+            original.push(' ');
+            original.push_str(&"~".repeat(padding));
+        }
+        original.push(']');
+
+        let shift = |start: usize| {
+            starts
+                .iter()
+                .find(|(old, _)| *old == start)
+                .map_or(start, |(_, new)| *new)
+        };
+        let shift_range = |range: &Range<usize>| {
+            let start = shift(range.start);
+            start..start + range.len()
+        };
+
+        Self {
+            original: Cow::Owned(original),
+            spans: self
+                .spans
+                .iter()
+                .map(|(key, range)| (key.clone(), shift_range(range)))
+                .collect(),
+            constraints: self
+                .constraints
+                .clone(),
+            directives: self
+                .directives
+                .iter()
+                .map(|(start, directive)| (shift(*start), directive.clone()))
+                .collect(),
+            defaults: self
+                .defaults
+                .iter()
+                .map(|(start, default)| (shift(*start), default.clone()))
+                .collect(),
+            formats: self
+                .formats
+                .iter()
+                .map(|(start, format)| (shift(*start), format.clone()))
+                .collect(),
+            references: self
+                .references
+                .iter()
+                .map(|(path, range)| (path.clone(), shift_range(range)))
+                .collect(),
+            language: self
+                .language
+                .clone(),
+            bidi_isolate: self.bidi_isolate,
+            raw: self
+                .raw
+                .iter()
+                .map(|start| shift(*start))
+                .collect(),
+            escape_html: self.escape_html,
+        }
+    }
+
+    /// Template type constraints getter.
+    ///
+    /// Constraints are only available from parsed
+    /// format strings, these are not kept in runtime.
+    ///
+    /// **Returns**
+    /// The templates that were annotated with a type
+    /// along their respective constraint.
+    pub fn constraints(&self) -> &[(String, TemplateConstraint)] {
+        &self.constraints
+    }
+
+    /// Template format specs getter.
+    ///
+    /// **Returns**
+    /// The distinct format specs declared in this string
+    /// such as `.2` in `{pi:.2}`, along their template key.
+    pub fn format_specs(&self) -> Vec<(String, String)> {
+        let mut specs = Vec::<(String, String)>::new();
+
+        for (start, format) in &self.formats {
+            if let Some((key, _)) = self
+                .spans
+                .iter()
+                .find(|(_, range)| range.start == *start)
+            {
+                let spec = (key.clone(), format.clone());
+
+                if !specs.contains(&spec) {
+                    specs.push(spec);
+                }
+            }
+        }
+
+        specs
+    }
+}
+
+impl FormatString {
+    /// Parse a string with custom placeholder delimiters.
+    ///
+    /// Same as [`parse`] but templates are delimited by the
+    /// provided delimiters instead of braces, so `%{name}`
+    /// is a template with `%{` and `}` as delimiters.
+    ///
+    /// **Parameters**
+    /// * `s` - The string to parse.
+    /// * `delimiters` - The placeholder delimiters.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(FormatString)` - The parsed string.
+    /// * `Err(TemplateError)` - If a template is unclosed or invalid.
+    ///
+    /// [`parse`]: str::parse
+    pub fn parse_with(s: &str, delimiters: &PlaceholderDelimiters) -> Result<Self, TemplateError> {
         let original = s.to_string();
         let mut spans = Vec::new();
+        let mut constraints = Vec::new();
+        let mut directives = Vec::new();
+        let mut defaults = Vec::new();
+        let mut formats = Vec::new();
+        let mut references = Vec::new();
+        let mut raw = Vec::new();
 
-        let char_to_byte = s
-            .char_indices()
-            .map(|(i, _)| i)
-            .collect::<Vec<usize>>();
+        let open = delimiters.open();
+        let close = delimiters.close();
 
-        let mut last_bracket_idx = None;
+        let mut last_bracket_idx = None::<usize>;
+        let mut is_reference = false;
+        let mut block_depth = 0usize;
         let mut current_tmpl_key = String::new();
-        for (char_idx, c) in original
+        let mut byte_idx = 0;
+
+        while let Some(c) = s[byte_idx..]
             .chars()
-            .enumerate()
+            .next()
         {
-            match (c, last_bracket_idx) {
-                // if last template index is the last character
-                // ignore current as is escaped.
-                ('{', Some(prev)) if prev == char_idx.saturating_sub(1) => last_bracket_idx = None,
-                // if last template index is anything but the last character
-                // set it as last index.
-                ('{', _) => last_bracket_idx = Some(char_idx),
-
-                // if last template index is not 0 and we find
-                // a closing bracket complete a range.
-                ('}', Some(open_idx)) => {
-                    let key = current_tmpl_key.clone();
-
-                    spans.push((
-                        parse_str::<Ident>(&key)
-                            .map_err(|_| TemplateError::InvalidIdent(key))?
-                            .to_string(),
-                        char_to_byte[open_idx]
-                            ..char_to_byte
-                                .get(char_idx + 1)
-                                .copied()
-                                .unwrap_or_else(|| s.len()),
-                    ));
+            let rest = &s[byte_idx..];
+
+            let in_conditional = || {
+                current_tmpl_key
+                    .split_once(',')
+                    .is_some_and(|(_, directive)| is_conditional_directive(directive, open))
+            };
+
+            match last_bracket_idx {
+                // inside a conditional block the delimiters
+                // are kept along the template until it's closed.
+                Some(_) if block_depth > 0 => {
+                    let delimiter = if rest.starts_with(open) {
+                        block_depth += 1;
+                        open
+                    } else if rest.starts_with(close) {
+                        block_depth -= 1;
+                        close
+                    } else {
+                        &s[byte_idx..byte_idx + c.len_utf8()]
+                    };
+
+                    current_tmpl_key.push_str(delimiter);
+                    byte_idx += delimiter.len();
+                },
+
+                // an opening after a conditional directive
+                // starts one of its blocks.
+                Some(_) if rest.starts_with(open) && in_conditional() => {
+                    block_depth = 1;
+                    current_tmpl_key.push_str(open);
+                    byte_idx += open.len();
+                },
+
+                // if the last template opening is right before this
+                // one ignore both as these are escaped.
+                Some(prev) if rest.starts_with(open) && prev + open.len() == byte_idx => {
+                    last_bracket_idx = None;
+                    is_reference = false;
+                    byte_idx += open.len();
+                },
+
+                // if the last template opening is anything else
+                // set this one as the last.
+                // an opening prefixed with `@` may be a reference.
+                _ if rest.starts_with(open) => {
+                    last_bracket_idx = Some(byte_idx);
+                    is_reference = s[..byte_idx].ends_with('@');
+                    byte_idx += open.len();
+                },
+
+                // if there is a reference opening and we find
+                // a closing delimiter complete a reference, only
+                // paths are references so `support@{domain}` is
+                // still a template.
+                Some(open_idx)
+                    if is_reference
+                        && rest.starts_with(close)
+                        && current_tmpl_key.contains("::")
+                        && !in_conditional() =>
+                {
+                    byte_idx += close.len();
+
+                    let path = current_tmpl_key
+                        .split("::")
+                        .map(|segment| {
+                            segment
+                                .trim()
+                                .to_string()
+                        })
+                        .collect::<Vec<_>>();
+
+                    if path
+                        .iter()
+                        .any(String::is_empty)
+                    {
+                        return Err(TemplateError::InvalidReference(
+                            current_tmpl_key
+                                .trim()
+                                .to_string(),
+                        ));
+                    }
+
+                    references.push((path, open_idx - 1..byte_idx));
+
+                    last_bracket_idx = None;
+                    is_reference = false;
+                    current_tmpl_key.clear();
+                },
+
+                // if there is an opening and we find
+                // a closing delimiter complete a range.
+                Some(open_idx) if rest.starts_with(close) => {
+                    byte_idx += close.len();
+
+                    // conditional blocks may contain commas,
+                    // so these are split apart from the key.
+                    let (key_part, conditional, directive_parts) =
+                        match current_tmpl_key.split_once(',') {
+                            Some((key_part, directive))
+                                if is_conditional_directive(directive, open) =>
+                            {
+                                (key_part.trim(), Some(directive.trim()), Vec::new())
+                            },
+
+                            _ => {
+                                let mut parts = current_tmpl_key
+                                    .split(',')
+                                    .map(str::trim);
+
+                                (
+                                    parts
+                                        .next()
+                                        .unwrap_or_default(),
+                                    None,
+                                    parts.collect::<Vec<_>>(),
+                                )
+                            },
+                        };
+
+                    let key_part = match key_part.strip_prefix("raw!") {
+                        Some(key_part) => {
+                            raw.push(open_idx);
+                            key_part.trim()
+                        },
+                        None => key_part,
+                    };
+
+                    let (key_part, default) = match key_part.split_once('=') {
+                        Some((key_part, default)) => (key_part.trim(), Some(default.trim())),
+                        None => (key_part, None),
+                    };
+
+                    // after the colon there may be a type constraint,
+                    // a format spec or both as in `{pi:float:.2}`.
+                    let (key, constraint, format) = match key_part.split_once(':') {
+                        Some((key, rest)) => {
+                            let rest = rest.trim();
+
+                            if rest
+                                .parse::<TemplateConstraint>()
+                                .is_ok()
+                            {
+                                (key.trim(), Some(rest), None)
+                            } else if is_format_spec(rest) {
+                                (key.trim(), None, Some(rest))
+                            } else {
+                                match rest.split_once(':') {
+                                    Some((constraint, format)) if is_format_spec(format.trim()) => {
+                                        (key.trim(), Some(constraint.trim()), Some(format.trim()))
+                                    },
+                                    _ => (key.trim(), Some(rest), None),
+                                }
+                            }
+                        },
+                        None => (key_part, None, None),
+                    };
+
+                    // positional templates such as `{0}` are keyed by their index.
+                    let key = if is_positional_key(key) {
+                        key.to_string()
+                    } else {
+                        parse_str::<Ident>(key)
+                            .map_err(|_| TemplateError::InvalidIdent(key.to_string()))?
+                            .to_string()
+                    };
+
+                    if let Some(constraint) = constraint {
+                        constraints.push((
+                            key.clone(),
+                            constraint
+                                .parse()
+                                .map_err(|_| {
+                                    TemplateError::InvalidConstraint(
+                                        key.clone(),
+                                        constraint.to_string(),
+                                    )
+                                })?,
+                        ));
+                    }
+
+                    if let Some(default) = default {
+                        defaults.push((open_idx, default.to_string()));
+                    }
+
+                    if let Some(format) = format {
+                        formats.push((open_idx, format.to_string()));
+                    }
+
+                    if let Some(conditional) = conditional {
+                        directives.push((
+                            open_idx,
+                            TemplateDirective::parse_conditional(conditional, open, close)
+                                .ok_or_else(|| {
+                                    TemplateError::InvalidConditional(
+                                        key.clone(),
+                                        conditional.to_string(),
+                                    )
+                                })?,
+                        ));
+                    }
+
+                    if let Some((name, arguments)) = directive_parts.split_first() {
+                        directives.push((
+                            open_idx,
+                            TemplateDirective::parse(name, arguments).ok_or_else(|| {
+                                TemplateError::InvalidDirective(
+                                    key.clone(),
+                                    directive_parts.join(", "),
+                                )
+                            })?,
+                        ));
+                    }
+
+                    spans.push((key, open_idx..byte_idx));
 
                     last_bracket_idx = None;
                     current_tmpl_key.clear();
                 },
 
-                (c, Some(_)) => current_tmpl_key.push(c),
+                Some(_) => {
+                    current_tmpl_key.push(c);
+                    byte_idx += c.len_utf8();
+                },
 
-                _ => {},
+                None => byte_idx += c.len_utf8(),
             }
         }
 
         if let Some(lbi) = last_bracket_idx {
-            Err(TemplateError::Unclosed(lbi))
+            Err(TemplateError::Unclosed(
+                s[..lbi]
+                    .chars()
+                    .count(),
+            ))
         } else {
-            Ok(FormatString { original, spans })
+            Ok(FormatString {
+                original: Cow::Owned(original),
+                spans,
+                constraints,
+                directives,
+                defaults,
+                formats,
+                references,
+                language: None,
+                bidi_isolate: false,
+                raw,
+                escape_html: false,
+            })
         }
     }
 }
 
+/// Parse method implementation.
+///
+/// This implementation leads to the implementation
+/// of the `parse` method for [`FormatString`] which
+/// parses all the templates on the string and stores
+/// them in a structure along the original string for
+/// future replacement.
+///
+/// Templates are delimited by braces, use [`parse_with`]
+/// for other delimiters.
+///
+/// [`parse_with`]: FormatString::parse_with
+impl FromStr for FormatString {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, &PlaceholderDelimiters::default())
+    }
+}
+
 /// Compile-time to runtime conversion implementation.
 ///
 /// This implementation generates a call to the [`from_data`]
@@ -214,7 +2598,9 @@ impl FromStr for FormatString {
 /// [`from_data`]: FormatString::from_data
 impl ToTokens for FormatString {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let original = &self.original;
+        let original = self
+            .original
+            .as_ref();
 
         let span_map = self
             .spans
@@ -232,5 +2618,61 @@ impl ToTokens for FormatString {
                 vec![#(#span_map),*]
             )
         });
+
+        if let Some(language) = &self.language {
+            tokens.append_all(quote! { .with_language(#language) });
+        }
+
+        if self.bidi_isolate {
+            tokens.append_all(quote! { .with_bidi_isolate(true) });
+        }
+
+        if self.escape_html {
+            tokens.append_all(quote! { .with_escape_html(true) });
+        }
+
+        if !self
+            .raw
+            .is_empty()
+        {
+            let raw = &self.raw;
+            tokens.append_all(quote! { .with_raw(vec![#(#raw),*]) });
+        }
+
+        if !self
+            .directives
+            .is_empty()
+        {
+            let directives = self
+                .directives
+                .iter()
+                .map(|(start, directive)| quote! { (#start, #directive) });
+
+            tokens.append_all(quote! { .with_directives(vec![#(#directives),*]) });
+        }
+
+        if !self
+            .defaults
+            .is_empty()
+        {
+            let defaults = self
+                .defaults
+                .iter()
+                .map(|(start, default)| quote! { (#start, #default.to_string()) });
+
+            tokens.append_all(quote! { .with_defaults(vec![#(#defaults),*]) });
+        }
+
+        if !self
+            .formats
+            .is_empty()
+        {
+            let formats = self
+                .formats
+                .iter()
+                .map(|(start, format)| quote! { (#start, #format.to_string()) });
+
+            tokens.append_all(quote! { .with_formats(vec![#(#formats),*]) });
+        }
     }
 }
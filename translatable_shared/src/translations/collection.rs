@@ -4,23 +4,188 @@
 //! a representation of each file found in the translations
 //! folder defined in the configuration file.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::convert::Infallible;
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, TokenStreamExt, quote};
 
-use super::node::{TranslationNode, TranslationObject};
-use crate::macros::collections::map_transform_to_tokens;
+use super::node::{
+    TranslationList,
+    TranslationNode,
+    TranslationNodeError,
+    TranslationObject,
+    TranslationObjectExt,
+};
+use crate::misc::language::Language;
+use crate::misc::suggestion::closest_match;
+use crate::misc::templating::{FormatString, PlaceholderDelimiters};
+
+/// Maximum translation reference depth.
+///
+/// How many references can be nested trough other
+/// translations before the expansion is aborted.
+const MAX_REFERENCE_DEPTH: usize = 8;
+
+/// FNV-1a 64 bit offset basis.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// FNV-1a 64 bit prime.
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Feed a field to an FNV-1a hash.
+///
+/// Each field is terminated with `0xff`, which can't
+/// appear in UTF-8 text, so adjacent fields can't be
+/// confused with each other.
+///
+/// **Arguments**
+/// * `hash` - The hash computed so far.
+/// * `bytes` - The field bytes.
+///
+/// **Returns**
+/// The hash with the field fed to it.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .chain([0xff].iter())
+        .fold(hash, |hash, byte| (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME))
+}
+
+/// Feed a translation node to an FNV-1a hash.
+///
+/// Nesting keys and languages are fed sorted, so the
+/// hash doesn't depend on the maps iteration order.
+///
+/// **Arguments**
+/// * `hash` - The hash computed so far.
+/// * `node` - The node to feed.
+///
+/// **Returns**
+/// The hash with the node fed to it.
+fn hash_node(hash: u64, node: &TranslationNode) -> u64 {
+    match node {
+        TranslationNode::Nesting(nesting) => {
+            let mut keys = nesting
+                .keys()
+                .collect::<Vec<_>>();
+            keys.sort();
+
+            keys.into_iter()
+                .fold(fnv1a(hash, b"nesting"), |hash, key| {
+                    hash_node(fnv1a(hash, key.as_bytes()), &nesting[key])
+                })
+        },
+
+        TranslationNode::Translation(translation) => {
+            let mut languages = translation
+                .keys()
+                .collect::<Vec<_>>();
+            languages.sort_by_key(|language| language.code());
+
+            languages
+                .into_iter()
+                .fold(fnv1a(hash, b"translation"), |hash, language| {
+                    fnv1a(
+                        fnv1a(
+                            hash,
+                            language
+                                .code()
+                                .as_bytes(),
+                        ),
+                        translation[language]
+                            .original()
+                            .as_bytes(),
+                    )
+                })
+        },
+
+        TranslationNode::List(list) => {
+            let mut languages = list
+                .keys()
+                .collect::<Vec<_>>();
+            languages.sort_by_key(|language| language.code());
+
+            languages
+                .into_iter()
+                .fold(fnv1a(hash, b"list"), |hash, language| {
+                    list[language]
+                        .iter()
+                        .fold(
+                            fnv1a(
+                                hash,
+                                language
+                                    .code()
+                                    .as_bytes(),
+                            ),
+                            |hash, translation| {
+                                fnv1a(
+                                    hash,
+                                    translation
+                                        .original()
+                                        .as_bytes(),
+                                )
+                            },
+                        )
+                })
+        },
+    }
+}
+
+/// Collects the path of every translation and list in a node.
+///
+/// **Arguments**
+/// * `node` - The node to walk.
+/// * `path` - The path segments that lead to `node`.
+/// * `paths` - Where the paths are collected.
+fn terminal_paths(node: &TranslationNode, path: &mut Vec<String>, paths: &mut Vec<Vec<String>>) {
+    match node {
+        TranslationNode::Nesting(nested) => {
+            for (key, node) in nested.iter() {
+                path.push(key.clone());
+                terminal_paths(node, path, paths);
+                path.pop();
+            }
+        },
+
+        TranslationNode::Translation(_) | TranslationNode::List(_) => paths.push(path.clone()),
+    }
+}
 
 /// Translation file collection.
 ///
-/// This tuple struct wraps a hashmap implementing
-/// a lookup trough all the files in ascending order.
+/// This struct wraps a hashmap implementing
+/// a lookup trough all the files in order.
 ///
 /// The internal hashmap contains the original file
-/// paths along all the unmerged [`TranslationNode`]
-/// found in each file.
-pub struct TranslationNodeCollection(HashMap<String, TranslationNode>);
+/// paths along all the [`TranslationNode`] found in
+/// each file, the lookup order is kept apart so the
+/// first file defining a path is always the one
+/// resolved. Once [`merge_languages`] is called, that
+/// definition also holds the languages it lacked from
+/// the files after it.
+///
+/// [`merge_languages`]: TranslationNodeCollection::merge_languages
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TranslationNodeCollection {
+    /// The translation nodes keyed by their file path.
+    nodes: HashMap<String, TranslationNode>,
+
+    /// The file paths in lookup order.
+    #[cfg_attr(feature = "serde", serde(default))]
+    order: Vec<String>,
+
+    /// Whether the path segments are matched
+    /// case-insensitively.
+    #[cfg_attr(feature = "serde", serde(default))]
+    case_insensitive: bool,
+
+    /// The languages merged into the resolved definition
+    /// of a path, along the file each one was taken from.
+    #[cfg_attr(feature = "serde", serde(default))]
+    inherited: Vec<(Vec<String>, Language, String)>,
+}
 
 impl TranslationNodeCollection {
     /// Create a new [`TranslationNodeCollection`].
@@ -32,13 +197,96 @@ impl TranslationNodeCollection {
     /// is usually called from a `to-runtime` implementation, if
     /// you want to obtain all the translation files use
     ///
+    /// The files are looked up in alphabetical order, collect
+    /// an iterator instead to look them up in its order.
+    ///
     /// **Arguments**
     /// * `collection` - An already populated collection for lookup.
     ///
     /// **Returns**
     /// The provided collection wrapped in a [`TranslationNodeCollection`].
     pub fn new(collection: HashMap<String, TranslationNode>) -> Self {
-        Self(collection)
+        let mut order = collection
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        order.sort();
+
+        Self {
+            nodes: collection,
+            order,
+            case_insensitive: false,
+            inherited: Vec::new(),
+        }
+    }
+
+    /// Iterate the nodes in lookup order.
+    ///
+    /// **Returns**
+    /// Each file path along its node, the first file
+    /// defining a path being the one resolved.
+    fn ordered_nodes(&self) -> impl Iterator<Item = (&String, &TranslationNode)> {
+        self.order
+            .iter()
+            .filter_map(|source| {
+                self.nodes
+                    .get_key_value(source)
+            })
+    }
+
+    /// Get the file paths in lookup order.
+    ///
+    /// **Returns**
+    /// The file paths, the first file defining a
+    /// path being the one resolved.
+    pub fn sources(&self) -> Vec<&str> {
+        self.ordered_nodes()
+            .map(|(source, _)| source.as_str())
+            .collect()
+    }
+
+    /// Set whether paths are matched case-insensitively.
+    ///
+    /// The lookups lowercase the path segments they are passed,
+    /// so the nodes must already be normalized with
+    /// [`TranslationNode::lowercase_paths`], this is done
+    /// while loading the translation files.
+    ///
+    /// **Arguments**
+    /// * `case_insensitive` - Whether the path segments are matched
+    ///   case-insensitively.
+    ///
+    /// **Returns**
+    /// The same collection with the matching mode set.
+    pub fn with_case_insensitive_paths(mut self, case_insensitive: bool) -> Self {
+        self.case_insensitive = case_insensitive;
+        self
+    }
+
+    /// Case-insensitive matching getter.
+    ///
+    /// **Returns**
+    /// Whether the path segments are matched case-insensitively.
+    pub fn case_insensitive_paths(&self) -> bool {
+        self.case_insensitive
+    }
+
+    /// Normalize a path to the canonical form of the nodes.
+    ///
+    /// **Arguments**
+    /// * `path` - The path segments to normalize.
+    ///
+    /// **Returns**
+    /// The path segments, lowercased if the paths are
+    /// matched case-insensitively.
+    fn normalize_path<I: ToString>(&self, path: &[I]) -> Vec<String> {
+        path.iter()
+            .map(|segment| {
+                let segment = segment.to_string();
+
+                if self.case_insensitive { segment.to_lowercase() } else { segment }
+            })
+            .collect()
     }
 
     /// Get a node from a file path.
@@ -55,7 +303,7 @@ impl TranslationNodeCollection {
     /// in that specific file.
     #[allow(unused)]
     pub fn get_node(&self, path: &str) -> Option<&TranslationNode> {
-        self.0
+        self.nodes
             .get(path)
     }
 
@@ -67,48 +315,784 @@ impl TranslationNodeCollection {
     /// translation object.
     ///
     /// **Arguments**
-    /// * `path` - The sections of the TOML path in order to access
-    /// the desired translation object.
+    /// * `path` - The sections of the TOML path in order to access the desired
+    ///   translation object.
     ///
     /// **Returns**
     /// A translation object containing a specific translation
     /// in all it's available languages.
+    #[allow(clippy::ptr_arg)]
     pub fn find_path<I: ToString>(&self, path: &Vec<I>) -> Option<&TranslationObject> {
-        self.0
+        let path = self.normalize_path(path);
+
+        self.ordered_nodes()
+            .find_map(|(_, node)| node.find_path(&path))
+    }
+
+    /// Search a path trough all the nodes along its source.
+    ///
+    /// Same as [`find_path`] but also returns the file
+    /// path where the translation object was found.
+    ///
+    /// **Arguments**
+    /// * `path` - The sections of the TOML path in order to access the desired
+    ///   translation object.
+    ///
+    /// **Returns**
+    /// The file path the translation object was defined in
+    /// along the translation object itself.
+    ///
+    /// [`find_path`]: TranslationNodeCollection::find_path
+    #[allow(clippy::ptr_arg)]
+    pub fn find_path_with_source<I: ToString>(
+        &self,
+        path: &Vec<I>,
+    ) -> Option<(&str, &TranslationObject)> {
+        let path = self.normalize_path(path);
+
+        self.ordered_nodes()
+            .find_map(|(source, node)| {
+                node.find_path(&path)
+                    .map(|translation| (source.as_str(), translation))
+            })
+    }
+
+    /// Search a list path trough all the nodes.
+    ///
+    /// Same as [`find_path`] but the path must lead
+    /// to a [`TranslationList`] instead.
+    ///
+    /// **Arguments**
+    /// * `path` - The sections of the TOML path in order to access the desired
+    ///   translation list.
+    ///
+    /// **Returns**
+    /// A translation list containing the lists in all it's
+    /// available languages.
+    ///
+    /// [`find_path`]: TranslationNodeCollection::find_path
+    pub fn find_list<I: ToString>(&self, path: &[I]) -> Option<&TranslationList> {
+        let path = self.normalize_path(path);
+
+        self.ordered_nodes()
+            .find_map(|(_, node)| node.find_list(&path))
+    }
+
+    /// Get the segments beneath a group of translations.
+    ///
+    /// Used to tell a path that leads to a group of
+    /// translations apart from a path that doesn't exist,
+    /// for example when the last segment is forgotten.
+    ///
+    /// **Arguments**
+    /// * `path` - The sections of the TOML path that were searched.
+    ///
+    /// **Returns**
+    /// The segments nested beneath the path in any file, sorted
+    /// alphabetically, or `None` if the path is empty or doesn't
+    /// lead to a group of translations in any file.
+    pub fn subtree_segments<I: ToString>(&self, path: &[I]) -> Option<Vec<String>> {
+        let path = self.normalize_path(path);
+
+        if path.is_empty() {
+            return None;
+        }
+
+        let mut segments = self
+            .ordered_nodes()
+            .filter_map(|(_, node)| match node.find_node(&path) {
+                Some(TranslationNode::Nesting(nested)) => Some(nested.keys()),
+                _ => None,
+            })
+            .flatten()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if segments.is_empty() {
+            return None;
+        }
+
+        segments.sort();
+        segments.dedup();
+
+        Some(segments)
+    }
+
+    /// Find the file closest to define a path.
+    ///
+    /// This method is used to give a hint when a path
+    /// is not found, the file that resolves the most
+    /// leading path segments is considered the closest
+    /// match, ties are resolved alphabetically.
+    ///
+    /// **Arguments**
+    /// * `path` - The sections of the TOML path that were searched.
+    ///
+    /// **Returns**
+    /// The file path of the closest match, or `None` if
+    /// not even the first path segment was found.
+    #[allow(clippy::ptr_arg)]
+    pub fn closest_match<I: ToString>(&self, path: &Vec<I>) -> Option<&str> {
+        let path = self.normalize_path(path);
+
+        self.nodes
+            .iter()
+            .map(|(source, node)| (node.matched_depth(&path), source))
+            .filter(|(depth, _)| *depth > 0)
+            .max_by(|(a_depth, a_source), (b_depth, b_source)| {
+                a_depth
+                    .cmp(b_depth)
+                    .then_with(|| b_source.cmp(a_source))
+            })
+            .map(|(_, source)| source.as_str())
+    }
+
+    /// Find the existing path most similar to a path.
+    ///
+    /// This method is used to suggest a path when the
+    /// requested one is not found, usually due to a typo,
+    /// comparing the whole `::` joined paths with
+    /// [`closest_match`].
+    ///
+    /// **Arguments**
+    /// * `path` - The sections of the TOML path that were searched.
+    ///
+    /// **Returns**
+    /// The similar path in `::` notation along the file path
+    /// it's defined in, or `None` if no path is close enough.
+    pub fn similar_path<I: ToString>(&self, path: &[I]) -> Option<(String, &str)> {
+        let path = self
+            .normalize_path(path)
+            .join("::");
+
+        let mut candidates = self
+            .nodes
+            .iter()
+            .flat_map(|(source, node)| {
+                node.leaves()
+                    .into_iter()
+                    .map(move |(path, _)| (path.join("::"), source.as_str()))
+            })
+            .collect::<Vec<_>>();
+        candidates.sort();
+
+        let paths = candidates
+            .iter()
+            .map(|(path, _)| path.as_str())
+            .collect::<Vec<_>>();
+
+        let similar = closest_match(&path, &paths)?;
+
+        candidates
+            .iter()
+            .find(|(path, _)| path == similar)
+            .map(|(path, source)| (path.clone(), *source))
+    }
+
+    /// Collect every translation beneath a path prefix.
+    ///
+    /// Same as [`leaves`] but only walking the nodes the
+    /// prefix leads to, if the prefix leads to a translation
+    /// object, that single translation is returned.
+    ///
+    /// **Arguments**
+    /// * `prefix` - The path segments the translations must start with.
+    ///
+    /// **Returns**
+    /// A list of full path segments and translation object pairs
+    /// with unique paths, sorted by path.
+    ///
+    /// [`leaves`]: TranslationNodeCollection::leaves
+    pub fn find_subtree<I: ToString>(
+        &self,
+        prefix: &[I],
+    ) -> Vec<(Vec<String>, &TranslationObject)> {
+        let prefix = self.normalize_path(prefix);
+
+        let mut seen = HashSet::new();
+
+        let mut subtree = self
+            .ordered_nodes()
+            .filter_map(|(_, node)| node.find_node(&prefix))
+            .flat_map(|node| node.leaves())
+            .map(|(path, translation)| ([prefix.clone(), path].concat(), translation))
+            .filter(|(path, _)| seen.insert(path.clone()))
+            .collect::<Vec<_>>();
+
+        subtree.sort_by(|(a, _), (b, _)| a.cmp(b));
+        subtree
+    }
+
+    /// Collect every translation trough all the nodes.
+    ///
+    /// This method walks all the nodes collecting each
+    /// translation object along its path. If a path is
+    /// found in multiple nodes only the first one is kept,
+    /// same as [`find_path`] would resolve it.
+    ///
+    /// **Returns**
+    /// A list of path segments and translation object pairs
+    /// with unique paths, sorted by path.
+    ///
+    /// [`find_path`]: TranslationNodeCollection::find_path
+    pub fn leaves(&self) -> Vec<(Vec<String>, &TranslationObject)> {
+        let mut seen = HashSet::new();
+
+        let mut leaves = self
+            .ordered_nodes()
+            .flat_map(|(_, node)| node.leaves())
+            .filter(|(path, _)| seen.insert(path.clone()))
+            .collect::<Vec<_>>();
+        leaves.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        leaves
+    }
+
+    /// Collect every path defined in more than one file.
+    ///
+    /// Only one of these definitions is ever resolved,
+    /// so the rest are usually a mistake.
+    ///
+    /// **Returns**
+    /// A list of path segments along the files defining them,
+    /// both sorted alphabetically.
+    pub fn duplicates(&self) -> Vec<(Vec<String>, Vec<&str>)> {
+        let mut sources = HashMap::<Vec<String>, Vec<&str>>::new();
+
+        for (source, node) in &self.nodes {
+            for (path, _) in node.leaves() {
+                sources
+                    .entry(path)
+                    .or_default()
+                    .push(source);
+            }
+        }
+
+        let mut duplicates = sources
+            .into_iter()
+            .filter(|(_, sources)| sources.len() > 1)
+            .map(|(path, mut sources)| {
+                sources.sort();
+                (path, sources)
+            })
+            .collect::<Vec<_>>();
+
+        duplicates.sort();
+        duplicates
+    }
+
+    /// Collect every path that is a leaf in a file and a nesting in another.
+    ///
+    /// Such as `menu` being a translation in one file while
+    /// another defines `menu::items`, where either of them
+    /// shadows the other depending on the lookup order.
+    ///
+    /// **Returns**
+    /// A list of path segments along the file defining them as a
+    /// translation or list and the file nesting other paths beneath,
+    /// sorted alphabetically.
+    pub fn shadowed_paths(&self) -> Vec<(Vec<String>, &str, &str)> {
+        let mut shadowed = Vec::new();
+
+        for (source, node) in &self.nodes {
+            let mut terminals = Vec::new();
+            terminal_paths(node, &mut Vec::new(), &mut terminals);
+
+            for path in terminals {
+                for length in 1..path.len() {
+                    let prefix = &path[..length];
+
+                    for (other_source, other) in &self.nodes {
+                        if other_source != source
+                            && matches!(
+                                other.find_node(prefix),
+                                Some(TranslationNode::Translation(_) | TranslationNode::List(_))
+                            )
+                        {
+                            shadowed.push((
+                                prefix.to_vec(),
+                                other_source.as_str(),
+                                source.as_str(),
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        shadowed.sort();
+        shadowed.dedup();
+        shadowed
+    }
+
+    /// Compute a hash of the translations content.
+    ///
+    /// Every file is hashed on its own and the file hashes
+    /// are combined sorted, so the same translations always
+    /// hash the same regardless of the file paths and the
+    /// lookup order. The hash is an FNV-1a, stable across
+    /// platforms and compiler versions, meant for cache
+    /// busting rather than integrity checks.
+    ///
+    /// **Returns**
+    /// The 64 bit content hash.
+    pub fn content_hash(&self) -> u64 {
+        let mut hashes = self
+            .nodes
+            .values()
+            .map(|node| hash_node(FNV_OFFSET_BASIS, node))
+            .collect::<Vec<_>>();
+        hashes.sort();
+
+        hashes
+            .into_iter()
+            .fold(FNV_OFFSET_BASIS, |hash, file_hash| fnv1a(hash, &file_hash.to_le_bytes()))
+    }
+
+    /// Merge the definitions of each path by language.
+    ///
+    /// The definition of a path in the first file, the one
+    /// resolved, is completed with the languages it lacks
+    /// from the files after it in lookup order, so files
+    /// contributing different languages to the same path
+    /// combine. As `overwrite` files are looked up before
+    /// the files found before them and `ignore` files after,
+    /// the last or the first definition of each language
+    /// wins respectively.
+    ///
+    /// Only definitions of the same kind are merged, a list
+    /// doesn't take languages from a translation.
+    pub fn merge_languages(&mut self) {
+        let mut paths = Vec::new();
+        for node in self
+            .nodes
             .values()
-            .find_map(|node| node.find_path(path))
+        {
+            terminal_paths(node, &mut Vec::new(), &mut paths);
+        }
+        paths.sort();
+        paths.dedup();
+
+        for path in paths {
+            let mut sources = self
+                .order
+                .iter()
+                .filter(|source| {
+                    self.nodes
+                        .get(*source)
+                        .and_then(|node| node.find_node(&path))
+                        .is_some_and(|node| !matches!(node, TranslationNode::Nesting(_)))
+                });
+
+            let Some(winner) = sources.next() else {
+                continue;
+            };
+
+            let Some(mut merged) = self
+                .nodes
+                .get(winner)
+                .and_then(|node| node.find_node(&path))
+                .cloned()
+            else {
+                continue;
+            };
+
+            let mut inherited = Vec::new();
+
+            for source in sources {
+                let Some(other) = self
+                    .nodes
+                    .get(source)
+                    .and_then(|node| node.find_node(&path))
+                else {
+                    continue;
+                };
+
+                let languages = match (&mut merged, other) {
+                    (TranslationNode::Translation(merged), TranslationNode::Translation(other)) => {
+                        let mut languages = Vec::new();
+
+                        for (language, translation) in other.iter() {
+                            if !merged.contains_key(language) {
+                                merged.insert(language.clone(), translation.clone());
+                                languages.push(language.clone());
+                            }
+                        }
+
+                        languages
+                    },
+
+                    (TranslationNode::List(merged), TranslationNode::List(other)) => {
+                        let mut languages = Vec::new();
+
+                        for (language, list) in other.iter() {
+                            if !merged.contains_key(language) {
+                                merged.insert(language.clone(), list.clone());
+                                languages.push(language.clone());
+                            }
+                        }
+
+                        languages
+                    },
+
+                    _ => Vec::new(),
+                };
+
+                inherited.extend(
+                    languages
+                        .into_iter()
+                        .map(|language| (path.clone(), language, source.clone())),
+                );
+            }
+
+            if inherited.is_empty() {
+                continue;
+            }
+
+            if let Some(node) = self
+                .nodes
+                .get_mut(winner)
+                .and_then(|node| node.find_node_mut(&path))
+            {
+                *node = merged;
+            }
+
+            self.inherited
+                .extend(inherited);
+        }
+
+        self.inherited
+            .sort();
+    }
+
+    /// Get the file a merged language was taken from.
+    ///
+    /// **Arguments**
+    /// * `path` - The path segments of the translation or list.
+    /// * `language` - The language to get the source of.
+    ///
+    /// **Returns**
+    /// The file the language was taken from if it was merged
+    /// into the resolved definition by [`merge_languages`],
+    /// otherwise `None`.
+    ///
+    /// [`merge_languages`]: TranslationNodeCollection::merge_languages
+    pub fn inherited_source(&self, path: &[String], language: &Language) -> Option<&str> {
+        self.inherited
+            .iter()
+            .find(|(inherited_path, inherited_language, _)| {
+                inherited_path == path && inherited_language == language
+            })
+            .map(|(_, _, source)| source.as_str())
+    }
+
+    /// Retain only the translations in the provided languages.
+    ///
+    /// This method removes the translations in any other
+    /// language from all the nodes, usually called before
+    /// embedding the collection to reduce the generated code.
+    ///
+    /// **Arguments**
+    /// * `languages` - The languages to keep.
+    pub fn retain_languages(&mut self, languages: &[Language]) {
+        self.nodes
+            .values_mut()
+            .for_each(|node| node.retain_languages(languages));
+    }
+
+    /// Retain only the translations in the languages matching a predicate.
+    ///
+    /// Same as [`retain_languages`] for languages that
+    /// can't be listed beforehand, such as custom tags.
+    ///
+    /// **Arguments**
+    /// * `keep` - Whether to keep the translations in a language.
+    ///
+    /// [`retain_languages`]: TranslationNodeCollection::retain_languages
+    pub fn retain_languages_with<F: Fn(&Language) -> bool>(&mut self, keep: F) {
+        self.nodes
+            .values_mut()
+            .for_each(|node| node.retain_languages_with(&keep));
+    }
+
+    /// Get every language used in the collection.
+    ///
+    /// **Returns**
+    /// The languages any translation or list is available
+    /// in, sorted and without duplicates.
+    pub fn languages(&self) -> Vec<Language> {
+        let mut languages = self
+            .nodes
+            .values()
+            .flat_map(|node| node.languages())
+            .collect::<Vec<_>>();
+
+        languages.sort();
+        languages.dedup();
+
+        languages
+    }
+
+    /// Count the translations available in each language.
+    ///
+    /// Each path is counted once, in the languages of the
+    /// translation [`find_path`] would resolve, so out of
+    /// [`leaves`] length translations.
+    ///
+    /// **Returns**
+    /// Each language along the amount of translations
+    /// available in it, sorted as the languages are declared.
+    ///
+    /// [`find_path`]: TranslationNodeCollection::find_path
+    /// [`leaves`]: TranslationNodeCollection::leaves
+    pub fn language_coverage(&self) -> Vec<(Language, usize)> {
+        let mut coverage = HashMap::<Language, usize>::new();
+
+        for (_, translation) in self.leaves() {
+            for language in translation.keys() {
+                *coverage
+                    .entry(language.clone())
+                    .or_default() += 1;
+            }
+        }
+
+        let mut coverage = coverage
+            .into_iter()
+            .collect::<Vec<_>>();
+        coverage.sort();
+
+        coverage
+    }
+
+    /// Pseudolocalize every translation.
+    ///
+    /// Replaces every translation string with its
+    /// [`FormatString::pseudolocalize`] counterpart, references
+    /// should be expanded first so these are not transformed twice.
+    pub fn pseudolocalize(&mut self) {
+        self.nodes
+            .values_mut()
+            .for_each(|node| {
+                let _ = node.try_for_each_format_string(&mut |_, translation| {
+                    *translation = translation.pseudolocalize();
+                    Ok::<(), Infallible>(())
+                });
+            });
+    }
+
+    /// Expand the translation references.
+    ///
+    /// Replaces every reference such as `@{brand::name}` with
+    /// the referenced translation in the same language, falling
+    /// back to its parents, so references are resolved once while
+    /// loading and never in runtime. Referenced translations may
+    /// reference others up to [`MAX_REFERENCE_DEPTH`] levels.
+    ///
+    /// **Arguments**
+    /// * `delimiters` - The placeholder delimiters to parse the expanded
+    ///   translations with.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(())` - If every reference was expanded.
+    /// * `Err((String, TranslationNodeError))` - The file of the translation
+    ///   whose reference can't be found, is not available in the language, is
+    ///   circular or too deeply nested, along the error.
+    pub fn expand_references(
+        &mut self,
+        delimiters: &PlaceholderDelimiters,
+    ) -> Result<(), (String, TranslationNodeError)> {
+        let source = self.clone();
+
+        let mut nodes = self
+            .nodes
+            .iter_mut()
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|(a, _)| *a);
+
+        for (file, node) in nodes {
+            node.try_for_each_format_string(&mut |path, translation| {
+                if translation
+                    .references()
+                    .is_empty()
+                {
+                    return Ok::<(), TranslationNodeError>(());
+                }
+
+                *translation = source.expand_translation(
+                    translation,
+                    delimiters,
+                    &mut vec![path.join("::")],
+                )?;
+
+                Ok(())
+            })
+            .map_err(|err| (file.clone(), err))?;
+        }
+
+        Ok(())
+    }
+
+    /// Recursive [`expand_references`] implementation.
+    ///
+    /// **Arguments**
+    /// * `translation` - The translation string to expand.
+    /// * `delimiters` - The placeholder delimiters.
+    /// * `stack` - The `::` joined paths being expanded, the last one being the
+    ///   path of `translation`.
+    ///
+    /// **Returns**
+    /// The translation string with its references expanded.
+    ///
+    /// [`expand_references`]: TranslationNodeCollection::expand_references
+    fn expand_translation(
+        &self,
+        translation: &FormatString,
+        delimiters: &PlaceholderDelimiters,
+        stack: &mut Vec<String>,
+    ) -> Result<FormatString, TranslationNodeError> {
+        translation.expand_references(delimiters, |reference| {
+            let current = stack
+                .last()
+                .cloned()
+                .unwrap_or_default();
+            let reference_display = reference.join("::");
+
+            if stack.contains(&reference_display) {
+                stack.push(reference_display);
+                return Err(TranslationNodeError::ReferenceCycle(stack.join(" -> ")));
+            }
+
+            if stack.len() > MAX_REFERENCE_DEPTH {
+                return Err(TranslationNodeError::ReferenceDepth(
+                    stack[0].clone(),
+                    MAX_REFERENCE_DEPTH,
+                ));
+            }
+
+            let referenced = self
+                .find_path(&reference.to_vec())
+                .ok_or_else(|| {
+                    TranslationNodeError::ReferenceNotFound(
+                        current.clone(),
+                        reference_display.clone(),
+                    )
+                })?;
+
+            let referenced = translation
+                .language()
+                .and_then(|language| referenced.get_or_parent(language))
+                .ok_or_else(|| {
+                    TranslationNodeError::ReferenceNotAvailable(
+                        current.clone(),
+                        reference_display.clone(),
+                        translation
+                            .language()
+                            .map(Language::code)
+                            .unwrap_or_default(),
+                    )
+                })?;
+
+            stack.push(reference_display);
+            let expanded = self.expand_translation(referenced, delimiters, stack)?;
+            stack.pop();
+
+            Ok(expanded
+                .original()
+                .to_string())
+        })
+    }
+}
+
+/// Translation cache serialization.
+///
+/// With the `serde` feature a collection can be serialized
+/// into a cache, for example to ship parsed translations
+/// loaded in runtime with `translate_with`.
+///
+/// The cache format is an implementation detail, use these
+/// methods instead of serializing the collection directly.
+#[cfg(feature = "serde")]
+impl TranslationNodeCollection {
+    /// Serialize the collection into a cache.
+    ///
+    /// **Returns**
+    /// The cache bytes, or the serialization error.
+    pub fn to_cache(&self) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(self)
+    }
+
+    /// Deserialize a collection from a cache.
+    ///
+    /// **Arguments**
+    /// * `cache` - The bytes obtained from [`to_cache`].
+    ///
+    /// **Returns**
+    /// The cached collection, or the deserialization error.
+    ///
+    /// [`to_cache`]: TranslationNodeCollection::to_cache
+    pub fn from_cache(cache: &[u8]) -> Result<Self, serde_json::Error> {
+        let collection = serde_json::from_slice::<Self>(cache)?;
+
+        // caches written before the lookup order was kept.
+        if collection
+            .order
+            .is_empty()
+        {
+            return Ok(Self::new(collection.nodes)
+                .with_case_insensitive_paths(collection.case_insensitive));
+        }
+
+        Ok(collection)
     }
 }
 
 /// Hashmap wrapper implementation.
 ///
 /// Abstraction to easily collect a [`HashMap<String, TranslationNode>`] and
-/// wrap it in a [`TranslationNodeCollection`].
+/// wrap it in a [`TranslationNodeCollection`], the files are looked up
+/// in the iterator order.
 impl FromIterator<(String, TranslationNode)> for TranslationNodeCollection {
     fn from_iter<T: IntoIterator<Item = (String, TranslationNode)>>(iter: T) -> Self {
-        Self(
-            iter.into_iter()
-                .collect(),
-        )
+        let mut nodes = HashMap::new();
+        let mut order = Vec::new();
+
+        for (source, node) in iter {
+            if nodes
+                .insert(source.clone(), node)
+                .is_none()
+            {
+                order.push(source);
+            }
+        }
+
+        Self {
+            nodes,
+            order,
+            case_insensitive: false,
+            inherited: Vec::new(),
+        }
     }
 }
 
 /// Compile-time to runtime implementation.
 ///
-/// This implementation generates the call to [`new`] on
-/// [`TranslationNodeCollection`] with the data from the current
-/// instance to perform a compile-time to runtime conversion.
-///
-/// [`new`]: TranslationNodeCollection::new
+/// This implementation generates the collection of the nodes
+/// into a [`TranslationNodeCollection`] in lookup order with the
+/// data from the current instance to perform a compile-time to
+/// runtime conversion.
 impl ToTokens for TranslationNodeCollection {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
-        let map =
-            map_transform_to_tokens(&self.0, |key, value| quote! { (#key.to_string(), #value) });
+        let nodes = self
+            .ordered_nodes()
+            .map(|(key, value)| quote! { (#key.to_string(), #value) });
+        let case_insensitive = self.case_insensitive;
 
         tokens.append_all(quote! {
-            translatable::shared::translations::collection::TranslationNodeCollection::new(
-                #map
-            )
+            vec![#(#nodes),*]
+                .into_iter()
+                .collect::<translatable::shared::translations::collection::TranslationNodeCollection>()
+                .with_case_insensitive_paths(#case_insensitive)
         });
     }
 }
@@ -0,0 +1,343 @@
+//! Translation file reading module.
+//!
+//! This module declares [`read_translation_file`] which
+//! reads a translation file into a TOML table, resolving
+//! the `include` directives it may contain so the included
+//! files are merged into the scope they were included from.
+
+use std::fs::{canonicalize, read_to_string};
+use std::io::Error as IoError;
+use std::path::Path;
+
+use thiserror::Error;
+use toml_edit::{DocumentMut, Item, Table, TomlError, Value};
+
+/// Include directive key.
+///
+/// A key with this name holding a string or an array
+/// of strings is considered an include directive, any
+/// other value is considered a regular translation key.
+const INCLUDE_KEY: &str = "include";
+
+/// Translation file reading errors.
+///
+/// These errors can occur while reading a translation
+/// file or any of the files it includes.
+#[derive(Error, Debug)]
+pub enum TranslationFileError {
+    /// I/O error.
+    ///
+    /// Raised when a translation file, or a file included
+    /// by it, couldn't be read.
+    ///
+    /// **Parameters**
+    /// * `0` — The underlying I/O error.
+    /// * `1` — The path of the file that couldn't be read.
+    #[error("IO Error: \"{0:#}\" while reading {1}.")]
+    Io(IoError, String),
+
+    /// TOML deserialization failure.
+    ///
+    /// Raised when the contents of a translation file cannot be
+    /// parsed as valid TOML data.
+    ///
+    /// **Parameters**
+    /// * `0` — The [`TomlError`] carrying the underlying deserialization error.
+    /// * `1` — The file path of the TOML file being parsed.
+    #[error(
+        "TOML Deserialization error '{reason}' {span} in {1}",
+        reason = _0.message(),
+        span = _0
+            .span()
+            .map(|range| format!("on {}:{}", range.start, range.end))
+            .unwrap_or_else(String::new)
+    )]
+    ParseToml(TomlError, String),
+
+    /// Invalid include directive.
+    ///
+    /// Raised when an include directive is an array
+    /// containing something other than strings.
+    ///
+    /// **Parameters**
+    /// * `0` — The path of the file containing the directive.
+    #[error("The include directive in {0} must be a string or an array of strings")]
+    InvalidInclude(String),
+
+    /// Circular include.
+    ///
+    /// Raised when a file ends up including itself,
+    /// directly or trough other files.
+    ///
+    /// **Parameters**
+    /// * `0` — The include chain, starting and ending with the same file.
+    #[error("Circular include detected: {}", .0.join(" -> "))]
+    CircularInclude(Vec<String>),
+
+    /// Unresolved include.
+    ///
+    /// Raised when a translation file parsed from its
+    /// contents contains an include directive, as there
+    /// is no file system to resolve it from.
+    ///
+    /// **Parameters**
+    /// * `0` — The path of the file containing the directive.
+    #[error("The include directive in {0} can't be resolved, as the file is not read from disk")]
+    UnresolvedInclude(String),
+}
+
+/// Reads a translation file resolving its includes.
+///
+/// Any table in the file, including the root one, may contain
+/// an `include` key with a path or an array of paths relative
+/// to the file itself. The included files are read the same way
+/// and merged into the table that included them.
+///
+/// The included files are considered found before the table
+/// that includes them, and each of them before the next one,
+/// so when the same key is defined more than once `overwrite`
+/// decides whether the last found definition wins or the first
+/// one is preserved. Nested tables are merged key by key.
+///
+/// **Arguments**
+/// * `path` — The translation file path.
+/// * `overwrite` — Whether the last found definition of a key wins.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok((Table, Vec<String>))` — The resolved table along the paths of every
+///   included file, in the order they were read.
+/// * `Err(TranslationFileError)` — If any file couldn't be read or parsed, or
+///   the includes are circular.
+pub fn read_translation_file(
+    path: &str,
+    overwrite: bool,
+) -> Result<(Table, Vec<String>), TranslationFileError> {
+    let mut included = Vec::new();
+    let table = read_table(path, overwrite, &mut Vec::new(), &mut included)?;
+
+    Ok((table, included))
+}
+
+/// Parses the contents of a translation file.
+///
+/// Used for translation files that are not read from
+/// disk, such as the ones embedded in the binary, thus
+/// include directives are not allowed.
+///
+/// **Arguments**
+/// * `contents` — The translation file contents.
+/// * `path` — The translation file path, for errors.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(Table)` — The parsed table.
+/// * `Err(TranslationFileError)` — If the contents couldn't be parsed or
+///   contain an include directive.
+pub fn parse_translation_file(contents: &str, path: &str) -> Result<Table, TranslationFileError> {
+    let table = contents
+        .parse::<DocumentMut>()
+        .map_err(|err| TranslationFileError::ParseToml(err, path.to_string()))?
+        .into_table();
+
+    if has_include(&table) {
+        return Err(TranslationFileError::UnresolvedInclude(path.to_string()));
+    }
+
+    Ok(table)
+}
+
+/// Obtains the namespace of a translation file.
+///
+/// The namespace is the file name up to its first dot,
+/// so both `buttons.toml` and `buttons.locale.toml` are
+/// namespaced as `buttons`.
+///
+/// **Arguments**
+/// * `path` — The translation file path.
+///
+/// **Returns**
+/// The file namespace, empty if the path has no file name.
+pub fn file_namespace(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default()
+        .split('.')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Nests a translation file table under its namespace.
+///
+/// **Arguments**
+/// * `table` — The resolved translation file table.
+/// * `path` — The translation file path.
+///
+/// **Returns**
+/// A table containing only the file table keyed by [`file_namespace`].
+pub fn namespace_table(table: Table, path: &str) -> Table {
+    let mut namespaced = Table::new();
+    namespaced.insert(&file_namespace(path), Item::Table(table));
+    namespaced
+}
+
+/// Reads a single file as part of an include chain.
+///
+/// **Arguments**
+/// * `path` — The file path.
+/// * `overwrite` — Whether the last found definition of a key wins.
+/// * `chain` — The files being read, as their canonical path and the path they
+///   were found with, to detect circular includes.
+/// * `included` — The included file paths read so far.
+///
+/// **Returns**
+/// The resolved table or the first error found.
+fn read_table(
+    path: &str,
+    overwrite: bool,
+    chain: &mut Vec<(String, String)>,
+    included: &mut Vec<String>,
+) -> Result<Table, TranslationFileError> {
+    let canonical = canonicalize(path)
+        .map(|canonical| {
+            canonical
+                .to_string_lossy()
+                .to_string()
+        })
+        .unwrap_or_else(|_| path.to_string());
+
+    if let Some(position) = chain
+        .iter()
+        .position(|(seen, _)| *seen == canonical)
+    {
+        let mut cycle = chain[position..]
+            .iter()
+            .map(|(_, path)| path.clone())
+            .collect::<Vec<_>>();
+
+        cycle.push(path.to_string());
+
+        return Err(TranslationFileError::CircularInclude(cycle));
+    }
+
+    let mut table = read_to_string(path)
+        .map_err(|err| TranslationFileError::Io(err, path.to_string()))?
+        .parse::<DocumentMut>()
+        .map_err(|err| TranslationFileError::ParseToml(err, path.to_string()))?
+        .into_table();
+
+    chain.push((canonical, path.to_string()));
+    resolve_includes(&mut table, path, overwrite, chain, included)?;
+    chain.pop();
+
+    Ok(table)
+}
+
+/// Resolves the include directives of a table and its nested tables.
+///
+/// **Arguments**
+/// * `table` — The table to resolve in place.
+/// * `path` — The path of the file the table belongs to.
+/// * `overwrite` — Whether the last found definition of a key wins.
+/// * `chain` — The files being read.
+/// * `included` — The included file paths read so far.
+///
+/// **Returns**
+/// An empty result or the first error found.
+fn resolve_includes(
+    table: &mut Table,
+    path: &str,
+    overwrite: bool,
+    chain: &mut Vec<(String, String)>,
+    included: &mut Vec<String>,
+) -> Result<(), TranslationFileError> {
+    for (_, item) in table.iter_mut() {
+        if let Item::Table(nested) = item {
+            resolve_includes(nested, path, overwrite, chain, included)?;
+        }
+    }
+
+    let includes = match table.get(INCLUDE_KEY) {
+        Some(Item::Value(Value::String(include))) => vec![
+            include
+                .value()
+                .clone(),
+        ],
+        Some(Item::Value(Value::Array(includes))) => includes
+            .iter()
+            .map(|include| {
+                include
+                    .as_str()
+                    .map(String::from)
+                    .ok_or_else(|| TranslationFileError::InvalidInclude(path.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        _ => return Ok(()),
+    };
+
+    table.remove(INCLUDE_KEY);
+
+    let directory = Path::new(path)
+        .parent()
+        .unwrap_or(Path::new(""));
+
+    let mut merged = Table::new();
+
+    for include in includes {
+        let include_path = directory
+            .join(include)
+            .to_string_lossy()
+            .to_string();
+
+        let include_table = read_table(&include_path, overwrite, chain, included)?;
+        included.push(include_path);
+
+        merge_tables(&mut merged, include_table, overwrite);
+    }
+
+    merge_tables(&mut merged, table.clone(), overwrite);
+    *table = merged;
+
+    Ok(())
+}
+
+/// Finds include directives in a table.
+///
+/// **Arguments**
+/// * `table` — The table to search, along its nested tables.
+///
+/// **Returns**
+/// Whether the table or any of its nested tables contains
+/// an include directive.
+fn has_include(table: &Table) -> bool {
+    matches!(table.get(INCLUDE_KEY), Some(Item::Value(Value::String(_) | Value::Array(_))))
+        || table
+            .iter()
+            .any(|(_, item)| matches!(item, Item::Table(nested) if has_include(nested)))
+}
+
+/// Merges a table into a previously found one.
+///
+/// Keys found in both tables are merged recursively if both
+/// are tables, otherwise the later one replaces the earlier one
+/// only if `overwrite` is set.
+///
+/// **Arguments**
+/// * `earlier` — The table found first, merged in place.
+/// * `later` — The table found after.
+/// * `overwrite` — Whether the later definition of a key wins.
+fn merge_tables(earlier: &mut Table, later: Table, overwrite: bool) {
+    for (key, item) in later {
+        match (earlier.get_mut(&key), item) {
+            (Some(Item::Table(earlier)), Item::Table(later)) => {
+                merge_tables(earlier, later, overwrite)
+            },
+            (Some(_), _) if !overwrite => {},
+            (_, item) => {
+                earlier.insert(&key, item);
+            },
+        }
+    }
+}
@@ -7,4 +7,7 @@
 //! rust-friendly way.
 
 pub mod collection;
+pub mod file;
 pub mod node;
+pub mod resolution;
+pub mod source;
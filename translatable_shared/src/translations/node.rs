@@ -6,6 +6,7 @@
 //! lead to translation objects or other paths.
 
 use std::collections::HashMap;
+use std::ops::{Deref, DerefMut};
 
 use proc_macro2::TokenStream as TokenStream2;
 use quote::{ToTokens, TokenStreamExt, quote};
@@ -15,7 +16,7 @@ use toml_edit::{Item, Table, Value};
 
 use crate::macros::collections::{map_to_tokens, map_transform_to_tokens};
 use crate::misc::language::Language;
-use crate::misc::templating::{FormatString, TemplateError};
+use crate::misc::templating::{FormatString, PlaceholderDelimiters, TemplateError};
 
 /// [`TranslationNode`] errors.
 ///
@@ -41,13 +42,18 @@ pub enum TranslationNodeError {
 
     /// Invalid value found inside a nesting.
     ///
-    /// This error signals that an invalid value was found
-    /// inside a nesting, such as mixed values.
+    /// This error signals that a table contains both
+    /// values and nested tables, so it's neither a
+    /// translation object nor a nesting.
+    ///
+    /// **Parameters**
+    /// * `0` - The first key holding a value.
+    /// * `1` - The first key holding a nested table.
     #[error(
-        "Mixed values are not allowed, a nesting can't contain strings and objects at the same \
-         time"
+        "Mixed values are not allowed, '{0}' is a translation but '{1}' is a nested table, a \
+         table must either contain languages or nested tables"
     )]
-    MixedValues,
+    MixedValues(String, String),
 
     /// Invalid ISO-639-1 translation key.
     ///
@@ -55,8 +61,29 @@ pub enum TranslationNodeError {
     /// translation inside a translation object.
     ///
     /// Translation keys must follow the ISO-639-1 standard.
-    #[error("Couldn't parse ISO 639-1 string for translation key")]
-    LanguageParsing(#[from] ParseError),
+    ///
+    /// **Parameters**
+    /// * `0` - The invalid key.
+    /// * `1` - The language parsing error.
+    #[error(
+        "The key '{0}' is not a language, a translation object can only contain languages and '@' \
+         metadata keys, nested translations must be tables"
+    )]
+    LanguageParsing(String, #[source] ParseError),
+
+    /// Unsupported translation value.
+    ///
+    /// This error signals that a value that can't be
+    /// converted to a translation was found where a
+    /// translation was expected, such as an inline table.
+    ///
+    /// **Parameters**
+    /// * `0` - The key of the unsupported value.
+    /// * `1` - The kind of value that was found.
+    #[error(
+        "The value for '{0}' is {1}, translations must be strings, numbers, booleans or datetimes"
+    )]
+    UnsupportedValue(String, &'static str),
 
     /// Empty translation file.
     ///
@@ -64,6 +91,89 @@ pub enum TranslationNodeError {
     /// is empty and cannot be parsed.
     #[error("A translation file cannot be empty")]
     EmptyTable,
+
+    /// Unknown metadata key.
+    ///
+    /// This error signals that a key prefixed with `@`
+    /// inside a translation object is not a known
+    /// metadata key, such as `@desc`.
+    ///
+    /// **Parameters**
+    /// * `0` - The unknown metadata key.
+    #[error("Unknown metadata key '{0}', the metadata keys allowed are '@desc' and '@raw'")]
+    UnknownMetadata(String),
+
+    /// Invalid raw metadata key.
+    ///
+    /// This error signals that the `@raw` metadata
+    /// key was declared with a value other than a boolean.
+    #[error("The '@raw' metadata key must be a boolean")]
+    InvalidRaw,
+
+    /// Case-insensitive key collision.
+    ///
+    /// This error signals that two keys in the same
+    /// nesting only differ in case, so they can't be
+    /// told apart with `case_insensitive_paths`.
+    ///
+    /// **Parameters**
+    /// * `0` - The first colliding key.
+    /// * `1` - The second colliding key.
+    #[error("The keys '{0}' and '{1}' collide when paths are case-insensitive")]
+    CaseCollision(String, String),
+
+    /// Mixed lists and translations.
+    ///
+    /// This error signals that a translation object contains
+    /// both arrays and single translations, every language
+    /// must either be a list or a single translation.
+    #[error(
+        "Mixed lists are not allowed, a translation object can't contain arrays and single \
+         translations at the same time"
+    )]
+    MixedLists,
+
+    /// Referenced translation not found.
+    ///
+    /// This error signals that a translation references
+    /// a path that doesn't lead to a translation object.
+    ///
+    /// **Parameters**
+    /// * `0` - The path of the translation declaring the reference.
+    /// * `1` - The referenced path.
+    #[error("The translation '{0}' references '{1}', which could not be found")]
+    ReferenceNotFound(String, String),
+
+    /// Referenced translation not available.
+    ///
+    /// This error signals that a translation references
+    /// a translation that is not available in its language
+    /// nor any of its parents.
+    ///
+    /// **Parameters**
+    /// * `0` - The path of the translation declaring the reference.
+    /// * `1` - The referenced path.
+    /// * `2` - The language of the translation declaring the reference.
+    #[error("The translation '{0}' references '{1}', which is not available in '{2}'")]
+    ReferenceNotAvailable(String, String, String),
+
+    /// Circular translation references.
+    ///
+    /// This error signals that a translation references
+    /// itself, directly or trough other translations.
+    ///
+    /// **Parameters**
+    /// * `0` - The paths in the cycle, joined by arrows.
+    #[error("Circular translation references found: {0}")]
+    ReferenceCycle(String),
+
+    /// Too deeply nested translation references.
+    ///
+    /// **Parameters**
+    /// * `0` - The path of the translation declaring the first reference.
+    /// * `1` - The maximum reference depth.
+    #[error("The translation '{0}' nests more than {1} references")]
+    ReferenceDepth(String, usize),
 }
 
 /// Nesting type alias.
@@ -73,19 +183,328 @@ pub enum TranslationNodeError {
 /// or another nesting.
 pub type TranslationNesting = HashMap<String, TranslationNode>;
 
-/// Object type alias.
+/// Translation object.
 ///
 /// This is one of the valid objects that might be found
-/// on a translation file, this object contains only translations
-/// keyed with their respective languages.
-pub type TranslationObject = HashMap<Language, FormatString>;
+/// on a translation file, this object contains translations
+/// keyed with their respective languages along the metadata
+/// declared with keys prefixed by `@`.
+///
+/// Dereferences to the translations map, metadata is
+/// meant for tooling and it's not embedded in the binary.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TranslationObject {
+    /// The translations keyed by language.
+    translations: HashMap<Language, FormatString>,
+
+    /// The `@desc` metadata key.
+    ///
+    /// Context for translators, such as
+    /// where or how the translation is used.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    description: Option<String>,
+}
+
+impl TranslationObject {
+    /// Creates an empty translation object.
+    ///
+    /// **Returns**
+    /// A translation object without translations nor metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Description getter.
+    ///
+    /// **Returns**
+    /// The `@desc` metadata key, if declared.
+    pub fn description(&self) -> Option<&str> {
+        self.description
+            .as_deref()
+    }
+
+    /// Description setter.
+    ///
+    /// **Arguments**
+    /// * `description` - The context for translators, or `None` to remove it.
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+
+    /// Placeholder mismatches between languages.
+    ///
+    /// Every placeholder required in any language must be
+    /// used in all the others. Placeholders declaring a default
+    /// wherever they are used are optional, so they're exempted.
+    ///
+    /// **Returns**
+    /// Each language missing any required placeholder along
+    /// the missing placeholders, sorted by language.
+    pub fn placeholder_mismatches(&self) -> Vec<(Language, Vec<String>)> {
+        let mut required = Vec::<String>::new();
+
+        for placeholder in self
+            .translations
+            .values()
+            .flat_map(FormatString::required_placeholders)
+        {
+            if !required.contains(&placeholder) {
+                required.push(placeholder);
+            }
+        }
+
+        required.sort();
+
+        self.translations
+            .available_languages()
+            .into_iter()
+            .filter_map(|language| {
+                let translation = self
+                    .translations
+                    .get(&language)?;
+
+                let missing = required
+                    .iter()
+                    .filter(|placeholder| !translation.uses_template(placeholder))
+                    .cloned()
+                    .collect::<Vec<_>>();
+
+                (!missing.is_empty()).then_some((language, missing))
+            })
+            .collect()
+    }
+}
+
+impl Deref for TranslationObject {
+    type Target = HashMap<Language, FormatString>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.translations
+    }
+}
+
+impl DerefMut for TranslationObject {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.translations
+    }
+}
+
+impl From<HashMap<Language, FormatString>> for TranslationObject {
+    fn from(translations: HashMap<Language, FormatString>) -> Self {
+        Self { translations, description: None }
+    }
+}
+
+impl FromIterator<(Language, FormatString)> for TranslationObject {
+    fn from_iter<T: IntoIterator<Item = (Language, FormatString)>>(iter: T) -> Self {
+        Self::from(
+            iter.into_iter()
+                .collect::<HashMap<_, _>>(),
+        )
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// Only the translations are converted, the metadata
+/// is left out of the binary.
+impl ToTokens for TranslationObject {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let map = map_to_tokens(&self.translations);
+
+        tokens.append_all(quote! {
+            translatable::shared::translations::node::TranslationObject::from(#map)
+        });
+    }
+}
+
+/// [`TranslationObject`] extension methods.
+///
+/// The translation lookup logic shared between compile-time
+/// and runtime is declared in this trait, it's implemented
+/// for the translations map so it's also available trough
+/// [`TranslationObject`] dereferencing.
+pub trait TranslationObjectExt {
+    /// Get a translation falling back to the language parents.
+    ///
+    /// If the requested language is not available, each
+    /// [`Language::fallback_parent`] is tried in order
+    /// until a translation is found.
+    ///
+    /// **Arguments**
+    /// * `language` - The requested language.
+    ///
+    /// **Returns**
+    /// The translation in the requested language or its
+    /// closest available parent.
+    fn get_or_parent(&self, language: &Language) -> Option<&FormatString> {
+        self.resolve(language)
+            .map(|(_, translation)| translation)
+    }
+
+    /// Resolve a translation falling back to the language parents.
+    ///
+    /// Same as [`get_or_parent`] but also returns the language
+    /// that actually served the translation.
+    ///
+    /// **Arguments**
+    /// * `language` - The requested language.
+    ///
+    /// **Returns**
+    /// The language that was found along its translation.
+    ///
+    /// [`get_or_parent`]: TranslationObjectExt::get_or_parent
+    fn resolve(&self, language: &Language) -> Option<(Language, &FormatString)>;
+
+    /// Get the languages this translation is available in.
+    ///
+    /// The languages are sorted by their ISO 639-1 code
+    /// so the result doesn't depend on the map ordering.
+    ///
+    /// **Returns**
+    /// The available languages for this translation.
+    fn available_languages(&self) -> Vec<Language>;
+}
+
+/// Resolve a language falling back to its parents.
+///
+/// Each [`Language::fallback_parent`] is tried in order
+/// until a value is found, this is shared between
+/// translation objects and lists.
+///
+/// **Arguments**
+/// * `values` - The values keyed by language.
+/// * `language` - The requested language.
+///
+/// **Returns**
+/// The language that was found along its value.
+pub fn resolve_language<'a, T>(
+    values: &'a HashMap<Language, T>,
+    language: &Language,
+) -> Option<(Language, &'a T)> {
+    let mut language = Some(language.clone());
+
+    while let Some(current) = language {
+        if let Some(value) = values.get(&current) {
+            return Some((current, value));
+        }
+
+        language = current.fallback_parent();
+    }
+
+    None
+}
+
+impl TranslationObjectExt for HashMap<Language, FormatString> {
+    fn resolve(&self, language: &Language) -> Option<(Language, &FormatString)> {
+        resolve_language(self, language)
+    }
+
+    fn available_languages(&self) -> Vec<Language> {
+        let mut languages = self
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        languages.sort_by_key(Language::code);
+        languages
+    }
+}
+
+/// Translation list.
+///
+/// This is one of the valid objects that might be found
+/// on a translation file, declared as a translation object
+/// whose values are arrays, it contains ordered lists of
+/// translations keyed with their respective languages.
+///
+/// Dereferences to the lists map, metadata is
+/// meant for tooling and it's not embedded in the binary.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TranslationList {
+    /// The lists keyed by language.
+    lists: HashMap<Language, Vec<FormatString>>,
+
+    /// The `@desc` metadata key.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Option::is_none"))]
+    description: Option<String>,
+}
+
+impl TranslationList {
+    /// Creates an empty translation list.
+    ///
+    /// **Returns**
+    /// A translation list without lists nor metadata.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Description getter.
+    ///
+    /// **Returns**
+    /// The `@desc` metadata key, if declared.
+    pub fn description(&self) -> Option<&str> {
+        self.description
+            .as_deref()
+    }
+
+    /// Description setter.
+    ///
+    /// **Arguments**
+    /// * `description` - The context for translators, or `None` to remove it.
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+    }
+}
+
+impl Deref for TranslationList {
+    type Target = HashMap<Language, Vec<FormatString>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.lists
+    }
+}
+
+impl DerefMut for TranslationList {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.lists
+    }
+}
+
+impl From<HashMap<Language, Vec<FormatString>>> for TranslationList {
+    fn from(lists: HashMap<Language, Vec<FormatString>>) -> Self {
+        Self { lists, description: None }
+    }
+}
+
+/// Compile-time to runtime conversion implementation.
+///
+/// Only the lists are converted, the metadata
+/// is left out of the binary.
+impl ToTokens for TranslationList {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let map = map_transform_to_tokens(
+            &self.lists,
+            |language, list| quote! { (#language, vec![#(#list),*]) },
+        );
+
+        tokens.append_all(quote! {
+            translatable::shared::translations::node::TranslationList::from(#map)
+        });
+    }
+}
 
 /// Translation node structure.
 ///
 /// This enum acts like an n-ary tree which
-/// may contain [`TranslationNesting`] or
-/// [`TranslationObject`] representing a tree
-/// that follows the translation file rules.
+/// may contain [`TranslationNesting`],
+/// [`TranslationObject`] or [`TranslationList`]
+/// representing a tree that follows the
+/// translation file rules.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TranslationNode {
     /// Branch containing a [`TranslationNesting`].
     ///
@@ -98,6 +517,12 @@ pub enum TranslationNode {
     /// Read the [`TranslationObject`] documentation for
     /// more information.
     Translation(TranslationObject),
+
+    /// Branch containing a [`TranslationList`].
+    ///
+    /// Read the [`TranslationList`] documentation for
+    /// more information.
+    List(TranslationList),
 }
 
 impl TranslationNode {
@@ -108,6 +533,7 @@ impl TranslationNode {
     ///
     /// **Returns**
     /// A reference to translations if path exists and points to leaf node.
+    #[allow(clippy::ptr_arg)]
     pub fn find_path<I: ToString>(&self, path: &Vec<I>) -> Option<&TranslationObject> {
         let path = path
             .iter()
@@ -124,8 +550,371 @@ impl TranslationNode {
             Self::Translation(translation) => path
                 .is_empty()
                 .then_some(translation),
+            Self::List(_) => None,
         }
     }
+
+    /// Resolves a list path through the nesting hierarchy.
+    ///
+    /// Same as [`find_path`] but the path must lead
+    /// to a [`TranslationList`] instead.
+    ///
+    /// **Arguments**
+    /// * `path` - Slice of path segments to resolve.
+    ///
+    /// **Returns**
+    /// A reference to the list if path exists and points to a list.
+    ///
+    /// [`find_path`]: TranslationNode::find_path
+    pub fn find_list<I: ToString>(&self, path: &[I]) -> Option<&TranslationList> {
+        match self.find_node(path)? {
+            Self::List(list) => Some(list),
+            _ => None,
+        }
+    }
+
+    /// Resolves a node through the nesting hierarchy.
+    ///
+    /// Unlike [`find_path`] the path may lead to a nesting,
+    /// an empty path leads to this same node.
+    ///
+    /// **Arguments**
+    /// * `path` - Slice of path segments to resolve.
+    ///
+    /// **Returns**
+    /// A reference to the node the path leads to, if it exists.
+    ///
+    /// [`find_path`]: TranslationNode::find_path
+    pub fn find_node<I: ToString>(&self, path: &[I]) -> Option<&TranslationNode> {
+        let mut node = self;
+
+        for segment in path {
+            match node {
+                Self::Nesting(nested) => node = nested.get(&segment.to_string())?,
+                Self::Translation(_) | Self::List(_) => return None,
+            }
+        }
+
+        Some(node)
+    }
+
+    /// Resolves a mutable node through the nesting hierarchy.
+    ///
+    /// Same as [`find_node`] but the node may be modified.
+    ///
+    /// **Arguments**
+    /// * `path` - Slice of path segments to resolve.
+    ///
+    /// **Returns**
+    /// A mutable reference to the node the path leads to, if it exists.
+    ///
+    /// [`find_node`]: TranslationNode::find_node
+    pub fn find_node_mut<I: ToString>(&mut self, path: &[I]) -> Option<&mut TranslationNode> {
+        let mut node = self;
+
+        for segment in path {
+            match node {
+                Self::Nesting(nested) => node = nested.get_mut(&segment.to_string())?,
+                Self::Translation(_) | Self::List(_) => return None,
+            }
+        }
+
+        Some(node)
+    }
+
+    /// Counts how many path segments resolve in the tree.
+    ///
+    /// Walks the nesting hierarchy with the path segments
+    /// until one of them is not found, this is used to
+    /// find the closest match for a path that doesn't exist.
+    ///
+    /// **Arguments**
+    /// * `path` - Slice of path segments to resolve.
+    ///
+    /// **Returns**
+    /// The amount of leading path segments that were found.
+    #[allow(clippy::ptr_arg)]
+    pub fn matched_depth<I: ToString>(&self, path: &Vec<I>) -> usize {
+        let mut node = self;
+        let mut depth = 0;
+
+        for segment in path {
+            match node {
+                Self::Nesting(nested) => match nested.get(&segment.to_string()) {
+                    Some(next) => {
+                        node = next;
+                        depth += 1;
+                    },
+                    None => break,
+                },
+                Self::Translation(_) | Self::List(_) => break,
+            }
+        }
+
+        depth
+    }
+
+    /// Collects every translation object in the tree.
+    ///
+    /// Walks the whole tree collecting each [`TranslationObject`]
+    /// along the path segments that lead to it from this node,
+    /// lists are not collected.
+    ///
+    /// **Returns**
+    /// A list of path segments and translation object pairs.
+    pub fn leaves(&self) -> Vec<(Vec<String>, &TranslationObject)> {
+        match self {
+            Self::Nesting(nested) => nested
+                .iter()
+                .flat_map(|(key, node)| {
+                    node.leaves()
+                        .into_iter()
+                        .map(move |(path, translation)| {
+                            ([vec![key.clone()], path].concat(), translation)
+                        })
+                })
+                .collect(),
+
+            Self::Translation(translation) => vec![(Vec::new(), translation)],
+            Self::List(_) => Vec::new(),
+        }
+    }
+
+    /// Lowercases the nesting keys trough the hierarchy.
+    ///
+    /// This is the canonical form of the paths when they
+    /// are matched case-insensitively, the translation
+    /// objects and their languages are kept as they are.
+    ///
+    /// **Returns**
+    /// A `Result` containing either:
+    /// * `Ok(TranslationNode)` - The node with its nesting keys lowercased.
+    /// * `Err(TranslationNodeError)` - A [`CaseCollision`] if two keys of the
+    ///   same nesting only differ in case.
+    ///
+    /// [`CaseCollision`]: TranslationNodeError::CaseCollision
+    pub fn lowercase_paths(self) -> Result<Self, TranslationNodeError> {
+        let Self::Nesting(nested) = self else {
+            return Ok(self);
+        };
+
+        let mut nested = nested
+            .into_iter()
+            .collect::<Vec<_>>();
+        nested.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        let mut originals = HashMap::new();
+
+        nested
+            .into_iter()
+            .map(|(key, node)| {
+                let lowercase = key.to_lowercase();
+
+                if let Some(original) = originals.insert(lowercase.clone(), key.clone()) {
+                    return Err(TranslationNodeError::CaseCollision(original, key));
+                }
+
+                Ok((lowercase, node.lowercase_paths()?))
+            })
+            .collect::<Result<TranslationNesting, _>>()
+            .map(Self::Nesting)
+    }
+
+    /// Sets the directional isolation of every translation.
+    ///
+    /// Walks the whole tree setting whether the replaced values
+    /// are wrapped in Unicode directional isolates, which only
+    /// applies to the translations in right-to-left languages.
+    ///
+    /// **Arguments**
+    /// * `bidi_isolate` - Whether the replaced values are isolated.
+    pub fn set_bidi_isolate(&mut self, bidi_isolate: bool) {
+        match self {
+            Self::Nesting(nested) => nested
+                .values_mut()
+                .for_each(|node| node.set_bidi_isolate(bidi_isolate)),
+
+            Self::Translation(translation) => translation
+                .values_mut()
+                .for_each(|translation| translation.set_bidi_isolate(bidi_isolate)),
+
+            Self::List(list) => list
+                .values_mut()
+                .flatten()
+                .for_each(|translation| translation.set_bidi_isolate(bidi_isolate)),
+        }
+    }
+
+    /// Sets the HTML escaping of every translation.
+    ///
+    /// Walks the whole tree setting whether the replaced
+    /// values are HTML-escaped, except for raw templates.
+    ///
+    /// **Arguments**
+    /// * `escape_html` - Whether the replaced values are escaped.
+    pub fn set_escape_html(&mut self, escape_html: bool) {
+        match self {
+            Self::Nesting(nested) => nested
+                .values_mut()
+                .for_each(|node| node.set_escape_html(escape_html)),
+
+            Self::Translation(translation) => translation
+                .values_mut()
+                .for_each(|translation| translation.set_escape_html(escape_html)),
+
+            Self::List(list) => list
+                .values_mut()
+                .flatten()
+                .for_each(|translation| translation.set_escape_html(escape_html)),
+        }
+    }
+
+    /// Visits every translation string in the tree.
+    ///
+    /// Walks the whole tree calling `visit` for each [`FormatString`]
+    /// of the translation objects and lists along the path segments
+    /// that lead to it from this node, in alphabetical order so the
+    /// first error is always the same one.
+    ///
+    /// **Arguments**
+    /// * `visit` - Called with the path and each translation string.
+    ///
+    /// **Returns**
+    /// The first error returned by `visit`, if any.
+    pub fn try_for_each_format_string<E, F>(&mut self, visit: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&[String], &mut FormatString) -> Result<(), E>,
+    {
+        self.visit_format_strings(&mut Vec::new(), visit)
+    }
+
+    /// Recursive [`try_for_each_format_string`] implementation.
+    ///
+    /// **Arguments**
+    /// * `path` - The path segments that lead to this node.
+    /// * `visit` - Called with the path and each translation string.
+    ///
+    /// **Returns**
+    /// The first error returned by `visit`, if any.
+    ///
+    /// [`try_for_each_format_string`]: TranslationNode::try_for_each_format_string
+    fn visit_format_strings<E, F>(&mut self, path: &mut Vec<String>, visit: &mut F) -> Result<(), E>
+    where
+        F: FnMut(&[String], &mut FormatString) -> Result<(), E>,
+    {
+        match self {
+            Self::Nesting(nested) => {
+                let mut nested = nested
+                    .iter_mut()
+                    .collect::<Vec<_>>();
+                nested.sort_by_key(|(a, _)| *a);
+
+                for (key, node) in nested {
+                    path.push(key.clone());
+                    node.visit_format_strings(path, visit)?;
+                    path.pop();
+                }
+            },
+
+            Self::Translation(translation) => {
+                let mut translations = translation
+                    .iter_mut()
+                    .collect::<Vec<_>>();
+                translations.sort_by_key(|(language, _)| language.code());
+
+                for (_, translation) in translations {
+                    visit(path, translation)?;
+                }
+            },
+
+            Self::List(list) => {
+                let mut lists = list
+                    .iter_mut()
+                    .collect::<Vec<_>>();
+                lists.sort_by_key(|(language, _)| language.code());
+
+                for (_, list) in lists {
+                    for translation in list {
+                        visit(path, translation)?;
+                    }
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    /// Retains only the translations in the provided languages.
+    ///
+    /// Walks the whole tree removing every [`FormatString`] keyed
+    /// with a language not present in `languages`. The tree structure
+    /// is kept, so a path may lead to an empty translation object.
+    ///
+    /// **Arguments**
+    /// * `languages` - The languages to keep.
+    pub fn retain_languages(&mut self, languages: &[Language]) {
+        self.retain_languages_with(&|language| languages.contains(language));
+    }
+
+    /// Retains only the translations in the languages matching a predicate.
+    ///
+    /// Same as [`retain_languages`] for languages that can't
+    /// be listed beforehand, such as custom tags.
+    ///
+    /// **Arguments**
+    /// * `keep` - Whether to keep the translations in a language.
+    ///
+    /// [`retain_languages`]: TranslationNode::retain_languages
+    pub fn retain_languages_with<F: Fn(&Language) -> bool>(&mut self, keep: &F) {
+        match self {
+            Self::Nesting(nested) => nested
+                .values_mut()
+                .for_each(|node| node.retain_languages_with(keep)),
+
+            Self::Translation(translation) => translation.retain(|language, _| keep(language)),
+
+            Self::List(list) => list.retain(|language, _| keep(language)),
+        }
+    }
+
+    /// Collects the languages used trough the whole tree.
+    ///
+    /// **Arguments**
+    /// * `languages` - The list the languages are pushed to, may contain
+    ///   duplicates.
+    fn collect_languages(&self, languages: &mut Vec<Language>) {
+        match self {
+            Self::Nesting(nested) => nested
+                .values()
+                .for_each(|node| node.collect_languages(languages)),
+
+            Self::Translation(translation) => languages.extend(
+                translation
+                    .keys()
+                    .cloned(),
+            ),
+
+            Self::List(list) => languages.extend(
+                list.keys()
+                    .cloned(),
+            ),
+        }
+    }
+
+    /// Get every language used in this tree.
+    ///
+    /// **Returns**
+    /// The languages any translation or list in the
+    /// tree is available in, sorted and without duplicates.
+    pub fn languages(&self) -> Vec<Language> {
+        let mut languages = Vec::new();
+        self.collect_languages(&mut languages);
+
+        languages.sort();
+        languages.dedup();
+
+        languages
+    }
 }
 
 /// Compile-time to runtime conversion implementation.
@@ -152,53 +941,216 @@ impl ToTokens for TranslationNode {
             },
 
             TranslationNode::Translation(translation) => {
-                let map = map_to_tokens(translation);
-
                 tokens.append_all(quote! {
                     translatable::shared::translations::node::TranslationNode::Translation(
-                        #map
+                        #translation
                     )
                 });
             },
+
+            TranslationNode::List(list) => {
+                tokens.append_all(quote! {
+                    translatable::shared::translations::node::TranslationNode::List(#list)
+                });
+            },
         }
     }
 }
 
-/// TOML table parsing.
+/// Converts a TOML leaf value to its translation string.
 ///
-/// This implementation parses a TOML table object
-/// reference usually taken from a `toml_edit::DocuemntMut`
-/// into a [`TranslationNode`] for validation and
-/// seeking the translations according to the rules.
-impl TryFrom<&Table> for TranslationNode {
-    type Error = TranslationNodeError;
+/// Strings are taken as they are, while numbers, booleans
+/// and datetimes are coerced to their representation in
+/// the file, so `1.10` is kept as `1.10`.
+///
+/// **Arguments**
+/// * `key` - The key of the value, used for error reporting.
+/// * `value` - The TOML value found as a leaf.
+///
+/// **Returns**
+/// The translation string or an [`UnsupportedValue`] error for
+/// arrays and inline tables, arrays are only allowed as the
+/// whole value of a list language.
+///
+/// [`UnsupportedValue`]: TranslationNodeError::UnsupportedValue
+fn leaf_value(key: &str, value: &Value) -> Result<String, TranslationNodeError> {
+    Ok(match value {
+        Value::String(value) => value
+            .value()
+            .clone(),
+        Value::Integer(value) => value
+            .display_repr()
+            .into_owned(),
+        Value::Float(value) => value
+            .display_repr()
+            .into_owned(),
+        Value::Boolean(value) => value
+            .display_repr()
+            .into_owned(),
+        Value::Datetime(value) => value
+            .display_repr()
+            .into_owned(),
+        Value::Array(_) => {
+            return Err(TranslationNodeError::UnsupportedValue(key.into(), "an array"));
+        },
+        Value::InlineTable(_) => {
+            return Err(TranslationNodeError::UnsupportedValue(key.into(), "an inline table"));
+        },
+    })
+}
 
-    // The top level can only contain objects is never enforced.
-    fn try_from(value: &Table) -> Result<Self, Self::Error> {
+impl TranslationNode {
+    /// Parse a TOML table with custom placeholder delimiters.
+    ///
+    /// Same as the [`TryFrom<&Table>`] implementation but
+    /// the translation strings are parsed with the provided
+    /// delimiters instead of braces.
+    ///
+    /// **Arguments**
+    /// * `table` - The TOML table to parse.
+    /// * `delimiters` - The placeholder delimiters.
+    ///
+    /// **Returns**
+    /// The parsed node, or a [`TranslationNodeError`] if the
+    /// table doesn't follow the translation rules.
+    pub fn from_table_with(
+        table: &Table,
+        delimiters: &PlaceholderDelimiters,
+    ) -> Result<Self, TranslationNodeError> {
         let mut result = None;
 
-        for (key, value) in value {
+        // a table holding any value is a translation object and one holding
+        // any table is a nesting, regardless of the order of their keys, so
+        // a nested table named as a language is never taken for a translation.
+        let first_key = |is_kind: fn(&Item) -> bool| {
+            table
+                .iter()
+                .find(|(_, value)| is_kind(value))
+                .map(|(key, _)| key.to_string())
+        };
+
+        if let (Some(value_key), Some(table_key)) =
+            (first_key(Item::is_value), first_key(Item::is_table))
+        {
+            return Err(TranslationNodeError::MixedValues(value_key, table_key));
+        }
+
+        // read before the translations, as it changes how they're parsed.
+        let raw = match table.get("@raw") {
+            None => false,
+            Some(Item::Value(Value::Boolean(raw))) => *raw.value(),
+            Some(_) => return Err(TranslationNodeError::InvalidRaw),
+        };
+
+        let parse = |value: &str| {
+            if raw {
+                Ok(FormatString::literal(value))
+            } else {
+                FormatString::parse_with(value, delimiters)
+            }
+        };
+
+        for (key, value) in table {
             match value {
-                Item::Value(Value::String(translation_value)) => {
-                    match result.get_or_insert_with(|| Self::Translation(HashMap::new())) {
-                        Self::Translation(translation) => {
+                Item::Value(translation_value) => {
+                    if let Some(metadata) = key.strip_prefix('@') {
+                        let description = match metadata {
+                            "desc" => Some(leaf_value(key, translation_value)?),
+                            "raw" => None,
+                            _ => return Err(TranslationNodeError::UnknownMetadata(key.into())),
+                        };
+
+                        match (
+                            result
+                                .get_or_insert_with(|| Self::Translation(TranslationObject::new())),
+                            description,
+                        ) {
+                            (Self::Translation(translation), Some(description)) => {
+                                translation.set_description(Some(description))
+                            },
+                            (Self::List(list), Some(description)) => {
+                                list.set_description(Some(description))
+                            },
+                            (Self::Nesting(_), _) => {
+                                unreachable!("Mixed tables are rejected before parsing.")
+                            },
+                            _ => {},
+                        }
+
+                        continue;
+                    }
+
+                    let node = result.get_or_insert_with(|| match translation_value {
+                        Value::Array(_) => Self::List(TranslationList::new()),
+                        _ => Self::Translation(TranslationObject::new()),
+                    });
+
+                    // the metadata may be declared before the first list.
+                    if let (Value::Array(_), Self::Translation(translation)) =
+                        (translation_value, &*node)
+                        && translation.is_empty()
+                    {
+                        let mut list = TranslationList::new();
+                        list.set_description(
+                            translation
+                                .description()
+                                .map(str::to_string),
+                        );
+
+                        *node = Self::List(list);
+                    }
+
+                    match (node, translation_value) {
+                        (Self::List(list), Value::Array(values)) => {
+                            let language = Language::from_tag(key).map_err(|error| {
+                                TranslationNodeError::LanguageParsing(key.into(), error)
+                            })?;
+                            let values = values
+                                .iter()
+                                .enumerate()
+                                .map(|(index, value)| {
+                                    let value = leaf_value(&format!("{key}[{index}]"), value)?;
+
+                                    Ok(parse(&value)?.with_language(language.clone()))
+                                })
+                                .collect::<Result<Vec<_>, TranslationNodeError>>()?;
+
+                            list.insert(language, values);
+                        },
+
+                        (Self::Translation(_), Value::Array(_)) | (Self::List(_), _) => {
+                            return Err(TranslationNodeError::MixedLists);
+                        },
+
+                        (Self::Translation(translation), translation_value) => {
+                            let translation_value = leaf_value(key, translation_value)?;
+                            let language = Language::from_tag(key).map_err(|error| {
+                                TranslationNodeError::LanguageParsing(key.into(), error)
+                            })?;
+
                             translation.insert(
-                                key.parse()?,
-                                translation_value
-                                    .value()
-                                    .parse()?,
+                                language.clone(),
+                                parse(&translation_value)?.with_language(language),
                             );
                         },
-                        Self::Nesting(_) => return Err(TranslationNodeError::MixedValues),
+
+                        (Self::Nesting(_), _) => {
+                            unreachable!("Mixed tables are rejected before parsing.")
+                        },
                     }
                 },
 
                 Item::Table(nesting_value) => {
                     match result.get_or_insert_with(|| Self::Nesting(HashMap::new())) {
                         Self::Nesting(nesting) => {
-                            nesting.insert(key.to_string(), Self::try_from(nesting_value)?);
+                            nesting.insert(
+                                key.to_string(),
+                                Self::from_table_with(nesting_value, delimiters)?,
+                            );
+                        },
+                        Self::Translation(_) | Self::List(_) => {
+                            unreachable!("Mixed tables are rejected before parsing.")
                         },
-                        Self::Translation(_) => return Err(TranslationNodeError::MixedValues),
                     }
                 },
                 _ => return Err(TranslationNodeError::InvalidNesting),
@@ -208,3 +1160,20 @@ impl TryFrom<&Table> for TranslationNode {
         result.ok_or(TranslationNodeError::EmptyTable)
     }
 }
+
+/// TOML table parsing.
+///
+/// This implementation parses a TOML table object
+/// reference usually taken from a `toml_edit::DocuemntMut`
+/// into a [`TranslationNode`] for validation and
+/// seeking the translations according to the rules.
+///
+/// Templates are delimited by braces, use
+/// [`TranslationNode::from_table_with`] for other delimiters.
+impl TryFrom<&Table> for TranslationNode {
+    type Error = TranslationNodeError;
+
+    fn try_from(value: &Table) -> Result<Self, Self::Error> {
+        Self::from_table_with(value, &PlaceholderDelimiters::default())
+    }
+}
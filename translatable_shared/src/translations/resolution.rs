@@ -0,0 +1,321 @@
+//! Translation resolution module.
+//!
+//! This module declares the resolution steps shared
+//! between the macro output, the compile-time resolution
+//! and any runtime lookup, so a translation is found,
+//! falls back and gets replaced the same way everywhere.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use super::collection::TranslationNodeCollection;
+use super::node::{TranslationObject, resolve_language};
+use crate::misc::language::Language;
+
+/// Translation resolution error.
+///
+/// The runtime error implements [`From<ResolutionError>`],
+/// so these errors are usually propagated with `?` and
+/// never seen directly.
+#[derive(Error, Debug)]
+pub enum ResolutionError {
+    /// The path could not be found.
+    ///
+    /// **Parameters**
+    /// * `0` - The segments of the path that could not be found.
+    /// * `1` - The translation file that defines most of the path, if any.
+    #[error("The path '{path}' could not be found", path = _0.join("::"))]
+    PathNotFound(Vec<String>, Option<String>),
+
+    /// The path leads to a group of translations
+    /// instead of a translation.
+    ///
+    /// **Parameters**
+    /// * `0` - The segments of the path.
+    /// * `1` - The segments nested beneath the path.
+    #[error(
+        "The path '{path}' leads to a group of translations, available segments: {segments}",
+        path = _0.join("::"),
+        segments = _1.join(", ")
+    )]
+    SubtreePath(Vec<String>, Vec<String>),
+
+    /// The language is not available for the path.
+    ///
+    /// **Parameters**
+    /// * `0` - The language that is not available.
+    /// * `1` - The segments of the path for which the language is not
+    ///   available.
+    /// * `2` - The translation file where the path is defined, if known.
+    #[error(
+        "The language '{0:?}' ('{0:#}') is not available for the path '{path}'",
+        path = _1.join("::")
+    )]
+    LanguageNotAvailable(Language, Vec<String>, Option<String>),
+}
+
+/// Language fallback strategy.
+///
+/// Decides which languages are tried when the
+/// requested language is not available.
+#[derive(Clone, Copy)]
+pub enum Fallback<'a> {
+    /// Only the language parents are tried, such
+    /// as `no` for `nb`.
+    Parents,
+
+    /// Only the requested language is tried.
+    Disabled,
+
+    /// The language parents are tried, then the
+    /// provided language and its parents.
+    Language(&'a Language),
+
+    /// The languages of the first rule whose pattern
+    /// matches the requested language are tried in order
+    /// along their parents, in place of the requested
+    /// language parents. Languages without a matching
+    /// rule fall back to their parents.
+    ///
+    /// **Parameters**
+    /// * `0` - The rules as language tag patterns, where `*` matches any
+    ///   sequence such as in `pt-*`, along their fallback languages.
+    Rules(&'a [(&'a str, &'a [Language])]),
+}
+
+/// Matches a language tag against a pattern.
+///
+/// The `*` wildcard matches any sequence, and the tag
+/// is matched regardless of its case, so `pt-*` matches
+/// `pt-BR` but not `pt`.
+///
+/// **Arguments**
+/// * `pattern` - The language tag pattern.
+/// * `tag` - The language tag to match.
+///
+/// **Returns**
+/// Whether the tag matches the pattern.
+pub fn matches_language_pattern(pattern: &str, tag: &str) -> bool {
+    let pattern = pattern.to_lowercase();
+    let tag = tag.to_lowercase();
+
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = tag.strip_prefix(
+        parts
+            .next()
+            .unwrap_or_default(),
+    ) else {
+        return false;
+    };
+
+    let parts = parts.collect::<Vec<_>>();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+/// Collects the path segments as strings.
+///
+/// **Arguments**
+/// * `path` - The path segments.
+///
+/// **Returns**
+/// The owned path segments.
+fn path_segments<I: ToString>(path: &[I]) -> Vec<String> {
+    path.iter()
+        .map(|segment| segment.to_string())
+        .collect()
+}
+
+/// Splits the path segments by a separator.
+///
+/// Every segment is split by the separator and each
+/// part is trimmed, so a path provided as a single
+/// string such as `"greetings . formal"` is looked up
+/// as `greetings::formal` with a `.` separator.
+///
+/// **Arguments**
+/// * `path` - The path segments.
+/// * `separator` - The configured path separator, must be non-empty.
+///
+/// **Returns**
+/// The owned and split path segments.
+pub fn split_path<I: ToString>(path: &[I], separator: &str) -> Vec<String> {
+    path.iter()
+        .flat_map(|segment| {
+            segment
+                .to_string()
+                .split(separator)
+                .map(|part| {
+                    part.trim()
+                        .to_string()
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Missing translation marker.
+///
+/// The text rendered in place of a translation that is
+/// not available when missing translations are configured
+/// to be marked, such as `⟪greetings::formal@es⟫`.
+///
+/// **Arguments**
+/// * `path` - The sections of the translation path.
+/// * `language` - The requested language.
+///
+/// **Returns**
+/// The `::` joined path and the ISO 639-1 code of the
+/// language wrapped in angle brackets.
+pub fn missing_marker<I: ToString>(path: &[I], language: &Language) -> String {
+    format!(
+        "\u{27EA}{}@{}\u{27EB}",
+        path_segments(path).join("::"),
+        language.code()
+    )
+}
+
+/// Look up a path in a collection.
+///
+/// **Arguments**
+/// * `collection` - The translations to look the path up in.
+/// * `path` - The sections of the translation path.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok((&str, &TranslationObject))` - The file the path is defined in along
+///   its translation object.
+/// * `Err(ResolutionError)` - A [`SubtreePath`] error if the path leads to a
+///   group of translations, otherwise a [`PathNotFound`] error along the
+///   closest match.
+///
+/// [`SubtreePath`]: ResolutionError::SubtreePath
+/// [`PathNotFound`]: ResolutionError::PathNotFound
+pub fn lookup<'a, I: ToString>(
+    collection: &'a TranslationNodeCollection,
+    path: &[I],
+) -> Result<(&'a str, &'a TranslationObject), ResolutionError> {
+    let path = path_segments(path);
+
+    collection
+        .find_path_with_source(&path)
+        .ok_or_else(|| {
+            if let Some(segments) = collection.subtree_segments(&path) {
+                return ResolutionError::SubtreePath(path, segments);
+            }
+
+            let closest_match = collection
+                .closest_match(&path)
+                .map(|source| source.to_string());
+
+            ResolutionError::PathNotFound(path, closest_match)
+        })
+}
+
+/// Resolve the language of a translation object.
+///
+/// Translation lists are resolved the same way, as
+/// their lists map is passed instead.
+///
+/// **Arguments**
+/// * `translation_object` - The translation object to resolve, or the
+///   translations map it dereferences to.
+/// * `source` - The translation file the object is defined in, if known.
+/// * `language` - The requested language.
+/// * `path` - The sections of the translation path, for errors.
+/// * `fallback` - The language fallback strategy.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok((Language, &T))` - The language that served the translation along the
+///   translation itself.
+/// * `Err(ResolutionError)` - A [`LanguageNotAvailable`] error if no language
+///   could serve the translation.
+///
+/// [`LanguageNotAvailable`]: ResolutionError::LanguageNotAvailable
+pub fn resolve_object<'a, T, I: ToString>(
+    translation_object: &'a HashMap<Language, T>,
+    source: Option<&str>,
+    language: &Language,
+    path: &[I],
+    fallback: Fallback,
+) -> Result<(Language, &'a T), ResolutionError> {
+    let resolved = match fallback {
+        Fallback::Parents => resolve_language(translation_object, language),
+
+        Fallback::Disabled => translation_object
+            .get(language)
+            .map(|translation| (language.clone(), translation)),
+
+        Fallback::Language(fallback) => resolve_language(translation_object, language)
+            .or_else(|| resolve_language(translation_object, fallback)),
+
+        Fallback::Rules(rules) => match rules
+            .iter()
+            .find(|(pattern, _)| matches_language_pattern(pattern, &language.code()))
+        {
+            Some((_, fallbacks)) => translation_object
+                .get(language)
+                .map(|translation| (language.clone(), translation))
+                .or_else(|| {
+                    fallbacks
+                        .iter()
+                        .find_map(|fallback| resolve_language(translation_object, fallback))
+                }),
+
+            None => resolve_language(translation_object, language),
+        },
+    };
+
+    resolved.ok_or_else(|| {
+        ResolutionError::LanguageNotAvailable(
+            language.clone(),
+            path_segments(path),
+            source.map(str::to_string),
+        )
+    })
+}
+
+/// Resolve a translation from a collection.
+///
+/// Looks the path up with [`lookup`], resolves its language
+/// with [`resolve_object`] and replaces its templates with
+/// the provided values, templates without a value are left
+/// untouched unless they declare a default.
+///
+/// **Arguments**
+/// * `collection` - The translations to resolve from.
+/// * `language` - The requested language.
+/// * `path` - The sections of the translation path.
+/// * `fallback` - The language fallback strategy.
+/// * `replacements` - The template values by key.
+///
+/// **Returns**
+/// A `Result` containing either:
+/// * `Ok(String)` - The translation with its templates replaced.
+/// * `Err(ResolutionError)` - If the path could not be found or the language is
+///   not available.
+pub fn resolve<I: ToString>(
+    collection: &TranslationNodeCollection,
+    language: &Language,
+    path: &[I],
+    fallback: Fallback,
+    replacements: &HashMap<String, String>,
+) -> Result<String, ResolutionError> {
+    let (source, translation_object) = lookup(collection, path)?;
+    let (_, translation) =
+        resolve_object(translation_object, Some(source), language, path, fallback)?;
+
+    Ok(translation.replace_with(replacements))
+}
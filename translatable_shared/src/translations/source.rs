@@ -0,0 +1,45 @@
+//! Translation source module.
+//!
+//! This module declares [`TranslationSource`], the
+//! abstraction runtime lookups are resolved trough,
+//! so translations may come from somewhere else than
+//! the translation files embedded in compile-time.
+
+use super::collection::TranslationNodeCollection;
+use super::node::TranslationObjectExt;
+use crate::misc::language::Language;
+use crate::misc::templating::FormatString;
+
+/// Runtime translation source.
+///
+/// Implement this trait to resolve translations from a
+/// user provided store, such as a database loaded at startup,
+/// and pass it to `translatable::translate_with`.
+///
+/// [`TranslationNodeCollection`] implements it, so the
+/// embedded or hot reloaded translations behave as usual.
+pub trait TranslationSource {
+    /// Resolve a translation.
+    ///
+    /// **Arguments**
+    /// * `lang` - The requested language.
+    /// * `path` - The sections of the translation path.
+    ///
+    /// **Returns**
+    /// The translation, or `None` if the path doesn't
+    /// exist or it's not available in the language.
+    fn resolve(&self, lang: Language, path: &[String]) -> Option<&FormatString>;
+}
+
+/// Embedded translations source.
+///
+/// Resolves the path with [`find_path`] falling back
+/// to the language parents, same as the macros do.
+///
+/// [`find_path`]: TranslationNodeCollection::find_path
+impl TranslationSource for TranslationNodeCollection {
+    fn resolve(&self, lang: Language, path: &[String]) -> Option<&FormatString> {
+        self.find_path(&path.to_vec())?
+            .get_or_parent(&lang)
+    }
+}